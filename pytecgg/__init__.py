@@ -1,3 +1,12 @@
 from .context import GNSSContext
+from .settings import Settings, configure, get_settings, reset_settings, settings_context
+from . import expressions  # noqa: F401  (registers the `gnss` and `tec` namespaces)
 
-__all__ = ["GNSSContext"]
+__all__ = [
+    "GNSSContext",
+    "Settings",
+    "configure",
+    "get_settings",
+    "reset_settings",
+    "settings_context",
+]
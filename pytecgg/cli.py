@@ -0,0 +1,34 @@
+"""
+Command-line interface of the package, installed as the `pytecgg` console script.
+
+The parsing of the command line and the dispatch of the subcommands are done by
+the Rust core, which calls the same readers and pipeline as the Python API:
+
+    $ pytecgg to-parquet ASIR00ITA_R_20242810000_01D_30S_MO.rnx asir.parquet
+    $ pytecgg qc ASIR00ITA_R_20242810000_01D_30S_MO.rnx
+    $ pytecgg tec ASIR00ITA_R_20242810000_01D_30S_MO.rnx BRDC00WRD_R_20242810000_01D_MN.rnx tec.parquet
+"""
+
+import sys
+from typing import Optional, Sequence
+
+from .pytecgg import run_cli as _run_cli
+
+
+def main(argv: Optional[Sequence[str]] = None) -> int:
+    """
+    Run the `pytecgg` command line.
+
+    Parameters
+    ----------
+    argv : sequence of str, optional
+        Command-line arguments, without the program name; by default, those of
+        the process.
+
+    Returns
+    -------
+    int
+        Exit status: 0 on success, 1 if processing failed (e.g., unreadable
+        file), 2 for an invalid command line.
+    """
+    return _run_cli(list(sys.argv[1:] if argv is None else argv))
@@ -2,6 +2,8 @@ from dataclasses import dataclass, field
 from typing import Any
 import warnings
 
+from .settings import get_settings
+
 SUPPORTED_SYSTEMS = {
     "GPS": "G",
     "GLONASS": "R",
@@ -33,7 +35,8 @@ class GNSSContext:
         The version of the source RINEX file (e.g., "2.11", "3.04").
     h_ipp : float
         The altitude of the thin-shell ionospheric model in meters.
-        Default is 350,000 m (350 km).
+        Default is that of the package settings, 350,000 m (350 km) unless
+        changed with `pytecgg.configure`.
     systems : list[str]
         List of active GNSS constellations, accepting full names or symbols:
         GPS (G), GLONASS (R), Galileo (E), BeiDou (C).
@@ -71,7 +74,7 @@ class GNSSContext:
     receiver_pos: tuple[float, float, float]
     receiver_name: str
     rinex_version: str
-    h_ipp: float = 350_000
+    h_ipp: float = field(default_factory=lambda: get_settings().h_ipp)
     systems: list[str] = field(default_factory=list)
     glonass_channels: dict[str, int] = field(default_factory=dict)
     freq_meta: dict[str, Any] = field(default_factory=dict)
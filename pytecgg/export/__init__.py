@@ -0,0 +1,14 @@
+from .dataset import write_dataset
+from .geojson import ipp_to_geojson
+from .hdf5 import write_hdf5
+from .madrigal import write_madrigal
+from .netcdf import to_xarray, write_netcdf
+
+__all__ = [
+    "ipp_to_geojson",
+    "to_xarray",
+    "write_dataset",
+    "write_hdf5",
+    "write_madrigal",
+    "write_netcdf",
+]
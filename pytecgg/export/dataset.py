@@ -0,0 +1,53 @@
+from pathlib import Path
+from typing import Literal, Optional, Sequence, Union
+
+import polars as pl
+
+from ..pytecgg import write_dataset as _write_dataset
+
+
+def write_dataset(
+    df: pl.DataFrame,
+    root: Union[str, Path],
+    partition_by: Sequence[str] = ("station", "year", "doy"),
+    station: Optional[str] = None,
+    existing: Literal["overwrite", "append", "error"] = "overwrite",
+    compression: Literal["zstd", "snappy", "lz4", "gzip", "uncompressed"] = "zstd",
+) -> list[Path]:
+    """
+    Write a DataFrame as a Hive-partitioned Parquet dataset.
+
+    Files are written by the Rust backend into nested 'key=value' directories
+    (e.g., `root/station=grot/year=2024/doy=1/part-0.parquet`), so that multi-year
+    archives can be queried lazily, with partition pruning, by Polars
+    (`pl.scan_parquet(root, hive_partitioning=True)`) or DuckDB
+    (`read_parquet('root/**/*.parquet', hive_partitioning = true)`).
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame to write, e.g. observations or TEC.
+    root : str or pathlib.Path
+        Root directory of the dataset.
+    partition_by : sequence of str, optional
+        Partition columns, nested in this order. 'year' and 'doy' (day of year)
+        are derived from the UTC 'epoch' column when missing. Partition columns
+        are stored in the directory names, not in the files.
+    station : str, optional
+        Station name added as a 'station' column, if the DataFrame does not have one.
+    existing : {'overwrite', 'append', 'error'}, optional
+        What to do with partitions that already contain Parquet files: replace
+        them (default, so that reprocessing a day replaces its data), add a new
+        file alongside, or raise a FileExistsError before anything is written.
+    compression : {'zstd', 'snappy', 'lz4', 'gzip', 'uncompressed'}, optional
+        Parquet compression codec, by default zstd.
+
+    Returns
+    -------
+    list[pathlib.Path]
+        Paths of the written files.
+    """
+    if station is not None and "station" not in df.columns:
+        df = df.with_columns(pl.lit(station).alias("station"))
+    written = _write_dataset(df, str(root), list(partition_by), existing, compression)
+    return [Path(p) for p in written]
@@ -0,0 +1,112 @@
+import json
+from pathlib import Path
+from typing import Literal, Optional, Union
+
+import polars as pl
+
+
+def _isoformat(epochs: pl.Series) -> list[Optional[str]]:
+    if isinstance(epochs.dtype, pl.Datetime) and epochs.dtype.time_zone is not None:
+        epochs = epochs.dt.convert_time_zone("UTC").dt.replace_time_zone(None)
+    return epochs.dt.strftime("%Y-%m-%dT%H:%M:%S%.6fZ").to_list()
+
+
+def _point_features(df: pl.DataFrame, value: str, lat: str, lon: str) -> list[dict]:
+    times = _isoformat(df["epoch"])
+    return [
+        {
+            "type": "Feature",
+            "geometry": {"type": "Point", "coordinates": [row[lon], row[lat]]},
+            "properties": {"sv": row["sv"], "time": t, value: row[value]},
+        }
+        for row, t in zip(df.iter_rows(named=True), times)
+    ]
+
+
+def _line_features(
+    df: pl.DataFrame, value: str, lat: str, lon: str, track: list[str]
+) -> list[dict]:
+    features = []
+    for keys, group in df.group_by(track, maintain_order=True):
+        # A LineString needs at least two positions
+        if group.height < 2:
+            continue
+        group = group.sort("epoch")
+        times = _isoformat(group["epoch"])
+        properties = dict(zip(track, keys))
+        properties.update(
+            {
+                "start": times[0],
+                "end": times[-1],
+                "times": times,
+                value: group[value].to_list(),
+            }
+        )
+        features.append(
+            {
+                "type": "Feature",
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": group.select(lon, lat).rows(),
+                },
+                "properties": properties,
+            }
+        )
+    return features
+
+
+def ipp_to_geojson(
+    df: pl.DataFrame,
+    value: str = "vtec",
+    geometry: Literal["line", "point"] = "line",
+    path: Optional[Union[str, Path]] = None,
+    lat: str = "lat_ipp",
+    lon: str = "lon_ipp",
+) -> dict:
+    """
+    Export IPP tracks and TEC values as a GeoJSON FeatureCollection.
+
+    The result can be loaded directly in GIS tools (e.g., QGIS) and web maps
+    (e.g., Leaflet, kepler.gl). Rows without IPP coordinates are skipped, and
+    missing values are exported as null.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with 'epoch', 'sv', IPP latitude and longitude (degrees) and
+        the `value` column, e.g. the output of `calculate_tec`.
+    value : str, optional
+        Column exported as feature property, by default 'vtec'.
+    geometry : {'line', 'point'}, optional
+        'line' exports one LineString per satellite track (per valid arc, if the
+        'id_arc_valid' column is present), with the epochs and values of its
+        vertices as list properties (tracks with a single IPP are skipped);
+        'point' exports one Point per observation.
+    path : str or pathlib.Path, optional
+        If given, the FeatureCollection is also written to this file.
+    lat, lon : str, optional
+        Names of the IPP latitude and longitude columns.
+
+    Returns
+    -------
+    dict
+        GeoJSON FeatureCollection, with coordinates as [longitude, latitude].
+    """
+    if geometry not in ("line", "point"):
+        raise ValueError(f"Invalid geometry '{geometry}': expected 'line' or 'point'")
+
+    # IPPs outside the shell are NaN, and NaN values are not valid JSON
+    df = df.filter(pl.col(lat).is_not_nan() & pl.col(lon).is_not_nan())
+    if df.schema[value].is_float():
+        df = df.with_columns(pl.col(value).fill_nan(None))
+    if geometry == "point":
+        features = _point_features(df, value, lat, lon)
+    else:
+        track = ["sv", "id_arc_valid"] if "id_arc_valid" in df.columns else ["sv"]
+        df = df.filter(pl.col(track[-1]).is_not_null())
+        features = _line_features(df, value, lat, lon, track)
+
+    collection = {"type": "FeatureCollection", "features": features}
+    if path is not None:
+        Path(path).write_text(json.dumps(collection))
+    return collection
@@ -0,0 +1,103 @@
+from dataclasses import asdict, is_dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, Optional, Union
+
+import numpy as np
+import polars as pl
+
+from pytecgg.context import GNSSContext
+
+
+def _write_attrs(group, metadata: dict[str, Any]) -> None:
+    for key, value in metadata.items():
+        if value is None or value == {} or value == []:
+            continue
+        if isinstance(value, dict):
+            # Nested mappings (e.g., GLONASS channels) become subgroups
+            _write_attrs(group.require_group(key), value)
+        elif isinstance(value, (list, tuple)) and value and isinstance(value[0], str):
+            group.attrs[key] = np.array(value, dtype=object)
+        else:
+            group.attrs[key] = value
+
+
+def _write_table(group, df: pl.DataFrame) -> None:
+    """Writes each column as a 1-D dataset of the group"""
+    import h5py
+
+    group.attrs["columns"] = np.array(df.columns, dtype=object)
+    for name, series in df.to_dict().items():
+        dtype = series.dtype
+        if isinstance(dtype, pl.Datetime):
+            if dtype.time_zone is not None:
+                series = series.dt.convert_time_zone("UTC").dt.replace_time_zone(None)
+            data = series.dt.epoch("us").fill_null(np.iinfo(np.int64).min).to_numpy()
+            dset = group.create_dataset(name, data=data, compression="gzip")
+            dset.attrs["units"] = "microseconds since 1970-01-01T00:00:00 UTC"
+            dset.attrs["fill_value"] = np.iinfo(np.int64).min
+        elif dtype.is_numeric() or dtype == pl.Boolean:
+            if dtype.is_integer() or dtype == pl.Boolean:
+                data = series.fill_null(-1).to_numpy()
+            else:
+                data = series.cast(pl.Float64).fill_null(np.nan).to_numpy()
+            group.create_dataset(name, data=data, compression="gzip")
+        else:
+            data = series.cast(pl.String).fill_null("").to_list()
+            group.create_dataset(
+                name, data=data, dtype=h5py.string_dtype(), compression="gzip"
+            )
+
+
+def write_hdf5(
+    path: Union[str, Path],
+    obs: Optional[pl.DataFrame] = None,
+    nav: Optional[dict[str, pl.DataFrame]] = None,
+    tec: Optional[pl.DataFrame] = None,
+    header: Optional[Union[GNSSContext, dict[str, Any]]] = None,
+) -> None:
+    """
+    Write parsed and derived data to an HDF5 file with a fixed group layout.
+
+    Every table is stored column-wise, one 1-D dataset per column, so that it can
+    be read directly from MATLAB (`h5read(file, '/obs/value')`) or IDL:
+
+    * `/header`: attributes with the receiver metadata (e.g., `receiver_pos`,
+      `receiver_name`, `rinex_version`, `h_ipp`, `systems`);
+    * `/obs`: observations, as returned by `read_rinex_obs`;
+    * `/nav/<CONSTELLATION>`: navigation messages, as returned by `read_rinex_nav`;
+    * `/tec`: TEC data, e.g. as returned by `calculate_tec`.
+
+    Epochs are stored as int64 microseconds since 1970-01-01 (UTC) and strings as
+    UTF-8. Missing values are NaN for floats, -1 for integers, the minimum int64
+    for epochs and empty strings for text; each group lists its columns, in order,
+    in the 'columns' attribute.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Output HDF5 file, overwritten if it exists.
+    obs : pl.DataFrame, optional
+        Observation DataFrame.
+    nav : dict[str, pl.DataFrame], optional
+        Navigation DataFrames keyed by constellation.
+    tec : pl.DataFrame, optional
+        TEC DataFrame.
+    header : GNSSContext or dict, optional
+        Receiver metadata stored in the '/header' group.
+    """
+    import h5py
+
+    with h5py.File(path, "w") as f:
+        f.attrs["source"] = "pytecgg"
+        f.attrs["created"] = f"{datetime.now(timezone.utc):%Y-%m-%dT%H:%M:%SZ}"
+        if header is not None:
+            _write_attrs(f.create_group("header"), asdict(header) if is_dataclass(header) else header)
+        if obs is not None:
+            _write_table(f.create_group("obs"), obs)
+        if nav is not None:
+            nav_group = f.create_group("nav")
+            for constellation, df in nav.items():
+                _write_table(nav_group.create_group(constellation), df)
+        if tec is not None:
+            _write_table(f.create_group("tec"), tec)
@@ -0,0 +1,321 @@
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional, Sequence, Union
+
+import numpy as np
+import polars as pl
+from pymap3d import ecef2geodetic
+
+from pytecgg.context import GNSSContext, SUPPORTED_SYSTEMS
+
+# Madrigal instrument code of the worldwide GNSS receiver network, and kinds of data
+# of the line-of-sight and receiver site tables
+MADRIGAL_KINST = 8000
+MADRIGAL_KINDAT_LOS = 3505
+MADRIGAL_KINDAT_SITE = 3506
+
+# Madrigal parameters: mnemonic, description, units and numpy type
+_PARAMETERS = {
+    "year": ("Year (universal time)", "y", "i8"),
+    "month": ("Month (universal time)", "m", "i8"),
+    "day": ("Day (universal time)", "d", "i8"),
+    "hour": ("Hour (universal time)", "h", "i8"),
+    "min": ("Minute (universal time)", "m", "i8"),
+    "sec": ("Second (universal time)", "s", "i8"),
+    "recno": ("Logical Record Number", "N/A", "i8"),
+    "kindat": ("Kind of data", "N/A", "i8"),
+    "kinst": ("Instrument Code", "N/A", "i8"),
+    "ut1_unix": ("Unix seconds (1/1/1970) at start", "s", "f8"),
+    "ut2_unix": ("Unix seconds (1/1/1970) at end", "s", "f8"),
+    "pierce_alt": ("Pierce Point Altitude", "km", "f8"),
+    "gps_site": ("GPS receiver site name", "N/A", "S4"),
+    "sat_id": ("Satellite id", "N/A", "i8"),
+    "gnss_type": ("GNSS type", "N/A", "S8"),
+    "gdlatr": ("Reference geod latitude (N hemi=pos)", "deg", "f8"),
+    "gdlonr": ("Reference geographic longitude", "deg", "f8"),
+    "los_tec": ("Line-of-sight TEC", "tec", "f8"),
+    "dlos_tec": ("Error in Line-of-sight TEC", "tec", "f8"),
+    "tec": ("Vertically integrated electron density", "tec", "f8"),
+    "azm": ("Mean azimuth angle (0=geog N,90=east)", "deg", "f8"),
+    "elm": ("Elevation angle (0=horizontal,90=vert)", "deg", "f8"),
+    "gdlat": ("Geodetic latitude of measurement", "deg", "f8"),
+    "glon": ("Geographic longitude of measurement", "deg", "f8"),
+    "rec_bias": ("Receiver bias", "tec", "f8"),
+    "drec_bias": ("Error in receiver bias", "tec", "f8"),
+}
+
+_TIME_COLUMNS = [
+    "year",
+    "month",
+    "day",
+    "hour",
+    "min",
+    "sec",
+    "recno",
+    "kindat",
+    "kinst",
+    "ut1_unix",
+    "ut2_unix",
+]
+_LOS_COLUMNS = _TIME_COLUMNS + [
+    "pierce_alt",
+    "gps_site",
+    "sat_id",
+    "gnss_type",
+    "gdlatr",
+    "gdlonr",
+    "los_tec",
+    "dlos_tec",
+    "tec",
+    "azm",
+    "elm",
+    "gdlat",
+    "glon",
+    "rec_bias",
+    "drec_bias",
+]
+_SITE_COLUMNS = _TIME_COLUMNS + ["gps_site", "gdlatr", "gdlonr"]
+
+_OPTIONAL_COLUMNS = {
+    "dlos_tec": "stec_error",
+    "rec_bias": "rec_bias",
+    "drec_bias": "rec_bias_error",
+}
+
+_GNSS_TYPES = {symbol_: name for name, symbol_ in SUPPORTED_SYSTEMS.items()}
+
+
+def _time_columns(start: pl.Expr, end: pl.Expr) -> list[pl.Expr]:
+    """Madrigal time parameters of records spanning `start` to `end` (UTC datetimes)."""
+    return [
+        start.dt.year().alias("year"),
+        start.dt.month().alias("month"),
+        start.dt.day().alias("day"),
+        start.dt.hour().alias("hour"),
+        start.dt.minute().alias("min"),
+        start.dt.second().alias("sec"),
+        (start.dt.epoch("us") / 1e6).alias("ut1_unix"),
+        (end.dt.epoch("us") / 1e6).alias("ut2_unix"),
+    ]
+
+
+def _utc(df: pl.DataFrame) -> pl.DataFrame:
+    epoch = pl.col("epoch")
+    if df.schema["epoch"].time_zone is None:
+        return df.with_columns(epoch.dt.replace_time_zone("UTC"))
+    return df.with_columns(epoch.dt.convert_time_zone("UTC"))
+
+
+def _los_table(
+    tec: pl.DataFrame,
+    ctx: GNSSContext,
+    kinst: int = MADRIGAL_KINST,
+    kindat: int = MADRIGAL_KINDAT_LOS,
+) -> pl.DataFrame:
+    """Line-of-sight table of a station, with the Madrigal parameters as columns."""
+    lat_r, lon_r, _ = ecef2geodetic(*ctx.receiver_pos)
+    # Errors and receiver bias, from the optional columns of the TEC DataFrame
+    optional = {
+        name: pl.col(column).cast(pl.Float64) if column in tec.columns else pl.lit(np.nan)
+        for name, column in _OPTIONAL_COLUMNS.items()
+    }
+    return (
+        _utc(tec)
+        .filter(pl.col("stec").is_not_null() & pl.col("stec").is_not_nan())
+        .select(
+            *_time_columns(pl.col("epoch"), pl.col("epoch")),
+            pl.lit(kindat).alias("kindat"),
+            pl.lit(kinst).alias("kinst"),
+            pl.lit(ctx.h_ipp / 1_000).alias("pierce_alt"),
+            pl.lit(ctx.receiver_name).alias("gps_site"),
+            pl.col("sv").str.slice(1).cast(pl.Int64).alias("sat_id"),
+            pl.col("sv")
+            .str.slice(0, 1)
+            .replace_strict(_GNSS_TYPES, default=None)
+            .alias("gnss_type"),
+            pl.lit(float(lat_r)).alias("gdlatr"),
+            pl.lit(float(lon_r)).alias("gdlonr"),
+            pl.col("stec").cast(pl.Float64).alias("los_tec"),
+            optional["dlos_tec"].alias("dlos_tec"),
+            pl.col("vtec").cast(pl.Float64).alias("tec"),
+            pl.col("azi").cast(pl.Float64).alias("azm"),
+            pl.col("ele").cast(pl.Float64).alias("elm"),
+            pl.col("lat_ipp").cast(pl.Float64).alias("gdlat"),
+            pl.col("lon_ipp").cast(pl.Float64).alias("glon"),
+            optional["rec_bias"].alias("rec_bias"),
+            optional["drec_bias"].alias("drec_bias"),
+        )
+    )
+
+
+def _site_table(
+    tec: pl.DataFrame,
+    ctx: GNSSContext,
+    kinst: int = MADRIGAL_KINST,
+    kindat: int = MADRIGAL_KINDAT_SITE,
+) -> pl.DataFrame:
+    """Receiver site table of a station, one record spanning its TEC data."""
+    lat_r, lon_r, _ = ecef2geodetic(*ctx.receiver_pos)
+    return (
+        _utc(tec)
+        .select(
+            *_time_columns(pl.col("epoch").min(), pl.col("epoch").max()),
+            pl.lit(kindat).alias("kindat"),
+            pl.lit(kinst).alias("kinst"),
+            pl.lit(ctx.receiver_name).alias("gps_site"),
+            pl.lit(float(lat_r)).alias("gdlatr"),
+            pl.lit(float(lon_r)).alias("gdlonr"),
+        )
+    )
+
+
+def _with_recno(table: pl.DataFrame, columns: list[str]) -> pl.DataFrame:
+    """Sorts the records by time and numbers them, one record per distinct time."""
+    return (
+        table.sort("ut1_unix", "gps_site")
+        .with_columns((pl.col("ut1_unix").rank("dense") - 1).cast(pl.Int64).alias("recno"))
+        .select(columns)
+    )
+
+
+def _write_madrigal_file(path: Path, table: pl.DataFrame, experiment: dict[str, str]) -> None:
+    """Writes a table in the Madrigal 3 HDF5 layout."""
+    import h5py
+
+    columns = list(table.columns)
+    dtype = np.dtype([(name, _PARAMETERS[name][2]) for name in columns])
+    records = np.empty(table.height, dtype=dtype)
+    for name in columns:
+        series = table[name]
+        if dtype[name].kind == "S":
+            records[name] = np.array(series.fill_null("").to_list(), dtype=dtype[name])
+        elif dtype[name].kind == "f":
+            records[name] = series.cast(pl.Float64).fill_null(np.nan).to_numpy()
+        else:
+            records[name] = series.cast(pl.Int64).fill_null(-1).to_numpy()
+
+    parameters = np.array(
+        [
+            (
+                name.upper().encode(),
+                _PARAMETERS[name][0].encode(),
+                int(name.startswith("d") and name[1:] in _PARAMETERS),
+                _PARAMETERS[name][1].encode(),
+                b"Time Related Parameter" if name in _TIME_COLUMNS else b"GNSS TEC",
+            )
+            for name in columns
+        ],
+        dtype=[
+            ("mnemonic", "S20"),
+            ("description", "S48"),
+            ("isError", "i8"),
+            ("units", "S7"),
+            ("category", "S33"),
+        ],
+    )
+    experiment_params = np.array(
+        [(key.encode(), str(value).encode()) for key, value in experiment.items()],
+        dtype=[("name", "S40"), ("value", "S200")],
+    )
+
+    with h5py.File(path, "w") as f:
+        f.create_group("Data").create_dataset("Table Layout", data=records, compression="gzip")
+        metadata = f.create_group("Metadata")
+        metadata.create_dataset("Data Parameters", data=parameters)
+        metadata.create_dataset("Experiment Parameters", data=experiment_params)
+
+
+def write_madrigal(
+    output_dir: Union[str, Path],
+    tec: Union[pl.DataFrame, Sequence[pl.DataFrame]],
+    ctx: Union[GNSSContext, Sequence[GNSSContext]],
+    kinst: int = MADRIGAL_KINST,
+    kindat_los: int = MADRIGAL_KINDAT_LOS,
+    kindat_site: int = MADRIGAL_KINDAT_SITE,
+    experiment: Optional[dict[str, str]] = None,
+) -> tuple[Path, Path]:
+    """
+    Write calibrated TEC to line-of-sight and receiver site files following the
+    conventions of the Madrigal GNSS TEC products.
+
+    Two Madrigal 3 HDF5 files are written, named after the first day of data:
+
+    * 'los_YYYYMMDD.001.h5': one record per epoch, station and satellite, with the
+      Madrigal parameters 'gps_site', 'sat_id', 'gnss_type', 'gdlatr', 'gdlonr'
+      (receiver), 'los_tec' (slant TEC), 'tec' (vertical TEC), 'azm', 'elm',
+      'gdlat', 'glon' (IPP) and 'pierce_alt', preceded by the standard time
+      parameters;
+    * 'site_YYYYMMDD.001.h5': one record per station, with its position and the
+      time span of its data.
+
+    Each file holds the records in the compound '/Data/Table Layout' dataset, and
+    the description of the parameters in '/Metadata/Data Parameters'. Errors and
+    receiver biases ('dlos_tec', 'rec_bias', 'drec_bias') are taken from the
+    'stec_error', 'rec_bias' and 'rec_bias_error' columns when present, and are NaN
+    otherwise.
+
+    Parameters
+    ----------
+    output_dir : str or pathlib.Path
+        Output directory, created if needed; existing files are overwritten.
+    tec : pl.DataFrame or sequence of pl.DataFrame
+        Calibrated TEC of one or more stations, as returned by `calculate_tec` or
+        `compute_tec`, with columns 'epoch', 'sv', 'stec', 'vtec', 'azi', 'ele',
+        'lat_ipp' and 'lon_ipp'.
+    ctx : GNSSContext or sequence of GNSSContext
+        Context of each station, in the order of `tec`, providing its name,
+        position and IPP height.
+    kinst : int, optional
+        Madrigal instrument code, by default 8000 (worldwide GNSS receiver network).
+    kindat_los, kindat_site : int, optional
+        Madrigal kinds of data of the line-of-sight and site files, by default 3505
+        and 3506.
+    experiment : dict[str, str], optional
+        Additional experiment parameters (e.g., 'Contact', 'Cedar file name').
+
+    Returns
+    -------
+    tuple[pathlib.Path, pathlib.Path]
+        Paths of the line-of-sight and site files.
+
+    Raises
+    ------
+    ValueError
+        If the numbers of TEC DataFrames and contexts differ, or without TEC data.
+    """
+    frames = [tec] if isinstance(tec, pl.DataFrame) else list(tec)
+    contexts = [ctx] if isinstance(ctx, GNSSContext) else list(ctx)
+    if len(frames) != len(contexts):
+        raise ValueError(
+            f"{len(frames)} TEC DataFrames for {len(contexts)} contexts: expected one context per station"
+        )
+
+    los = [_los_table(df, c, kinst, kindat_los) for df, c in zip(frames, contexts)]
+    sites = [
+        _site_table(df, c, kinst, kindat_site)
+        for df, c in zip(frames, contexts)
+        if not df.is_empty()
+    ]
+    los_table = _with_recno(pl.concat(los), _LOS_COLUMNS)
+    if los_table.is_empty():
+        raise ValueError("No TEC data to export")
+    site_table = _with_recno(pl.concat(sites), _SITE_COLUMNS)
+
+    first = datetime.fromtimestamp(los_table["ut1_unix"].min(), tz=timezone.utc)
+    output_dir = Path(output_dir)
+    output_dir.mkdir(parents=True, exist_ok=True)
+    los_path = output_dir / f"los_{first:%Y%m%d}.001.h5"
+    site_path = output_dir / f"site_{first:%Y%m%d}.001.h5"
+
+    last = datetime.fromtimestamp(los_table["ut2_unix"].max(), tz=timezone.utc)
+    experiment = {
+        "instrument code(s)": kinst,
+        "start time": f"{first:%Y-%m-%d %H:%M:%S} UT",
+        "end time": f"{last:%Y-%m-%d %H:%M:%S} UT",
+        "number of sites": len(contexts),
+        "source": "pytecgg",
+        **(experiment or {}),
+    }
+    _write_madrigal_file(los_path, los_table, {**experiment, "kind of data": kindat_los})
+    _write_madrigal_file(site_path, site_table, {**experiment, "kind of data": kindat_site})
+    return los_path, site_path
@@ -0,0 +1,142 @@
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal, Optional, Sequence, Union
+
+import numpy as np
+import polars as pl
+
+# CF attributes of the variables commonly produced by pytecgg
+_VARIABLE_ATTRS = {
+    "stec": {"long_name": "slant total electron content", "units": "TECU"},
+    "vtec": {"long_name": "vertical total electron content", "units": "TECU"},
+    "veq": {"long_name": "vertical equivalent total electron content", "units": "TECU"},
+    "bias": {"long_name": "arc calibration bias", "units": "TECU"},
+    "gflc_phase": {"long_name": "geometry-free phase combination", "units": "TECU"},
+    "gflc_code": {"long_name": "geometry-free code combination", "units": "TECU"},
+    "lat_ipp": {"long_name": "ionospheric pierce point latitude", "units": "degrees_north"},
+    "lon_ipp": {"long_name": "ionospheric pierce point longitude", "units": "degrees_east"},
+    "azi": {"long_name": "satellite azimuth", "units": "degree"},
+    "ele": {"long_name": "satellite elevation", "units": "degree"},
+}
+
+_COORD_ATTRS = {
+    "time": {"standard_name": "time", "axis": "T"},
+    "sv": {"long_name": "satellite vehicle", "cf_role": "timeseries_id"},
+    "lat": {"standard_name": "latitude", "units": "degrees_north", "axis": "Y"},
+    "lon": {"standard_name": "longitude", "units": "degrees_east", "axis": "X"},
+}
+
+
+def _naive_utc(epochs: pl.Series) -> np.ndarray:
+    if epochs.dtype.time_zone is not None:
+        epochs = epochs.dt.convert_time_zone("UTC").dt.replace_time_zone(None)
+    return epochs.to_numpy()
+
+
+def to_xarray(
+    df: pl.DataFrame,
+    variables: Optional[Sequence[str]] = None,
+    layout: Literal["sv", "grid"] = "sv",
+    lat: str = "lat",
+    lon: str = "lon",
+    attrs: Optional[dict] = None,
+):
+    """
+    Convert a long-format DataFrame into a CF-compliant xarray Dataset.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        Observation, TEC or VTEC DataFrame with an 'epoch' column and, depending
+        on `layout`, an 'sv' column or latitude/longitude columns. Each
+        combination of the dimensions is expected to appear at most once (the
+        last occurrence is kept otherwise).
+    variables : sequence of str, optional
+        Columns to export; by default, all numeric columns other than the
+        dimensions.
+    layout : {'sv', 'grid'}, optional
+        'sv' produces (time, sv) variables, e.g. for STEC/VTEC series per
+        satellite; 'grid' produces (time, lat, lon) variables, e.g. for VTEC maps.
+    lat, lon : str, optional
+        Names of the latitude and longitude columns with the 'grid' layout.
+    attrs : dict, optional
+        Additional global attributes (e.g., 'title', 'institution').
+
+    Returns
+    -------
+    xarray.Dataset
+        Dataset with missing combinations filled with NaN.
+    """
+    import xarray as xr
+
+    if layout not in ("sv", "grid"):
+        raise ValueError(f"Invalid layout '{layout}': expected 'sv' or 'grid'")
+
+    dims = {"time": "epoch"}
+    if layout == "sv":
+        dims["sv"] = "sv"
+    else:
+        dims.update({"lat": lat, "lon": lon})
+    if variables is None:
+        variables = [
+            c for c, dtype in df.schema.items()
+            if dtype.is_numeric() and c not in dims.values()
+        ]
+
+    df = df.drop_nulls(list(dims.values()))
+    coords, index = {}, []
+    for dim, col in dims.items():
+        values = df[col].unique().sort()
+        coords[dim] = _naive_utc(values) if dim == "time" else values.to_numpy()
+        index.append(df[col].rank("dense").cast(pl.Int64).to_numpy() - 1)
+    shape = tuple(len(c) for c in coords.values())
+
+    data_vars = {}
+    for var in variables:
+        dense = np.full(shape, np.nan)
+        dense[tuple(index)] = df[var].cast(pl.Float64).fill_null(np.nan).to_numpy()
+        data_vars[var] = (tuple(dims), dense, _VARIABLE_ATTRS.get(var, {}))
+
+    ds = xr.Dataset(
+        data_vars,
+        coords={dim: (dim, values, _COORD_ATTRS[dim]) for dim, values in coords.items()},
+    )
+    ds.attrs = {
+        "Conventions": "CF-1.8",
+        "source": "pytecgg",
+        "history": f"{datetime.now(timezone.utc):%Y-%m-%dT%H:%M:%SZ} created by pytecgg",
+        **(attrs or {}),
+    }
+    if layout == "sv":
+        ds.attrs["featureType"] = "timeSeries"
+    return ds
+
+
+def write_netcdf(
+    df: pl.DataFrame,
+    path: Union[str, Path],
+    variables: Optional[Sequence[str]] = None,
+    layout: Literal["sv", "grid"] = "sv",
+    lat: str = "lat",
+    lon: str = "lon",
+    attrs: Optional[dict] = None,
+) -> None:
+    """
+    Write a long-format DataFrame to a CF-compliant netCDF file.
+
+    The DataFrame is converted with `to_xarray` and written with compression;
+    epochs are encoded as microseconds since 1970-01-01 (UTC).
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        Observation, TEC or VTEC DataFrame (see `to_xarray`).
+    path : str or pathlib.Path
+        Output netCDF file.
+    variables, layout, lat, lon, attrs
+        See `to_xarray`.
+    """
+    ds = to_xarray(df, variables, layout, lat, lon, attrs)
+    encoding = {var: {"zlib": True, "complevel": 4} for var in ds.data_vars}
+    encoding["time"] = {"units": "microseconds since 1970-01-01", "calendar": "standard"}
+    ds.to_netcdf(path, encoding=encoding)
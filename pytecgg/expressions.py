@@ -0,0 +1,319 @@
+"""
+Polars expression namespaces backed by the Rust core.
+
+Importing `pytecgg` registers the `gnss` and `tec` namespaces on Polars
+expressions, so that GNSS-specific computations compose with lazy queries:
+
+    >>> import pytecgg
+    >>> (
+    ...     lf.with_columns(pl.col("observable").gnss.frequency("sv").alias("freq"))
+    ...     .with_columns(pl.col("stec").tec.rot().over("sv").alias("rot"))
+    ...     .with_columns(pl.col("stec").tec.mapping("ele").alias("vtec"))
+    ...     .with_columns(pl.col("rot").tec.rolling_std(300).alias("roti"))
+    ... )
+"""
+
+from pathlib import Path
+from typing import Any, Optional, Union
+
+import polars as pl
+from polars.plugins import register_plugin_function
+
+from .linear_combinations.constants import C
+from .settings import get_settings
+
+# Directory of the compiled Rust core, which exports the expression plugins
+_PLUGIN_PATH = Path(__file__).parent
+
+IntoExpr = Union[str, pl.Expr]
+
+
+def _col(expr: IntoExpr) -> pl.Expr:
+    return pl.col(expr) if isinstance(expr, str) else expr
+
+
+def _plugin(
+    name: str, args: list[IntoExpr], kwargs: dict[str, Any], is_elementwise: bool = False
+) -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=_PLUGIN_PATH,
+        function_name=name,
+        args=[_col(arg) for arg in args],
+        kwargs=kwargs,
+        is_elementwise=is_elementwise,
+    )
+
+
+@pl.api.register_expr_namespace("gnss")
+class GNSSNamespace:
+    """GNSS signal expressions, available as `pl.Expr.gnss`."""
+
+    def __init__(self, expr: pl.Expr):
+        self._expr = expr
+
+    def frequency(
+        self,
+        sv: IntoExpr = "sv",
+        glonass_channels: Optional[dict[str, int]] = None,
+    ) -> pl.Expr:
+        """
+        Carrier frequency of RINEX observable codes.
+
+        Parameters
+        ----------
+        sv : str or pl.Expr, optional
+            Satellite identifiers (e.g., 'G01'), by default the 'sv' column.
+        glonass_channels : dict[str, int], optional
+            GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`),
+            required for the FDMA bands 1 and 2.
+
+        Returns
+        -------
+        pl.Expr
+            Frequency in Hz, null for unknown bands or channels.
+        """
+        return _plugin(
+            "gnss_frequency",
+            [self._expr, sv],
+            {"glonass_channels": glonass_channels},
+            is_elementwise=True,
+        )
+
+    def wavelength(
+        self,
+        sv: IntoExpr = "sv",
+        glonass_channels: Optional[dict[str, int]] = None,
+    ) -> pl.Expr:
+        """
+        Carrier wavelength of RINEX observable codes, e.g. to convert carrier phases
+        from cycles into meters.
+
+        Parameters
+        ----------
+        sv : str or pl.Expr, optional
+            Satellite identifiers (e.g., 'G01'), by default the 'sv' column.
+        glonass_channels : dict[str, int], optional
+            GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`),
+            required for the FDMA bands 1 and 2.
+
+        Returns
+        -------
+        pl.Expr
+            Wavelength in meters, null for unknown bands or channels.
+
+        Examples
+        --------
+        >>> obs.with_columns(
+        ...     pl.when(pl.col("observable").str.starts_with("L"))
+        ...     .then(pl.col("value") * pl.col("observable").gnss.wavelength())
+        ...     .otherwise(pl.col("value"))
+        ... )
+        """
+        return C / self.frequency(sv, glonass_channels)
+
+
+@pl.api.register_expr_namespace("tec")
+class TECNamespace:
+    """TEC expressions, available as `pl.Expr.tec`."""
+
+    def __init__(self, expr: pl.Expr):
+        self._expr = expr
+
+    def rot(self, epoch: IntoExpr = "epoch", max_gap: Optional[float] = None) -> pl.Expr:
+        """
+        Rate of change of TEC (ROT) between consecutive epochs.
+
+        The TEC values are expected in time order; use `.over("sv")` (or over the
+        arc identifier) to compute the rate per satellite.
+
+        Parameters
+        ----------
+        epoch : str or pl.Expr, optional
+            Epochs of the TEC values, by default the 'epoch' column.
+        max_gap : float, optional
+            Largest time step in seconds over which the rate is computed; the rate
+            is null across longer data gaps.
+
+        Returns
+        -------
+        pl.Expr
+            ROT in TECU/min, null for the first epoch.
+        """
+        return _plugin("tec_rate", [self._expr, epoch], {"max_gap": max_gap})
+
+    def mapping(self, elevation: IntoExpr = "ele", h_ipp: Optional[float] = None) -> pl.Expr:
+        """
+        Map slant TEC onto the vertical with the mapping function of the settings.
+
+        Parameters
+        ----------
+        elevation : str or pl.Expr, optional
+            Satellite elevation in degrees, by default the 'ele' column.
+        h_ipp : float, optional
+            Height of the ionospheric shell in meters, by default that of the
+            settings (350 km, see `pytecgg.configure`).
+
+        Returns
+        -------
+        pl.Expr
+            Vertical TEC, i.e. the slant TEC times the cosine of the zenith angle
+            at the IPP.
+        """
+        settings = get_settings()
+        h_ipp = settings.h_ipp if h_ipp is None else h_ipp
+        factor = _plugin(
+            "tec_mapping",
+            [elevation],
+            {
+                "h_ipp": float(h_ipp),
+                "alpha": float(settings.mapping_alpha),
+                "earth_radius": float(settings.earth_radius),
+            },
+            is_elementwise=True,
+        )
+        return self._expr * factor
+
+    def _rolling(
+        self,
+        window: float,
+        statistic: str,
+        arc: IntoExpr,
+        epoch: IntoExpr,
+        min_samples: int,
+        center: bool,
+        degree: int = 3,
+        ddof: int = 0,
+    ) -> pl.Expr:
+        return _plugin(
+            "arc_rolling",
+            [self._expr, epoch, arc],
+            {
+                "window": float(window),
+                "statistic": statistic,
+                "degree": degree,
+                "ddof": ddof,
+                "min_samples": min_samples,
+                "center": center,
+            },
+        )
+
+    def rolling_mean(
+        self,
+        window: float,
+        arc: IntoExpr = "id_arc_valid",
+        epoch: IntoExpr = "epoch",
+        min_samples: int = 1,
+        center: bool = False,
+    ) -> pl.Expr:
+        """
+        Rolling mean over a time window, within each arc (e.g., for smoothing).
+
+        Unlike the generic rolling operations of Polars, windows never span two
+        arcs, without needing `.over()`, and rows may be in any order.
+
+        Parameters
+        ----------
+        window : float
+            Length of the window in seconds.
+        arc : str or pl.Expr, optional
+            Arc identifiers, by default the 'id_arc_valid' column; rows with a null
+            identifier give null.
+        epoch : str or pl.Expr, optional
+            Epochs of the values, by default the 'epoch' column.
+        min_samples : int, optional
+            Smallest number of samples in a window giving a value, by default 1.
+        center : bool, optional
+            If True, the window is centered on each epoch; by default, it ends at
+            each epoch.
+
+        Returns
+        -------
+        pl.Expr
+            Rolling mean, null for null values and windows with too few samples.
+        """
+        return self._rolling(window, "mean", arc, epoch, min_samples, center)
+
+    def rolling_std(
+        self,
+        window: float,
+        arc: IntoExpr = "id_arc_valid",
+        epoch: IntoExpr = "epoch",
+        ddof: int = 0,
+        min_samples: int = 2,
+        center: bool = False,
+    ) -> pl.Expr:
+        """
+        Rolling standard deviation over a time window, within each arc.
+
+        Applied to the ROT with a 5-minute window, this gives the rate of TEC
+        index (ROTI).
+
+        Parameters
+        ----------
+        window : float
+            Length of the window in seconds (e.g., 300 for ROTI).
+        arc : str or pl.Expr, optional
+            Arc identifiers, by default the 'id_arc_valid' column; rows with a null
+            identifier give null.
+        epoch : str or pl.Expr, optional
+            Epochs of the values, by default the 'epoch' column.
+        ddof : int, optional
+            Delta degrees of freedom, by default 0 as in the definition of ROTI.
+        min_samples : int, optional
+            Smallest number of samples in a window giving a value, by default 2.
+        center : bool, optional
+            If True, the window is centered on each epoch; by default, it ends at
+            each epoch.
+
+        Returns
+        -------
+        pl.Expr
+            Rolling standard deviation, null for null values and windows with too
+            few samples.
+        """
+        return self._rolling(window, "std", arc, epoch, min_samples, center, ddof=ddof)
+
+    def rolling_detrend(
+        self,
+        window: float,
+        degree: int = 3,
+        arc: IntoExpr = "id_arc_valid",
+        epoch: IntoExpr = "epoch",
+        min_samples: Optional[int] = None,
+        center: bool = True,
+    ) -> pl.Expr:
+        """
+        Residuals of a rolling polynomial fit over a time window, within each arc.
+
+        Removes the slow trends (e.g., satellite motion and background TEC) of
+        phase or TEC before computing scintillation or fluctuation indices.
+
+        Parameters
+        ----------
+        window : float
+            Length of the window in seconds.
+        degree : int, optional
+            Degree of the polynomial, by default 3.
+        arc : str or pl.Expr, optional
+            Arc identifiers, by default the 'id_arc_valid' column; rows with a null
+            identifier give null.
+        epoch : str or pl.Expr, optional
+            Epochs of the values, by default the 'epoch' column.
+        min_samples : int, optional
+            Smallest number of samples in a window giving a value, by default
+            twice the number of coefficients of the polynomial.
+        center : bool, optional
+            If True (default), the window is centered on each epoch; otherwise it
+            ends at each epoch.
+
+        Returns
+        -------
+        pl.Expr
+            Values minus the fitted polynomial at their epoch, null for null values
+            and windows with too few samples.
+        """
+        if min_samples is None:
+            min_samples = 2 * (degree + 1)
+        return self._rolling(
+            window, "detrend", arc, epoch, min_samples, center, degree=degree
+        )
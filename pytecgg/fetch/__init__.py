@@ -0,0 +1,20 @@
+from .auth import set_earthdata_credentials
+from .bias import fetch_bias
+from .cache import cache_dir, set_cache_dir
+from .centers import DATA_CENTERS, DataCenter
+from .products import fetch_clk, fetch_sp3
+from .rinex import fetch_brdc, fetch_nav, fetch_obs
+
+__all__ = [
+    "DATA_CENTERS",
+    "DataCenter",
+    "cache_dir",
+    "fetch_bias",
+    "fetch_brdc",
+    "fetch_clk",
+    "fetch_nav",
+    "fetch_obs",
+    "fetch_sp3",
+    "set_cache_dir",
+    "set_earthdata_credentials",
+]
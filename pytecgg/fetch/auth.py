@@ -0,0 +1,83 @@
+import os
+from base64 import b64encode
+from typing import Optional
+from urllib.parse import urlparse
+
+import requests
+from requests.auth import AuthBase
+from requests.utils import get_netrc_auth
+
+# NASA Earthdata Login (EDL) server, and the data centers protected by it
+EARTHDATA_LOGIN = "urs.earthdata.nasa.gov"
+EARTHDATA_HOSTS = {EARTHDATA_LOGIN, "cddis.nasa.gov"}
+
+_credentials: dict[str, Optional[str]] = {"username": None, "password": None, "token": None}
+
+
+def set_earthdata_credentials(
+    username: Optional[str] = None,
+    password: Optional[str] = None,
+    token: Optional[str] = None,
+) -> None:
+    """
+    Set the NASA Earthdata Login credentials used for CDDIS downloads.
+
+    Credentials are looked up, in order, from this function, from the
+    `EARTHDATA_TOKEN` or `EARTHDATA_USERNAME`/`EARTHDATA_PASSWORD` environment
+    variables, and from the `urs.earthdata.nasa.gov` entry of the `~/.netrc`
+    file (or of the file set by `NETRC`). They are only sent to Earthdata hosts.
+
+    Parameters
+    ----------
+    username, password : str, optional
+        Earthdata Login username and password.
+    token : str, optional
+        Earthdata Login bearer token, used instead of username and password.
+    """
+    if token is None and (username is None) != (password is None):
+        raise ValueError("Both username and password are required")
+    _credentials.update(username=username, password=password, token=token)
+
+
+def _authorization() -> Optional[str]:
+    """Authorization header value of the configured Earthdata credentials, if any."""
+    token = _credentials["token"] or os.environ.get("EARTHDATA_TOKEN")
+    if token:
+        return f"Bearer {token}"
+    username, password = _credentials["username"], _credentials["password"]
+    if username is None:
+        username = os.environ.get("EARTHDATA_USERNAME")
+        password = os.environ.get("EARTHDATA_PASSWORD")
+    if not (username and password):
+        login = get_netrc_auth(f"https://{EARTHDATA_LOGIN}")
+        if login is None:
+            return None
+        username, password = login
+    return "Basic " + b64encode(f"{username}:{password}".encode()).decode()
+
+
+class _EarthdataAuth(AuthBase):
+    """Adds the Earthdata credentials to the requests sent to Earthdata hosts only."""
+
+    def __init__(self, authorization: str):
+        self.authorization = authorization
+
+    def __call__(self, request: requests.PreparedRequest) -> requests.PreparedRequest:
+        if urlparse(request.url).hostname in EARTHDATA_HOSTS:
+            request.headers["Authorization"] = self.authorization
+        return request
+
+
+class _EarthdataSession(requests.Session):
+    """
+    Session following the Earthdata Login redirects.
+
+    CDDIS redirects to the login server, which redirects back once authenticated:
+    requests drops credentials on redirects to another host, so they are added
+    again whenever the redirect stays among the Earthdata hosts.
+    """
+
+    def rebuild_auth(self, prepared_request, response):
+        super().rebuild_auth(prepared_request, response)
+        if isinstance(self.auth, _EarthdataAuth):
+            self.auth(prepared_request)
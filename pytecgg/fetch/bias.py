@@ -0,0 +1,111 @@
+from dataclasses import dataclass
+from datetime import datetime, timedelta
+from pathlib import Path
+from typing import Literal
+
+from .cache import cache_dir
+from .http import _fetch_all
+from .rinex import DateLike, _epochs
+
+
+@dataclass(frozen=True)
+class _BiasProduct:
+    # URL and filename templates, formatted with the start epoch of the file (`t`)
+    url: str
+    name: str
+    monthly: bool
+
+
+# Bias products by provider and kind: observable-specific signal biases (OSB, in
+# SINEX-BIAS format) or differential code biases (DCB)
+_BIAS_PRODUCTS = {
+    ("code", "osb"): _BiasProduct(
+        url="http://ftp.aiub.unibe.ch/CODE/{t:%Y}/{name}",
+        name="COD0OPSFIN_{t:%Y%j}0000_01D_01D_OSB.BIA.gz",
+        monthly=False,
+    ),
+    ("code", "dcb"): _BiasProduct(
+        url="http://ftp.aiub.unibe.ch/CODE/{t:%Y}/{name}",
+        name="P1P2{t:%y%m}.DCB.Z",
+        monthly=True,
+    ),
+    ("code", "p1c1"): _BiasProduct(
+        url="http://ftp.aiub.unibe.ch/CODE/{t:%Y}/{name}",
+        name="P1C1{t:%y%m}.DCB.Z",
+        monthly=True,
+    ),
+    ("cas", "osb"): _BiasProduct(
+        url="https://cddis.nasa.gov/archive/gnss/products/bias/{t:%Y}/{name}",
+        name="CAS0OPSRAP_{t:%Y%j}0000_01D_01D_OSB.BIA.gz",
+        monthly=False,
+    ),
+    ("cas", "dcb"): _BiasProduct(
+        url="https://cddis.nasa.gov/archive/gnss/products/bias/{t:%Y}/{name}",
+        name="CAS0MGXRAP_{t:%Y%j}0000_01D_01D_DCB.BSX.gz",
+        monthly=False,
+    ),
+}
+
+
+def _bias_product(provider: str, kind: str) -> _BiasProduct:
+    try:
+        return _BIAS_PRODUCTS[(provider.lower(), kind.lower())]
+    except KeyError:
+        raise ValueError(
+            f"Unknown bias product '{provider}' '{kind}': expected one of "
+            f"{sorted(_BIAS_PRODUCTS)}"
+        ) from None
+
+
+def fetch_bias(
+    day: DateLike,
+    provider: Literal["code", "cas"] = "code",
+    kind: Literal["osb", "dcb", "p1c1"] = "osb",
+    retries: int = 3,
+) -> Path:
+    """
+    Get the satellite and receiver bias product covering a day, downloading it if needed.
+
+    The available products are:
+
+    * CODE OSB: daily final observable-specific signal biases (SINEX-BIAS).
+    * CODE DCB: monthly P1-P2 differential code biases (Bernese DCB format).
+    * CODE P1C1: monthly P1-C1 differential code biases (Bernese DCB format), to
+      make C1 and P1 code observations consistent.
+    * CAS OSB and DCB: daily rapid multi-GNSS biases (SINEX-BIAS), from CDDIS,
+      which requires an Earthdata login.
+
+    Files are kept in the local cache directory (see `cache_dir`), so that the
+    same monthly file serves every day of the month.
+
+    Parameters
+    ----------
+    day : datetime.date or datetime.datetime
+        Day of the observations to correct.
+    provider : {'code', 'cas'}, optional
+        Analysis center, by default CODE.
+    kind : {'osb', 'dcb', 'p1c1'}, optional
+        Bias representation, by default observable-specific signal biases; 'p1c1'
+        is only provided by CODE.
+    retries : int, optional
+        Number of retries of the download after transient failures.
+
+    Returns
+    -------
+    pathlib.Path
+        Local path of the compressed bias file.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the product is not available (yet) for the day.
+    """
+    product = _bias_product(provider, kind)
+    t: datetime = _epochs(day, None, timedelta(days=1))[0]
+    if product.monthly:
+        t = t.replace(day=1)
+    name = product.name.format(t=t)
+    dest = cache_dir() / "bias" / f"{t:%Y}" / name
+    if not _fetch_all([(product.url.format(t=t, name=name), dest)], retries=retries):
+        raise FileNotFoundError(f"{name} is not available for {t:%Y-%m-%d}")
+    return dest
@@ -0,0 +1,96 @@
+import hashlib
+import json
+import logging
+import os
+import time
+from datetime import timedelta
+from pathlib import Path
+from typing import Optional, Union
+
+logger = logging.getLogger(__name__)
+
+# Environment variable overriding the default cache directory
+CACHE_ENV = "PYTECGG_CACHE_DIR"
+
+# Per-directory record of the downloaded files: source URL, size, SHA-256, time
+MANIFEST = ".manifest.json"
+
+_cache_dir: Optional[Path] = None
+
+
+def cache_dir() -> Path:
+    """
+    Local cache directory of the downloaded products, shared by all fetchers.
+
+    Defaults to `~/.cache/pytecgg` (or `$XDG_CACHE_HOME/pytecgg`); it can be set
+    with `set_cache_dir` or with the `PYTECGG_CACHE_DIR` environment variable.
+    """
+    if _cache_dir is not None:
+        return _cache_dir
+    if root := os.environ.get(CACHE_ENV):
+        return Path(root).expanduser()
+    xdg = os.environ.get("XDG_CACHE_HOME")
+    return (Path(xdg) if xdg else Path.home() / ".cache") / "pytecgg"
+
+
+def set_cache_dir(path: Optional[Union[str, Path]]) -> None:
+    """
+    Set the local cache directory of the downloaded products.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path or None
+        Cache directory; None restores the default (see `cache_dir`).
+    """
+    global _cache_dir
+    _cache_dir = Path(path).expanduser() if path is not None else None
+
+
+def _sha256(path: Path) -> str:
+    digest = hashlib.sha256()
+    with open(path, "rb") as f:
+        for block in iter(lambda: f.read(1 << 20), b""):
+            digest.update(block)
+    return digest.hexdigest()
+
+
+def _read_manifest(directory: Path) -> dict:
+    try:
+        return json.loads((directory / MANIFEST).read_text())
+    except (OSError, ValueError):
+        return {}
+
+
+def _record(dest: Path, url: str) -> None:
+    """Record size and checksum of a downloaded file in the manifest of its directory."""
+    manifest = _read_manifest(dest.parent)
+    manifest[dest.name] = {
+        "url": url,
+        "size": dest.stat().st_size,
+        "sha256": _sha256(dest),
+        "time": time.time(),
+    }
+    (dest.parent / MANIFEST).write_text(json.dumps(manifest, indent=1, sort_keys=True))
+
+
+def _is_valid(dest: Path, max_age: Optional[timedelta] = None) -> bool:
+    """
+    Whether a local file can be used instead of downloading it again.
+
+    Files recorded in the manifest must still match their size and checksum,
+    and must be more recent than `max_age` when given; files not downloaded by
+    PyTECGg are trusted as long as they are not empty.
+    """
+    if not dest.is_file():
+        return False
+    size = dest.stat().st_size
+    entry = _read_manifest(dest.parent).get(dest.name)
+    if entry is None:
+        return size > 0
+    if size != entry.get("size") or _sha256(dest) != entry.get("sha256"):
+        logger.warning(f"Corrupted cached file, downloading again: {dest.name}")
+        return False
+    if max_age is not None and time.time() - entry.get("time", 0) > max_age.total_seconds():
+        logger.info(f"Stale cached file, downloading again: {dest.name}")
+        return False
+    return True
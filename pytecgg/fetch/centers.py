@@ -0,0 +1,73 @@
+from dataclasses import dataclass
+from datetime import datetime
+
+# Start of the GPS time scale, origin of the GPS week numbers
+GPS_EPOCH = datetime(1980, 1, 6)
+
+
+def gps_week(t: datetime) -> int:
+    """GPS week number of an epoch."""
+    return (t.replace(tzinfo=None) - GPS_EPOCH).days // 7
+
+
+@dataclass(frozen=True)
+class DataCenter:
+    """
+    Directory layout of an IGS data center.
+
+    Templates are formatted with the start epoch of the file (`t`, a datetime),
+    its GPS week (`week`) and the filename (`name`).
+
+    Attributes
+    ----------
+    name : str
+        Short name of the data center.
+    daily_obs, hourly_obs, daily_nav : str
+        URL templates of daily and hourly observation files and of daily
+        broadcast navigation files.
+    products : str
+        URL template of the IGS products (orbits, clocks), by GPS week.
+    """
+
+    name: str
+    daily_obs: str
+    hourly_obs: str
+    daily_nav: str
+    products: str
+
+    def url(self, template: str, t: datetime, name: str) -> str:
+        return template.format(t=t, week=gps_week(t), name=name)
+
+
+DATA_CENTERS = {
+    "cddis": DataCenter(
+        name="cddis",
+        daily_obs="https://cddis.nasa.gov/archive/gnss/data/daily/{t:%Y}/{t:%j}/{t:%y}d/{name}",
+        hourly_obs="https://cddis.nasa.gov/archive/gnss/data/hourly/{t:%Y}/{t:%j}/{t:%H}/{name}",
+        daily_nav="https://cddis.nasa.gov/archive/gnss/data/daily/{t:%Y}/{t:%j}/{t:%y}p/{name}",
+        products="https://cddis.nasa.gov/archive/gnss/products/{week}/{name}",
+    ),
+    "bkg": DataCenter(
+        name="bkg",
+        daily_obs="https://igs.bkg.bund.de/root_ftp/IGS/obs/{t:%Y}/{t:%j}/{name}",
+        hourly_obs="https://igs.bkg.bund.de/root_ftp/IGS/nrt/{t:%j}/{t:%H}/{name}",
+        daily_nav="https://igs.bkg.bund.de/root_ftp/IGS/BRDC/{t:%Y}/{t:%j}/{name}",
+        products="https://igs.bkg.bund.de/root_ftp/IGS/products/{week}/{name}",
+    ),
+    "ign": DataCenter(
+        name="ign",
+        daily_obs="https://igs.ign.fr/pub/igs/data/{t:%Y}/{t:%j}/{name}",
+        hourly_obs="https://igs.ign.fr/pub/igs/data/hourly/{t:%Y}/{t:%j}/{name}",
+        daily_nav="https://igs.ign.fr/pub/igs/data/{t:%Y}/{t:%j}/{name}",
+        products="https://igs.ign.fr/pub/igs/products/{week}/{name}",
+    ),
+}
+
+
+def get_data_center(center: str) -> DataCenter:
+    try:
+        return DATA_CENTERS[center.lower()]
+    except KeyError:
+        raise ValueError(
+            f"Unknown data center '{center}': expected one of {sorted(DATA_CENTERS)}"
+        ) from None
@@ -0,0 +1,148 @@
+import logging
+import time
+from datetime import timedelta
+from pathlib import Path
+from typing import Iterable, Optional
+from urllib.parse import urlparse
+
+import requests
+from requests.exceptions import ConnectionError, RequestException
+
+from pytecgg.utils.download_rinex import USER_AGENT
+
+from .auth import EARTHDATA_HOSTS, _authorization, _EarthdataAuth, _EarthdataSession
+from .cache import _is_valid, _record
+
+logger = logging.getLogger(__name__)
+
+# HTTP statuses worth retrying: rate limiting and transient server errors
+_TRANSIENT_STATUS = {429, 500, 502, 503, 504}
+
+
+def _session() -> requests.Session:
+    """
+    HTTP session shared by the downloads of a fetch call, authenticated on the
+    Earthdata hosts when credentials are available.
+    """
+    session = _EarthdataSession()
+    session.headers.update({"User-Agent": USER_AGENT})
+    if (authorization := _authorization()) is not None:
+        session.auth = _EarthdataAuth(authorization)
+    return session
+
+
+def _expected_size(response: requests.Response, resumed: bool) -> Optional[int]:
+    """Full size of the file announced by the server, if any."""
+    if resumed:
+        # 'Content-Range: bytes start-end/total'
+        total = response.headers.get("Content-Range", "").rpartition("/")[2]
+        return int(total) if total.isdigit() else None
+    length = response.headers.get("Content-Length")
+    return int(length) if length is not None and length.isdigit() else None
+
+
+def _download_resumable(
+    session: requests.Session,
+    url: str,
+    dest: Path,
+    retries: int = 3,
+    backoff: float = 2.0,
+    timeout: int = 30,
+) -> None:
+    """
+    Download a single file, retrying transient failures and resuming partial downloads.
+
+    Data is written to a '.part' file, renamed to the destination only once
+    complete, i.e. once its size matches the one announced by the server. After a
+    failure, the download resumes from the partial file with an HTTP Range
+    request, or restarts if the server does not support ranges.
+
+    Parameters
+    ----------
+    session : requests.Session
+        An active HTTP session for connection pooling
+    url : str
+        The full URL of the file to be downloaded
+    dest : Path
+        The final local destination path (including filename)
+    retries : int, optional
+        Number of retries after the first attempt (default: 3)
+    backoff : float, optional
+        Base of the exponential delay between attempts, in seconds (default: 2)
+    timeout : int, optional
+        Maximum time in seconds to wait for a server response (default: 30)
+
+    Raises
+    ------
+    RequestException
+        If the download still fails after all retries, or on a non-transient
+        HTTP error (e.g., 404)
+    """
+    part = dest.with_name(dest.name + ".part")
+    for attempt in range(retries + 1):
+        offset = part.stat().st_size if part.exists() else 0
+        headers = {"Range": f"bytes={offset}-"} if offset else {}
+        try:
+            with session.get(url, stream=True, timeout=timeout, headers=headers) as response:
+                if response.status_code == 416:
+                    # The partial file is already complete
+                    break
+                response.raise_for_status()
+                # Servers ignoring the Range header send the whole file again
+                resumed = response.status_code == 206
+                expected = _expected_size(response, resumed)
+                with open(part, "ab" if resumed else "wb") as f:
+                    for chunk in response.iter_content(chunk_size=65536):
+                        if chunk:
+                            f.write(chunk)
+            if expected is not None and part.stat().st_size != expected:
+                # Retried as a dropped connection, resuming from the partial file
+                raise ConnectionError(
+                    f"incomplete download ({part.stat().st_size} of {expected} bytes)"
+                )
+            break
+        except RequestException as e:
+            status = getattr(e.response, "status_code", None)
+            if attempt == retries or (status is not None and status not in _TRANSIENT_STATUS):
+                hint = ""
+                if status == 401 and urlparse(url).hostname in EARTHDATA_HOSTS:
+                    hint = " (Earthdata login required, see set_earthdata_credentials)"
+                logger.error(f"Failed to download {url}: {e}{hint}")
+                raise
+            delay = backoff**attempt
+            logger.warning(f"Retrying {url} in {delay:.0f} s ({e})")
+            time.sleep(delay)
+
+    part.replace(dest)
+    logger.info(f"Successfully downloaded: {dest.name}")
+
+
+def _fetch_all(
+    tasks: Iterable[tuple[str, Path]],
+    retries: int = 3,
+    max_age: Optional[timedelta] = None,
+) -> list[Path]:
+    """
+    Download (url, destination) pairs, skipping valid files already present locally.
+
+    Downloaded files are recorded with their size and checksum, so that corrupted
+    or truncated local copies, and copies older than `max_age`, are downloaded
+    again. Failed downloads are logged and skipped.
+
+    Returns
+    -------
+    list[Path]
+        Local paths of the files available after the downloads.
+    """
+    available = []
+    with _session() as session:
+        for url, dest in tasks:
+            if not _is_valid(dest, max_age):
+                dest.parent.mkdir(parents=True, exist_ok=True)
+                try:
+                    _download_resumable(session, url, dest, retries=retries)
+                    _record(dest, url)
+                except (RequestException, OSError):
+                    continue
+            available.append(dest)
+    return available
@@ -0,0 +1,157 @@
+import logging
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import Literal, Optional, Sequence
+
+from .cache import cache_dir
+from .centers import get_data_center, gps_week
+from .http import _fetch_all
+from .rinex import DateLike, _epochs
+
+logger = logging.getLogger(__name__)
+
+Tier = Literal["final", "rapid", "ultra"]
+
+# Latency tiers of the IGS combined products, from the most to the least accurate:
+# solution code and typical delay after the end of the day
+_TIERS = {
+    "final": ("FIN", timedelta(days=13)),
+    "rapid": ("RAP", timedelta(hours=17)),
+    "ultra": ("ULT", timedelta(hours=3)),
+}
+
+# Period, sampling and content of each product; ultra-rapid orbits span two days
+# (observed and predicted halves) and are not paired with a clock file
+_PRODUCTS = {
+    ("sp3", "final"): "01D_15M_ORB.SP3",
+    ("sp3", "rapid"): "01D_15M_ORB.SP3",
+    ("sp3", "ultra"): "02D_15M_ORB.SP3",
+    ("clk", "final"): "01D_30S_CLK.CLK",
+    ("clk", "rapid"): "01D_05M_CLK.CLK",
+}
+
+
+def _product_name(product: str, tier: str, t: datetime) -> str:
+    code = _TIERS[tier][0]
+    return f"IGS0OPS{code}_{t:%Y%j}0000_{_PRODUCTS[(product, tier)]}.gz"
+
+
+def _candidate_tiers(product: str, t: datetime, tier: Optional[str], now: datetime) -> list[str]:
+    """Tiers to try, best first, skipping those not yet expected to be published."""
+    if tier is not None:
+        if (product, tier) not in _PRODUCTS:
+            raise ValueError(
+                f"Unknown {product.upper()} tier '{tier}': expected one of "
+                f"{[k for p, k in _PRODUCTS if p == product]}"
+            )
+        return [tier]
+    end_of_day = t + timedelta(days=1)
+    tiers = [
+        name
+        for name, (_, latency) in _TIERS.items()
+        if (product, name) in _PRODUCTS and end_of_day + latency <= now
+    ]
+    # Ultra-rapid orbits predict the day ahead, so they are tried even before it ends
+    if not tiers and (product, "ultra") in _PRODUCTS:
+        tiers = ["ultra"]
+    return tiers
+
+
+def _fetch_product(
+    product: str,
+    day: DateLike,
+    tier: Optional[str],
+    centers: Sequence[str],
+    retries: int,
+) -> Path:
+    t = _epochs(day, None, timedelta(days=1))[0]
+    now = datetime.now(timezone.utc).replace(tzinfo=None)
+    tiers = _candidate_tiers(product, t, tier, now)
+    for name in tiers:
+        filename = _product_name(product, name, t)
+        dest = cache_dir() / "products" / str(gps_week(t)) / filename
+        for center in centers:
+            dc = get_data_center(center)
+            if _fetch_all([(dc.url(dc.products, t, filename), dest)], retries=retries):
+                logger.info(f"Using {name} {product.upper()} product for {t:%Y-%m-%d}")
+                return dest
+    raise FileNotFoundError(
+        f"No {product.upper()} product available for {t:%Y-%m-%d} "
+        f"(tiers: {', '.join(tiers) or 'none'}; centers: {', '.join(centers)})"
+    )
+
+
+def fetch_sp3(
+    day: DateLike,
+    tier: Optional[Tier] = None,
+    centers: Sequence[str] = ("bkg", "ign", "cddis"),
+    retries: int = 3,
+) -> Path:
+    """
+    Get the IGS precise orbit file (SP3) of a day, downloading it if needed.
+
+    By default the most accurate product available is selected: final, then
+    rapid, then ultra-rapid orbits. Tiers whose usual latency has not elapsed yet
+    are skipped without querying the data centers. Files are kept in the local
+    cache directory (see `cache_dir`).
+
+    Parameters
+    ----------
+    day : datetime.date or datetime.datetime
+        Day of the orbits.
+    tier : {'final', 'rapid', 'ultra'}, optional
+        Product tier; by default the best available one.
+    centers : sequence of str, optional
+        Data centers, in order of preference.
+    retries : int, optional
+        Number of retries of each download after transient failures.
+
+    Returns
+    -------
+    pathlib.Path
+        Local path of the gzipped SP3 file.
+
+    Raises
+    ------
+    FileNotFoundError
+        If no tier is available from any data center.
+    """
+    return _fetch_product("sp3", day, tier, centers, retries)
+
+
+def fetch_clk(
+    day: DateLike,
+    tier: Optional[Literal["final", "rapid"]] = None,
+    centers: Sequence[str] = ("bkg", "ign", "cddis"),
+    retries: int = 3,
+) -> Path:
+    """
+    Get the IGS precise clock file (RINEX clock) of a day, downloading it if needed.
+
+    By default the most accurate product available is selected, final clocks
+    (30 s) or rapid clocks (5 min); ultra-rapid clocks are only provided within
+    the SP3 orbit files. Files are kept in the local cache directory (see
+    `cache_dir`).
+
+    Parameters
+    ----------
+    day : datetime.date or datetime.datetime
+        Day of the clocks.
+    tier : {'final', 'rapid'}, optional
+        Product tier; by default the best available one.
+    centers : sequence of str, optional
+        Data centers, in order of preference.
+    retries : int, optional
+        Number of retries of each download after transient failures.
+
+    Returns
+    -------
+    pathlib.Path
+        Local path of the gzipped clock file.
+
+    Raises
+    ------
+    FileNotFoundError
+        If no tier is available from any data center.
+    """
+    return _fetch_product("clk", day, tier, centers, retries)
@@ -0,0 +1,204 @@
+from datetime import date, datetime, timedelta, timezone
+from pathlib import Path
+from typing import Optional, Sequence, Union
+
+from .cache import cache_dir
+from .centers import get_data_center
+from .http import _fetch_all
+
+DateLike = Union[date, datetime]
+
+# Refresh interval of the cached navigation files of the current day
+_INCOMPLETE_MAX_AGE = timedelta(hours=1)
+
+# Type codes of the broadcast navigation files, per constellation
+_NAV_TYPES = {"mixed": "M", "gps": "G", "glonass": "R", "galileo": "E", "beidou": "C"}
+
+
+def _as_datetime(t: DateLike) -> datetime:
+    if isinstance(t, datetime):
+        return t.replace(tzinfo=None)
+    return datetime(t.year, t.month, t.day)
+
+
+def _epochs(start: DateLike, end: Optional[DateLike], step: timedelta) -> list[datetime]:
+    """File start epochs from `start` to `end` (inclusive), aligned on `step`."""
+    first = _as_datetime(start)
+    last = _as_datetime(end) if end is not None else first
+    if step == timedelta(days=1):
+        first = first.replace(hour=0, minute=0, second=0, microsecond=0)
+    else:
+        first = first.replace(minute=0, second=0, microsecond=0)
+    epochs = []
+    while first <= last:
+        epochs.append(first)
+        first += step
+    return epochs
+
+
+def fetch_obs(
+    station: str,
+    start: DateLike,
+    end: Optional[DateLike] = None,
+    output_path: Optional[Union[str, Path]] = None,
+    center: str = "bkg",
+    hourly: bool = False,
+    sample_rate: str = "30S",
+    retries: int = 3,
+) -> list[Path]:
+    """
+    Download RINEX 3 observation files of a station from an IGS data center.
+
+    Files are Hatanaka-compressed and gzipped ('.crx.gz'), with IGS long names,
+    and are saved under `output_path/<STATION>/`. Valid files already present
+    locally are not downloaded again; transient failures are retried, and
+    interrupted downloads are resumed.
+
+    Parameters
+    ----------
+    station : str
+        9-character station identifier (e.g., 'GRAZ00AUT').
+    start : datetime.date or datetime.datetime
+        First day (or hour, with `hourly`) to download.
+    end : datetime.date or datetime.datetime, optional
+        Last day (or hour) to download, included; by default only `start`.
+    output_path : str or pathlib.Path, optional
+        Root directory of the downloads; by default the 'obs' directory of the
+        local cache (see `cache_dir`).
+    center : {'bkg', 'cddis', 'ign'}, optional
+        Data center, by default BKG. CDDIS requires an Earthdata login.
+    hourly : bool, optional
+        If True, hourly files are downloaded instead of daily files.
+    sample_rate : str, optional
+        Sampling rate field of the filenames, by default '30S' (hourly files
+        are usually '30S', high-rate files '01S').
+    retries : int, optional
+        Number of retries of each download after transient failures.
+
+    Returns
+    -------
+    list[pathlib.Path]
+        Local paths of the available files; failed downloads are logged and
+        omitted.
+    """
+    if len(station) != 9:
+        raise ValueError(
+            f"Invalid station '{station}': the 9-character identifier is required (e.g., 'GRAZ00AUT')"
+        )
+    dc = get_data_center(center)
+    station = station.upper()
+    period, template = ("01H", dc.hourly_obs) if hourly else ("01D", dc.daily_obs)
+    step = timedelta(hours=1) if hourly else timedelta(days=1)
+
+    root = Path(output_path) if output_path is not None else cache_dir() / "obs"
+
+    tasks = []
+    for t in _epochs(start, end, step):
+        name = f"{station}_R_{t:%Y%j%H%M}_{period}_{sample_rate.upper()}_MO.crx.gz"
+        tasks.append((dc.url(template, t, name), root / station[:4] / name))
+    return _fetch_all(tasks, retries=retries)
+
+
+def _brdc_name(t: datetime, constellation: str = "mixed") -> str:
+    try:
+        code = _NAV_TYPES[constellation.lower()]
+    except KeyError:
+        raise ValueError(
+            f"Unknown constellation '{constellation}': expected one of {sorted(_NAV_TYPES)}"
+        ) from None
+    return f"BRDC00IGS_R_{t:%Y%j}0000_01D_{code}N.rnx.gz"
+
+
+def _brdc_dir(t: datetime) -> Path:
+    return cache_dir() / "brdc" / f"{t:%Y}"
+
+
+def _brdc_max_age(t: datetime) -> Optional[timedelta]:
+    """Files of days not yet complete are updated by the data centers during the day."""
+    now = datetime.now(timezone.utc).replace(tzinfo=None)
+    return _INCOMPLETE_MAX_AGE if t + timedelta(days=1, hours=1) > now else None
+
+
+def fetch_nav(
+    start: DateLike,
+    end: Optional[DateLike] = None,
+    output_path: Optional[Union[str, Path]] = None,
+    center: str = "bkg",
+    retries: int = 3,
+) -> list[Path]:
+    """
+    Download daily merged broadcast navigation files (BRDC) from an IGS data center.
+
+    Parameters
+    ----------
+    start : datetime.date or datetime.datetime
+        First day to download.
+    end : datetime.date or datetime.datetime, optional
+        Last day to download, included; by default only `start`.
+    output_path : str or pathlib.Path, optional
+        Directory of the downloads; by default the yearly 'brdc' directories of
+        the local cache (see `cache_dir`), shared with `fetch_brdc`.
+    center : {'bkg', 'cddis', 'ign'}, optional
+        Data center, by default BKG. CDDIS requires an Earthdata login.
+    retries : int, optional
+        Number of retries of each download after transient failures.
+
+    Returns
+    -------
+    list[pathlib.Path]
+        Local paths of the available files.
+    """
+    dc = get_data_center(center)
+    available = []
+    for t in _epochs(start, end, timedelta(days=1)):
+        name = _brdc_name(t)
+        directory = Path(output_path) if output_path is not None else _brdc_dir(t)
+        task = (dc.url(dc.daily_nav, t, name), directory / name)
+        available += _fetch_all([task], retries=retries, max_age=_brdc_max_age(t))
+    return available
+
+
+def fetch_brdc(
+    day: DateLike,
+    constellation: str = "mixed",
+    centers: Sequence[str] = ("bkg", "ign", "cddis"),
+    retries: int = 3,
+) -> Path:
+    """
+    Get the merged broadcast navigation file (BRDC) of a day, downloading it if needed.
+
+    Files are kept in the local cache directory (see `cache_dir`), so that
+    processing several stations or reprocessing the same day does not download
+    the file again; files of the current day, still being updated, are refreshed
+    hourly. Data centers are tried in order until one provides the file.
+
+    Parameters
+    ----------
+    day : datetime.date or datetime.datetime
+        Day of the navigation file, e.g. the day of the observation file.
+    constellation : {'mixed', 'gps', 'glonass', 'galileo', 'beidou'}, optional
+        Constellations of the navigation file, by default all of them.
+    centers : sequence of str, optional
+        Data centers, in order of preference.
+    retries : int, optional
+        Number of retries of each download after transient failures.
+
+    Returns
+    -------
+    pathlib.Path
+        Local path of the navigation file, ready for `read_rinex_nav`.
+
+    Raises
+    ------
+    FileNotFoundError
+        If no data center provides the file.
+    """
+    t = _epochs(day, None, timedelta(days=1))[0]
+    name = _brdc_name(t, constellation)
+    dest = _brdc_dir(t) / name
+    for center in centers:
+        dc = get_data_center(center)
+        task = (dc.url(dc.daily_nav, t, name), dest)
+        if _fetch_all([task], retries=retries, max_age=_brdc_max_age(t)):
+            return dest
+    raise FileNotFoundError(f"{name} is not available from {', '.join(centers)}")
@@ -0,0 +1,31 @@
+from .conversions import ecef_to_geodetic, geodetic_to_ecef
+from .topocentric import ecef_to_enu, enu_to_ecef
+from .magnetic import (
+    add_geomagnetic_coordinates,
+    geomagnetic_coordinates,
+    geomagnetic_pole,
+    magnetic_local_time,
+)
+from .network import common_view, shared_ipp_coverage, station_baselines
+from .solar import add_solar_geometry, local_solar_time, solar_zenith_angle
+from .troposphere import add_tropospheric_delay, niell_mapping, saastamoinen
+
+__all__ = [
+    "add_geomagnetic_coordinates",
+    "add_solar_geometry",
+    "add_tropospheric_delay",
+    "common_view",
+    "ecef_to_enu",
+    "ecef_to_geodetic",
+    "enu_to_ecef",
+    "geodetic_to_ecef",
+    "geomagnetic_coordinates",
+    "geomagnetic_pole",
+    "local_solar_time",
+    "magnetic_local_time",
+    "niell_mapping",
+    "saastamoinen",
+    "shared_ipp_coverage",
+    "solar_zenith_angle",
+    "station_baselines",
+]
@@ -0,0 +1,71 @@
+from typing import Union
+
+import numpy as np
+import polars as pl
+
+from ..pytecgg import (
+    ecef_to_geodetic as _ecef_to_geodetic,
+    geodetic_to_ecef as _geodetic_to_ecef,
+)
+
+ArrayLike = Union[pl.Series, np.ndarray, list[float], float]
+
+
+def _to_series(values: ArrayLike, name: str) -> pl.Series:
+    if isinstance(values, pl.Series):
+        return values.alias(name)
+    return pl.Series(name, np.atleast_1d(np.asarray(values, dtype=np.float64)))
+
+
+def _apply(func, inputs: tuple[ArrayLike, ...], names: tuple[str, ...], *args):
+    """Runs a vectorized Rust transformation, returning the same kind of input."""
+    outputs = func(*(_to_series(v, n) for v, n in zip(inputs, names)), *args)
+    if any(isinstance(v, pl.Series) for v in inputs):
+        return outputs
+    if all(np.ndim(v) == 0 for v in inputs):
+        return tuple(float(s[0]) if s[0] is not None else np.nan for s in outputs)
+    return tuple(s.to_numpy() for s in outputs)
+
+
+def ecef_to_geodetic(x: ArrayLike, y: ArrayLike, z: ArrayLike) -> tuple:
+    """
+    Convert ECEF coordinates into WGS-84 geodetic coordinates.
+
+    The conversion is vectorized in the Rust backend (Bowring's method refined
+    by fixed-point iteration), which is much faster than point-wise Python
+    implementations on large datasets.
+
+    Parameters
+    ----------
+    x, y, z : pl.Series, np.ndarray or float
+        ECEF coordinates in meters.
+
+    Returns
+    -------
+    tuple
+        Latitude and longitude (degrees) and ellipsoidal height (meters), as
+        Series named 'lat', 'lon', 'height' if the inputs are Series, as numpy
+        arrays if they are arrays, or as floats if they are scalars.
+    """
+    return _apply(_ecef_to_geodetic, (x, y, z), ("x", "y", "z"))
+
+
+def geodetic_to_ecef(lat: ArrayLike, lon: ArrayLike, height: ArrayLike) -> tuple:
+    """
+    Convert WGS-84 geodetic coordinates into ECEF coordinates.
+
+    Parameters
+    ----------
+    lat, lon : pl.Series, np.ndarray or float
+        Geodetic latitude and longitude in degrees.
+    height : pl.Series, np.ndarray or float
+        Ellipsoidal height in meters.
+
+    Returns
+    -------
+    tuple
+        ECEF coordinates in meters, as Series named 'x', 'y', 'z' if the inputs
+        are Series, as numpy arrays if they are arrays, or as floats if they
+        are scalars.
+    """
+    return _apply(_geodetic_to_ecef, (lat, lon, height), ("lat", "lon", "height"))
@@ -0,0 +1,187 @@
+from functools import lru_cache
+from typing import Optional
+
+import numpy as np
+import polars as pl
+
+from .conversions import ArrayLike
+from .solar import EpochLike, _julian_days, _subsolar_point
+
+__all__ = [
+    "geomagnetic_pole",
+    "geomagnetic_coordinates",
+    "magnetic_local_time",
+    "add_geomagnetic_coordinates",
+]
+
+
+@lru_cache(maxsize=1)
+def _dipole_coefficients() -> tuple[np.ndarray, np.ndarray, np.ndarray, np.ndarray]:
+    """Decimal years and first-degree (g10, g11, h11) IGRF coefficients, in nT."""
+    from ppigrf.ppigrf import read_shc
+
+    g, h = read_shc()
+    years = np.asarray(g.index.year + (g.index.dayofyear - 1) / 365.25, dtype=float)
+    return (
+        years,
+        g[(1, 0)].to_numpy(dtype=float),
+        g[(1, 1)].to_numpy(dtype=float),
+        h[(1, 1)].to_numpy(dtype=float),
+    )
+
+
+def geomagnetic_pole(epochs: EpochLike) -> tuple[np.ndarray, np.ndarray]:
+    """
+    Geographic latitude and longitude of the IGRF geomagnetic (dipole) north pole.
+
+    The pole of the centered dipole is derived from the first-degree IGRF
+    coefficients, linearly interpolated at the given epochs.
+
+    Parameters
+    ----------
+    epochs : pl.Series, np.ndarray or datetime
+        Epochs at which the pole is evaluated.
+
+    Returns
+    -------
+    tuple[np.ndarray, np.ndarray]
+        Latitude and longitude of the pole in degrees (e.g., about 80.7°N,
+        72.7°W in 2020).
+    """
+    years, g10, g11, h11 = _dipole_coefficients()
+    decimal_years = 2000.0 + (_julian_days(epochs) - 2451544.5) / 365.25
+    g10, g11, h11 = (np.interp(decimal_years, years, c) for c in (g10, g11, h11))
+
+    b0 = np.sqrt(g10**2 + g11**2 + h11**2)
+    lat = 90.0 - np.degrees(np.arccos(-g10 / b0))
+    lon = np.degrees(np.arctan2(-h11, -g11))
+    return lat, lon
+
+
+def _resolve_pole(
+    epochs: Optional[EpochLike], pole: Optional[tuple[float, float]]
+) -> tuple[np.ndarray, np.ndarray]:
+    if pole is not None:
+        return np.asarray(pole[0], dtype=float), np.asarray(pole[1], dtype=float)
+    if epochs is None:
+        raise ValueError("Either 'epochs' or 'pole' must be given")
+    return geomagnetic_pole(epochs)
+
+
+def geomagnetic_coordinates(
+    lat: ArrayLike,
+    lon: ArrayLike,
+    epochs: Optional[EpochLike] = None,
+    pole: Optional[tuple[float, float]] = None,
+) -> tuple[np.ndarray, np.ndarray]:
+    """
+    Convert geographic coordinates into centered-dipole geomagnetic coordinates.
+
+    Parameters
+    ----------
+    lat, lon : pl.Series, np.ndarray or float
+        Geographic latitude and longitude in degrees (e.g., of the IPPs).
+    epochs : pl.Series, np.ndarray or datetime, optional
+        Epochs at which the IGRF dipole pole is evaluated.
+    pole : tuple[float, float], optional
+        Latitude and longitude of the geomagnetic north pole in degrees, used
+        instead of the IGRF pole at `epochs`.
+
+    Returns
+    -------
+    tuple[np.ndarray, np.ndarray]
+        Geomagnetic latitude and longitude in degrees; the geomagnetic prime
+        meridian contains the geographic south pole.
+    """
+    pole_lat, pole_lon = np.radians(_resolve_pole(epochs, pole))
+    lat = np.radians(np.asarray(lat, dtype=float))
+    dlon = np.radians(np.asarray(lon, dtype=float)) - pole_lon
+
+    sin_mlat = np.sin(lat) * np.sin(pole_lat) + np.cos(lat) * np.cos(pole_lat) * np.cos(dlon)
+    mlat = np.degrees(np.arcsin(np.clip(sin_mlat, -1.0, 1.0)))
+    mlon = np.degrees(
+        np.arctan2(
+            np.cos(lat) * np.sin(dlon),
+            np.sin(pole_lat) * np.cos(lat) * np.cos(dlon) - np.cos(pole_lat) * np.sin(lat),
+        )
+    )
+    return mlat, mlon
+
+
+def magnetic_local_time(
+    lat: ArrayLike,
+    lon: ArrayLike,
+    epochs: EpochLike,
+    pole: Optional[tuple[float, float]] = None,
+) -> np.ndarray:
+    """
+    Compute the magnetic local time (MLT) of geographic locations.
+
+    MLT is the geomagnetic longitude difference from the anti-solar magnetic
+    meridian, in hours: it is 12 h on the magnetic meridian of the subsolar point.
+
+    Parameters
+    ----------
+    lat, lon : pl.Series, np.ndarray or float
+        Geographic latitude and longitude in degrees.
+    epochs : pl.Series, np.ndarray or datetime
+        UTC epochs of the locations.
+    pole : tuple[float, float], optional
+        Latitude and longitude of the geomagnetic north pole in degrees, used
+        instead of the IGRF pole at `epochs`.
+
+    Returns
+    -------
+    np.ndarray
+        Magnetic local time in hours, in [0, 24).
+    """
+    pole = tuple(_resolve_pole(epochs, pole))
+    _, mlon = geomagnetic_coordinates(lat, lon, pole=pole)
+    _, mlon_sun = geomagnetic_coordinates(*_subsolar_point(epochs), pole=pole)
+    return ((mlon - mlon_sun) / 15.0 + 12.0) % 24.0
+
+
+def add_geomagnetic_coordinates(
+    df: pl.DataFrame,
+    lat: str = "lat_ipp",
+    lon: str = "lon_ipp",
+    epoch: str = "epoch",
+    pole: Optional[tuple[float, float]] = None,
+) -> pl.DataFrame:
+    """
+    Add geomagnetic latitude, longitude and magnetic local time to a DataFrame.
+
+    Typically applied to the output of `calculate_ipp`, so that TEC can be
+    organized in magnetic coordinates.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with geographic latitude and longitude (degrees) and epochs.
+    lat, lon, epoch : str, optional
+        Names of the latitude, longitude and epoch columns.
+    pole : tuple[float, float], optional
+        Latitude and longitude of the geomagnetic north pole in degrees, used
+        instead of the IGRF pole at each epoch.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with added columns 'mlat', 'mlon' (degrees) and 'mlt' (hours).
+    """
+    if df.is_empty():
+        return df.with_columns(
+            pl.lit(None, pl.Float64).alias(c) for c in ("mlat", "mlon", "mlt")
+        )
+
+    epochs = df[epoch]
+    pole = tuple(_resolve_pole(epochs, pole))
+    lats, lons = df[lat].to_numpy(), df[lon].to_numpy()
+    mlat, mlon = geomagnetic_coordinates(lats, lons, pole=pole)
+    mlt = magnetic_local_time(lats, lons, epochs, pole=pole)
+
+    return df.with_columns(
+        pl.Series("mlat", mlat),
+        pl.Series("mlon", mlon),
+        pl.Series("mlt", mlt),
+    )
@@ -0,0 +1,240 @@
+from datetime import timedelta
+from itertools import combinations
+from typing import Optional, Union
+
+import numpy as np
+import polars as pl
+
+from .conversions import ecef_to_geodetic
+from .topocentric import ecef_to_enu
+from pytecgg.settings import get_settings
+
+_BASELINE_SCHEMA = {
+    "station_a": pl.String,
+    "station_b": pl.String,
+    "length": pl.Float64,
+    "distance": pl.Float64,
+    "azimuth": pl.Float64,
+    "east": pl.Float64,
+    "north": pl.Float64,
+    "up": pl.Float64,
+}
+
+_WINDOW_SCHEMA = {
+    "station_a": pl.String,
+    "station_b": pl.String,
+    "sv": pl.String,
+    "start": pl.Datetime("us", "UTC"),
+    "end": pl.Datetime("us", "UTC"),
+    "duration": pl.Duration("us"),
+    "n_epochs": pl.UInt32,
+}
+
+_COVERAGE_SCHEMA = {
+    "station_a": pl.String,
+    "station_b": pl.String,
+    "cells_a": pl.UInt32,
+    "cells_b": pl.UInt32,
+    "shared_cells": pl.UInt32,
+    "overlap": pl.Float64,
+}
+
+
+def _great_circle_km(
+    lat_a: np.ndarray, lon_a: np.ndarray, lat_b: np.ndarray, lon_b: np.ndarray
+) -> np.ndarray:
+    """Great-circle distances (km) between every point of A (rows) and of B (columns)."""
+    earth_radius_km = get_settings().earth_radius / 1_000
+    lat_a, lon_a = np.radians(lat_a)[:, None], np.radians(lon_a)[:, None]
+    lat_b, lon_b = np.radians(lat_b)[None, :], np.radians(lon_b)[None, :]
+    h = (
+        np.sin((lat_b - lat_a) / 2) ** 2
+        + np.cos(lat_a) * np.cos(lat_b) * np.sin((lon_b - lon_a) / 2) ** 2
+    )
+    return 2 * earth_radius_km * np.arcsin(np.sqrt(np.clip(h, 0.0, 1.0)))
+
+
+def station_baselines(stations: dict[str, tuple[float, float, float]]) -> pl.DataFrame:
+    """
+    Compute the baselines between every pair of stations.
+
+    Parameters
+    ----------
+    stations : dict[str, tuple[float, float, float]]
+        ECEF positions of the stations in meters, keyed by station name (e.g.,
+        `ctx.receiver_pos` per `ctx.receiver_name`).
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per pair of stations (in the order of `stations`), with columns
+        'station_a', 'station_b', 'length' (straight-line baseline, m), 'distance'
+        (great-circle distance on the sphere of the settings, km), 'azimuth'
+        (degrees from north, of station B seen from station A) and 'east', 'north',
+        'up' (components of the baseline in the local frame of station A, m).
+    """
+    rows = []
+    for (name_a, pos_a), (name_b, pos_b) in combinations(stations.items(), 2):
+        east, north, up = ecef_to_enu(*pos_b, reference=tuple(pos_a))
+        lat_a, lon_a, _ = ecef_to_geodetic(*pos_a)
+        lat_b, lon_b, _ = ecef_to_geodetic(*pos_b)
+        distance = _great_circle_km(
+            np.array([lat_a]), np.array([lon_a]), np.array([lat_b]), np.array([lon_b])
+        )[0, 0]
+        rows.append(
+            (
+                name_a,
+                name_b,
+                float(np.linalg.norm(np.subtract(pos_b, pos_a))),
+                float(distance),
+                float(np.degrees(np.arctan2(east, north)) % 360),
+                east,
+                north,
+                up,
+            )
+        )
+    return pl.DataFrame(rows, schema=_BASELINE_SCHEMA, orient="row")
+
+
+def _visible(df: pl.DataFrame, min_elevation: Optional[float]) -> pl.DataFrame:
+    visible = df.select(pl.col("epoch").cast(pl.Datetime("us", "UTC")), "sv", "ele")
+    visible = visible.filter(pl.col("ele").is_not_null() & pl.col("ele").is_not_nan())
+    if min_elevation is not None:
+        visible = visible.filter(pl.col("ele") >= min_elevation)
+    return visible.select("epoch", "sv").unique()
+
+
+def common_view(
+    geometry: dict[str, pl.DataFrame],
+    min_elevation: Optional[float] = None,
+    max_gap: Optional[timedelta] = None,
+    min_duration: Optional[timedelta] = None,
+) -> pl.DataFrame:
+    """
+    Find the windows in which each satellite is seen from both stations of each pair.
+
+    Parameters
+    ----------
+    geometry : dict[str, pl.DataFrame]
+        Satellite geometry keyed by station name, with columns 'epoch', 'sv' and
+        'ele' (degrees), e.g. as returned by `calculate_ipp` or `annotate_geometry`.
+    min_elevation : float, optional
+        Elevation mask in degrees, applied at both stations. By default, that of
+        the package settings (none, unless set with `pytecgg.configure`).
+    max_gap : timedelta, optional
+        Largest interval between consecutive common epochs within a window; by
+        default, twice the most frequent interval of the pair.
+    min_duration : timedelta, optional
+        Shortest window kept; by default, all the windows are kept.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per window, sorted by pair of stations, start and satellite, with
+        columns 'station_a', 'station_b', 'sv', 'start', 'end', 'duration' and
+        'n_epochs' (number of common epochs).
+    """
+    if min_elevation is None:
+        min_elevation = get_settings().min_elevation
+
+    windows = [pl.DataFrame(schema=_WINDOW_SCHEMA)]
+    visible = {name: _visible(df, min_elevation) for name, df in geometry.items()}
+    for name_a, name_b in combinations(visible, 2):
+        common = visible[name_a].join(visible[name_b], on=["epoch", "sv"]).sort("sv", "epoch")
+        if common.is_empty():
+            continue
+        gap = max_gap
+        if gap is None:
+            steps = common.select(pl.col("epoch").diff().over("sv")).drop_nulls()["epoch"]
+            gap = 2 * steps.mode().sort()[0] if len(steps) else timedelta(0)
+
+        runs = (
+            common.with_columns(
+                (pl.col("epoch").diff().over("sv") > gap)
+                .fill_null(True)
+                .cum_sum()
+                .over("sv")
+                .alias("_window")
+            )
+            .group_by("sv", "_window")
+            .agg(
+                pl.col("epoch").min().alias("start"),
+                pl.col("epoch").max().alias("end"),
+                pl.len().alias("n_epochs"),
+            )
+            .with_columns(
+                pl.lit(name_a).alias("station_a"),
+                pl.lit(name_b).alias("station_b"),
+                (pl.col("end") - pl.col("start")).alias("duration"),
+            )
+        )
+        if min_duration is not None:
+            runs = runs.filter(pl.col("duration") >= min_duration)
+        windows.append(runs.select(list(_WINDOW_SCHEMA)).cast(_WINDOW_SCHEMA))
+
+    return pl.concat(windows).sort("station_a", "station_b", "start", "sv")
+
+
+def shared_ipp_coverage(
+    ipps: dict[str, pl.DataFrame],
+    resolution: float = 1.0,
+    cadence: Optional[Union[str, timedelta]] = None,
+) -> pl.DataFrame:
+    """
+    Measure the overlap of the IPP coverage of every pair of stations.
+
+    The IPPs of each station are binned on a regular latitude/longitude grid (and,
+    optionally, in time), and the cells reached by both stations of a pair are
+    counted, e.g. to choose the station pairs of a differential TEC analysis or to
+    assess the redundancy of a network.
+
+    Parameters
+    ----------
+    ipps : dict[str, pl.DataFrame]
+        IPPs keyed by station name, with columns 'lat_ipp' and 'lon_ipp' (degrees)
+        and, with `cadence`, 'epoch'.
+    resolution : float, optional
+        Size of the grid cells in degrees, by default 1°.
+    cadence : str or timedelta, optional
+        Length of the time bins (e.g., '5m'): cells are only shared if both
+        stations reach them within the same bin. By default, the coverage over the
+        whole period is compared.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per pair of stations, with columns 'station_a', 'station_b',
+        'cells_a', 'cells_b' (cells reached by each station), 'shared_cells' and
+        'overlap' (shared cells over the cells reached by either station).
+    """
+    keys = ["_lat", "_lon"]
+    if cadence is not None:
+        keys.append("epoch")
+        if isinstance(cadence, timedelta):
+            cadence = f"{int(cadence.total_seconds() * 1e6)}us"
+
+    cells = {}
+    for name, df in ipps.items():
+        binned = df.drop_nulls(["lat_ipp", "lon_ipp"]).with_columns(
+            (pl.col("lat_ipp") / resolution).floor().cast(pl.Int64).alias("_lat"),
+            (pl.col("lon_ipp") / resolution).floor().cast(pl.Int64).alias("_lon"),
+        )
+        if cadence is not None:
+            binned = binned.with_columns(pl.col("epoch").dt.truncate(cadence))
+        cells[name] = binned.select(keys).unique()
+
+    rows = []
+    for name_a, name_b in combinations(cells, 2):
+        shared = cells[name_a].join(cells[name_b], on=keys).height
+        union = cells[name_a].height + cells[name_b].height - shared
+        rows.append(
+            (
+                name_a,
+                name_b,
+                cells[name_a].height,
+                cells[name_b].height,
+                shared,
+                shared / union if union else None,
+            )
+        )
+    return pl.DataFrame(rows, schema=_COVERAGE_SCHEMA, orient="row")
@@ -0,0 +1,132 @@
+from datetime import datetime
+from typing import Union
+
+import numpy as np
+import polars as pl
+
+from .conversions import ArrayLike
+
+__all__ = ["solar_zenith_angle", "local_solar_time", "add_solar_geometry"]
+
+EpochLike = Union[pl.Series, np.ndarray, datetime]
+
+# Julian date of the J2000.0 epoch
+_J2000 = 2451545.0
+# Julian date of the Unix epoch
+_JD_UNIX = 2440587.5
+
+
+def _julian_days(epochs: EpochLike) -> np.ndarray:
+    """Julian dates of UTC epochs given as Datetime Series, datetime64 arrays or datetimes."""
+    if isinstance(epochs, pl.Series):
+        epochs = epochs.dt.replace_time_zone(None).to_numpy()
+    elif isinstance(epochs, datetime):
+        if epochs.tzinfo is not None:
+            epochs = epochs.replace(tzinfo=None) - epochs.utcoffset()
+        epochs = np.datetime64(epochs)
+    micros = np.atleast_1d(np.asarray(epochs, dtype="datetime64[us]")).astype(np.int64)
+    return _JD_UNIX + micros / 86_400e6
+
+
+def _subsolar_point(epochs: EpochLike) -> tuple[np.ndarray, np.ndarray]:
+    """
+    Geographic latitude and longitude (degrees) of the subsolar point.
+
+    Based on the low-precision solar coordinates of the Astronomical Almanac,
+    accurate to about 0.01° between 1950 and 2050.
+    """
+    n = _julian_days(epochs) - _J2000
+    mean_lon = np.radians(280.460 + 0.9856474 * n)
+    anomaly = np.radians(357.528 + 0.9856003 * n)
+    ecl_lon = mean_lon + np.radians(1.915 * np.sin(anomaly) + 0.020 * np.sin(2 * anomaly))
+    obliquity = np.radians(23.439 - 4e-7 * n)
+
+    right_ascension = np.arctan2(np.cos(obliquity) * np.sin(ecl_lon), np.cos(ecl_lon))
+    declination = np.arcsin(np.sin(obliquity) * np.sin(ecl_lon))
+    gmst = np.radians(280.46061837 + 360.98564736629 * n)
+
+    lon = np.degrees(right_ascension - gmst)
+    return np.degrees(declination), (lon + 180.0) % 360.0 - 180.0
+
+
+def solar_zenith_angle(lat: ArrayLike, lon: ArrayLike, epochs: EpochLike) -> np.ndarray:
+    """
+    Compute the solar zenith angle (SZA) at geographic locations.
+
+    Parameters
+    ----------
+    lat, lon : pl.Series, np.ndarray or float
+        Geographic latitude and longitude in degrees (e.g., of the IPPs).
+    epochs : pl.Series, np.ndarray or datetime
+        UTC epochs of the locations.
+
+    Returns
+    -------
+    np.ndarray
+        Solar zenith angle in degrees, from 0 (Sun at zenith) to 180; the Sun
+        is below the horizon beyond 90°.
+    """
+    sun_lat, sun_lon = np.radians(_subsolar_point(epochs))
+    lat = np.radians(np.asarray(lat, dtype=float))
+    dlon = np.radians(np.asarray(lon, dtype=float)) - sun_lon
+
+    cos_sza = np.sin(lat) * np.sin(sun_lat) + np.cos(lat) * np.cos(sun_lat) * np.cos(dlon)
+    return np.degrees(np.arccos(np.clip(cos_sza, -1.0, 1.0)))
+
+
+def local_solar_time(lon: ArrayLike, epochs: EpochLike) -> np.ndarray:
+    """
+    Compute the apparent local solar time at geographic longitudes.
+
+    Unlike the mean local time (UT + longitude / 15), the apparent solar time
+    includes the equation of time, so that it is 12 h exactly at solar noon.
+
+    Parameters
+    ----------
+    lon : pl.Series, np.ndarray or float
+        Geographic longitude in degrees (e.g., of the IPPs).
+    epochs : pl.Series, np.ndarray or datetime
+        UTC epochs of the locations.
+
+    Returns
+    -------
+    np.ndarray
+        Local solar time in hours, in [0, 24).
+    """
+    _, sun_lon = _subsolar_point(epochs)
+    return ((np.asarray(lon, dtype=float) - sun_lon) / 15.0 + 12.0) % 24.0
+
+
+def add_solar_geometry(
+    df: pl.DataFrame,
+    lat: str = "lat_ipp",
+    lon: str = "lon_ipp",
+    epoch: str = "epoch",
+) -> pl.DataFrame:
+    """
+    Add the solar zenith angle and the local solar time to a DataFrame.
+
+    Typically applied to the output of `calculate_ipp`, e.g. to separate
+    daytime and nighttime TEC.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with geographic latitude and longitude (degrees) and epochs.
+    lat, lon, epoch : str, optional
+        Names of the latitude, longitude and epoch columns.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with added columns 'sza' (degrees) and 'lst' (hours).
+    """
+    if df.is_empty():
+        return df.with_columns(pl.lit(None, pl.Float64).alias(c) for c in ("sza", "lst"))
+
+    epochs = df[epoch]
+    lons = df[lon].to_numpy()
+    return df.with_columns(
+        pl.Series("sza", solar_zenith_angle(df[lat].to_numpy(), lons, epochs)),
+        pl.Series("lst", local_solar_time(lons, epochs)),
+    )
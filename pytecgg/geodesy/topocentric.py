@@ -0,0 +1,74 @@
+from ..pytecgg import (
+    ecef_to_enu as _ecef_to_enu,
+    enu_to_ecef as _enu_to_ecef,
+)
+from .conversions import ArrayLike, _apply
+
+
+def ecef_to_enu(
+    x: ArrayLike,
+    y: ArrayLike,
+    z: ArrayLike,
+    reference: tuple[float, float, float],
+    velocity: bool = False,
+) -> tuple:
+    """
+    Convert ECEF coordinates into the local East-North-Up (topocentric) frame.
+
+    Positions are expressed relative to the reference site (e.g., a baseline
+    from the receiver), while vectors such as velocities are only rotated into
+    the local frame (e.g., for range-rate analysis).
+
+    Parameters
+    ----------
+    x, y, z : pl.Series, np.ndarray or float
+        ECEF coordinates in meters, or ECEF vector components with `velocity`.
+    reference : tuple[float, float, float]
+        ECEF position of the reference site in meters, defining the local frame.
+    velocity : bool, optional
+        If True, the inputs are vectors that are rotated without subtracting the
+        reference position.
+
+    Returns
+    -------
+    tuple
+        East, north and up components, as Series named 'east', 'north', 'up' if
+        the inputs are Series, as numpy arrays if they are arrays, or as floats
+        if they are scalars.
+    """
+    return _apply(_ecef_to_enu, (x, y, z), ("x", "y", "z"), tuple(reference), velocity)
+
+
+def enu_to_ecef(
+    east: ArrayLike,
+    north: ArrayLike,
+    up: ArrayLike,
+    reference: tuple[float, float, float],
+    velocity: bool = False,
+) -> tuple:
+    """
+    Convert local East-North-Up components of a reference site into ECEF coordinates.
+
+    Parameters
+    ----------
+    east, north, up : pl.Series, np.ndarray or float
+        Local components in meters, or local vector components with `velocity`.
+    reference : tuple[float, float, float]
+        ECEF position of the reference site in meters, defining the local frame.
+    velocity : bool, optional
+        If True, the inputs are vectors that are rotated without adding the
+        reference position.
+
+    Returns
+    -------
+    tuple
+        ECEF components, as Series named 'x', 'y', 'z' if the inputs are Series,
+        as numpy arrays if they are arrays, or as floats if they are scalars.
+    """
+    return _apply(
+        _enu_to_ecef,
+        (east, north, up),
+        ("east", "north", "up"),
+        tuple(reference),
+        velocity,
+    )
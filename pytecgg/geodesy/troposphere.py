@@ -0,0 +1,217 @@
+from typing import Optional
+
+import numpy as np
+import polars as pl
+
+from .conversions import ArrayLike, ecef_to_geodetic
+from .solar import EpochLike, _julian_days
+
+__all__ = ["saastamoinen", "niell_mapping", "add_tropospheric_delay"]
+
+# Latitudes (degrees) of the Niell (1996) coefficient tables
+_NMF_LATITUDES = np.array([15.0, 30.0, 45.0, 60.0, 75.0])
+
+# Hydrostatic coefficients: average and seasonal amplitude, and height correction
+_NMF_HYDRO_AVG = np.array(
+    [
+        [1.2769934e-3, 1.2683230e-3, 1.2465397e-3, 1.2196049e-3, 1.2045996e-3],
+        [2.9153695e-3, 2.9152299e-3, 2.9288445e-3, 2.9022565e-3, 2.9024912e-3],
+        [62.610505e-3, 62.837393e-3, 63.721774e-3, 63.824265e-3, 64.258455e-3],
+    ]
+)
+_NMF_HYDRO_AMP = np.array(
+    [
+        [0.0, 1.2709626e-5, 2.6523662e-5, 3.4000452e-5, 4.1202191e-5],
+        [0.0, 2.1414979e-5, 3.0160779e-5, 7.2562722e-5, 11.723375e-5],
+        [0.0, 9.0128400e-5, 4.3497037e-5, 84.795348e-5, 170.37206e-5],
+    ]
+)
+_NMF_HEIGHT = (2.53e-5, 5.49e-3, 1.14e-3)
+
+_NMF_WET = np.array(
+    [
+        [5.8021897e-4, 5.6794847e-4, 5.8118019e-4, 5.9727542e-4, 6.1641693e-4],
+        [1.4275268e-3, 1.5138625e-3, 1.4572752e-3, 1.5007428e-3, 1.7599082e-3],
+        [4.3472961e-2, 4.6729510e-2, 4.3908931e-2, 4.4626982e-2, 5.4736038e-2],
+    ]
+)
+
+# Modified Julian date of the 28th of January 1980, the phase of the seasonal terms
+_MJD_NMF_PHASE = 44239.0 + 27.0
+
+
+def _marini(sin_el: np.ndarray, a, b, c) -> np.ndarray:
+    """Continued fraction of Marini (1972), normalized to one at the zenith."""
+    return (1 + a / (1 + b / (1 + c))) / (sin_el + a / (sin_el + b / (sin_el + c)))
+
+
+def saastamoinen(
+    lat: ArrayLike,
+    height: ArrayLike,
+    pressure: Optional[ArrayLike] = None,
+    temperature: Optional[ArrayLike] = None,
+    humidity: Optional[ArrayLike] = None,
+) -> tuple[np.ndarray, np.ndarray]:
+    """
+    Compute the zenith hydrostatic and wet tropospheric delays of Saastamoinen.
+
+    Missing meteorological values are taken from a standard atmosphere (1013.25 hPa
+    and 15 °C at sea level, 70% relative humidity), sufficient for code-based
+    processing when no measurements are available.
+
+    Parameters
+    ----------
+    lat : pl.Series, np.ndarray or float
+        Geodetic latitude in degrees.
+    height : pl.Series, np.ndarray or float
+        Height in meters (ellipsoidal heights are close enough).
+    pressure : pl.Series, np.ndarray or float, optional
+        Surface pressure in hPa (RINEX 'PR').
+    temperature : pl.Series, np.ndarray or float, optional
+        Dry temperature in °C (RINEX 'TD').
+    humidity : pl.Series, np.ndarray or float, optional
+        Relative humidity in percent (RINEX 'HR').
+
+    Returns
+    -------
+    tuple[np.ndarray, np.ndarray]
+        Zenith hydrostatic and wet delays in meters.
+    """
+    lat = np.radians(np.asarray(lat, dtype=float))
+    height = np.clip(np.asarray(height, dtype=float), 0.0, None)
+
+    if pressure is None:
+        pressure = 1013.25 * (1 - 2.2557e-5 * height) ** 5.2568
+    if temperature is None:
+        temperature = 15.0 - 6.5e-3 * height
+    if humidity is None:
+        humidity = 70.0
+    kelvin = np.asarray(temperature, dtype=float) + 273.15
+    pressure = np.asarray(pressure, dtype=float)
+    # Water vapour pressure (hPa)
+    vapour = (
+        6.108
+        * np.asarray(humidity, dtype=float)
+        / 100.0
+        * np.exp((17.15 * kelvin - 4684.0) / (kelvin - 38.45))
+    )
+
+    zhd = 0.0022768 * pressure / (1 - 0.00266 * np.cos(2 * lat) - 0.00028 * height / 1e3)
+    zwd = 0.002277 * (1255.0 / kelvin + 0.05) * vapour
+    return zhd, zwd
+
+
+def niell_mapping(
+    elevation: ArrayLike, lat: ArrayLike, height: ArrayLike, epochs: EpochLike
+) -> tuple[np.ndarray, np.ndarray]:
+    """
+    Compute the hydrostatic and wet mapping functions of Niell (1996).
+
+    The mapping functions scale the zenith delays to the slant delays at a given
+    elevation; they depend on the latitude, the day of year (hydrostatic seasonal
+    terms, reversed in the southern hemisphere) and the height.
+
+    Parameters
+    ----------
+    elevation : pl.Series, np.ndarray or float
+        Satellite elevation in degrees.
+    lat : pl.Series, np.ndarray or float
+        Geodetic latitude in degrees.
+    height : pl.Series, np.ndarray or float
+        Ellipsoidal height in meters.
+    epochs : pl.Series, np.ndarray or datetime
+        Epochs of the observations.
+
+    Returns
+    -------
+    tuple[np.ndarray, np.ndarray]
+        Hydrostatic and wet mapping functions (dimensionless, one at the zenith).
+    """
+    sin_el = np.sin(np.radians(np.asarray(elevation, dtype=float)))
+    lat = np.asarray(lat, dtype=float)
+    height = np.asarray(height, dtype=float)
+
+    abs_lat = np.abs(lat)
+    days = _julian_days(epochs) - 2400000.5 - _MJD_NMF_PHASE
+    days = np.where(lat < 0, days + 365.25 / 2, days)
+    season = np.cos(2 * np.pi * days / 365.25)
+
+    hydro = [
+        np.interp(abs_lat, _NMF_LATITUDES, avg) - np.interp(abs_lat, _NMF_LATITUDES, amp) * season
+        for avg, amp in zip(_NMF_HYDRO_AVG, _NMF_HYDRO_AMP)
+    ]
+    wet = [np.interp(abs_lat, _NMF_LATITUDES, coeffs) for coeffs in _NMF_WET]
+
+    height_correction = (1 / sin_el - _marini(sin_el, *_NMF_HEIGHT)) * height / 1e3
+    return _marini(sin_el, *hydro) + height_correction, _marini(sin_el, *wet)
+
+
+def add_tropospheric_delay(
+    df: pl.DataFrame,
+    receiver_pos: tuple[float, float, float],
+    met: Optional[pl.DataFrame] = None,
+    elevation: str = "ele",
+    epoch: str = "epoch",
+) -> pl.DataFrame:
+    """
+    Add the slant tropospheric delay of each observation to a DataFrame.
+
+    The zenith delays of Saastamoinen are mapped to the satellite elevation with the
+    Niell mapping functions. Meteorological measurements, if given, are matched to
+    the nearest epoch; the standard atmosphere is used otherwise. Typically applied
+    to the output of `calculate_ipp`, so that the delay can be removed from the code
+    observations (e.g., single point positioning, or sanity checks of code-levelled
+    TEC).
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with satellite elevations (degrees) and epochs.
+    receiver_pos : tuple[float, float, float]
+        ECEF receiver position in meters (e.g., `ctx.receiver_pos`).
+    met : pl.DataFrame, optional
+        Meteorological data with an 'epoch' column and the 'PR' (hPa), 'TD' (°C) and
+        'HR' (%) columns, as returned by `read_rinex_met`; missing columns or values
+        fall back to the standard atmosphere.
+    elevation, epoch : str, optional
+        Names of the elevation and epoch columns.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with an added 'tropo' column (slant delay in meters), null for
+        satellites below the horizon.
+    """
+    if df.is_empty():
+        return df.with_columns(pl.lit(None, pl.Float64).alias("tropo"))
+
+    lat, _, height = ecef_to_geodetic(*receiver_pos)
+
+    weather: dict[str, Optional[np.ndarray]] = {"PR": None, "TD": None, "HR": None}
+    if met is not None and not met.is_empty():
+        matched = (
+            df.select(pl.col(epoch).alias("epoch"))
+            .with_row_index("_row")
+            .sort("epoch")
+            .join_asof(
+                met.select("epoch", *(c for c in weather if c in met.columns)).sort("epoch"),
+                on="epoch",
+                strategy="nearest",
+            )
+            .sort("_row")
+        )
+        for code in weather:
+            if code in matched.columns:
+                weather[code] = matched[code].cast(pl.Float64).to_numpy()
+
+    zhd, zwd = saastamoinen(lat, height)
+    if any(v is not None for v in weather.values()):
+        met_zhd, met_zwd = saastamoinen(lat, height, weather["PR"], weather["TD"], weather["HR"])
+        # Fall back to the standard atmosphere for the epochs without measurements
+        zhd = np.where(np.isnan(met_zhd), zhd, met_zhd)
+        zwd = np.where(np.isnan(met_zwd), zwd, met_zwd)
+
+    ele = df[elevation].cast(pl.Float64).fill_null(np.nan).to_numpy()
+    mh, mw = niell_mapping(ele, lat, height, df[epoch])
+    tropo = np.where(ele > 0, zhd * mh + zwd * mw, np.nan)
+    return df.with_columns(pl.Series("tropo", tropo).fill_nan(None))
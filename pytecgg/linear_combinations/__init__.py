@@ -1,8 +1,34 @@
 from .lc_calculation import calculate_linear_combinations
 from .cs_lol_detection import detect_cs_lol
+from .mw import melbourne_wubbena
+from .iflc import ionosphere_free
+from .wl_nl import wide_lane, narrow_lane
+from .antenna import correct_antenna_phase_center
+from .windup import correct_phase_windup
+from .quarter_cycle import align_quarter_cycles, quarter_cycle_offsets
+from .doppler import doppler_conventions, normalize_doppler, range_rate
+from .differences import (
+    between_satellite_differences,
+    double_differences,
+    single_differences,
+)
 
 
 __all__ = [
     "calculate_linear_combinations",
     "detect_cs_lol",
+    "melbourne_wubbena",
+    "ionosphere_free",
+    "wide_lane",
+    "narrow_lane",
+    "correct_antenna_phase_center",
+    "correct_phase_windup",
+    "single_differences",
+    "double_differences",
+    "between_satellite_differences",
+    "quarter_cycle_offsets",
+    "align_quarter_cycles",
+    "doppler_conventions",
+    "normalize_doppler",
+    "range_rate",
 ]
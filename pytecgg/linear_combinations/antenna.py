@@ -0,0 +1,135 @@
+from typing import Optional
+
+import numpy as np
+import polars as pl
+
+from .constants import C
+from ..parsing.antex import Antex
+
+
+def _interpolate_pcv(pcv: pl.DataFrame, azimuth: np.ndarray, zenith: np.ndarray) -> np.ndarray:
+    """
+    Bilinear interpolation of the phase-center variations of one frequency, from the
+    azimuth-dependent grid if the calibration has one, from the 'NOAZI' values otherwise
+    """
+    grid = pcv.filter(pl.col("azimuth").is_not_null())
+    if grid.is_empty():
+        noazi = pcv.sort("zenith")
+        return np.interp(zenith, noazi["zenith"].to_numpy(), noazi["pcv"].to_numpy())
+
+    azimuths = np.unique(grid["azimuth"].to_numpy())
+    zeniths = np.unique(grid["zenith"].to_numpy())
+    values = (
+        grid.sort("azimuth", "zenith")["pcv"]
+        .to_numpy()
+        .reshape(len(azimuths), len(zeniths))
+    )
+    azimuth = np.mod(azimuth, 360.0)
+    i = np.clip(np.searchsorted(azimuths, azimuth, side="right") - 1, 0, len(azimuths) - 2)
+    w = np.clip((azimuth - azimuths[i]) / (azimuths[i + 1] - azimuths[i]), 0.0, 1.0)
+    lower = np.array([np.interp(z, zeniths, values[k]) for z, k in zip(zenith, i)])
+    upper = np.array([np.interp(z, zeniths, values[k + 1]) for z, k in zip(zenith, i)])
+    return (1 - w) * lower + w * upper
+
+
+def correct_antenna_phase_center(
+    obs_data: pl.DataFrame,
+    antex: Antex,
+    antenna_type: str,
+    radome: str = "NONE",
+    serial: Optional[str] = None,
+    glonass_channels: Optional[dict[str, int]] = None,
+) -> pl.DataFrame:
+    """
+    Correct carrier-phase observations for the receiver antenna phase-center
+    offsets (PCO) and variations (PCV), referring them to the antenna reference point.
+
+    The correction of each observation is the projection of the offset onto the line
+    of sight minus the variation interpolated at the satellite azimuth and zenith
+    angle, converted to cycles. Since the offsets and variations differ between
+    frequencies, applying it before `calculate_linear_combinations` removes their
+    elevation-dependent contribution to the geometry-free combination, hence to TEC.
+
+    Calibrations missing for a constellation (e.g., GPS-only calibrations) fall back
+    to the GPS calibration of the same band; observations left without a calibration
+    or a frequency (GLONASS without `glonass_channels`) are returned unchanged.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with the columns 'sv', 'observable', 'value'
+        (carrier phases in cycles) and the satellite 'azi' and 'ele' (degrees), e.g.
+        joined from the output of `calculate_ipp`.
+    antex : Antex
+        Antenna calibrations, as returned by `read_antex`.
+    antenna_type : str
+        IGS antenna type of the receiver (e.g., 'TRM59800.00').
+    radome : str, optional
+        Radome code, by default 'NONE'.
+    serial : str, optional
+        Antenna serial number, to use its individual calibration if available.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`).
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with corrected carrier phases; other observables are unchanged.
+
+    Raises
+    ------
+    KeyError
+        If `antex` has no calibration of the antenna and radome.
+    """
+    calibration = antex.antenna(antenna_type, radome, serial)
+    available = set(calibration.pco["frequency"].to_list())
+
+    df = obs_data.with_row_index("_row")
+    phases = df.filter(
+        pl.col("observable").str.starts_with("L")
+        & pl.col("value").is_not_null()
+        & pl.col("azi").is_not_null()
+        & pl.col("ele").is_not_null()
+    ).with_columns(
+        (pl.col("sv").str.slice(0, 1) + "0" + pl.col("observable").str.slice(1, 1)).alias(
+            "_code"
+        ),
+        pl.col("observable").gnss.frequency("sv", glonass_channels).alias("_freq"),
+    )
+    phases = phases.with_columns(
+        pl.when(pl.col("_code").is_in(list(available)))
+        .then(pl.col("_code"))
+        .otherwise("G0" + pl.col("observable").str.slice(1, 1))
+        .alias("_code")
+    ).filter(pl.col("_code").is_in(list(available)) & pl.col("_freq").is_not_null())
+
+    rows, corrections = [], []
+    for (code,), group in phases.group_by("_code"):
+        north, east, up = calibration.pco.filter(pl.col("frequency") == code).row(0)[4:]
+        azimuth = group["azi"].to_numpy()
+        elevation = group["ele"].to_numpy()
+        az, el = np.radians(azimuth), np.radians(elevation)
+        offset = (
+            east * np.cos(el) * np.sin(az) + north * np.cos(el) * np.cos(az) + up * np.sin(el)
+        )
+        pcv = calibration.pcv.filter(pl.col("frequency") == code)
+        variation = (
+            _interpolate_pcv(pcv, azimuth, 90.0 - elevation) if not pcv.is_empty() else 0.0
+        )
+        rows.append(group["_row"])
+        corrections.append(
+            pl.Series((offset - variation) * group["_freq"].to_numpy() / C)
+        )
+
+    if not rows:
+        return obs_data
+
+    correction = pl.DataFrame(
+        {"_row": pl.concat(rows), "_correction": pl.concat(corrections)}
+    )
+    return (
+        df.join(correction, on="_row", how="left")
+        .sort("_row")
+        .with_columns(pl.col("value") + pl.col("_correction").fill_null(0.0))
+        .drop("_row", "_correction")
+    )
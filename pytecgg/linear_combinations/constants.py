@@ -8,9 +8,10 @@ FREQ_BANDS: dict[str, dict] = {
         "L2": lambda n: (1246 + n * 0.4375) * 1e6,
     },
     "E": {"L1": 1575.42e6, "L5": 1176.45e6, "L7": 1207.14e6, "L8": 1191.795e6},
+    # Band naming of RINEX 3.03 and later: B1C in band 1, B1I in band 2
     "C": {
-        "L1": 1561.098e6,
-        "L2": 1589.742e6,
+        "L1": 1575.42e6,
+        "L2": 1561.098e6,
         "L5": 1176.45e6,
         "L6": 1268.52e6,
         "L7": 1207.14e6,
@@ -23,12 +24,12 @@ PHASE_FREQ_PRIORITY = {
     "G": [("L2", "L1"), ("L5", "L1")],
     "E": [("L5", "L1"), ("L7", "L1"), ("L8", "L1")],
     "C": [
-        ("L7", "L1"),
         ("L7", "L2"),
-        ("L6", "L1"),
         ("L6", "L2"),
-        ("L5", "L1"),
+        ("L7", "L1"),
+        ("L6", "L1"),
         ("L5", "L2"),
+        ("L5", "L1"),
     ],
     "R": [("L2", "L1")],
 }
@@ -36,16 +37,70 @@ CODE_FREQ_PRIORITY = {
     "G": [("C2", "C1"), ("C5", "C1")],
     "E": [("C5", "C1"), ("C7", "C1"), ("C8", "C1")],
     "C": [
-        ("C7", "C1"),
         ("C7", "C2"),
+        ("C6", "C2"),
+        ("C7", "C1"),
         ("C6", "C1"),
+        ("C5", "C2"),
+        ("C5", "C1"),
+    ],
+    "R": [("C2", "C1")],
+}
+
+# Up to RINEX 3.02, BeiDou B1I is recorded in band 1 (e.g. C1I/L1I), or in band 2 as in 3.01
+LEGACY_FREQ_BANDS: dict[str, dict] = {
+    **FREQ_BANDS,
+    "C": {**FREQ_BANDS["C"], "L1": 1561.098e6},
+}
+LEGACY_PHASE_FREQ_PRIORITY = {
+    **PHASE_FREQ_PRIORITY,
+    "C": [
+        ("L7", "L1"),
+        ("L6", "L1"),
+        ("L7", "L2"),
+        ("L6", "L2"),
+        ("L5", "L1"),
+        ("L5", "L2"),
+    ],
+}
+LEGACY_CODE_FREQ_PRIORITY = {
+    **CODE_FREQ_PRIORITY,
+    "C": [
+        ("C7", "C1"),
+        ("C6", "C1"),
+        ("C7", "C2"),
         ("C6", "C2"),
         ("C5", "C1"),
         ("C5", "C2"),
     ],
-    "R": [("C2", "C1")],
 }
 
+
+def is_legacy_naming(rinex_version: str | None) -> bool:
+    """Whether a RINEX version (e.g. "3.02") predates the BeiDou band naming of 3.03"""
+    if rinex_version is None:
+        return False
+    major, _, minor = str(rinex_version).strip().partition(".")
+    try:
+        return (int(major), int(minor or 99)) < (3, 3)
+    except ValueError:
+        return False
+
+
+def freq_bands(rinex_version: str | None) -> dict[str, dict]:
+    """Carrier frequencies per constellation and band, for the band naming of a RINEX version"""
+    return LEGACY_FREQ_BANDS if is_legacy_naming(rinex_version) else FREQ_BANDS
+
+
+def freq_priority(
+    rinex_version: str | None,
+) -> tuple[dict[str, list], dict[str, list]]:
+    """Phase and code frequency pair priorities for the band naming of a RINEX version"""
+    if is_legacy_naming(rinex_version):
+        return LEGACY_PHASE_FREQ_PRIORITY, LEGACY_CODE_FREQ_PRIORITY
+    return PHASE_FREQ_PRIORITY, CODE_FREQ_PRIORITY
+
+
 # Priorities for channel suffixes
 PHASE_CHAN_PRIORITY = ["C", "L", "S", "I", "Q", "W", "X", "P"]
 CODE_CHAN_PRIORITY = ["C", "L", "S", "I", "Q", "W", "X", "P"]
@@ -0,0 +1,111 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import (
+    between_satellite_differences as _between_satellite_differences,
+    double_differences as _double_differences,
+    single_differences as _single_differences,
+)
+
+
+def single_differences(obs_data: pl.DataFrame, reference: pl.DataFrame) -> pl.DataFrame:
+    """
+    Compute the between-station single differences of observations in the Rust core.
+
+    Observations of the two stations are aligned on (epoch, sv, observable) and
+    differenced, which removes the satellite clocks and hardware delays. The result
+    has the long format of `read_rinex_obs`, so that the linear combinations can be
+    formed on it: the geometry-free combination of single differences is the relative
+    ionosphere (ΔTEC) between the stations, up to their receiver biases.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Observations of the first station, with columns 'epoch', 'sv',
+        'observable', 'value'.
+    reference : pl.DataFrame
+        Observations of the reference station, in the same format; epochs are
+        converted to the time unit of `obs_data`.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value' (first station
+        minus reference station), for the observations common to both stations, in
+        the row order of `obs_data`.
+    """
+    return _single_differences(obs_data, reference)
+
+
+def double_differences(sd_data: pl.DataFrame, elevations: pl.DataFrame) -> pl.DataFrame:
+    """
+    Compute double differences of single differences in the Rust core.
+
+    At each epoch and for each constellation, the highest satellite (among those
+    with single differences and a known elevation) is taken as the reference, and
+    its single differences are subtracted from those of the other satellites on the
+    same observable, which also removes the receiver clocks and hardware delays.
+    Since the reference can change from one epoch to the next, it is reported on
+    every row. GLONASS FDMA carrier phases, in cycles of different wavelengths, are
+    differenced as they are.
+
+    Parameters
+    ----------
+    sd_data : pl.DataFrame
+        Single differences with columns 'epoch', 'sv', 'observable', 'value', as
+        returned by `single_differences`.
+    elevations : pl.DataFrame
+        Satellite elevations with columns 'epoch', 'sv', 'ele' (degrees), e.g. the
+        output of `calculate_ipp` for one of the stations.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'reference_sv', 'observable', 'value'
+        (satellite minus reference satellite), sorted by epoch, constellation, sv
+        and observable; epochs without any elevation are skipped.
+    """
+    return _double_differences(sd_data, elevations.select("epoch", "sv", "ele"))
+
+
+def between_satellite_differences(
+    obs_data: pl.DataFrame,
+    elevations: Optional[pl.DataFrame] = None,
+    reference: Optional[dict[str, str]] = None,
+) -> pl.DataFrame:
+    """
+    Compute between-satellite single differences of one receiver in the Rust core.
+
+    At each epoch and for each constellation, the observations of a reference
+    satellite are subtracted from those of the other satellites on the same
+    observable, which removes the receiver clock and hardware delays: the
+    geometry-free combination of the differences is a receiver-bias-free relative
+    TEC. The reference is the fixed satellite of the constellation when given and
+    observed, the highest satellite otherwise; it is reported on every row.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Observations with columns 'epoch', 'sv', 'observable', 'value'.
+    elevations : pl.DataFrame, optional
+        Satellite elevations with columns 'epoch', 'sv', 'ele' (degrees), e.g. the
+        output of `calculate_ipp`, to select the highest satellite as the reference.
+    reference : dict[str, str], optional
+        Fixed reference satellite per constellation, e.g. {"G": "G05"}.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'reference_sv', 'observable', 'value'
+        (satellite minus reference satellite), sorted by epoch, constellation, sv
+        and observable; epochs without a reference are skipped.
+
+    Raises
+    ------
+    ValueError
+        If neither `elevations` nor `reference` is given.
+    """
+    if elevations is not None:
+        elevations = elevations.select("epoch", "sv", "ele")
+    return _between_satellite_differences(obs_data, elevations, reference)
@@ -0,0 +1,235 @@
+from typing import Literal, Optional
+
+import polars as pl
+
+from .constants import C
+
+# Largest interval (s) between consecutive phases for their rate to approximate the Doppler
+_MAX_INTERVAL = 60.0
+
+
+def _wavelength(glonass_channels: Optional[dict[str, int]]) -> pl.Expr:
+    return C / pl.col("observable").gnss.frequency("sv", glonass_channels)
+
+
+def doppler_conventions(
+    obs_data: pl.DataFrame,
+    glonass_channels: Optional[dict[str, int]] = None,
+    tolerance: float = 0.1,
+    phase_unit: Literal["cycles", "meters"] = "cycles",
+) -> pl.DataFrame:
+    """
+    Detect the sign and unit conventions of the Doppler observables, against the rate
+    of change of the carrier phase of the same signal.
+
+    RINEX Doppler observables are in Hz, positive for approaching satellites, i.e.
+    the opposite of the carrier phase rate (phases grow with the range). Receivers
+    and converters do not all follow this convention: some record the opposite sign,
+    or a range rate in m/s. For each constellation and Doppler observable, the ratio
+    of the Doppler to the opposite of the phase rate (averaged between consecutive
+    epochs up to 60 seconds apart) is taken per satellite and then as the median over
+    the satellites; it is ±1 for Doppler in Hz, and ±1 once multiplied by the
+    wavelength for Doppler in m/s.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'epoch', 'sv', 'observable' and
+        'value' (RINEX 3 codes), with the carrier phases ('L') of the Doppler ('D')
+        observables.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`),
+        required to recognize GLONASS FDMA Doppler in m/s.
+    tolerance : float, optional
+        Largest relative distance between the ratio and ±1 for the convention to be
+        trusted; by default 0.1.
+    phase_unit : {'cycles', 'meters'}, optional
+        Unit of the carrier phases of `obs_data`, by default cycles (see the
+        `phase_unit` option of `read_rinex_obs`).
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per constellation and Doppler observable with a carrier phase, with
+        columns 'system', 'observable', 'ratio' (median ratio of the Doppler to the
+        opposite of the phase rate, in Hz), 'unit' ('Hz' or 'm/s'), 'sign' (1 if
+        positive for approaching satellites, -1 otherwise) and 'n_sv' (satellites
+        with both observables); 'unit' and 'sign' are null beyond the tolerance.
+
+    Raises
+    ------
+    ValueError
+        If the phase unit is not supported.
+    """
+    if phase_unit not in ("cycles", "meters"):
+        raise ValueError(f"Unsupported phase unit '{phase_unit}': expected 'cycles' or 'meters'")
+    schema = {
+        "system": pl.String,
+        "observable": pl.String,
+        "ratio": pl.Float64,
+        "unit": pl.String,
+        "sign": pl.Int8,
+        "n_sv": pl.UInt32,
+    }
+    dopplers = obs_data.filter(
+        pl.col("observable").str.contains(r"^D\d[A-Z]$") & pl.col("value").is_not_null()
+    ).select("epoch", "sv", "observable", "value")
+    phases = obs_data.filter(
+        pl.col("observable").str.contains(r"^L\d[A-Z]$") & pl.col("value").is_not_null()
+    ).select(
+        "epoch",
+        "sv",
+        pl.col("observable").str.replace("^L", "D").alias("observable"),
+        pl.col("value").alias("_phase"),
+    )
+    pairs = dopplers.join(phases, on=["epoch", "sv", "observable"])
+    if pairs.is_empty():
+        return pl.DataFrame(schema=schema)
+
+    keys = ["sv", "observable"]
+    dt = pl.col("epoch").diff().dt.total_microseconds().over(keys) / 1e6
+    rates = (
+        pairs.sort(*keys, "epoch")
+        .with_columns(
+            dt.alias("_dt"),
+            pl.col("_phase").diff().over(keys).alias("_dphase"),
+            ((pl.col("value") + pl.col("value").shift(1)) / 2).over(keys).alias("_doppler"),
+        )
+        .filter((pl.col("_dt") > 0) & (pl.col("_dt") <= _MAX_INTERVAL) & (pl.col("_dphase") != 0))
+        .with_columns((-pl.col("_dphase") / pl.col("_dt")).alias("_rate"))
+        .with_columns(
+            pl.col("_rate") / _wavelength(glonass_channels)
+            if phase_unit == "meters"
+            else pl.col("_rate")
+        )
+        .with_columns(
+            (pl.col("_doppler") / pl.col("_rate")).alias("_ratio"),
+            (pl.col("_doppler") / (pl.col("_rate") * _wavelength(glonass_channels))).alias(
+                "_ratio_ms"
+            ),
+        )
+    )
+    per_sv = rates.group_by("observable", "sv").agg(
+        pl.col("_ratio").median(), pl.col("_ratio_ms").median()
+    )
+
+    hz = (pl.col("ratio").abs() - 1).abs() <= tolerance
+    ms = (pl.col("_ratio_ms").abs() - 1).abs() <= tolerance
+    conventions = (
+        per_sv.with_columns(pl.col("sv").str.slice(0, 1).alias("system"))
+        .group_by("system", "observable")
+        .agg(
+            pl.col("_ratio").median().alias("ratio"),
+            pl.col("_ratio_ms").median(),
+            pl.len().cast(pl.UInt32).alias("n_sv"),
+        )
+        .with_columns(
+            pl.when(hz).then(pl.lit("Hz")).when(ms).then(pl.lit("m/s")).alias("unit"),
+            pl.when(hz)
+            .then(pl.col("ratio").sign())
+            .when(ms)
+            .then(pl.col("_ratio_ms").sign())
+            .alias("sign"),
+        )
+        .sort("system", "observable")
+    )
+    return conventions.select(list(schema)).cast(schema)
+
+
+def normalize_doppler(
+    obs_data: pl.DataFrame,
+    conventions: Optional[pl.DataFrame] = None,
+    glonass_channels: Optional[dict[str, int]] = None,
+    phase_unit: Literal["cycles", "meters"] = "cycles",
+) -> pl.DataFrame:
+    """
+    Convert the Doppler observables to the RINEX convention: Hz, positive for
+    approaching satellites.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'sv', 'observable' and 'value'.
+    conventions : pl.DataFrame, optional
+        Conventions per constellation and Doppler observable, with columns 'system',
+        'observable', 'unit' and 'sign', as returned by `doppler_conventions`;
+        detected on `obs_data` by default. Observables of unknown convention are
+        left unchanged.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`),
+        required to convert GLONASS FDMA Doppler from m/s.
+    phase_unit : {'cycles', 'meters'}, optional
+        Unit of the carrier phases of `obs_data`, by default cycles; only used to
+        detect the conventions.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with normalized Doppler values.
+    """
+    if conventions is None:
+        conventions = doppler_conventions(obs_data, glonass_channels, phase_unit=phase_unit)
+    conventions = conventions.filter(
+        pl.col("unit").is_not_null() & ((pl.col("unit") != "Hz") | (pl.col("sign") != 1))
+    )
+    if conventions.is_empty():
+        return obs_data
+
+    scale = pl.col("_sign") / pl.when(pl.col("_unit") == "m/s").then(
+        _wavelength(glonass_channels)
+    ).otherwise(1.0)
+    return (
+        obs_data.with_row_index("_row")
+        .with_columns(pl.col("sv").str.slice(0, 1).alias("_system"))
+        .join(
+            conventions.select(
+                pl.col("system").alias("_system"),
+                "observable",
+                pl.col("unit").alias("_unit"),
+                pl.col("sign").alias("_sign"),
+            ),
+            on=["_system", "observable"],
+            how="left",
+        )
+        .sort("_row")
+        .with_columns(
+            pl.when(pl.col("_unit").is_not_null())
+            .then(pl.col("value") * scale)
+            .otherwise(pl.col("value"))
+            .cast(obs_data.schema["value"])
+            .alias("value")
+        )
+        .drop("_row", "_system", "_unit", "_sign")
+    )
+
+
+def range_rate(
+    obs_data: pl.DataFrame,
+    glonass_channels: Optional[dict[str, int]] = None,
+) -> pl.DataFrame:
+    """
+    Add the range rate derived from the Doppler observables.
+
+    The range rate is the opposite of the Doppler (RINEX convention, see
+    `normalize_doppler`) times the wavelength of the signal, i.e. positive for
+    receding satellites, as the time derivative of the pseudorange.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'sv', 'observable' and 'value'.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per satellite (e.g., `ctx.glonass_channels`),
+        required for the FDMA bands 1 and 2.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with a 'range_rate' column (m/s), null for the observables
+        other than Doppler and for unknown frequencies.
+    """
+    return obs_data.with_columns(
+        pl.when(pl.col("observable").str.starts_with("D"))
+        .then(-pl.col("value").cast(pl.Float64) * _wavelength(glonass_channels))
+        .alias("range_rate")
+    )
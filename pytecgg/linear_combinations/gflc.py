@@ -1,6 +1,18 @@
 import polars as pl
 
 from .constants import C
+from ..settings import get_settings
+
+
+def _meters_to_tecu(freq1: pl.Expr, freq2: pl.Expr) -> pl.Expr:
+    """Conversion factor of a geometry-free combination from meters to TECU"""
+    settings = get_settings()
+    return (
+        (1 / settings.iono_constant)
+        * (freq1**2 * freq2**2)
+        / (freq1**2 - freq2**2)
+        / settings.tecu
+    )
 
 
 def _calculate_gflc_phase(
@@ -19,7 +31,7 @@ def _calculate_gflc_phase(
     """
     lambda1 = C / freq1
     lambda2 = C / freq2
-    pr_to_tec = (1 / 40.308) * (freq1**2 * freq2**2) / (freq1**2 - freq2**2) / 1e16
+    pr_to_tec = _meters_to_tecu(freq1, freq2)
     return (phase1 * lambda1 - phase2 * lambda2) * pr_to_tec
 
 
@@ -37,5 +49,5 @@ def _calculate_gflc_code(
     Returns:
         pl.Expr: Expression for the calculated GFLC
     """
-    pr_to_tec = (1 / 40.308) * (freq1**2 * freq2**2) / (freq1**2 - freq2**2) / 1e16
+    pr_to_tec = _meters_to_tecu(freq1, freq2)
     return (code2 - code1) * pr_to_tec
@@ -1,5 +1,9 @@
+from typing import Optional
+
 import polars as pl
 
+from ..pytecgg import ionosphere_free as _ionosphere_free
+
 
 def _calculate_iflc_phase(
     phase1: pl.Expr, phase2: pl.Expr, freq1: pl.Expr, freq2: pl.Expr
@@ -33,3 +37,48 @@ def _calculate_iflc_code(
         pl.Expr: Expression for the calculated GFLC
     """
     return (freq1**2 * code1 - freq2**2 * code2) / (freq1**2 - freq2**2)
+
+
+def ionosphere_free(
+    obs_data: pl.DataFrame,
+    phase: Optional[tuple[str, str]] = None,
+    code: Optional[tuple[str, str]] = None,
+    glonass_channels: Optional[dict[str, int]] = None,
+    rinex_version: Optional[str] = None,
+) -> pl.DataFrame:
+    """
+    Compute the ionosphere-free combinations (L3/P3) per (epoch, sv) in the Rust core.
+
+    Frequency coefficients are taken from the band of each observable and the
+    constellation of each satellite, so a single call covers all SVs sharing the
+    same observable codes.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    phase : tuple[str, str], optional
+        Phase observables (in cycles) on the two frequencies, e.g. ("L1C", "L2W").
+    code : tuple[str, str], optional
+        Code observables (in meters) on the two frequencies, e.g. ("C1C", "C2W").
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; required to combine GLONASS FDMA signals.
+    rinex_version : str, optional
+        RINEX version of the observations (e.g. "3.02"), which sets the BeiDou band
+        naming; RINEX 3.03 and later by default.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', and 'iflc_phase' and/or 'iflc_code'
+        (both in meters), depending on the observable pairs provided.
+
+    Raises
+    ------
+    ValueError
+        If neither `phase` nor `code` is provided.
+    """
+    channels = {
+        sv: k for sv, k in (glonass_channels or {}).items() if k is not None
+    }
+    return _ionosphere_free(obs_data, phase, code, channels, rinex_version)
@@ -1,7 +1,7 @@
 import polars as pl
 from typing import Literal, Any
 
-from .constants import FREQ_BANDS
+from .constants import freq_bands
 from .observables import retrieve_observable_pairs, _extract_band
 from .gflc import _calculate_gflc_code, _calculate_gflc_phase
 from .iflc import _calculate_iflc_code, _calculate_iflc_phase
@@ -40,6 +40,7 @@ def calculate_linear_combinations(
         DataFrame with the requested linear combinations.
     """
     results = []
+    bands = freq_bands(ctx.rinex_version)
 
     for system_ in ctx.systems:
         best_pairs = retrieve_observable_pairs(
@@ -82,8 +83,8 @@ def calculate_linear_combinations(
                 )
 
             # Map GLONASS channels to frequencies per SV
-            f1_map = FREQ_BANDS["R"][_extract_band(phase1)]
-            f2_map = FREQ_BANDS["R"][_extract_band(phase2)]
+            f1_map = bands["R"][_extract_band(phase1)]
+            f2_map = bands["R"][_extract_band(phase2)]
 
             # Metadata in MHz
             ctx.freq_meta[system_] = {
@@ -98,8 +99,8 @@ def calculate_linear_combinations(
             freq1, freq2 = f1_map(pl.col("_k")), f2_map(pl.col("_k"))
         else:
             try:
-                f1 = FREQ_BANDS[system_][_extract_band(phase1)]
-                f2 = FREQ_BANDS[system_][_extract_band(phase2)]
+                f1 = bands[system_][_extract_band(phase1)]
+                f2 = bands[system_][_extract_band(phase2)]
 
                 # MHz for metadata
                 ctx.freq_meta[system_] = (f1 / 1e6, f2 / 1e6)
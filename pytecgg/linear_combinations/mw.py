@@ -1,6 +1,9 @@
+from typing import Optional
+
 import polars as pl
 
 from .constants import C
+from ..pytecgg import melbourne_wubbena as _melbourne_wubbena
 
 
 def _calculate_melbourne_wubbena(
@@ -32,3 +35,43 @@ def _calculate_melbourne_wubbena(
     # Narrow-lane code combination (in meters)
     pn = (freq1 * code1 + freq2 * code2) / (freq1 + freq2)
     return lw - pn
+
+
+def melbourne_wubbena(
+    obs_data: pl.DataFrame,
+    phase: tuple[str, str],
+    code: tuple[str, str],
+    glonass_channels: Optional[dict[str, int]] = None,
+    rinex_version: Optional[str] = None,
+) -> pl.DataFrame:
+    """
+    Compute the Melbourne-Wübbena (MW) combination per (epoch, sv) in the Rust core.
+
+    Observables are paired internally, so the long-format output of `read_rinex_obs`
+    can be passed directly, without pivoting.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    phase : tuple[str, str]
+        Phase observables (in cycles) on the two frequencies, e.g. ("L1C", "L2W").
+    code : tuple[str, str]
+        Code observables (in meters) on the two frequencies, e.g. ("C1C", "C2W").
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV (e.g. `GNSSContext.glonass_channels`);
+        required to combine GLONASS FDMA signals.
+    rinex_version : str, optional
+        RINEX version of the observations (e.g. "3.02"), which sets the BeiDou band
+        naming; RINEX 3.03 and later by default.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'mw' (in meters); 'mw' is null where
+        an observable is missing or its frequency is unknown.
+    """
+    channels = {
+        sv: k for sv, k in (glonass_channels or {}).items() if k is not None
+    }
+    return _melbourne_wubbena(obs_data, phase, code, channels, rinex_version)
@@ -3,10 +3,9 @@ from typing import Optional, Tuple
 import polars as pl
 
 from .constants import (
-    PHASE_FREQ_PRIORITY,
-    CODE_FREQ_PRIORITY,
     PHASE_CHAN_PRIORITY,
     CODE_CHAN_PRIORITY,
+    freq_priority,
 )
 
 _band_re = re.compile(r"^([A-Za-z]\d+)")
@@ -55,7 +54,8 @@ def retrieve_observable_pairs(
     system : str
         GNSS system identifier ("G", "R", "E", "C").
     rinex_version : str
-        RINEX version ("2" or "3").
+        RINEX version (e.g. "2", "3.02" or "3.04"); BeiDou bands are named
+        differently before 3.03.
     prefer_by_suffix : bool
         If True, selects based on suffix priority; otherwise uses occurrence count.
     df_for_counts : pl.DataFrame | None
@@ -133,10 +133,9 @@ def retrieve_observable_pairs(
                 return o1, o2
         return None
 
-    phase_pair = choose_best_pair(
-        phase_avail, PHASE_FREQ_PRIORITY.get(system, []), True
-    )
-    code_pair = choose_best_pair(code_avail, CODE_FREQ_PRIORITY.get(system, []), False)
+    phase_priority, code_priority = freq_priority(rinex_version)
+    phase_pair = choose_best_pair(phase_avail, phase_priority.get(system, []), True)
+    code_pair = choose_best_pair(code_avail, code_priority.get(system, []), False)
 
     if phase_pair and code_pair:
         return phase_pair, code_pair
@@ -0,0 +1,169 @@
+import math
+from typing import Optional
+
+import polars as pl
+
+from .constants import PHASE_CHAN_PRIORITY
+
+
+def _channel_rank(observable: str) -> int:
+    attribute = observable[2:3]
+    return PHASE_CHAN_PRIORITY.index(attribute) if attribute in PHASE_CHAN_PRIORITY else 99
+
+
+def _wrap(cycles: pl.Expr) -> pl.Expr:
+    """Fractions of cycle wrapped into [-0.5, 0.5)"""
+    return cycles - (cycles + 0.5).floor()
+
+
+def quarter_cycle_offsets(
+    obs_data: pl.DataFrame,
+    tolerance: float = 0.05,
+) -> pl.DataFrame:
+    """
+    Detect quarter-cycle offsets between the carrier phases of the same band tracked
+    on different channels (e.g., L2W and L2X).
+
+    For each constellation and band, the phase of the preferred tracking channel is
+    the reference. The offset of every other channel is the circular mean of its
+    difference to the reference (modulo one cycle, so that integer ambiguities and
+    cycle slips cancel), taken per satellite and then as the median over the
+    satellites, and rounded to the nearest quarter of a cycle.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'epoch', 'sv', 'observable' and
+        'value' (carrier phases in cycles, RINEX 3 codes).
+    tolerance : float, optional
+        Largest distance (cycles) between the estimated offset and the nearest
+        quarter of a cycle for the offset to be trusted; by default 0.05.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per constellation and channel with a reference, with columns
+        'system', 'observable', 'reference', 'fraction' (estimated offset, cycles in
+        [-0.5, 0.5)), 'offset' (nearest quarter, null beyond the tolerance) and
+        'n_sv' (satellites tracking both channels).
+    """
+    schema = {
+        "system": pl.String,
+        "observable": pl.String,
+        "reference": pl.String,
+        "fraction": pl.Float64,
+        "offset": pl.Float64,
+        "n_sv": pl.UInt32,
+    }
+    phases = obs_data.filter(
+        pl.col("observable").str.contains(r"^L\d[A-Z]$") & pl.col("value").is_not_null()
+    ).select(
+        "epoch",
+        "sv",
+        "observable",
+        "value",
+        pl.col("sv").str.slice(0, 1).alias("system"),
+        pl.col("observable").str.slice(1, 1).alias("band"),
+    )
+    if phases.is_empty():
+        return pl.DataFrame(schema=schema)
+
+    references = (
+        phases.select("system", "band", "observable")
+        .unique()
+        .with_columns(
+            pl.col("observable")
+            .map_elements(_channel_rank, return_dtype=pl.Int64)
+            .alias("_rank")
+        )
+        .sort("system", "band", "_rank", "observable")
+        .group_by("system", "band", maintain_order=True)
+        .first()
+        .select("system", "band", pl.col("observable").alias("reference"))
+    )
+
+    pairs = (
+        phases.join(references, on=["system", "band"])
+        .filter(pl.col("observable") != pl.col("reference"))
+        .join(
+            phases.select(
+                "epoch",
+                "sv",
+                pl.col("observable").alias("reference"),
+                pl.col("value").alias("_reference_value"),
+            ),
+            on=["epoch", "sv", "reference"],
+        )
+        .with_columns(
+            (2 * math.pi * (pl.col("value") - pl.col("_reference_value"))).alias("_angle")
+        )
+    )
+    per_sv = pairs.group_by("system", "observable", "reference", "sv").agg(
+        (
+            pl.arctan2(pl.col("_angle").sin().mean(), pl.col("_angle").cos().mean())
+            / (2 * math.pi)
+        ).alias("fraction")
+    )
+    # Circular median: fractions taken around the first satellite to avoid the wrap
+    first = pl.col("fraction").first().over("system", "observable")
+    offsets = (
+        per_sv.with_columns(
+            (first + _wrap(pl.col("fraction") - first)).alias("fraction")
+        )
+        .group_by("system", "observable", "reference")
+        .agg(pl.col("fraction").median(), pl.len().cast(pl.UInt32).alias("n_sv"))
+        .with_columns(_wrap(pl.col("fraction")).alias("fraction"))
+        .with_columns((pl.col("fraction") * 4).round().truediv(4).alias("offset"))
+        .with_columns(
+            pl.when((pl.col("fraction") - pl.col("offset")).abs() <= tolerance)
+            .then(pl.col("offset"))
+            .alias("offset")
+        )
+        .sort("system", "observable")
+    )
+    return offsets.select(list(schema)).cast(schema)
+
+
+def align_quarter_cycles(
+    obs_data: pl.DataFrame,
+    offsets: Optional[pl.DataFrame] = None,
+) -> pl.DataFrame:
+    """
+    Remove the quarter-cycle offsets between the carrier phases of the same band,
+    aligning every channel on the reference channel of its band.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'sv', 'observable' and 'value'
+        (carrier phases in cycles).
+    offsets : pl.DataFrame, optional
+        Offsets per constellation and observable, with columns 'system',
+        'observable' and 'offset' (cycles), as returned by `quarter_cycle_offsets`;
+        detected on `obs_data` by default. Null offsets are not applied.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with aligned carrier phases.
+    """
+    if offsets is None:
+        offsets = quarter_cycle_offsets(obs_data)
+    offsets = offsets.filter(pl.col("offset").is_not_null() & (pl.col("offset") != 0.0))
+    if offsets.is_empty():
+        return obs_data
+
+    return (
+        obs_data.with_row_index("_row")
+        .with_columns(pl.col("sv").str.slice(0, 1).alias("_system"))
+        .join(
+            offsets.select(
+                pl.col("system").alias("_system"), "observable", pl.col("offset").alias("_offset")
+            ),
+            on=["_system", "observable"],
+            how="left",
+        )
+        .sort("_row")
+        .with_columns(pl.col("value") - pl.col("_offset").fill_null(0.0))
+        .drop("_row", "_system", "_offset")
+    )
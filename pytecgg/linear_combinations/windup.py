@@ -0,0 +1,43 @@
+import polars as pl
+
+
+def correct_phase_windup(obs_data: pl.DataFrame, windup: pl.DataFrame) -> pl.DataFrame:
+    """
+    Remove the phase wind-up from carrier-phase observations.
+
+    The wind-up is the same number of cycles on all the frequencies, hence a
+    different range on each of them: removing it before
+    `calculate_linear_combinations` avoids its slowly varying bias in the
+    geometry-free combination (about a quarter of TECU per half cycle on GPS L1/L2).
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with the columns 'epoch', 'sv', 'observable' and
+        'value' (carrier phases in cycles).
+    windup : pl.DataFrame
+        Wind-up per satellite and epoch, with the columns 'epoch', 'sv' and 'windup'
+        (cycles), as returned by `phase_windup`.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with corrected carrier phases; other observables, and phases
+        without a wind-up value, are unchanged.
+    """
+    return (
+        obs_data.with_row_index("_row")
+        .join(
+            windup.select("epoch", "sv", pl.col("windup").alias("_windup")),
+            on=["epoch", "sv"],
+            how="left",
+        )
+        .sort("_row")
+        .with_columns(
+            pl.when(pl.col("observable").str.starts_with("L"))
+            .then(pl.col("value") - pl.col("_windup").fill_null(0.0))
+            .otherwise(pl.col("value"))
+            .alias("value")
+        )
+        .drop("_row", "_windup")
+    )
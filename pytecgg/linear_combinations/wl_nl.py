@@ -0,0 +1,79 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import wide_lane as _wide_lane, narrow_lane as _narrow_lane
+
+
+def _valid_channels(glonass_channels: Optional[dict[str, int]]) -> dict[str, int]:
+    return {sv: k for sv, k in (glonass_channels or {}).items() if k is not None}
+
+
+def wide_lane(
+    obs_data: pl.DataFrame,
+    phase: Optional[tuple[str, str]] = None,
+    code: Optional[tuple[str, str]] = None,
+    glonass_channels: Optional[dict[str, int]] = None,
+    rinex_version: Optional[str] = None,
+) -> pl.DataFrame:
+    """
+    Compute the wide-lane phase and/or code combinations per (epoch, sv) in the Rust core.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    phase : tuple[str, str], optional
+        Phase observables (in cycles) on the two frequencies, e.g. ("L1C", "L2W").
+    code : tuple[str, str], optional
+        Code observables (in meters) on the two frequencies, e.g. ("C1C", "C2W").
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; required to combine GLONASS FDMA signals.
+    rinex_version : str, optional
+        RINEX version of the observations (e.g. "3.02"), which sets the BeiDou band
+        naming; RINEX 3.03 and later by default.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', and 'wl_phase' and/or 'wl_code'
+        (both in meters), depending on the observable pairs provided.
+    """
+    return _wide_lane(
+        obs_data, phase, code, _valid_channels(glonass_channels), rinex_version
+    )
+
+
+def narrow_lane(
+    obs_data: pl.DataFrame,
+    phase: Optional[tuple[str, str]] = None,
+    code: Optional[tuple[str, str]] = None,
+    glonass_channels: Optional[dict[str, int]] = None,
+    rinex_version: Optional[str] = None,
+) -> pl.DataFrame:
+    """
+    Compute the narrow-lane phase and/or code combinations per (epoch, sv) in the Rust core.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    phase : tuple[str, str], optional
+        Phase observables (in cycles) on the two frequencies, e.g. ("L1C", "L2W").
+    code : tuple[str, str], optional
+        Code observables (in meters) on the two frequencies, e.g. ("C1C", "C2W").
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; required to combine GLONASS FDMA signals.
+    rinex_version : str, optional
+        RINEX version of the observations (e.g. "3.02"), which sets the BeiDou band
+        naming; RINEX 3.03 and later by default.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', and 'nl_phase' and/or 'nl_code'
+        (both in meters), depending on the observable pairs provided.
+    """
+    return _narrow_lane(
+        obs_data, phase, code, _valid_channels(glonass_channels), rinex_version
+    )
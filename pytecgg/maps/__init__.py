@@ -0,0 +1,3 @@
+from .gridding import grid_vtec
+
+__all__ = ["grid_vtec"]
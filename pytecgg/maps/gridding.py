@@ -0,0 +1,222 @@
+from datetime import timedelta
+from typing import Literal, Optional, Sequence, Union
+
+import numpy as np
+import polars as pl
+
+from pytecgg.geodesy.network import _great_circle_km
+
+GridMethod = Literal["nearest", "idw", "kriging"]
+
+_MAP_SCHEMA = {
+    "epoch": pl.Datetime("us", "UTC"),
+    "lat": pl.Float64,
+    "lon": pl.Float64,
+    "vtec": pl.Float64,
+    "n_obs": pl.UInt32,
+}
+
+
+def _cadence(cadence: Union[str, timedelta]) -> str:
+    if isinstance(cadence, timedelta):
+        return f"{int(cadence.total_seconds() * 1e6)}us"
+    return cadence
+
+
+def _axis(values: np.ndarray, bounds: Optional[tuple[float, float]], step: float) -> np.ndarray:
+    """Grid nodes covering the bounds, or the data extent rounded to the step."""
+    if bounds is None:
+        bounds = (
+            np.floor(np.nanmin(values) / step) * step,
+            np.ceil(np.nanmax(values) / step) * step,
+        )
+    return np.arange(bounds[0], bounds[1] + step / 2, step)
+
+
+def _interpolate(
+    dist: np.ndarray,
+    obs_dist: np.ndarray,
+    values: np.ndarray,
+    method: GridMethod,
+    power: float,
+    variogram_range: float,
+) -> np.ndarray:
+    """Values at the nodes (rows of `dist`) from the observations (its columns)."""
+    if method == "nearest":
+        return values[np.argmin(dist, axis=1)]
+
+    if method == "idw":
+        with np.errstate(divide="ignore"):
+            weights = 1.0 / dist**power
+        exact = np.isinf(weights)
+        # Nodes on an observation take its value
+        weights = np.where(exact.any(axis=1, keepdims=True), exact.astype(float), weights)
+        return (weights @ values) / weights.sum(axis=1)
+
+    # Ordinary kriging with an exponential covariance, the sill being the sample variance
+    n = values.size
+    sill = values.var() if n > 1 and values.var() > 0 else 1.0
+    system = np.ones((n + 1, n + 1))
+    system[:n, :n] = sill * np.exp(-obs_dist / variogram_range)
+    system[n, n] = 0.0
+    # Slight nugget, keeping the system invertible with co-located observations
+    system[np.arange(n), np.arange(n)] += 1e-6 * sill
+    rhs = np.ones((n + 1, dist.shape[0]))
+    rhs[:n] = sill * np.exp(-dist.T / variogram_range)
+    weights = np.linalg.lstsq(system, rhs, rcond=None)[0][:n]
+    return weights.T @ values
+
+
+def grid_vtec(
+    df: Union[pl.DataFrame, Sequence[pl.DataFrame]],
+    cadence: Union[str, timedelta] = "5m",
+    resolution: Union[float, tuple[float, float]] = 1.0,
+    lat_bounds: Optional[tuple[float, float]] = None,
+    lon_bounds: Optional[tuple[float, float]] = None,
+    method: GridMethod = "idw",
+    max_distance: Optional[float] = 500.0,
+    power: float = 2.0,
+    variogram_range: float = 1_000.0,
+    value: str = "vtec",
+    output: Literal["dataframe", "array"] = "dataframe",
+) -> Union[pl.DataFrame, tuple[np.ndarray, np.ndarray, np.ndarray, np.ndarray]]:
+    """
+    Interpolate calibrated VTEC at the IPPs of one or more stations onto a series of
+    regular latitude/longitude grids, at a fixed cadence.
+
+    The observations are binned in time (e.g., 5 minutes), then averaged per grid
+    cell, so that dense networks and high-rate data weigh as much as sparse ones,
+    before the interpolation onto the grid nodes:
+
+    * 'nearest': value of the nearest cell with data.
+    * 'idw': inverse distance weighting, with weights 1/d^power.
+    * 'kriging': ordinary kriging ("kriging-lite") with an exponential covariance
+      of range `variogram_range` and sill equal to the variance of the cells
+      with data of the time bin.
+
+    Parameters
+    ----------
+    df : pl.DataFrame or sequence of pl.DataFrame
+        Calibrated TEC of one or more stations, as returned by `calculate_tec` or
+        `compute_tec`, with columns 'epoch', 'lat_ipp', 'lon_ipp' (degrees) and the
+        VTEC column.
+    cadence : str or timedelta, optional
+        Length of the time bins, by default '5m'. Epochs of the maps are the start
+        of the bins.
+    resolution : float or tuple[float, float], optional
+        Grid spacing in degrees, or (latitude, longitude) spacings; by default 1°.
+    lat_bounds, lon_bounds : tuple[float, float], optional
+        Latitudes and longitudes (degrees) of the first and last grid nodes; by
+        default, the extent of the IPPs rounded to the resolution.
+    method : {'nearest', 'idw', 'kriging'}, optional
+        Interpolation method, by default 'idw'.
+    max_distance : float, optional
+        Nodes farther than this distance (km) from any IPP of the time bin are left
+        empty (NaN), rather than extrapolated; by default 500 km. None interpolates
+        every node.
+    power : float, optional
+        Power of the inverse distance weighting, by default 2.
+    variogram_range : float, optional
+        Range (km) of the exponential covariance of kriging, by default 1000 km.
+    value : str, optional
+        Column of the VTEC values, by default 'vtec'.
+    output : {'dataframe', 'array'}, optional
+        Long DataFrame (default), or dense arrays.
+
+    Returns
+    -------
+    pl.DataFrame or tuple
+        With 'dataframe' output, one row per map epoch and grid node, with columns
+        'epoch', 'lat', 'lon', 'vtec' (null for empty nodes) and 'n_obs' (number of
+        observations of the time bin within `max_distance`), ready for
+        `write_netcdf(layout="grid")`. With 'array' output, a tuple of the map
+        epochs (datetime64), the latitudes and longitudes of the nodes, and the
+        VTEC maps as an array of shape (epochs, latitudes, longitudes).
+
+    Raises
+    ------
+    ValueError
+        If the interpolation method or the output is not supported.
+    """
+    if method not in ("nearest", "idw", "kriging"):
+        raise ValueError(
+            f"Unsupported method '{method}': expected 'nearest', 'idw' or 'kriging'"
+        )
+    if output not in ("dataframe", "array"):
+        raise ValueError(f"Unsupported output '{output}': expected 'dataframe' or 'array'")
+
+    frames = [df] if isinstance(df, pl.DataFrame) else list(df)
+    obs = pl.concat(
+        [
+            f.select(
+                pl.col("epoch").cast(pl.Datetime("us", "UTC")),
+                pl.col("lat_ipp", "lon_ipp", value).cast(pl.Float64),
+            )
+            for f in frames
+        ]
+    ).drop_nulls().filter(pl.col(value).is_not_nan())
+
+    d_lat, d_lon = resolution if isinstance(resolution, tuple) else (resolution, resolution)
+    if obs.is_empty():
+        if output == "array":
+            lats = _axis(np.array([]), lat_bounds, d_lat) if lat_bounds else np.array([])
+            lons = _axis(np.array([]), lon_bounds, d_lon) if lon_bounds else np.array([])
+            return (
+                np.array([], dtype="datetime64[us]"),
+                lats,
+                lons,
+                np.empty((0, lats.size, lons.size)),
+            )
+        return pl.DataFrame(schema=_MAP_SCHEMA)
+
+    lats = _axis(obs["lat_ipp"].to_numpy(), lat_bounds, d_lat)
+    lons = _axis(obs["lon_ipp"].to_numpy(), lon_bounds, d_lon)
+    node_lat, node_lon = (a.ravel() for a in np.meshgrid(lats, lons, indexing="ij"))
+
+    # Superobservations: mean VTEC per time bin and grid cell
+    cells = (
+        obs.with_columns(
+            pl.col("epoch").dt.truncate(_cadence(cadence)),
+            ((pl.col("lat_ipp") - lats[0]) / d_lat).round().alias("_i"),
+            ((pl.col("lon_ipp") - lons[0]) / d_lon).round().alias("_j"),
+        )
+        .group_by("epoch", "_i", "_j")
+        .agg(
+            pl.col("lat_ipp").mean(),
+            pl.col("lon_ipp").mean(),
+            pl.col(value).mean(),
+            pl.len().alias("_count"),
+        )
+        .sort("epoch", "_i", "_j")
+    )
+
+    epochs, maps, counts = [], [], []
+    for (epoch,), group in cells.group_by("epoch", maintain_order=True):
+        lat_obs, lon_obs = group["lat_ipp"].to_numpy(), group["lon_ipp"].to_numpy()
+        values = group[value].to_numpy()
+        dist = _great_circle_km(node_lat, node_lon, lat_obs, lon_obs)
+        obs_dist = _great_circle_km(lat_obs, lon_obs, lat_obs, lon_obs) if method == "kriging" else None
+
+        grid = _interpolate(dist, obs_dist, values, method, power, variogram_range)
+        near = dist <= max_distance if max_distance is not None else np.ones_like(dist, dtype=bool)
+        n_obs = near.astype(np.int64) @ group["_count"].to_numpy()
+        grid = np.where(near.any(axis=1), grid, np.nan)
+
+        epochs.append(epoch)
+        maps.append(grid.reshape(lats.size, lons.size))
+        counts.append(n_obs)
+
+    if output == "array":
+        times = pl.Series(epochs).dt.replace_time_zone(None).to_numpy()
+        return times, lats, lons, np.stack(maps)
+
+    n_nodes = node_lat.size
+    return pl.DataFrame(
+        {
+            "epoch": pl.Series(epochs).gather(np.repeat(np.arange(len(epochs)), n_nodes)),
+            "lat": np.tile(node_lat, len(epochs)),
+            "lon": np.tile(node_lon, len(epochs)),
+            "vtec": np.concatenate([m.ravel() for m in maps]),
+            "n_obs": np.concatenate(counts),
+        }
+    ).with_columns(pl.col("vtec").fill_nan(None)).cast(_MAP_SCHEMA)
@@ -1,18 +1,105 @@
+import glob
+import warnings
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import asdict, dataclass
+from datetime import date, datetime
 from pathlib import Path
-from typing import Union
+from typing import Any, Optional, Union
 
 import polars as pl
 
 from ..pytecgg import (
+    ParseStats,
+    ReadOptions,
     read_rinex_obs as _read_rinex_obs,
     read_rinex_nav as _read_rinex_nav,
+    read_rinex_events as _read_rinex_events,
+    read_rinex_positions as _read_rinex_positions,
+    read_rinex_site as _read_rinex_site,
+    read_rinex_phase_shifts as _read_rinex_phase_shifts,
+    read_rinex_scale_factors as _read_rinex_scale_factors,
+    rinex_time_bounds as _rinex_time_bounds,
+    split_constellations as _split_constellations,
 )
+from ..linear_combinations.doppler import normalize_doppler as _normalize_doppler
+from .aio import read_rinex_nav_async, read_rinex_obs_async
+from .almanac import read_sem_almanac, read_yuma_almanac
+from .antex import Antex, read_antex
+from .binex import read_binex
+from .clock import read_rinex_clk
+from .conformity import RinexConformityError, check_rinex_obs, validate_rinex_obs
+from .dcb import read_bernese_dcb
+from .filenames import RinexFilename, _station_and_date, parse_rinex_filename
+from .header import detect_type, read_rinex_obs_types
+from .ionex import read_ionex
+from .ismr import read_ismr
+from .met import read_rinex_met
+from .nmea import read_nmea
+from .raw import _NAV_DATETIMES
+from .rtcm import read_rtcm
+from .sbf import read_sbf
+from .signals import observable_table
+from .sitelog import SiteLog, read_igs_sitelog
+from .ubx import read_ubx
+from pytecgg.settings import Settings, get_settings
 
-__all__ = ["read_rinex_obs", "read_rinex_nav"]
+__all__ = [
+    "read_rinex",
+    "detect_type",
+    "read_rinex_obs",
+    "read_rinex_obs_with_stats",
+    "split_constellations",
+    "ReadOptions",
+    "ParseStats",
+    "validate_rinex_obs",
+    "RinexConformityError",
+    "read_rinex_obs_glob",
+    "read_rinex_obs_async",
+    "read_rinex_nav",
+    "read_rinex_nav_async",
+    "read_rinex_events",
+    "read_rinex_positions",
+    "read_rinex_site",
+    "read_rinex_obs_types",
+    "read_rinex_phase_shifts",
+    "read_rinex_scale_factors",
+    "observable_table",
+    "read_rinex_met",
+    "read_rinex_clk",
+    "read_ionex",
+    "rinex_time_bounds",
+    "read_rtcm",
+    "read_binex",
+    "read_ubx",
+    "read_sbf",
+    "read_nmea",
+    "read_ismr",
+    "SiteInfo",
+    "parse_rinex_filename",
+    "RinexFilename",
+    "read_igs_sitelog",
+    "SiteLog",
+    "read_antex",
+    "Antex",
+    "read_bernese_dcb",
+    "read_yuma_almanac",
+    "read_sem_almanac",
+]
+
+
+def _settings_tecu(df: pl.DataFrame) -> pl.DataFrame:
+    """Converts the geometry-free combinations of the Rust backend, computed with the
+    default constants, into the TEC units of the settings."""
+    settings, default = get_settings(), Settings()
+    scale = (default.iono_constant * default.tecu) / (settings.iono_constant * settings.tecu)
+    if scale == 1.0:
+        return df
+    return df.with_columns(pl.col("gflc_phase", "gflc_code") * scale)
 
 
 def read_rinex_obs(
     path: Union[str, Path],
+    options: Optional[ReadOptions] = None,
 ) -> tuple[pl.DataFrame, tuple[float, float, float], str]:
     """
     Parses a RINEX observation file and returns the extracted observation data as a Polars DataFrame.
@@ -21,41 +108,609 @@ def read_rinex_obs(
     ----------
     path : str or pathlib.Path
         Path to the RINEX observation file (.rnx, .crx, or .gz).
+    options : ReadOptions, optional
+        Options of the reader, reusable across calls, by default those of
+        `ReadOptions()`. Besides the time window (`start`, `end`), the constellation,
+        observable and satellite filters (`constellations`, `observables`,
+        `exclude_sv`) and the dtype of the 'value' column (`value_dtype`):
+        - geometry_free (bool): If True, signals are paired internally (per
+          constellation, following the default band and tracking channel
+          priorities) and the geometry-free phase (L4) and code (P4) combinations
+          are returned instead of the raw observables, in the TEC units of the
+          package settings. GLONASS frequencies rely on the 'GLONASS SLOT / FRQ #'
+          header record.
+        - time_scale ({'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}): Timescale
+          of the 'epoch' column, by default 'GPST' (i.e. GPS time labelled as UTC,
+          which keeps the epochs on the nominal sampling grid). 'UTC' applies the
+          leap seconds in force at each epoch.
+        - time_unit ({'us', 'ns'}): Precision of the 'epoch' column, by default
+          microseconds. Nanoseconds preserve the full precision of high-rate and
+          event-timing data.
+        - epoch_dtype ({'datetime', 'i64_us', 'i64_ns'}): Type of the 'epoch'
+          column: UTC-aware Datetime in `time_unit` (default), or raw Int64
+          timestamps since the Unix epoch in microseconds or nanoseconds (overriding
+          `time_unit`), for pipelines that only do arithmetic on epochs. The
+          timestamps follow `time_scale` like the datetimes.
+        - snap_to (str): Nominal sampling interval (e.g., '30s', '1s', '100ms') to
+          which epochs are rounded, so that receivers stamping slightly off-grid
+          epochs can be joined with other datasets. The removed offset (original
+          minus snapped epoch) is reported in a 'snap_offset' Duration column.
+        - duplicates ({'keep', 'first', 'last', 'error'}): What to do with repeated
+          (epoch, sv, observable) entries (including those created by `snap_to`):
+          keep them all (default), keep only the first or the last occurrence, or
+          raise a ValueError.
+        - sort (bool): If True, the DataFrame is sorted by (epoch, sv, observable)
+          and the 'epoch' column is flagged as sorted, so that rolling and asof
+          operations can skip an extra sort.
+        - max_rows (int): Maximum number of observation rows, e.g. to protect a
+          long-running service from unexpectedly large or malformed files. The
+          limits are first checked against all the values recorded in the file,
+          counted before parsing (regardless of the epoch, constellation and
+          observable filters), so that only the epochs within them are parsed; the
+          output rows are checked again.
+        - max_memory_mb (float): Maximum estimated memory of the observations in MB
+          (about 128 bytes per row while the DataFrame is built).
+        - on_limit ({'error', 'truncate'}): What to do with files exceeding
+          `max_rows` or `max_memory_mb`: raise a ValueError (default), or keep the
+          leading epochs within the limits and emit a warning.
+        - phase_shift ({'keep', 'apply', 'remove'}): What to do with the carrier
+          phase shifts declared in RINEX 3/4 headers ('SYS / PHASE SHIFT', e.g. the
+          quarter cycle of L2C against L1C): keep the phases as recorded (default),
+          add the declared shifts (for files declaring shifts not applied to the
+          phases), or subtract them (recovering the phases as tracked by the
+          receiver). Shifts apply before the `geometry_free` combinations; see
+          `read_rinex_phase_shifts`.
+        - phase_unit ({'cycles', 'meters'}): Unit of the carrier phases: cycles as
+          recorded (default), or meters, i.e. cycles times the wavelength of each
+          signal, so that phases can be combined with pseudoranges without a
+          frequency table. GLONASS FDMA wavelengths rely on the
+          'GLONASS SLOT / FRQ #' header record; phases of unknown wavelength are
+          null. Ignored with `geometry_free`.
+        - strict (bool): If True, the header is checked against the RINEX
+          specification before parsing, and a non-conforming file is rejected with a
+          RinexConformityError listing all its violations (see
+          `validate_rinex_obs`). By default, the parser is lenient and reads
+          whatever it can.
+        - normalize_doppler (bool): If True, the Doppler observables are converted
+          to the RINEX convention (Hz, positive for approaching satellites), for
+          receivers recording the opposite sign or a range rate in m/s. The
+          convention of each observable is detected from the rate of the carrier
+          phase of the same signal (see `doppler_conventions` in
+          `pytecgg.linear_combinations`); GLONASS FDMA Doppler in m/s, which need
+          the frequency channels, are left unchanged. Ignored with `geometry_free`.
+        - ssi (bool): If True, an 'ssi' column gives the signal strength indicator
+          digit recorded with each observation (1: minimum, 5: threshold of a good
+          S/N, 9: maximum; null if blank or 0), for older files without SNR
+          observables (see `snr_from_ssi` in `pytecgg.qc`). Ignored with
+          `geometry_free`.
 
     Returns
     -------
     tuple
-        - pl.DataFrame: DataFrame with columns 'epoch', 'sv', 'observable', 'value'
+        - pl.DataFrame: DataFrame with columns 'epoch', 'sv', 'observable', 'value',
+          'epoch_flag' (0: OK, 1: power failure since the previous epoch,
+          6: cycle slip records) and 'rx_clock_offset' (receiver clock offset in
+          seconds, as optionally recorded with each epoch; null otherwise), 'ssi'
+          with `options.ssi`, or
+          'epoch', 'sv', 'gflc_phase', 'gflc_code' in TECu with `options.geometry_free`
         - tuple[float, float, float]: Receiver's position in ECEF coordinates (meters)
         - str: RINEX version
+
+    Examples
+    --------
+    >>> options = ReadOptions(constellations=["G", "E"], start=datetime(2024, 10, 7, 6))
+    >>> df, rec_pos, version = read_rinex_obs("ASIR00ITA_R_20242810000_01D_30S_MO.rnx", options=options)
     """
-    path_str = str(path)
-    df, rec_pos, rinex_version = _read_rinex_obs(path_str)
-    return (
-        df.with_columns(pl.col("epoch").dt.replace_time_zone("UTC")),
-        rec_pos,
-        rinex_version,
-    )
+    df, rec_pos, rinex_version, _ = read_rinex_obs_with_stats(path, options)
+    return df, rec_pos, rinex_version
+
+
+def read_rinex_obs_with_stats(
+    path: Union[str, Path],
+    options: Optional[ReadOptions] = None,
+) -> tuple[pl.DataFrame, tuple[float, float, float], str, ParseStats]:
+    """
+    Parses a RINEX observation file as `read_rinex_obs`, also returning parse statistics.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+    options : ReadOptions, optional
+        Options of the reader, as for `read_rinex_obs`.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Observations, as returned by `read_rinex_obs`
+        - tuple[float, float, float]: Receiver's position in ECEF coordinates (meters)
+        - str: RINEX version
+        - ParseStats: Number of epochs and signals read, satellites seen per
+          constellation, time span, detected sampling interval, size of the file and
+          time spent parsing it
+
+    Examples
+    --------
+    >>> df, _, _, stats = read_rinex_obs_with_stats("ASIR00ITA_R_20242810000_01D_30S_MO.rnx")
+    >>> stats.epochs, stats.interval
+    """
+    options = options if options is not None else ReadOptions()
+    if options.strict:
+        check_rinex_obs(path)
+
+    df, rec_pos, rinex_version, stats = _read_rinex_obs(str(path), options)
+    integer_epochs = options.epoch_dtype != "datetime"
+    epoch = pl.col("epoch") if integer_epochs else pl.col("epoch").dt.replace_time_zone("UTC")
+    df = df.with_columns(epoch.set_sorted() if options.sort else epoch)
+    if options.geometry_free:
+        df = _settings_tecu(df)
+    elif options.normalize_doppler and integer_epochs:
+        # The detection works on datetimes: convert the timestamps back and forth
+        unit = options.epoch_dtype.removeprefix("i64_")
+        df = _normalize_doppler(
+            df.with_columns(pl.col("epoch").cast(pl.Datetime(unit))), phase_unit=options.phase_unit
+        ).with_columns(pl.col("epoch").to_physical())
+    elif options.normalize_doppler:
+        df = _normalize_doppler(df, phase_unit=options.phase_unit)
+    return df, rec_pos, rinex_version, stats
+
+
+def split_constellations(df: pl.DataFrame) -> dict[str, pl.DataFrame]:
+    """
+    Splits long-format observations into a dictionary of DataFrames, one per constellation.
+
+    The split is done by the Rust backend, rather than by a large `group_by` in Python,
+    for constellations processed independently.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        Observations with an 'sv' column, as returned by `read_rinex_obs`.
+
+    Returns
+    -------
+    dict[str, pl.DataFrame]
+        Observations keyed by constellation name, like the DataFrames of
+        `read_rinex_nav` ('GPS', 'GLONASS', 'GALILEO', 'BEIDOU', plus 'QZSS', 'IRNSS'
+        and 'SBAS'), with the rows in their original order; a sorted 'epoch' column
+        stays flagged as sorted.
+
+    Examples
+    --------
+    >>> df, _, _ = read_rinex_obs("ASIR00ITA_R_20242810000_01D_30S_MO.rnx")
+    >>> gps = split_constellations(df)["GPS"]
+    """
+    parts = _split_constellations(df)
+    if "epoch" in df.columns and df["epoch"].flags["SORTED_ASC"]:
+        return {name: part.with_columns(pl.col("epoch").set_sorted()) for name, part in parts.items()}
+    return parts
 
 
-def read_rinex_nav(path: Union[str, Path]) -> dict[str, pl.DataFrame]:
+def read_rinex_obs_glob(
+    pattern: Union[str, Path],
+    concat: bool = False,
+    workers: Optional[int] = None,
+    **kwargs,
+) -> Union[dict[tuple[str, date], pl.DataFrame], pl.DataFrame]:
+    """
+    Reads all the RINEX observation files matching a glob pattern, keyed by station and day.
+
+    Station and date are taken from the filenames, in the short (e.g.,
+    'grot0010.24o') or long (e.g., 'GROT00ITA_R_20240010000_01D_30S_MO.crx.gz')
+    naming conventions; files following neither are skipped with a warning.
+    Files are parsed concurrently by the Rust backend.
+
+    Parameters
+    ----------
+    pattern : str or pathlib.Path
+        Glob pattern, recursive with '**' (e.g., 'data/**/*.crx.gz').
+    concat : bool, optional
+        If True, a single DataFrame is returned, with 'station' and 'date' columns.
+    workers : int, optional
+        Number of files parsed in parallel, by default the number of CPUs.
+    **kwargs
+        Arguments passed to `read_rinex_obs` (e.g., `options`).
+
+    Returns
+    -------
+    dict[tuple[str, datetime.date], pl.DataFrame] or pl.DataFrame
+        Observations keyed by (station, date), with files sharing the same key (e.g.,
+        hourly files) concatenated in filename order, or a single DataFrame with
+        'station' and 'date' columns if `concat` is True.
+    """
+    files = []
+    for path in sorted(glob.glob(str(pattern), recursive=True)):
+        key = _station_and_date(path)
+        if key is None:
+            warnings.warn(f"Skipping {path}: not a RINEX filename")
+            continue
+        files.append((key, path))
+
+    with ThreadPoolExecutor(max_workers=workers) as pool:
+        frames = pool.map(lambda f: read_rinex_obs(f[1], **kwargs)[0], files)
+        grouped: dict[tuple[str, date], list[pl.DataFrame]] = {}
+        for (key, _), df in zip(files, frames):
+            grouped.setdefault(key, []).append(df)
+
+    if concat:
+        if not grouped:
+            return pl.DataFrame()
+        return pl.concat(
+            [
+                df.with_columns(
+                    pl.lit(station).alias("station"), pl.lit(day).alias("date")
+                )
+                for (station, day), dfs in grouped.items()
+                for df in dfs
+            ],
+            how="diagonal",
+        )
+    return {
+        key: dfs[0] if len(dfs) == 1 else pl.concat(dfs, how="diagonal")
+        for key, dfs in grouped.items()
+    }
+
+
+def read_rinex_nav(
+    path: Union[str, Path],
+    time_scale: Optional[str] = None,
+    time_unit: str = "us",
+    sort: bool = False,
+) -> dict[str, pl.DataFrame]:
     """
     Parses a RINEX navigation file into a dictionary of DataFrames.
 
+    RINEX 2 GLONASS ('G') and GEO ('H') navigation files are read into the same
+    'GLONASS' and 'SBAS' DataFrames as the records of RINEX 3/4 files, with their
+    epochs in UTC (GLONASS) and GPS time (SBAS) respectively.
+
     Parameters
     ----------
     path : str or pathlib.Path
         Path to the RINEX navigation file.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the 'epoch' column. By default, epochs are given in the
+        timescale of each constellation (e.g., BDT for BeiDou, UTC(SU) for
+        GLONASS), as recorded in the file.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' column, by default microseconds.
+    sort : bool, optional
+        If True, each DataFrame is sorted by (epoch, sv) and the 'epoch' column is
+        flagged as sorted.
 
     Returns
     -------
     dict[str, pl.DataFrame]
-        Dictionary keyed by constellation (e.g., 'GPS'), containing
-        DataFrames with 'epoch' as datetime[μs, UTC] and orbital parameters.
+        Dictionary keyed by constellation (e.g., 'GPS', 'SBAS'), containing
+        DataFrames with 'epoch' as datetime[μs, UTC] (or datetime[ns, UTC], with
+        `time_unit='ns'`) and orbital parameters, along with:
+            - gps_week: Continuous GPS week (no 1024-week rollover) in true GPST
+            - tow: GPS time of week in seconds
+            - native_week, native_tow: Week and time of week in the constellation's
+              own timescale (GPS, Galileo GST or BeiDou BDT weeks; null for GLONASS
+              and SBAS)
+            - ura_index: Broadcast accuracy index (URA index for GPS, BeiDou and
+              SBAS, SISA index for Galileo; absent for GLONASS)
+            - ura: Broadcast accuracy in meters (upper bound of the URA interval, or
+              SISA), null without accuracy prediction, e.g. to weight or screen the
+              ephemerides
+            - fit_interval: Validity duration of the record (the broadcast GPS fit
+              interval, 4 hours by default; nominally 4 hours for Galileo, 2 hours
+              for BeiDou, 30 minutes for GLONASS and 6 minutes for SBAS)
+            - valid_from, valid_until: Validity window of the record, centred on
+              its epoch, in the timescale of the 'epoch' column
+            - toc: Clock reference epoch (the epoch of the record), to which the
+              clock polynomial ('clock_bias', 'clock_drift', ...) refers
+            - toe_epoch: Orbit reference time, i.e. 'toe' (seconds of the week)
+              as a datetime in the timescale of the 'epoch' column; absent for
+              GLONASS and SBAS, whose state vectors refer to the record epoch
+            - satPosX, ..., accelZ: State vectors of GLONASS and SBAS (km, km/s,
+              km/s²), with zero values kept as zeros
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    ValueError
+        If the file is not a RINEX navigation file.
     """
+    if detect_type(path) != "nav":
+        raise ValueError(f"Not a RINEX navigation file: {path}")
     path_str = str(path)
-    nav_dict = _read_rinex_nav(path_str)
+    nav_dict = _read_rinex_nav(path_str, time_scale, time_unit, sort)
+    epoch = pl.col("epoch").dt.replace_time_zone("UTC")
     return {
-        const: df.with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+        const: df.with_columns(
+            epoch.set_sorted() if sort else epoch,
+            pl.col([c for c in _NAV_DATETIMES if c in df.columns]).dt.replace_time_zone("UTC"),
+        )
         for const, df in nav_dict.items()
     }
+
+
+def read_rinex(path: Union[str, Path], **kwargs: Any) -> Any:
+    """
+    Reads a RINEX or IONEX file with the reader of its type, detected from the header.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the file (optionally Hatanaka-compressed, '.crx', or
+        gzip-compressed, '.gz').
+    **kwargs
+        Options of the reader (e.g., `time_scale`), only supported by the
+        observation and navigation readers.
+
+    Returns
+    -------
+    tuple, dict[str, pl.DataFrame] or pl.DataFrame
+        Depending on the type returned by `detect_type`:
+            - 'obs': the tuple of `read_rinex_obs`
+            - 'nav': the dictionary of `read_rinex_nav`
+            - 'met': the DataFrame of `read_rinex_met`
+            - 'clk': the DataFrame of `read_rinex_clk`
+            - 'ionex': the DataFrame of `read_ionex`
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    ValueError
+        If the file type is not supported, or options are given for a reader
+        without options.
+
+    Examples
+    --------
+    >>> nav = read_rinex("BRDC00IGS_R_20250950000_01D_MN.rnx")
+    >>> nav["GPS"]
+    """
+    file_type = detect_type(path)
+    if file_type == "obs":
+        return read_rinex_obs(path, **kwargs)
+    if file_type == "nav":
+        return read_rinex_nav(path, **kwargs)
+    if kwargs:
+        raise ValueError(f"Options are not supported for '{file_type}' files: {sorted(kwargs)}")
+    readers = {"met": read_rinex_met, "clk": read_rinex_clk, "ionex": read_ionex}
+    return readers[file_type](path)
+
+
+def read_rinex_events(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Parses the special event records of a RINEX observation file.
+
+    Epochs with flags 2 to 5 do not carry observations and are skipped by
+    `read_rinex_obs`; they mark antenna moves, new site occupations, header records
+    inserted in the body (e.g., a new antenna after a swap) and external events.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - epoch: Epoch of the event as datetime[μs, UTC] (null if not given)
+            - flag: Epoch flag (2 to 5)
+            - event: 'antenna_moving', 'new_site_occupation', 'header_information'
+              or 'external_event'
+            - records: Lines following the event record, newline-separated
+              (e.g., the inserted header records)
+    """
+    df = _read_rinex_events(str(path))
+    return df.with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+
+
+def read_rinex_positions(
+    path: Union[str, Path],
+    trajectory: Optional[pl.DataFrame] = None,
+) -> pl.DataFrame:
+    """
+    Returns the marker position at every observation epoch of a RINEX file.
+
+    Kinematic (e.g., shipborne or airborne) files do not have a single static
+    position: an 'antenna being moved' event (flag 2) starts a kinematic segment
+    with unknown position, which ends at the next 'new site occupation' (flag 3).
+    New positions are taken from the 'APPROX POSITION XYZ' records following the
+    events. Externally estimated positions (e.g., a PPP or INS trajectory) can be
+    supplied and take precedence wherever they cover the observation epochs.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+    trajectory : pl.DataFrame, optional
+        External positions with columns 'epoch', 'x', 'y', 'z' (ECEF, meters),
+        linearly interpolated at the observation epochs.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - epoch: Observation epoch as datetime[μs, UTC]
+            - x, y, z: Marker position in ECEF coordinates (meters), null if unknown
+            - kinematic: Whether the antenna is being moved
+            - source: 'header', 'event' or 'trajectory'
+    """
+    if trajectory is not None:
+        trajectory = trajectory.select(
+            pl.col("epoch").dt.replace_time_zone(None).dt.cast_time_unit("us"),
+            pl.col("x", "y", "z").cast(pl.Float64),
+        )
+    df = _read_rinex_positions(str(path), trajectory)
+    return df.with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+
+
+def read_rinex_phase_shifts(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Reads the carrier phase shifts declared in the header of a RINEX 3/4 observation file.
+
+    Since RINEX 3.01, the 'SYS / PHASE SHIFT' records declare the corrections (in
+    cycles) aligning the phases of the signals of a frequency band, e.g. the
+    quarter-cycle shifts between L2C and L2P. Shifts unaccounted for bias the
+    combinations of phases from different tracking modes, such as the
+    geometry-free combination.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with one row per declared shift and columns:
+            - system: Constellation (RINEX letter, e.g. 'G')
+            - observable: Carrier phase observable (e.g. 'L2S')
+            - shift: Correction in cycles
+            - sv: Satellite concerned, null if the shift applies to all the
+              satellites of the constellation
+    """
+    return _read_rinex_phase_shifts(str(path))
+
+
+def read_rinex_scale_factors(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Reads the scale factors declared in the header of a RINEX 3/4 observation file.
+
+    High-precision files may record some observation types multiplied by a power
+    of ten ('SYS / SCALE FACTOR'); `read_rinex_obs` divides the values by their
+    factors, and reports the scaled observables in its `ParseStats`.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with one row per scaled observation type and columns:
+            - system: Constellation (RINEX letter, e.g. 'G')
+            - observable: Observation type (e.g. 'L1C'), null if the factor applies
+              to all the observation types of the constellation
+            - factor: Scale factor (1, 10, 100 or 1000)
+    """
+    return _read_rinex_scale_factors(str(path))
+
+
+def rinex_time_bounds(
+    path: Union[str, Path],
+) -> tuple[Optional[datetime], Optional[datetime], int]:
+    """
+    Returns the first and last epochs of a RINEX observation file, and its number of epochs.
+
+    Only the epoch lines are scanned, without decoding the observations (Hatanaka
+    compressed files are not decompressed either), so that archive indexes can be
+    built much faster than by parsing the files.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    tuple
+        - datetime or None: First epoch, as recorded in the file (i.e. in GPS time
+          for GPS and mixed files), labelled as UTC like the 'epoch' column of
+          `read_rinex_obs`; None without observation epochs
+        - datetime or None: Last epoch
+        - int: Number of observation epochs (special events excluded)
+
+    Examples
+    --------
+    >>> first, last, n_epochs = rinex_time_bounds("GROT00ITA_R_20250950000_01D_30S_MO.crx")
+    """
+    return _rinex_time_bounds(str(path))
+
+
+@dataclass
+class SiteInfo:
+    """
+    Marker, receiver and antenna metadata of a RINEX observation header.
+
+    Attributes
+    ----------
+    marker_name, marker_number, marker_type : str or None
+        Marker records of the header.
+    receiver_type, receiver_serial, receiver_firmware : str or None
+        'REC # / TYPE / VERS' record.
+    antenna_type, radome, antenna_serial : str or None
+        IGS antenna type and radome code (e.g., 'TRM59800.00' and 'SCIS';
+        'NONE' without radome) and serial number, from 'ANT # / TYPE'.
+    delta_hen : tuple[float, float, float]
+        Antenna height and east/north eccentricities of the antenna reference
+        point (ARP) above the marker (meters).
+    marker_position : tuple[float, float, float] or None
+        Marker position in ECEF coordinates (meters).
+    arp_position : tuple[float, float, float] or None
+        ARP position in ECEF coordinates (meters), i.e. the marker position
+        shifted by `delta_hen`.
+    phase_center_position : tuple[float, float, float] or None
+        Mean phase center position in ECEF coordinates (meters), when a phase
+        center offset was provided.
+    """
+
+    marker_name: Optional[str]
+    marker_number: Optional[str]
+    marker_type: Optional[str]
+    receiver_type: Optional[str]
+    receiver_serial: Optional[str]
+    receiver_firmware: Optional[str]
+    antenna_type: Optional[str]
+    radome: Optional[str]
+    antenna_serial: Optional[str]
+    delta_hen: tuple[float, float, float]
+    marker_position: Optional[tuple[float, float, float]]
+    arp_position: Optional[tuple[float, float, float]]
+    phase_center_position: Optional[tuple[float, float, float]]
+
+    def to_dict(self) -> dict:
+        """Metadata as a dictionary of JSON-compatible values (positions as lists)."""
+        return {
+            name: list(value) if isinstance(value, tuple) else value
+            for name, value in asdict(self).items()
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "SiteInfo":
+        """Metadata from a dictionary as returned by `to_dict`."""
+        positions = ("delta_hen", "marker_position", "arp_position", "phase_center_position")
+        return cls(
+            **{
+                name: tuple(value) if name in positions and value is not None else value
+                for name, value in data.items()
+            }
+        )
+
+
+def read_rinex_site(
+    path: Union[str, Path],
+    phase_center_offset: Optional[tuple[float, float, float]] = None,
+) -> SiteInfo:
+    """
+    Reads the marker, receiver and antenna metadata of a RINEX observation file.
+
+    The header position refers to the marker, whereas precise workflows need the
+    antenna reference point (ARP) or the antenna phase center: the 'ANTENNA: DELTA
+    H/E/N' eccentricities are applied in the local frame of the marker to obtain
+    the ARP, and an optional phase center offset is applied on top of it.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+    phase_center_offset : tuple[float, float, float], optional
+        Mean phase center offset from the ARP as (north, east, up) in meters,
+        following the ANTEX convention.
+
+    Returns
+    -------
+    SiteInfo
+        Marker, receiver and antenna metadata, with marker, ARP and (optionally)
+        phase center positions in ECEF coordinates.
+    """
+    return SiteInfo(**_read_rinex_site(str(path), phase_center_offset))
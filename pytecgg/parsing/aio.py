@@ -0,0 +1,83 @@
+import asyncio
+import functools
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import spawn_blocking as _spawn_blocking
+
+
+def _resolve(future: asyncio.Future, result, error: Optional[BaseException]) -> None:
+    # The awaiting task may have been cancelled while the file was being parsed
+    if future.cancelled():
+        return
+    if error is not None:
+        future.set_exception(error)
+    else:
+        future.set_result(result)
+
+
+async def _run(func, *args, **kwargs):
+    """Awaits `func(*args, **kwargs)`, run on the thread pool of the Rust backend."""
+    loop = asyncio.get_running_loop()
+    future = loop.create_future()
+    _spawn_blocking(func, args, kwargs, loop, functools.partial(_resolve, future))
+    return await future
+
+
+async def read_rinex_obs_async(
+    path: Union[str, Path], **kwargs
+) -> tuple[pl.DataFrame, tuple[float, float, float], str]:
+    """
+    Awaitable variant of `read_rinex_obs`, for asyncio-based ingestion services.
+
+    The file is parsed on a thread pool of the Rust backend, without holding the
+    GIL, so that the event loop keeps serving other tasks; several files can be
+    read concurrently with `asyncio.gather`.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+    **kwargs
+        Arguments of `read_rinex_obs` (e.g., `options`).
+
+    Returns
+    -------
+    tuple
+        The DataFrame, receiver position and RINEX version returned by `read_rinex_obs`.
+
+    Examples
+    --------
+    >>> obs, rec_pos, version = await read_rinex_obs_async("grot0010.24o")
+    >>> results = await asyncio.gather(*(read_rinex_obs_async(p) for p in paths))
+    """
+    from . import read_rinex_obs
+
+    return await _run(read_rinex_obs, path, **kwargs)
+
+
+async def read_rinex_nav_async(path: Union[str, Path], **kwargs) -> dict[str, pl.DataFrame]:
+    """
+    Awaitable variant of `read_rinex_nav`, parsing the file on a thread pool of the Rust backend.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX navigation file.
+    **kwargs
+        Options of `read_rinex_nav` (e.g., `time_scale`, `sort`).
+
+    Returns
+    -------
+    dict[str, pl.DataFrame]
+        DataFrames keyed by constellation, as returned by `read_rinex_nav`.
+
+    Examples
+    --------
+    >>> nav = await read_rinex_nav_async("BRDC00IGS_R_20240010000_01D_MN.rnx.gz")
+    """
+    from . import read_rinex_nav
+
+    return await _run(read_rinex_nav, path, **kwargs)
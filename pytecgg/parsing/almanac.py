@@ -0,0 +1,159 @@
+import gzip
+import math
+from pathlib import Path
+from typing import Union
+
+import polars as pl
+
+# Nominal inclination of the GPS orbits (semicircles), the reference of the SEM 'delta i'
+_SEM_I0 = 0.30
+
+# Almanac parameters, named as the orbital parameters of `read_rinex_nav`
+_ALMANAC_SCHEMA = {
+    "sv": pl.String,
+    "week": pl.Int32,
+    "toa": pl.Float64,
+    "health": pl.Int32,
+    "e": pl.Float64,
+    "i0": pl.Float64,
+    "omegaDot": pl.Float64,
+    "sqrta": pl.Float64,
+    "omega0": pl.Float64,
+    "omega": pl.Float64,
+    "m0": pl.Float64,
+    "af0": pl.Float64,
+    "af1": pl.Float64,
+}
+
+# Leading words of the YUMA labels
+_YUMA_FIELDS = {
+    "id": "sv",
+    "health": "health",
+    "eccentricity": "e",
+    "time": "toa",
+    "orbital": "i0",
+    "rate": "omegaDot",
+    "sqrt(a)": "sqrta",
+    "right": "omega0",
+    "argument": "omega",
+    "mean": "m0",
+    "af0(s)": "af0",
+    "af1(s/s)": "af1",
+    "week": "week",
+}
+
+
+def _read_text(path: Union[str, Path]) -> str:
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+    opener = gzip.open if path.suffix == ".gz" else open
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        return f.read()
+
+
+def _almanac_frame(records: list[dict]) -> pl.DataFrame:
+    return pl.DataFrame(records, schema=_ALMANAC_SCHEMA).sort("sv")
+
+
+def read_yuma_almanac(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read a GPS almanac in the YUMA format.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the YUMA almanac (e.g., '.alm', optionally gzip-compressed, '.gz').
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per satellite, with columns 'sv' (e.g., 'G01'), 'week' (GPS week as
+        broadcast, i.e. modulo 1024), 'toa' (time of applicability, seconds of the
+        week), 'health', 'e', 'i0' (rad), 'omegaDot' (rad/s), 'sqrta' (m^1/2),
+        'omega0' (rad), 'omega' (rad), 'm0' (rad), 'af0' (s) and 'af1' (s/s).
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    records = []
+    record: dict = {}
+    for line in _read_text(path).splitlines():
+        if line.startswith("*"):
+            if record:
+                records.append(record)
+            record = {}
+            continue
+        label, sep, value = line.partition(":")
+        if not sep or not value.strip():
+            continue
+        name = _YUMA_FIELDS.get(label.split()[0].lower()) if label.split() else None
+        if name is None:
+            continue
+        if name == "sv":
+            record[name] = f"G{int(value):02d}"
+        elif name in ("week", "health"):
+            record[name] = int(value)
+        else:
+            record[name] = float(value)
+    if record:
+        records.append(record)
+    return _almanac_frame(records)
+
+
+def read_sem_almanac(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read a GPS almanac in the SEM format.
+
+    The angles of the SEM format, in semicircles, are converted to radians, and the
+    inclination offset to the absolute inclination, as in `read_yuma_almanac`.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the SEM almanac (e.g., '.al3', optionally gzip-compressed, '.gz').
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per satellite, with the columns of `read_yuma_almanac`.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    ValueError
+        If the file is truncated.
+    """
+    lines = _read_text(path).splitlines()
+    count = int(lines[0].split()[0])
+    week, toa = lines[1].split()[:2]
+    # PRN, SVN, URA, nine orbital and clock parameters, health and configuration
+    tokens = " ".join(lines[2:]).split()
+    if len(tokens) < 14 * count:
+        raise ValueError(f"Truncated SEM almanac: {count} records announced")
+
+    records = []
+    for k in range(count):
+        t = tokens[14 * k : 14 * (k + 1)]
+        e, delta_i, omega_dot, sqrta, omega0, omega, m0, af0, af1 = map(float, t[3:12])
+        records.append(
+            {
+                "sv": f"G{int(t[0]):02d}",
+                "week": int(week),
+                "toa": float(toa),
+                "health": int(t[12]),
+                "e": e,
+                "i0": (_SEM_I0 + delta_i) * math.pi,
+                "omegaDot": omega_dot * math.pi,
+                "sqrta": sqrta,
+                "omega0": omega0 * math.pi,
+                "omega": omega * math.pi,
+                "m0": m0 * math.pi,
+                "af0": af0,
+                "af1": af1,
+            }
+        )
+    return _almanac_frame(records)
@@ -0,0 +1,179 @@
+from dataclasses import dataclass
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+_PCO_SCHEMA = {
+    "antenna_type": pl.String,
+    "radome": pl.String,
+    "serial": pl.String,
+    "frequency": pl.String,
+    "north": pl.Float64,
+    "east": pl.Float64,
+    "up": pl.Float64,
+}
+
+_PCV_SCHEMA = {
+    "antenna_type": pl.String,
+    "radome": pl.String,
+    "serial": pl.String,
+    "frequency": pl.String,
+    "azimuth": pl.Float64,
+    "zenith": pl.Float64,
+    "pcv": pl.Float64,
+}
+
+
+@dataclass
+class Antex:
+    """
+    Antenna calibrations of an ANTEX file.
+
+    Attributes
+    ----------
+    pco : pl.DataFrame
+        Phase-center offsets, one row per antenna and frequency: 'antenna_type',
+        'radome' ('NONE' without radome), 'serial' (empty for type-mean
+        calibrations), 'frequency' (ANTEX code, e.g. 'G01'), 'north', 'east',
+        'up' (meters, from the antenna reference point).
+    pcv : pl.DataFrame
+        Phase-center variations, one row per antenna, frequency and grid node:
+        'antenna_type', 'radome', 'serial', 'frequency', 'azimuth' (degrees,
+        null for the azimuth-independent 'NOAZI' values), 'zenith' (degrees),
+        'pcv' (meters).
+    """
+
+    pco: pl.DataFrame
+    pcv: pl.DataFrame
+
+    def antenna(
+        self, antenna_type: str, radome: str = "NONE", serial: Optional[str] = None
+    ) -> "Antex":
+        """
+        Calibration of a single antenna.
+
+        Parameters
+        ----------
+        antenna_type : str
+            IGS antenna type (e.g., 'TRM59800.00'), as in the 'ANT # / TYPE' record.
+        radome : str, optional
+            Radome code, by default 'NONE'.
+        serial : str, optional
+            Antenna serial number: its individual calibration is used when the file
+            has one, the type-mean calibration otherwise.
+
+        Returns
+        -------
+        Antex
+            Calibrations of the antenna.
+
+        Raises
+        ------
+        KeyError
+            If the file has no calibration of the antenna and radome.
+        """
+        radome = radome.strip() or "NONE"
+        selected = self.pco.filter(
+            (pl.col("antenna_type") == antenna_type) & (pl.col("radome") == radome)
+        )
+        if serial and (selected["serial"] == serial).any():
+            serial_ = serial
+        else:
+            serial_ = ""
+        keys = (
+            (pl.col("antenna_type") == antenna_type)
+            & (pl.col("radome") == radome)
+            & (pl.col("serial") == serial_)
+        )
+        pco = self.pco.filter(keys)
+        if pco.is_empty():
+            raise KeyError(f"No calibration of antenna '{antenna_type} {radome}'")
+        return Antex(pco=pco, pcv=self.pcv.filter(keys))
+
+
+def _floats(text: str) -> list[float]:
+    return [float(value) for value in text.split()]
+
+
+def read_antex(path: Union[str, Path]) -> Antex:
+    """
+    Read the receiver antenna calibrations of an ANTEX 1.4 file.
+
+    Satellite antennas (identified by their PRN in the serial number field) are
+    read as well, with their PRN as serial number. Offsets and variations are
+    converted from millimeters to meters.
+
+    Parameters
+    ----------
+    path : str or Path
+        Path to the ANTEX file (e.g., 'igs20.atx').
+
+    Returns
+    -------
+    Antex
+        Phase-center offsets and variations per antenna and frequency.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    pco_rows: list[tuple] = []
+    pcv_rows: list[tuple] = []
+    antenna: Optional[tuple[str, str, str]] = None
+    frequency: Optional[str] = None
+    zeniths: list[float] = []
+    in_header, in_rms = True, False
+
+    with path.open("r", encoding="ascii", errors="replace") as f:
+        for line in f:
+            line = line.rstrip("\r\n")
+            label = line[60:80].strip()
+            if in_header:
+                in_header = label != "END OF HEADER"
+                continue
+
+            if label == "START OF FREQ RMS":
+                in_rms = True
+            elif label == "END OF FREQ RMS":
+                in_rms = False
+            elif in_rms:
+                continue
+            elif label == "TYPE / SERIAL NO":
+                antenna = (
+                    line[0:16].strip(),
+                    line[16:20].strip() or "NONE",
+                    line[20:40].strip(),
+                )
+            elif label == "ZEN1 / ZEN2 / DZEN":
+                zen1, zen2, dzen = _floats(line[2:20])
+                zeniths = [zen1 + i * dzen for i in range(round((zen2 - zen1) / dzen) + 1)]
+            elif label == "START OF FREQUENCY":
+                frequency = line[3:6].replace(" ", "0")
+            elif label == "END OF FREQUENCY":
+                frequency = None
+            elif label == "NORTH / EAST / UP" and antenna and frequency:
+                north, east, up = _floats(line[0:30])
+                pco_rows.append((*antenna, frequency, north / 1e3, east / 1e3, up / 1e3))
+            elif antenna and frequency and line[3:8] == "NOAZI":
+                values = _floats(line[8:])
+                pcv_rows.extend(
+                    (*antenna, frequency, None, zenith, value / 1e3)
+                    for zenith, value in zip(zeniths, values)
+                )
+            elif antenna and frequency and line.strip():
+                azimuth, *values = _floats(line[: 8 + 8 * len(zeniths)])
+                pcv_rows.extend(
+                    (*antenna, frequency, azimuth, zenith, value / 1e3)
+                    for zenith, value in zip(zeniths, values)
+                )
+
+    return Antex(
+        pco=pl.DataFrame(pco_rows, schema=_PCO_SCHEMA, orient="row"),
+        pcv=pl.DataFrame(pcv_rows, schema=_PCV_SCHEMA, orient="row"),
+    )
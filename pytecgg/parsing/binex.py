@@ -0,0 +1,67 @@
+from datetime import date, datetime
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import BinexDecoder
+from .raw import RawSource, _feed, _reference_nanos, _with_utc
+
+
+def read_binex(
+    source: RawSource,
+    reference: Optional[Union[date, datetime]] = None,
+    time_scale: str = "GPST",
+    nav_time_scale: Optional[str] = None,
+    time_unit: str = "us",
+    glonass_channels: Optional[dict[str, int]] = None,
+) -> tuple[pl.DataFrame, dict[str, pl.DataFrame]]:
+    """
+    Decodes a BINEX log into observation and navigation DataFrames.
+
+    Big-endian, forward-readable records (synchronization byte 0xE2) are supported:
+    0x7F-05 observations (Trimble NetR8 and later receivers) and 0x01-01, 0x01-02
+    and 0x01-04 decoded GPS, GLONASS and Galileo ephemerides. They are mapped onto
+    the schemas of `read_rinex_obs` and `read_rinex_nav`, so that BINEX archives
+    can be processed as RINEX files. Records with a wrong checksum, and those
+    protected by CRC-32 or MD5 (larger than 4 kB), are skipped.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or binary file-like object
+        Path to the BINEX file (optionally gzip-compressed, '.gz'), or an open
+        binary stream.
+    reference : datetime.date or datetime.datetime, optional
+        Approximate date of the data, only needed to resolve the day of GLONASS
+        ephemerides recorded before the first observation epoch. By default, the
+        modification time of the file, or the current time for streams.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the observation epochs, by default 'GPST'.
+    nav_time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the ephemeris epochs; by default, the timescale of each
+        constellation, as `read_rinex_nav`.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' columns, by default microseconds.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV (e.g., {'R01': 1}), for the carrier phase
+        of satellites whose channel is not given in the records.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Observations, with the columns of `read_rinex_obs`
+          (including the receiver clock offset, when recorded)
+        - dict[str, pl.DataFrame]: Broadcast ephemerides keyed by constellation,
+          with the columns and units of `read_rinex_nav`
+
+    Examples
+    --------
+    >>> obs, nav = read_binex("p1230010.25.bnx.gz")
+    """
+    decoder = BinexDecoder(_reference_nanos(source, reference), glonass_channels)
+    _feed(decoder, source)
+    obs = _with_utc(decoder.take_obs(time_scale, time_unit))
+    nav = {
+        const: _with_utc(df)
+        for const, df in decoder.take_nav(nav_time_scale, time_unit).items()
+    }
+    return obs, nav
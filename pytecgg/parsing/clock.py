@@ -0,0 +1,93 @@
+import gzip
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+# Values of a clock record: bias, rate and acceleration, each with its sigma (seconds)
+_CLOCK_VALUES = (
+    "bias",
+    "bias_sigma",
+    "rate",
+    "rate_sigma",
+    "acceleration",
+    "acceleration_sigma",
+)
+
+
+def _epoch(fields: list[str]) -> datetime:
+    year, month, day, hour, minute = (int(v) for v in fields[:5])
+    return datetime(year, month, day, hour, minute, tzinfo=timezone.utc) + timedelta(
+        seconds=float(fields[5])
+    )
+
+
+def read_rinex_clk(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read a RINEX clock file (versions 2 to 3.04), e.g. the IGS precise clocks.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX clock file (e.g., '.clk' or '_CLK.CLK', optionally
+        gzip-compressed, '.gz'), as returned by `fetch_clk`.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - type: Record type ('AS' satellite, 'AR' receiver, 'CR' calibration,
+              'DR' discontinuity, 'MS' monitor clocks)
+            - name: Satellite (e.g., 'G01') or station (e.g., 'ALGO') identifier
+            - epoch: Epoch of the record (as recorded, GPS time labelled as UTC)
+            - bias, bias_sigma: Clock bias and its sigma (seconds)
+            - rate, rate_sigma: Clock rate and its sigma (s/s), null if not given
+            - acceleration, acceleration_sigma: Clock acceleration and its sigma
+              (1/s), null if not given
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    opener = gzip.open if path.suffix == ".gz" else open
+    types: list[str] = []
+    names: list[str] = []
+    epochs: list[datetime] = []
+    rows: list[list[Optional[float]]] = []
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        for line in f:
+            if line[60:].strip() == "END OF HEADER":
+                break
+
+        for line in f:
+            fields = line.split()
+            if len(fields) < 10:
+                continue
+            # Record type, name, epoch (6 fields), number of values, then the values,
+            # continued on the next line beyond the first two
+            n_values = int(fields[8])
+            values = [float(v.replace("D", "E")) for v in fields[9:]]
+            while len(values) < n_values:
+                values += [float(v.replace("D", "E")) for v in next(f, "").split()]
+            types.append(fields[0])
+            names.append(fields[1])
+            epochs.append(_epoch(fields[2:8]))
+            rows.append(values[:n_values] + [None] * (len(_CLOCK_VALUES) - n_values))
+
+    return pl.DataFrame(
+        {
+            "type": pl.Series(types, dtype=pl.String),
+            "name": pl.Series(names, dtype=pl.String),
+            "epoch": pl.Series(epochs, dtype=pl.Datetime("us", "UTC")),
+            **{
+                column: pl.Series([r[i] for r in rows], dtype=pl.Float64)
+                for i, column in enumerate(_CLOCK_VALUES)
+            },
+        }
+    )
@@ -0,0 +1,252 @@
+import re
+from datetime import datetime
+from pathlib import Path
+from typing import Optional, Union
+
+from .header import _obs_type_codes, _read_header
+
+# Header labels of RINEX 2, 3 and 4 observation files (columns 61-80)
+_LABELS = {
+    "RINEX VERSION / TYPE",
+    "PGM / RUN BY / DATE",
+    "COMMENT",
+    "MARKER NAME",
+    "MARKER NUMBER",
+    "MARKER TYPE",
+    "OBSERVER / AGENCY",
+    "REC # / TYPE / VERS",
+    "ANT # / TYPE",
+    "APPROX POSITION XYZ",
+    "ANTENNA: DELTA H/E/N",
+    "ANTENNA: DELTA X/Y/Z",
+    "ANTENNA:PHASECENTER",
+    "ANTENNA: B.SIGHT XYZ",
+    "ANTENNA: ZERODIR AZI",
+    "ANTENNA: ZERODIR XYZ",
+    "CENTER OF MASS: XYZ",
+    "DOI",
+    "LICENSE OF USE",
+    "STATION INFORMATION",
+    "WAVELENGTH FACT L1/2",
+    "# / TYPES OF OBSERV",
+    "SYS / # / OBS TYPES",
+    "SIGNAL STRENGTH UNIT",
+    "INTERVAL",
+    "TIME OF FIRST OBS",
+    "TIME OF LAST OBS",
+    "RCV CLOCK OFFS APPL",
+    "SYS / DCBS APPLIED",
+    "SYS / PCVS APPLIED",
+    "SYS / SCALE FACTOR",
+    "SYS / PHASE SHIFT",
+    "GLONASS SLOT / FRQ #",
+    "GLONASS COD/PHS/BIS",
+    "LEAP SECONDS",
+    "# OF SATELLITES",
+    "PRN / # OF OBS",
+    "END OF HEADER",
+}
+
+_MANDATORY_V2 = [
+    "RINEX VERSION / TYPE",
+    "PGM / RUN BY / DATE",
+    "MARKER NAME",
+    "OBSERVER / AGENCY",
+    "REC # / TYPE / VERS",
+    "ANT # / TYPE",
+    "APPROX POSITION XYZ",
+    "ANTENNA: DELTA H/E/N",
+    "WAVELENGTH FACT L1/2",
+    "# / TYPES OF OBSERV",
+    "TIME OF FIRST OBS",
+    "END OF HEADER",
+]
+
+_MANDATORY_V3 = [
+    "RINEX VERSION / TYPE",
+    "PGM / RUN BY / DATE",
+    "MARKER NAME",
+    "OBSERVER / AGENCY",
+    "REC # / TYPE / VERS",
+    "ANT # / TYPE",
+    "ANTENNA: DELTA H/E/N",
+    "SYS / # / OBS TYPES",
+    "TIME OF FIRST OBS",
+    "END OF HEADER",
+]
+
+_SYSTEMS = "GRECJISM"
+_CODE_V2 = re.compile(r"^[CLPDS][125678]$")
+_CODE_V3 = re.compile(r"^[CLDSX][0-9][A-Z]$")
+
+class RinexConformityError(ValueError):
+    """
+    Raised by `read_rinex_obs` in strict mode when a file violates the RINEX specification.
+
+    Attributes
+    ----------
+    path : str
+        Path to the rejected file.
+    violations : list[str]
+        All the violations found, as returned by `validate_rinex_obs`.
+    """
+
+    def __init__(self, path: str, violations: list[str]):
+        self.path = path
+        self.violations = violations
+        super().__init__(
+            f"{path} does not conform to the RINEX specification "
+            f"({len(violations)} violations):\n  " + "\n  ".join(violations)
+        )
+
+
+def _floats(text: str, width: int, count: int) -> Optional[list[float]]:
+    try:
+        return [float(text[i * width : (i + 1) * width]) for i in range(count)]
+    except ValueError:
+        return None
+
+
+def _check_record(label: str, line: str) -> Optional[str]:
+    """Violation of the fixed-format fields of a single record, if any."""
+    if label in ("APPROX POSITION XYZ", "ANTENNA: DELTA H/E/N"):
+        if _floats(line, 14, 3) is None:
+            return f"'{label}' must hold three F14.4 values"
+    elif label == "TIME OF FIRST OBS":
+        try:
+            fields = [int(line[i * 6 : (i + 1) * 6]) for i in range(5)]
+            seconds = float(line[30:43])
+            datetime(*fields, int(seconds))
+        except ValueError:
+            return f"'{label}' is not a valid epoch: '{line[:43].strip()}'"
+    elif label == "INTERVAL":
+        interval = _floats(line, 10, 1)
+        if interval is None or interval[0] <= 0:
+            return f"'{label}' must be a positive number of seconds"
+    elif label == "LEAP SECONDS":
+        try:
+            int(line[:6])
+        except ValueError:
+            return f"'{label}' must be an integer number of seconds"
+    return None
+
+
+def _check_obs_types(records: list[tuple[int, str]], version: float) -> list[str]:
+    """Violations of the observation types records: declared counts and codes."""
+    violations = []
+    # Records of each declaration: the first line carries the count, the
+    # continuation lines (blank count) the remaining codes
+    groups: list[tuple[int, str, int, list[str]]] = []
+    for number, line in records:
+        codes = _obs_type_codes(line, version)
+        if line[:6].strip():
+            system = "G" if version < 3 else line[0]
+            try:
+                count = int(line[1:6] if version >= 3 else line[:6])
+            except ValueError:
+                violations.append(f"line {number}: invalid number of observation types")
+                count = len(codes)
+            if version >= 3 and system not in _SYSTEMS.replace("M", ""):
+                violations.append(f"line {number}: unknown constellation '{system}' of the observation types")
+            groups.append((number, system, count, codes))
+        elif groups:
+            groups[-1][3].extend(codes)
+        else:
+            violations.append(f"line {number}: observation types without a declared count")
+
+    pattern = _CODE_V2 if version < 3 else _CODE_V3
+    for number, system, count, codes in groups:
+        if count != len(codes):
+            violations.append(
+                f"line {number}: {count} observation types declared for '{system}', {len(codes)} listed"
+            )
+        invalid = [c for c in codes if not pattern.match(c)]
+        if invalid:
+            violations.append(f"line {number}: invalid observation codes {invalid}")
+    return violations
+
+
+def validate_rinex_obs(path: Union[str, Path]) -> list[str]:
+    """
+    Checks the header of a RINEX observation file against the specification.
+
+    All the violations are collected rather than stopping at the first one, so that
+    a non-conforming file can be fixed in a single pass. The checks cover the
+    fixed-format layout of the records (80 columns, known labels), the version and
+    file type, the mandatory records of the version, the numeric fields (positions,
+    antenna eccentricities, first epoch, interval, leap seconds) and the
+    observation types (declared counts and valid codes).
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    list[str]
+        Violations found, each prefixed with its line number; empty if the header
+        conforms.
+
+    Examples
+    --------
+    >>> for violation in validate_rinex_obs("GROT00ITA_R_20250950000_01D_30S_MO.crx"):
+    ...     print(violation)
+    """
+    lines = _read_header(Path(path))
+    if not lines:
+        return ["empty file"]
+
+    violations = []
+    number, first = lines[0]
+    version = 0.0
+    if first[60:].strip() != "RINEX VERSION / TYPE":
+        violations.append(f"line {number}: the header must start with 'RINEX VERSION / TYPE'")
+    else:
+        try:
+            version = float(first[:9])
+        except ValueError:
+            violations.append(f"line {number}: invalid RINEX version '{first[:9].strip()}'")
+        if version and not 2 <= version < 5:
+            violations.append(f"line {number}: unsupported RINEX version {version}")
+        if first[20:21] != "O":
+            violations.append(f"line {number}: file type '{first[20:21]}' is not observation data ('O')")
+        if first[40:41] not in _SYSTEMS + " ":
+            violations.append(f"line {number}: unknown satellite system '{first[40:41]}'")
+
+    seen = set()
+    obs_types = []
+    for number, line in lines:
+        if len(line) > 80:
+            violations.append(f"line {number}: longer than 80 characters")
+        label = line[60:].strip()
+        if not label:
+            violations.append(f"line {number}: missing header label")
+            continue
+        if label not in _LABELS:
+            violations.append(f"line {number}: unknown header label '{label}'")
+            continue
+        seen.add(label)
+        if label in ("# / TYPES OF OBSERV", "SYS / # / OBS TYPES"):
+            obs_types.append((number, line))
+        elif (violation := _check_record(label, line)) is not None:
+            violations.append(f"line {number}: {violation}")
+
+    if version:
+        violations.extend(_check_obs_types(obs_types, version))
+        mandatory = list(_MANDATORY_V2 if version < 3 else _MANDATORY_V3)
+        if 3.01 <= version < 4:
+            mandatory.append("SYS / PHASE SHIFT")
+        if version >= 3.02 and any(line[0] == "R" for _, line in obs_types):
+            mandatory += ["GLONASS SLOT / FRQ #", "GLONASS COD/PHS/BIS"]
+        for label in mandatory:
+            if label not in seen:
+                violations.append(f"missing mandatory record '{label}'")
+    return violations
+
+
+def check_rinex_obs(path: Union[str, Path]) -> None:
+    """Raises a RinexConformityError listing the violations of a non-conforming file."""
+    violations = validate_rinex_obs(path)
+    if violations:
+        raise RinexConformityError(str(path), violations)
@@ -0,0 +1,70 @@
+import gzip
+from pathlib import Path
+from typing import Union
+
+import polars as pl
+
+_SCHEMA = {
+    "system": pl.String,
+    "sv": pl.String,
+    "station": pl.String,
+    "bias": pl.Float64,
+    "rms": pl.Float64,
+}
+
+
+def read_bernese_dcb(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read a differential code bias file in the Bernese DCB format.
+
+    This is the format of the monthly CODE P1-P2 ('P1P2yymm.DCB') and P1-C1
+    ('P1C1yymm.DCB') solutions. LZW-compressed files ('.Z', as distributed) must be
+    uncompressed first; gzip-compressed files are read directly.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the DCB file.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per satellite or receiver, with columns 'system' (RINEX letter),
+        'sv' (e.g. 'G01', null for receivers), 'station' (null for satellites),
+        'bias' and 'rms' (nanoseconds).
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    opener = gzip.open if path.suffix == ".gz" else open
+    rows = []
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        # Records follow the '***   ****...' line under the column titles
+        for line in f:
+            if line.startswith("***"):
+                break
+        for line in f:
+            line = line.rstrip("\r\n")
+            if not line.strip():
+                continue
+            # A3,2X,A16,4X,F9.3,3X,F9.3
+            prn, station = line[0:3].strip(), line[5:21].strip()
+            values = line[21:].split()
+            if not prn or len(values) < 2:
+                continue
+            rows.append(
+                (
+                    prn[0],
+                    prn if len(prn) == 3 and not station else None,
+                    station or None,
+                    float(values[0]),
+                    float(values[1]),
+                )
+            )
+    return pl.DataFrame(rows, schema=_SCHEMA, orient="row")
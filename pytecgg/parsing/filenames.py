@@ -0,0 +1,195 @@
+import re
+from dataclasses import dataclass
+from datetime import date, datetime, timedelta, timezone
+from pathlib import Path
+from typing import Literal, Optional, Union
+
+# Short names (RINEX 2): ssssdddf.yyt, with optional compression suffixes
+_SHORT_NAME = re.compile(
+    r"^(?P<station>[a-z0-9]{4})(?P<doy>\d{3})(?P<session>[a-x0])"
+    r"\.(?P<yy>\d{2})(?P<type>[a-z])(?:\.(?P<compression>gz|Z|bz2|zip))?$",
+    re.IGNORECASE,
+)
+# Long names (RINEX 3+): SSSSMRCCC_S_YYYYDDDHHMM_PPP[_FRU]_DT.FFF, with optional
+# compression suffixes; navigation files have no sampling rate
+_LONG_NAME = re.compile(
+    r"^(?P<site_id>(?P<station>[A-Z0-9]{4})\d\d(?P<country>[A-Z]{3}))"
+    r"_(?P<source>[RSU])_(?P<start>\d{11})_(?P<period>\d{2}[MHDYU])"
+    r"(?:_(?P<rate>\d{2}[CZSMHDU]))?_(?P<constellation>[GRECJISM])(?P<type>[ONM])"
+    r"\.(?P<format>rnx|crx)(?:\.(?P<compression>gz|Z|bz2|zip))?$",
+    re.IGNORECASE,
+)
+
+_FILE_TYPES = {"O": "observation", "N": "navigation", "M": "meteorological"}
+
+# Short-name type letters: file type, constellation (for navigation files), Hatanaka
+_SHORT_TYPES = {
+    "o": ("observation", None, False),
+    "d": ("observation", None, True),
+    "n": ("navigation", "G", False),
+    "g": ("navigation", "R", False),
+    "l": ("navigation", "E", False),
+    "f": ("navigation", "C", False),
+    "q": ("navigation", "J", False),
+    "h": ("navigation", "S", False),
+    "p": ("navigation", "M", False),
+    "m": ("meteorological", None, False),
+}
+
+# Seconds per unit of the period and sampling rate fields ('Z' is in Hz)
+_UNIT_SECONDS = {"C": 0.01, "S": 1, "M": 60, "H": 3600, "D": 86400, "Y": 365 * 86400}
+
+
+@dataclass(frozen=True)
+class RinexFilename:
+    """
+    Metadata encoded in a RINEX filename.
+
+    Attributes
+    ----------
+    convention : {'short', 'long'}
+        Naming convention: RINEX 2 short names (ssssdddf.yyt) or IGS/RINEX 3
+        long names.
+    station : str
+        4-character station (marker) name, lowercase.
+    site_id : str or None
+        9-character site identifier (e.g., 'GROT00ITA'), for long names.
+    country : str or None
+        ISO 3166 country code, for long names.
+    source : {'receiver', 'stream', 'unknown'} or None
+        Data source, for long names.
+    start : datetime
+        Start epoch of the file (UTC).
+    period : timedelta or None
+        Nominal file period (e.g., 1 day, or 1 hour for hourly sessions).
+    sample_rate : timedelta or None
+        Nominal sampling interval, when encoded in the name.
+    file_type : {'observation', 'navigation', 'meteorological'}
+        Content of the file.
+    constellation : str or None
+        Constellation code ('M' for mixed), when encoded in the name.
+    format : {'RINEX', 'CRINEX'}
+        Plain or Hatanaka-compressed RINEX.
+    compression : str or None
+        Compression suffix (e.g., 'gz', 'Z').
+    """
+
+    convention: Literal["short", "long"]
+    station: str
+    site_id: Optional[str]
+    country: Optional[str]
+    source: Optional[Literal["receiver", "stream", "unknown"]]
+    start: datetime
+    period: Optional[timedelta]
+    sample_rate: Optional[timedelta]
+    file_type: Literal["observation", "navigation", "meteorological"]
+    constellation: Optional[str]
+    format: Literal["RINEX", "CRINEX"]
+    compression: Optional[str]
+
+
+def _duration(field: Optional[str]) -> Optional[timedelta]:
+    """Period or sampling rate field (e.g., '01D', '30S', '05Z'); None if unspecified."""
+    if field is None:
+        return None
+    value, unit = int(field[:2]), field[2].upper()
+    if unit == "Z":
+        return timedelta(seconds=1 / value) if value else None
+    if unit == "U" or unit not in _UNIT_SECONDS:
+        return None
+    return timedelta(seconds=value * _UNIT_SECONDS[unit])
+
+
+def _parse_long(match: re.Match) -> RinexFilename:
+    start = datetime.strptime(match["start"], "%Y%j%H%M").replace(tzinfo=timezone.utc)
+    return RinexFilename(
+        convention="long",
+        station=match["station"].lower(),
+        site_id=match["site_id"].upper(),
+        country=match["country"].upper(),
+        source={"R": "receiver", "S": "stream", "U": "unknown"}[match["source"].upper()],
+        start=start,
+        period=_duration(match["period"]),
+        sample_rate=_duration(match["rate"]),
+        file_type=_FILE_TYPES[match["type"].upper()],
+        constellation=match["constellation"].upper(),
+        format="CRINEX" if match["format"].lower() == "crx" else "RINEX",
+        compression=match["compression"],
+    )
+
+
+def _parse_short(match: re.Match) -> RinexFilename:
+    file_type = _SHORT_TYPES.get(match["type"].lower())
+    if file_type is None:
+        raise ValueError(f"Unknown RINEX file type '{match['type']}'")
+    yy = int(match["yy"])
+    year = 2000 + yy if yy < 80 else 1900 + yy
+    session = match["session"].lower()
+    # Session '0' is a daily file, letters 'a' to 'x' are hourly sessions
+    hour = 0 if session == "0" else ord(session) - ord("a")
+    start = datetime(year, 1, 1, hour, tzinfo=timezone.utc) + timedelta(
+        days=int(match["doy"]) - 1
+    )
+    return RinexFilename(
+        convention="short",
+        station=match["station"].lower(),
+        site_id=None,
+        country=None,
+        source=None,
+        start=start,
+        period=timedelta(days=1) if session == "0" else timedelta(hours=1),
+        sample_rate=None,
+        file_type=file_type[0],
+        constellation=file_type[1],
+        format="CRINEX" if file_type[2] else "RINEX",
+        compression=match["compression"],
+    )
+
+
+def parse_rinex_filename(path: Union[str, Path]) -> RinexFilename:
+    """
+    Parse the metadata encoded in a RINEX filename, without reading the file.
+
+    Both the short RINEX 2 convention (e.g., 'grot0010.24o', 'grot001a.24d.Z') and
+    the IGS long convention (e.g., 'GROT00ITA_R_20240010000_01D_30S_MO.crx.gz') are
+    supported, e.g. to catalog archives before parsing.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        RINEX file path or name.
+
+    Returns
+    -------
+    RinexFilename
+        Station, start epoch, period, sampling rate, file type and format
+        (along with country and data source for long names).
+
+    Raises
+    ------
+    ValueError
+        If the name follows neither convention.
+    """
+    name = Path(path).name
+    if match := _LONG_NAME.match(name):
+        parse = _parse_long
+    elif match := _SHORT_NAME.match(name):
+        parse = _parse_short
+    else:
+        raise ValueError(f"Not a RINEX filename: '{name}'")
+    try:
+        return parse(match)
+    except ValueError as e:
+        raise ValueError(f"Invalid RINEX filename '{name}': {e}") from e
+
+
+def _station_and_date(path: Union[str, Path]) -> Optional[tuple[str, date]]:
+    """
+    Station (4-character, lowercase) and start date encoded in a short or long
+    RINEX filename, or None if the name follows neither convention.
+    """
+    try:
+        meta = parse_rinex_filename(path)
+    except ValueError:
+        return None
+    return meta.station, meta.start.date()
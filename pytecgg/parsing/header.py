@@ -0,0 +1,132 @@
+import gzip
+from pathlib import Path
+from typing import Literal, Union
+
+_CRINEX_LABELS = {"CRINEX VERS   / TYPE", "CRINEX PROG / DATE"}
+
+# Headers are short: stop looking for 'END OF HEADER' after this many lines
+_MAX_HEADER_LINES = 5000
+
+# Constellations sharing the observation types of mixed RINEX 2 files
+_V2_MIXED_SYSTEMS = "GRES"
+
+# File types of the 'RINEX VERSION / TYPE' record (column 21), navigation files of
+# RINEX 2 being split by constellation ('G' GLONASS, 'H' SBAS, 'L' Galileo)
+_RINEX_TYPES = {"O": "obs", "N": "nav", "G": "nav", "H": "nav", "L": "nav", "M": "met", "C": "clk"}
+
+
+def _read_header(path: Path) -> list[tuple[int, str]]:
+    """Numbered header lines, up to 'END OF HEADER' (Hatanaka preamble excluded)."""
+    opener = gzip.open if path.suffix == ".gz" else open
+    lines = []
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        for number, line in enumerate(f, start=1):
+            line = line.rstrip("\r\n")
+            if number <= 2 and line[60:].strip() in _CRINEX_LABELS:
+                continue
+            lines.append((number, line))
+            if line[60:].strip() == "END OF HEADER" or number >= _MAX_HEADER_LINES:
+                break
+    return lines
+
+
+def detect_type(path: Union[str, Path]) -> Literal["obs", "nav", "met", "clk", "ionex"]:
+    """
+    Determines the type of a RINEX or IONEX file from its first header line.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the file (optionally Hatanaka-compressed, '.crx', or
+        gzip-compressed, '.gz').
+
+    Returns
+    -------
+    {'obs', 'nav', 'met', 'clk', 'ionex'}
+        Observation, navigation, meteorological, clock or IONEX file.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    ValueError
+        If the file is not a RINEX or IONEX file, or its type is not supported.
+
+    Examples
+    --------
+    >>> detect_type("ASIR00ITA_R_20242810000_01D_MN.rnx")
+    'nav'
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    header = _read_header(path)
+    first = header[0][1] if header else ""
+    label = first[60:].strip()
+    if label == "IONEX VERSION / TYPE":
+        return "ionex"
+    if label != "RINEX VERSION / TYPE":
+        raise ValueError(f"Not a RINEX file (missing 'RINEX VERSION / TYPE'): {path}")
+    file_type = first[20:21]
+    if file_type not in _RINEX_TYPES:
+        raise ValueError(f"Unsupported RINEX file type '{file_type}': {path}")
+    return _RINEX_TYPES[file_type]
+
+
+def _obs_type_codes(line: str, version: float) -> list[str]:
+    """Observation codes listed on a '# / TYPES OF OBSERV' or 'SYS / # / OBS TYPES' line."""
+    # RINEX 2: 6X,9(4X,A2); RINEX 3/4: A1,2X,I3,13(1X,A3)
+    offset, step, width, per_line = (10, 6, 2, 9) if version < 3 else (7, 4, 3, 13)
+    codes = [line[offset + i * step : offset + i * step + width].strip() for i in range(per_line)]
+    return [c for c in codes if c]
+
+
+def read_rinex_obs_types(path: Union[str, Path]) -> dict[str, list[str]]:
+    """
+    Returns the observation types declared in the header of a RINEX observation file.
+
+    Only the header is read, so that the signals a file should contain are known
+    before parsing it (e.g., to select files or observables), and can serve as the
+    reference of completeness checks after parsing.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+
+    Returns
+    -------
+    dict[str, list[str]]
+        Observation codes keyed by constellation (RINEX letter, e.g. 'G'), in the
+        order of the header. RINEX 2 codes (e.g. 'L1', 'P2') are declared once for
+        all the constellations of the file: mixed files repeat them for GPS,
+        GLONASS, Galileo and SBAS.
+
+    Examples
+    --------
+    >>> read_rinex_obs_types("ASIR00ITA_R_20242810000_01D_30S_MO.rnx")["E"]
+    ['C1C', 'L1C', 'D1C', 'S1C', 'C5Q', 'L5Q', 'D5Q', 'S5Q', 'C7Q', ...]
+    """
+    lines = _read_header(Path(path))
+    first = lines[0][1] if lines else ""
+    if first[60:].strip() != "RINEX VERSION / TYPE" or first[20:21] != "O":
+        raise ValueError(f"Not a RINEX observation file: {path}")
+    version = float(first[:9])
+
+    declared: dict[str, list[str]] = {}
+    current = None
+    for _, line in lines:
+        if line[60:].strip() not in ("# / TYPES OF OBSERV", "SYS / # / OBS TYPES"):
+            continue
+        if line[:6].strip():
+            current = "G" if version < 3 else line[0]
+            declared.setdefault(current, [])
+        if current is not None:
+            declared[current].extend(_obs_type_codes(line, version))
+
+    if version < 3 and "G" in declared:
+        system = first[40:41].strip() or "G"
+        systems = _V2_MIXED_SYSTEMS if system == "M" else system
+        return {s: list(declared["G"]) for s in systems}
+    return declared
@@ -0,0 +1,93 @@
+import gzip
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Union
+
+import numpy as np
+import polars as pl
+
+# Values per line of a latitude band (16I5), and the marker of missing values
+_VALUES_PER_LINE = 16
+_VALUE_WIDTH = 5
+_MISSING = 9999
+
+
+def _epoch(line: str) -> datetime:
+    year, month, day, hour, minute, second = (int(line[i : i + 6]) for i in range(0, 36, 6))
+    return datetime(year, month, day, hour, minute, second, tzinfo=timezone.utc)
+
+
+def read_ionex(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read the TEC maps of an IONEX file, e.g. a global ionosphere map (GIM).
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the IONEX file (e.g., '.24i' or '_GIM.INX', optionally
+        gzip-compressed, '.gz').
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with one row per map epoch and grid node, with columns 'epoch',
+        'lat', 'lon' (degrees), 'tec' and 'rms' (TECU, null if missing or without
+        RMS maps), ready to be compared with `grid_vtec`. Height maps of 3D files
+        are not read: the nodes are those of the single-layer maps.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    opener = gzip.open if path.suffix == ".gz" else open
+    exponent = -1
+    maps: dict[str, dict[datetime, list[tuple[float, float, float]]]] = {"TEC": {}, "RMS": {}}
+    current = None
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        for line in f:
+            line = line.rstrip("\r\n")
+            label = line[60:].strip()
+            if label == "EXPONENT":
+                exponent = int(line[:6])
+            elif label in ("START OF TEC MAP", "START OF RMS MAP"):
+                kind, epoch = label[9:12], None
+            elif label == "EPOCH OF CURRENT MAP":
+                epoch = _epoch(line)
+                current = maps[kind].setdefault(epoch, [])
+            elif label in ("END OF TEC MAP", "END OF RMS MAP"):
+                current = None
+            elif label == "LAT/LON1/LON2/DLON/H" and current is not None:
+                lat, lon1, lon2, dlon = (float(line[i : i + 6]) for i in range(2, 26, 6))
+                n_values = int(round((lon2 - lon1) / dlon)) + 1
+                values: list[int] = []
+                while len(values) < n_values:
+                    row = next(f, "").rstrip("\r\n")
+                    count = min(n_values - len(values), _VALUES_PER_LINE)
+                    fields = (row[i * _VALUE_WIDTH : (i + 1) * _VALUE_WIDTH] for i in range(count))
+                    values += [int(v) if v.strip() else _MISSING for v in fields]
+                scale = 10.0**exponent
+                current.extend(
+                    (lat, lon1 + i * dlon, np.nan if v == _MISSING else v * scale)
+                    for i, v in enumerate(values)
+                )
+
+    schema = {"epoch": pl.Datetime("us", "UTC"), "lat": pl.Float64, "lon": pl.Float64}
+    frames = {
+        kind: pl.DataFrame(
+            [(epoch, *node) for epoch, nodes in by_epoch.items() for node in nodes],
+            schema={**schema, kind.lower(): pl.Float64},
+            orient="row",
+        )
+        for kind, by_epoch in maps.items()
+    }
+    return (
+        frames["TEC"]
+        .join(frames["RMS"], on=["epoch", "lat", "lon"], how="left")
+        .with_columns(pl.col("tec", "rms").fill_nan(None))
+        .sort("epoch", "lat", "lon")
+    )
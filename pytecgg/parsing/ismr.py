@@ -0,0 +1,187 @@
+import gzip
+import io
+from pathlib import Path
+from typing import Union
+
+import polars as pl
+
+from ..timescales import convert_timescale
+
+_GPS_EPOCH_NANOS = 315_964_800 * 10**9
+_WEEK_NANOS = 604_800 * 10**9
+
+# Columns (1-based) of the per-signal statistics: C/N0, total S4, S4 correction,
+# phase standard deviations over 1 to 60 s; lock times are given apart
+_SIGNAL_COLUMNS = {1: 7, 2: 32, 3: 46}
+_LOCK_TIME_COLUMNS = {1: 25, 2: 42, 3: 56}
+_STATISTICS = [
+    "cn0",
+    "s4",
+    "s4_correction",
+    "sigma_phi_1s",
+    "sigma_phi_3s",
+    "sigma_phi_10s",
+    "sigma_phi_30s",
+    "sigma_phi_60s",
+]
+
+# RINEX codes of the first, second and third signals of each constellation
+_SIGNALS = {
+    "G": ("1C", "2L", "5Q"),
+    "R": ("1C", "2C", None),
+    "E": ("1C", "5Q", "7Q"),
+    "S": ("1C", "5I", None),
+    "C": ("2I", "7I", "6I"),
+    "J": ("1C", "2L", "5Q"),
+    "I": ("5A", None, None),
+}
+
+# Columns of the TEC at 45, 30, 15 and 0 s before the end of the minute, each
+# followed by the TEC variation over the preceding 15 s
+_TEC_COLUMNS = {45: 17, 30: 19, 15: 21, 0: 23}
+
+
+def _sv(svid: pl.Expr) -> pl.Expr:
+    """RINEX identifier of an SBF satellite number"""
+    ranges = [
+        (1, 37, "G", 0),
+        (38, 61, "R", 37),
+        (63, 68, "R", 38),
+        (71, 106, "E", 70),
+        (120, 140, "S", 100),
+        (141, 180, "C", 140),
+        (181, 190, "J", 180),
+        (191, 197, "I", 190),
+        (198, 215, "S", 157),
+        (216, 222, "I", 208),
+        (223, 245, "C", 182),
+    ]
+    expr = pl.lit(None, dtype=pl.String)
+    for low, high, system, offset in reversed(ranges):
+        prn = (svid - offset).cast(pl.String).str.zfill(2)
+        expr = pl.when(svid.is_between(low, high)).then(pl.lit(system) + prn).otherwise(expr)
+    return expr
+
+
+def _column(df: pl.DataFrame, index: int) -> pl.Expr:
+    """Numeric value of a 1-based column, null if missing, empty or 'nan'"""
+    name = f"column_{index}"
+    if name not in df.columns:
+        return pl.lit(None, dtype=pl.Float64)
+    value = pl.col(name).str.strip_chars().cast(pl.Float64, strict=False)
+    return pl.when(value.is_nan()).then(None).otherwise(value)
+
+
+def read_ismr(
+    path: Union[str, Path],
+    time_scale: str = "GPST",
+    time_unit: str = "us",
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    """
+    Reads an ISMR file of an ionospheric scintillation monitor into scintillation and TEC DataFrames.
+
+    ISMR files are the comma-separated minute summaries of Septentrio PolaRxS
+    receivers (and of the NovAtel GSV4004, whose files match the first columns):
+    one line per satellite and minute, with the statistics of up to three
+    signals. Epochs follow the convention of the other readers (GPST labelled as
+    UTC by default), and the columns match the scintillation DataFrame of
+    `read_sbf`, so that ISMR and RINEX-derived data can be compared directly.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the ISMR file (optionally gzip-compressed, '.gz').
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the 'epoch' columns, by default 'GPST'.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' columns, by default microseconds.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Scintillation indices, one row per satellite signal, with
+          columns 'epoch' (end of the 60-second interval), 'sv', 'signal' (RINEX
+          code, e.g. '1C'), 'azimuth', 'elevation' (degrees), 'cn0' (dB-Hz), 's4',
+          's4_correction' (thermal noise contribution), 'sigma_phi_1s' to
+          'sigma_phi_60s' (phase standard deviations over 1, 3, 10, 30 and 60 s,
+          radians) and 'lock_time' (s); signals without C/N0 are skipped
+        - pl.DataFrame: Slant TEC every 15 seconds, with columns 'epoch', 'sv',
+          'tec' and 'dtec' (variation over the previous 15 s), in TECu
+
+    Examples
+    --------
+    >>> scintillation, tec = read_ismr("SEPT060A.25_.ismr")
+    """
+    path = Path(path)
+    opener = gzip.open if path.suffix == ".gz" else open
+    with opener(path, "rb") as f:
+        content = f.read()
+    df = pl.read_csv(
+        io.BytesIO(content),
+        has_header=False,
+        infer_schema=False,
+        truncate_ragged_lines=True,
+    )
+
+    epoch = (
+        pl.lit(_GPS_EPOCH_NANOS)
+        + _column(df, 1).cast(pl.Int64) * _WEEK_NANOS
+        + (_column(df, 2) * 1e9).round().cast(pl.Int64)
+    )
+    svid = _column(df, 3).cast(pl.Int64)
+    base = df.select(
+        epoch.alias("epoch"),
+        _sv(svid).alias("sv"),
+        _column(df, 5).alias("azimuth"),
+        _column(df, 6).alias("elevation"),
+        *(
+            _column(df, start + i).alias(f"{name}_{signal}")
+            for signal, start in _SIGNAL_COLUMNS.items()
+            for i, name in enumerate(_STATISTICS)
+        ),
+        *(_column(df, index).alias(f"lock_time_{signal}") for signal, index in _LOCK_TIME_COLUMNS.items()),
+        *(_column(df, index).alias(f"tec_{lag}") for lag, index in _TEC_COLUMNS.items()),
+        *(_column(df, index + 1).alias(f"dtec_{lag}") for lag, index in _TEC_COLUMNS.items()),
+    ).filter(pl.col("sv").is_not_null() & pl.col("epoch").is_not_null())
+
+    system = pl.col("sv").str.slice(0, 1)
+    frames = []
+    for signal in _SIGNAL_COLUMNS:
+        code = pl.lit(None, dtype=pl.String)
+        for letter, codes in _SIGNALS.items():
+            code = pl.when(system == letter).then(pl.lit(codes[signal - 1])).otherwise(code)
+        frames.append(
+            base.select(
+                "epoch",
+                "sv",
+                code.alias("signal"),
+                "azimuth",
+                "elevation",
+                *(pl.col(f"{name}_{signal}").alias(name) for name in _STATISTICS),
+                pl.col(f"lock_time_{signal}").alias("lock_time"),
+            )
+        )
+    scintillation = (
+        pl.concat(frames)
+        .filter(pl.col("signal").is_not_null() & pl.col("cn0").is_not_null())
+        .sort("epoch", "sv", "signal")
+    )
+
+    tec = pl.concat(
+        [
+            base.select(
+                (pl.col("epoch") - lag * 10**9).alias("epoch"),
+                "sv",
+                pl.col(f"tec_{lag}").alias("tec"),
+                pl.col(f"dtec_{lag}").alias("dtec"),
+            )
+            for lag in _TEC_COLUMNS
+        ]
+    ).filter(pl.col("tec").is_not_null()).sort("epoch", "sv")
+
+    def with_epochs(frame: pl.DataFrame) -> pl.DataFrame:
+        epochs = frame["epoch"].cast(pl.Datetime("ns"))
+        epochs = convert_timescale(epochs, "GPST", time_scale).dt.cast_time_unit(time_unit)
+        return frame.with_columns(epochs.dt.replace_time_zone("UTC").alias("epoch"))
+
+    return with_epochs(scintillation), with_epochs(tec)
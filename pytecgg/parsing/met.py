@@ -0,0 +1,95 @@
+import gzip
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+from .header import _obs_type_codes
+
+# Values on the first line of a record, then on each continuation line (F7.1)
+_VALUES_FIRST_LINE = 8
+_VALUES_PER_LINE = 10
+_VALUE_WIDTH = 7
+
+
+def _values(text: str, count: int) -> list[Optional[float]]:
+    values = []
+    for i in range(count):
+        field = text[i * _VALUE_WIDTH : (i + 1) * _VALUE_WIDTH].strip()
+        values.append(float(field) if field else None)
+    return values
+
+
+def _epoch(line: str, version: float) -> datetime:
+    # RINEX 2: 6I3 (two-digit year); RINEX 3/4: 1X,I4,5(1X,I2)
+    if version < 3:
+        year, month, day, hour, minute, second = (int(line[i : i + 3]) for i in range(0, 18, 3))
+        year += 2000 if year < 80 else 1900
+    else:
+        year = int(line[1:5])
+        month, day, hour, minute, second = (int(line[i : i + 3]) for i in range(5, 20, 3))
+    return datetime(year, month, day, hour, minute, second, tzinfo=timezone.utc)
+
+
+def read_rinex_met(path: Union[str, Path]) -> pl.DataFrame:
+    """
+    Read a RINEX meteorological file (versions 2 to 4).
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX meteorological file (e.g., '.24m' or '_MM.rnx', optionally
+        gzip-compressed, '.gz').
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with an 'epoch' column (as recorded, GPS time labelled as UTC) and
+        one column per observation type of the header, e.g. 'PR' (pressure, hPa),
+        'TD' (dry temperature, °C) and 'HR' (relative humidity, %); missing values
+        are null.
+
+    Raises
+    ------
+    FileNotFoundError
+        If the file does not exist.
+    """
+    path = Path(path)
+    if not path.exists():
+        raise FileNotFoundError(f"File not found: {path}")
+
+    opener = gzip.open if path.suffix == ".gz" else open
+    version = 2.0
+    types: list[str] = []
+    epochs: list[datetime] = []
+    rows: list[list[Optional[float]]] = []
+    with opener(path, "rt", encoding="ascii", errors="replace") as f:
+        for line in f:
+            line = line.rstrip("\r\n")
+            label = line[60:].strip()
+            if label == "RINEX VERSION / TYPE":
+                version = float(line[0:9])
+            elif label == "# / TYPES OF OBSERV":
+                types += _obs_type_codes(line, 2.0)
+            elif label == "END OF HEADER":
+                break
+
+        start = 18 if version < 3 else 20
+        for line in f:
+            line = line.rstrip("\r\n")
+            if not line.strip():
+                continue
+            values = _values(line[start:], min(len(types), _VALUES_FIRST_LINE))
+            while len(values) < len(types):
+                count = min(len(types) - len(values), _VALUES_PER_LINE)
+                values += _values(next(f, "").rstrip("\r\n")[4:], count)
+            epochs.append(_epoch(line, version))
+            rows.append(values)
+
+    return pl.DataFrame(
+        {
+            "epoch": pl.Series(epochs, dtype=pl.Datetime("us", "UTC")),
+            **{code: pl.Series([r[i] for r in rows], dtype=pl.Float64) for i, code in enumerate(types)},
+        }
+    )
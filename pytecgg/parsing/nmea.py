@@ -0,0 +1,282 @@
+import gzip
+from datetime import date, datetime, timedelta
+from functools import reduce
+from pathlib import Path
+from typing import Iterable, Iterator, Optional, TextIO, Union
+
+import polars as pl
+
+from ..geodesy import geodetic_to_ecef
+from ..timescales import convert_timescale
+
+_KNOTS = 1852.0 / 3600.0
+_HALF_DAY = timedelta(hours=12)
+
+# Constellation of the satellites reported by each talker
+_TALKER_SYSTEMS = {"GP": "G", "GL": "R", "GA": "E", "GB": "C", "BD": "C", "GQ": "J", "QZ": "J", "GI": "I"}
+
+_POSITION_SCHEMA = {
+    "epoch": pl.Datetime("us"),
+    "lat": pl.Float64,
+    "lon": pl.Float64,
+    "height": pl.Float64,
+    "fix_quality": pl.UInt8,
+    "num_satellites": pl.UInt8,
+    "hdop": pl.Float64,
+    "speed": pl.Float64,
+    "course": pl.Float64,
+}
+
+_SATELLITE_SCHEMA = {
+    "epoch": pl.Datetime("us"),
+    "sv": pl.String,
+    "elevation": pl.Float64,
+    "azimuth": pl.Float64,
+    "snr": pl.Float64,
+    "signal_id": pl.UInt8,
+}
+
+
+def _sentences(lines: Iterable[Union[str, bytes]]) -> Iterator[tuple[str, str, list[str]]]:
+    """Yields the talker, type and fields of the sentences with a valid (or no) checksum."""
+    for line in lines:
+        if isinstance(line, bytes):
+            line = line.decode("ascii", "replace")
+        start = line.find("$")
+        if start < 0:
+            continue
+        body, sep, checksum = line[start + 1 :].strip().partition("*")
+        if sep:
+            expected = reduce(lambda acc, c: acc ^ ord(c), body, 0)
+            try:
+                if int(checksum[:2], 16) != expected:
+                    continue
+            except ValueError:
+                continue
+        address, *fields = body.split(",")
+        if len(address) == 5:
+            yield address[:2], address[2:], fields
+
+
+def _float(value: str) -> Optional[float]:
+    try:
+        return float(value)
+    except ValueError:
+        return None
+
+
+def _time_of_day(value: str) -> Optional[timedelta]:
+    """hhmmss.ss time of day"""
+    if len(value) < 6 or not value[:6].isdigit():
+        return None
+    seconds = _float(value[4:])
+    if seconds is None:
+        return None
+    return timedelta(hours=int(value[:2]), minutes=int(value[2:4]), seconds=seconds)
+
+
+def _coordinate(value: str, hemisphere: str, degree_digits: int) -> Optional[float]:
+    """(d)ddmm.mmmm angle and N/S or E/W hemisphere, in decimal degrees"""
+    minutes = _float(value[degree_digits:])
+    if minutes is None or not value[:degree_digits].isdigit():
+        return None
+    degrees = int(value[:degree_digits]) + minutes / 60
+    return -degrees if hemisphere in ("S", "W") else degrees
+
+
+def _sv(talker: str, prn: int) -> Optional[str]:
+    """RINEX identifier of a satellite, from the talker and the NMEA satellite number"""
+    system = _TALKER_SYSTEMS.get(talker)
+    if system in (None, "G"):
+        # GPS talker (or combined 'GN'): NMEA 2.x/3.x numbering of all constellations
+        if 1 <= prn <= 32:
+            return f"G{prn:02d}"
+        if 33 <= prn <= 64:
+            return f"S{prn - 13:02d}"
+        if 65 <= prn <= 96:
+            return f"R{prn - 64:02d}"
+        if 120 <= prn <= 158:
+            return f"S{prn - 100:02d}"
+        if 193 <= prn <= 202:
+            return f"J{prn - 192:02d}"
+        return None
+    if system == "R" and 65 <= prn <= 96:
+        prn -= 64
+    elif system == "J" and prn >= 193:
+        prn -= 192
+    elif system == "C" and prn > 200:
+        prn -= 200
+    elif system == "E" and prn > 300:
+        prn -= 300
+    return f"{system}{prn:02d}" if prn > 0 else None
+
+
+def _lines(source) -> Iterator[Union[str, bytes]]:
+    if isinstance(source, (str, Path)):
+        path = Path(source)
+        opener = gzip.open if path.suffix == ".gz" else open
+        with opener(path, "rt", encoding="ascii", errors="replace") as stream:
+            yield from stream
+    else:
+        yield from source
+
+
+def read_nmea(
+    source: Union[str, Path, TextIO],
+    reference: Optional[date] = None,
+    time_scale: str = "GPST",
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    """
+    Parses the GGA, RMC and GSV sentences of an NMEA log into position and satellite DataFrames.
+
+    The positions can be passed as the `trajectory` of `read_rinex_positions`, to
+    supply the receiver positions of kinematic (e.g., shipborne) recordings to the
+    TEC geometry; the satellite view gives a quick diagnostic of the tracking of a
+    receiver. Sentences with a wrong checksum are skipped.
+
+    NMEA times are UTC times of day: the date is taken from the RMC sentences,
+    accounting for midnight crossings, or from `reference` for logs without RMC.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or file-like object
+        Path to the NMEA log (optionally gzip-compressed, '.gz'), or an open text
+        or binary stream (e.g., a serial port).
+    reference : datetime.date, optional
+        Date of the first sentence, only needed for logs without RMC sentences.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the 'epoch' columns, by default 'GPST', as `read_rinex_obs`.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Positions of the valid fixes, with columns 'epoch', 'lat',
+          'lon' (degrees), 'height' (ellipsoidal, meters), 'fix_quality' (GGA
+          quality indicator: 1 GPS, 2 DGPS, 4 RTK fixed, 5 RTK float, ...),
+          'num_satellites', 'hdop', 'speed' (m/s), 'course' (degrees from north),
+          and 'x', 'y', 'z' (ECEF, meters); GGA fields are null for epochs with
+          RMC sentences only, and RMC fields for epochs with GGA sentences only
+        - pl.DataFrame: Satellites in view, with columns 'epoch' (of the latest
+          GGA or RMC sentence), 'sv', 'elevation', 'azimuth' (degrees), 'snr'
+          (dB-Hz) and 'signal_id' (NMEA 4.10 signal, null for older logs)
+
+    Raises
+    ------
+    ValueError
+        If the log has no RMC sentence and no reference date is given.
+
+    Examples
+    --------
+    >>> positions, satellites = read_nmea("ship_20250301.nmea")
+    >>> read_rinex_positions("SHIP00ITA_R_20250600000_01D_01S_MO.rnx", trajectory=positions)
+    """
+    events = []
+    for talker, kind, fields in _sentences(_lines(source)):
+        if kind in ("GGA", "RMC") and fields:
+            tod = _time_of_day(fields[0])
+            if tod is not None:
+                events.append((kind, tod, fields))
+        elif kind == "GSV":
+            events.append((kind, talker, fields))
+
+    # Date of the first timed sentence: that of the first RMC sentence, less the
+    # midnight crossings before it
+    timed = [e for e in events if e[0] != "GSV"]
+    day = None
+    crossings = 0
+    for i, (kind, tod, fields) in enumerate(timed):
+        if i > 0 and tod < timed[i - 1][1] - _HALF_DAY:
+            crossings += 1
+        if kind == "RMC" and len(fields) > 8:
+            try:
+                day = datetime.strptime(fields[8], "%d%m%y").date() - timedelta(days=crossings)
+                break
+            except ValueError:
+                continue
+    if day is None:
+        if reference is None and timed:
+            raise ValueError("The NMEA log has no RMC sentence: a reference date is required")
+        day = reference
+
+    positions: dict[datetime, dict] = {}
+    satellites = []
+    epoch = None
+    last_tod = None
+    for kind, *event in events:
+        if kind == "GSV":
+            talker, fields = event
+            if epoch is None:
+                continue
+            # Four satellites per sentence, then the NMEA 4.10 signal identifier
+            groups = (len(fields) - 3) // 4
+            signal_id = None
+            if len(fields) - 3 - 4 * groups == 1 and fields[-1]:
+                try:
+                    signal_id = int(fields[-1], 16)
+                except ValueError:
+                    pass
+            for g in range(groups):
+                prn, elevation, azimuth, snr = fields[3 + 4 * g : 7 + 4 * g]
+                sv = _sv(talker, int(prn)) if prn.isdigit() else None
+                if sv is not None:
+                    satellites.append(
+                        {
+                            "epoch": epoch,
+                            "sv": sv,
+                            "elevation": _float(elevation),
+                            "azimuth": _float(azimuth),
+                            "snr": _float(snr),
+                            "signal_id": signal_id,
+                        }
+                    )
+            continue
+
+        tod, fields = event
+        if kind == "RMC" and len(fields) > 8:
+            try:
+                day = datetime.strptime(fields[8], "%d%m%y").date()
+            except ValueError:
+                pass
+        elif last_tod is not None and tod < last_tod - _HALF_DAY:
+            day += timedelta(days=1)
+        last_tod = tod
+        epoch = datetime(day.year, day.month, day.day) + tod
+
+        if kind == "GGA" and len(fields) >= 11:
+            quality = int(fields[5]) if fields[5].isdigit() else 0
+            lat = _coordinate(fields[1], fields[2], 2)
+            lon = _coordinate(fields[3], fields[4], 3)
+            if quality == 0 or lat is None or lon is None:
+                continue
+            altitude, separation = _float(fields[8]), _float(fields[10])
+            row = positions.setdefault(epoch, {"epoch": epoch})
+            row.update(
+                lat=lat,
+                lon=lon,
+                height=altitude + (separation or 0.0) if altitude is not None else None,
+                fix_quality=quality,
+                num_satellites=int(fields[6]) if fields[6].isdigit() else None,
+                hdop=_float(fields[7]),
+            )
+        elif kind == "RMC" and len(fields) >= 8 and fields[1] == "A":
+            lat = _coordinate(fields[2], fields[3], 2)
+            lon = _coordinate(fields[4], fields[5], 3)
+            if lat is None or lon is None:
+                continue
+            speed = _float(fields[6])
+            row = positions.setdefault(epoch, {"epoch": epoch})
+            # GGA coordinates take precedence
+            row.setdefault("lat", lat)
+            row.setdefault("lon", lon)
+            row.update(speed=speed * _KNOTS if speed is not None else None, course=_float(fields[7]))
+
+    positions_df = pl.DataFrame(list(positions.values()), schema=_POSITION_SCHEMA)
+    x, y, z = geodetic_to_ecef(positions_df["lat"], positions_df["lon"], positions_df["height"])
+    positions_df = positions_df.with_columns(x, y, z)
+    satellites_df = pl.DataFrame(satellites, schema=_SATELLITE_SCHEMA)
+
+    def with_scale(df: pl.DataFrame) -> pl.DataFrame:
+        epochs = convert_timescale(df["epoch"], "UTC", time_scale)
+        return df.with_columns(epochs.dt.replace_time_zone("UTC").alias("epoch"))
+
+    return with_scale(positions_df), with_scale(satellites_df)
@@ -0,0 +1,49 @@
+import gzip
+import time
+from datetime import date, datetime, timezone
+from pathlib import Path
+from typing import BinaryIO, Optional, Union
+
+import polars as pl
+
+_CHUNK_SIZE = 1 << 20
+
+# Binary logs of receivers and streams: a path or an open binary stream
+RawSource = Union[str, Path, BinaryIO]
+
+
+def _reference_nanos(source: RawSource, reference: Optional[Union[date, datetime]]) -> int:
+    """
+    Approximate time of the data (Unix-like nanoseconds): the given date (noon UTC),
+    else the modification time of the file, else the current time.
+    """
+    if reference is None:
+        if not isinstance(source, (str, Path)):
+            return time.time_ns()
+        reference = datetime.fromtimestamp(Path(source).stat().st_mtime, timezone.utc)
+    if not isinstance(reference, datetime):
+        reference = datetime(reference.year, reference.month, reference.day, 12)
+    if reference.tzinfo is None:
+        reference = reference.replace(tzinfo=timezone.utc)
+    return int(reference.timestamp()) * 10**9
+
+
+def _feed(decoder, source: RawSource) -> None:
+    """Feeds a file (optionally gzip-compressed, '.gz') or a binary stream to a decoder."""
+    if isinstance(source, (str, Path)):
+        path = Path(source)
+        opener = gzip.open if path.suffix == ".gz" else open
+        with opener(path, "rb") as stream:
+            _feed(decoder, stream)
+        return
+    while data := source.read(_CHUNK_SIZE):
+        decoder.feed(data)
+
+
+_NAV_DATETIMES = ("valid_from", "valid_until", "toc", "toe_epoch")
+
+
+def _with_utc(df: pl.DataFrame) -> pl.DataFrame:
+    # Navigation frames also carry the validity window and reference times of the ephemerides
+    columns = [c for c in ("epoch", *_NAV_DATETIMES) if c in df.columns]
+    return df.with_columns(pl.col(columns).dt.replace_time_zone("UTC"))
@@ -0,0 +1,72 @@
+from datetime import date, datetime
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import RtcmDecoder
+from .raw import RawSource, _feed, _reference_nanos, _with_utc
+
+
+def read_rtcm(
+    source: RawSource,
+    reference: Optional[Union[date, datetime]] = None,
+    time_scale: str = "GPST",
+    nav_time_scale: Optional[str] = None,
+    time_unit: str = "us",
+    glonass_channels: Optional[dict[str, int]] = None,
+) -> tuple[pl.DataFrame, dict[str, pl.DataFrame], Optional[tuple[float, float, float]]]:
+    """
+    Decodes a logged RTCM 3 stream into observation and navigation DataFrames.
+
+    MSM4 to MSM7 observation messages and broadcast ephemeris messages (1019 GPS,
+    1020 GLONASS, 1042 BeiDou, 1045/1046 Galileo F/NAV and I/NAV) are decoded into
+    the schemas of `read_rinex_obs` and `read_rinex_nav`, so that recorded streams
+    can be processed exactly as RINEX files. Frames with a wrong checksum are
+    skipped.
+
+    RTCM epochs only carry the time of week (or of day, for GLONASS): they are
+    resolved with an approximate date of the data, which must be within a few
+    days of it.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or binary file-like object
+        Path to the RTCM 3 file (optionally gzip-compressed, '.gz'), or an open
+        binary stream (e.g., `sys.stdin.buffer` or a serial port).
+    reference : datetime.date or datetime.datetime, optional
+        Approximate date of the data. By default, the modification time of the
+        file (i.e. the end of the recording), or the current time for streams.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the observation epochs, by default 'GPST'.
+    nav_time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the ephemeris epochs; by default, the timescale of each
+        constellation, as `read_rinex_nav`.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' columns, by default microseconds.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV (e.g., {'R01': 1}), needed for the
+        GLONASS carrier phase of MSM4/MSM6 messages received before the
+        corresponding ephemeris (message 1020).
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Observations, with the columns of `read_rinex_obs`
+        - dict[str, pl.DataFrame]: Broadcast ephemerides keyed by constellation,
+          with the columns and units of `read_rinex_nav`; repeated broadcasts of
+          an ephemeris are returned once
+        - tuple[float, float, float] or None: Antenna reference point in ECEF
+          coordinates (meters), from messages 1005/1006
+
+    Examples
+    --------
+    >>> obs, nav, position = read_rtcm("GRAZ00AUT0_20250301.rtcm3", reference=date(2025, 3, 1))
+    """
+    decoder = RtcmDecoder(_reference_nanos(source, reference), glonass_channels)
+    _feed(decoder, source)
+    obs = _with_utc(decoder.take_obs(True, time_scale, time_unit))
+    nav = {
+        const: _with_utc(df)
+        for const, df in decoder.take_nav(nav_time_scale, time_unit).items()
+    }
+    return obs, nav, decoder.position
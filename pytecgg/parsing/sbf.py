@@ -0,0 +1,60 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import SbfDecoder
+from .raw import RawSource, _feed, _with_utc
+
+
+def read_sbf(
+    source: RawSource,
+    time_scale: str = "GPST",
+    nav_time_scale: Optional[str] = None,
+    time_unit: str = "us",
+) -> tuple[pl.DataFrame, dict[str, pl.DataFrame], pl.DataFrame]:
+    """
+    Decodes a Septentrio SBF log into observation, navigation and scintillation DataFrames.
+
+    MeasEpoch measurements, GPSNav and GALNav ephemerides and ISMR scintillation
+    indices are mapped onto the schemas of `read_rinex_obs` and `read_rinex_nav`,
+    so that the native logs of Septentrio receivers (e.g., PolaRxS scintillation
+    monitors) can be processed without converting them to RINEX first. Blocks
+    carry the full GPS week, so no reference date is needed. Measurements of
+    auxiliary antennas are skipped, as well as blocks with a wrong CRC.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or binary file-like object
+        Path to the SBF file (optionally gzip-compressed, '.gz'), or an open binary
+        stream.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the observation and scintillation epochs, by default 'GPST'.
+    nav_time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the ephemeris epochs; by default, the timescale of each
+        constellation, as `read_rinex_nav`.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' columns, by default microseconds.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Observations, with the columns of `read_rinex_obs`
+        - dict[str, pl.DataFrame]: Broadcast ephemerides keyed by constellation,
+          with the columns and units of `read_rinex_nav`
+        - pl.DataFrame: Scintillation indices, with columns 'epoch' (end of the
+          60-second interval), 'sv', 'signal' (RINEX code, e.g. '1C'), 's4' and
+          'sigma_phi_60s' (phase standard deviation, radians); null if not available
+
+    Examples
+    --------
+    >>> obs, nav, ismr = read_sbf("SEPT0010.25_")
+    """
+    decoder = SbfDecoder()
+    _feed(decoder, source)
+    obs = _with_utc(decoder.take_obs(time_scale, time_unit))
+    nav = {
+        const: _with_utc(df)
+        for const, df in decoder.take_nav(nav_time_scale, time_unit).items()
+    }
+    ismr = _with_utc(decoder.take_ismr(time_scale, time_unit))
+    return obs, nav, ismr
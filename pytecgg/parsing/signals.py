@@ -0,0 +1,69 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import observable_table as _observable_table
+
+_KEYS = ["system", "code"]
+
+
+def observable_table(extra: Optional[pl.DataFrame] = None) -> pl.DataFrame:
+    """
+    Mapping of the RINEX 3 observation codes to constellation, frequency band,
+    carrier frequency, signal and tracking mode, as used to harmonize the observables
+    (carrier frequencies, wavelengths and the pairing of signals).
+
+    Parameters
+    ----------
+    extra : pl.DataFrame, optional
+        Additional or corrected entries for unusual signals, with columns 'system'
+        and 'code' and any of the other columns of the table (missing ones are
+        null); they replace the built-in entries of the same constellation and
+        code.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per constellation and code, with columns:
+            - system: Constellation (RINEX letter, e.g. 'G')
+            - code: Band and tracking channel attribute (e.g. '1C'), shared by the
+              code ('C'), phase ('L'), Doppler ('D') and SNR ('S') observables
+            - band: RINEX frequency band (e.g. '1')
+            - signal: Signal name (e.g. 'L1', 'E5a', 'B1I')
+            - tracking_mode: Tracking mode of the attribute (e.g. 'C/A', 'L2C (M)')
+            - frequency: Carrier frequency in Hz (channel 0 for the GLONASS FDMA
+              bands)
+            - channel_spacing: Frequency step between the GLONASS FDMA channels in
+              Hz (null for CDMA signals)
+            - priority: Rank of the tracking mode when pairing signals (0 first,
+              null if never preferred)
+
+    Raises
+    ------
+    ValueError
+        If `extra` lacks the 'system' or 'code' columns, or has columns not in the
+        table.
+
+    Examples
+    --------
+    >>> table = observable_table()
+    >>> obs.with_columns(
+    ...     system=pl.col("sv").str.slice(0, 1), code=pl.col("observable").str.slice(1)
+    ... ).join(table, on=["system", "code"], how="left")
+    """
+    table = _observable_table()
+    if extra is None:
+        return table
+    missing = [key for key in _KEYS if key not in extra.columns]
+    unknown = [name for name in extra.columns if name not in table.columns]
+    if missing or unknown:
+        raise ValueError(
+            f"Invalid extra entries: missing columns {missing}, unknown columns {unknown}"
+        )
+    extra = extra.select(
+        [
+            pl.col(name).cast(dtype) if name in extra.columns else pl.lit(None, dtype).alias(name)
+            for name, dtype in table.schema.items()
+        ]
+    )
+    return pl.concat([table.join(extra.select(_KEYS), on=_KEYS, how="anti"), extra])
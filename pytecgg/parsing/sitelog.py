@@ -0,0 +1,292 @@
+import re
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+# Section headers ('3.   GNSS Receiver Information') and numbered entries
+# ('3.2  Receiver Type : ...'); 'x' entries are the blank templates of the log
+_SECTION = re.compile(r"^(?P<section>\d+)\.\s{2,}\S")
+_ENTRY = re.compile(r"^(?P<section>\d+)\.(?P<entry>\d+|x)\s+(?P<rest>.*)$")
+_FIELD = re.compile(r"^\s*(?P<key>[^:]*?[A-Za-z)][^:]*?)\s*:\s?(?P<value>.*)$")
+
+_DATETIME = pl.Datetime("us", "UTC")
+
+_RECEIVER_COLUMNS = {
+    "receiver_type": pl.String,
+    "satellite_system": pl.String,
+    "serial": pl.String,
+    "firmware": pl.String,
+    "elevation_cutoff": pl.String,
+    "installed": _DATETIME,
+    "removed": _DATETIME,
+}
+
+_ANTENNA_COLUMNS = {
+    "antenna_type": pl.String,
+    "radome": pl.String,
+    "serial": pl.String,
+    "arp": pl.String,
+    "ecc_up": pl.Float64,
+    "ecc_north": pl.Float64,
+    "ecc_east": pl.Float64,
+    "installed": _DATETIME,
+    "removed": _DATETIME,
+}
+
+
+@dataclass
+class SiteLog:
+    """
+    Contents of an IGS site log.
+
+    Attributes
+    ----------
+    site_id : str or None
+        Four or nine character station identifier (e.g., 'GROT00ITA').
+    site_name : str or None
+        Site name.
+    domes : str or None
+        IERS DOMES number.
+    country : str or None
+        Country or region.
+    position : tuple[float, float, float] or None
+        Approximate ECEF position (meters, ITRF).
+    lat, lon, height : float or None
+        Approximate geodetic latitude and longitude (degrees) and ellipsoidal
+        height (meters).
+    receivers : pl.DataFrame
+        Receiver history (section 3): 'receiver_type', 'satellite_system',
+        'serial', 'firmware', 'elevation_cutoff', 'installed', 'removed'.
+    antennas : pl.DataFrame
+        Antenna history (section 4): 'antenna_type', 'radome', 'serial', 'arp',
+        'ecc_up', 'ecc_north', 'ecc_east' (marker to ARP, meters), 'installed',
+        'removed'.
+    """
+
+    site_id: Optional[str]
+    site_name: Optional[str]
+    domes: Optional[str]
+    country: Optional[str]
+    position: Optional[tuple[float, float, float]]
+    lat: Optional[float]
+    lon: Optional[float]
+    height: Optional[float]
+    receivers: pl.DataFrame
+    antennas: pl.DataFrame
+
+    @property
+    def equipment_changes(self) -> list[datetime]:
+        """Dates at which a receiver or an antenna was replaced, in time order."""
+        installed = pl.concat([self.receivers["installed"], self.antennas["installed"]])
+        dates = installed.drop_nulls().unique().sort().to_list()
+        return dates[1:]
+
+    def hardware_periods(self) -> pl.DataFrame:
+        """
+        Split the station history into periods of constant hardware.
+
+        A new period starts whenever a receiver or an antenna is installed, so
+        that observations can be segmented by hardware, e.g. with
+        `df.join_asof(periods, left_on="epoch", right_on="start")`.
+
+        Returns
+        -------
+        pl.DataFrame
+            DataFrame with columns 'start', 'end' (null for the current period),
+            'receiver_type', 'receiver_serial', 'firmware', 'antenna_type',
+            'radome', 'antenna_serial', 'ecc_up', 'ecc_north', 'ecc_east'.
+        """
+        starts = (
+            pl.concat([self.receivers["installed"], self.antennas["installed"]])
+            .drop_nulls()
+            .unique()
+            .sort()
+            .to_list()
+        )
+
+        def active(df: pl.DataFrame, t: datetime) -> dict:
+            rows = df.filter(
+                (pl.col("installed") <= t)
+                & (pl.col("removed").is_null() | (pl.col("removed") > t))
+            )
+            return rows.row(-1, named=True) if rows.height else {}
+
+        rows = []
+        for start, end in zip(starts, starts[1:] + [None]):
+            receiver, antenna = active(self.receivers, start), active(self.antennas, start)
+            rows.append(
+                {
+                    "start": start,
+                    "end": end,
+                    "receiver_type": receiver.get("receiver_type"),
+                    "receiver_serial": receiver.get("serial"),
+                    "firmware": receiver.get("firmware"),
+                    "antenna_type": antenna.get("antenna_type"),
+                    "radome": antenna.get("radome"),
+                    "antenna_serial": antenna.get("serial"),
+                    "ecc_up": antenna.get("ecc_up"),
+                    "ecc_north": antenna.get("ecc_north"),
+                    "ecc_east": antenna.get("ecc_east"),
+                }
+            )
+        return pl.DataFrame(
+            rows,
+            schema={
+                "start": _DATETIME,
+                "end": _DATETIME,
+                "receiver_type": pl.String,
+                "receiver_serial": pl.String,
+                "firmware": pl.String,
+                "antenna_type": pl.String,
+                "radome": pl.String,
+                "antenna_serial": pl.String,
+                "ecc_up": pl.Float64,
+                "ecc_north": pl.Float64,
+                "ecc_east": pl.Float64,
+            },
+        )
+
+
+def _text(value: Optional[str]) -> Optional[str]:
+    """Strips a field value; empty values and unfilled '(...)' templates are None."""
+    if value is None:
+        return None
+    value = value.strip()
+    if not value or value.startswith("("):
+        return None
+    return value
+
+
+def _float(value: Optional[str]) -> Optional[float]:
+    value = _text(value)
+    if value is None:
+        return None
+    try:
+        return float(value.split()[0])
+    except ValueError:
+        return None
+
+
+def _date(value: Optional[str]) -> Optional[datetime]:
+    """Parses 'CCYY-MM-DDThh:mmZ' or 'CCYY-MM-DD' dates."""
+    value = _text(value)
+    if value is None:
+        return None
+    for fmt in ("%Y-%m-%dT%H:%MZ", "%Y-%m-%dT%H:%M", "%Y-%m-%d"):
+        try:
+            return datetime.strptime(value, fmt).replace(tzinfo=timezone.utc)
+        except ValueError:
+            continue
+    return None
+
+
+def _dms(value: Optional[str], degree_digits: int) -> Optional[float]:
+    """Converts a '+DDMMSS.SS' (or '+DDDMMSS.SS') angle into degrees."""
+    value = _text(value)
+    if value is None:
+        return None
+    sign = -1.0 if value.startswith("-") else 1.0
+    digits = value.lstrip("+-")
+    try:
+        deg = float(digits[:degree_digits])
+        minutes = float(digits[degree_digits : degree_digits + 2])
+        seconds = float(digits[degree_digits + 2 :])
+    except ValueError:
+        return None
+    return sign * (deg + minutes / 60 + seconds / 3600)
+
+
+def _receiver(fields: dict[str, str]) -> dict:
+    return {
+        "receiver_type": _text(fields.get("Receiver Type")),
+        "satellite_system": _text(fields.get("Satellite System")),
+        "serial": _text(fields.get("Serial Number")),
+        "firmware": _text(fields.get("Firmware Version")),
+        "elevation_cutoff": _text(fields.get("Elevation Cutoff Setting")),
+        "installed": _date(fields.get("Date Installed")),
+        "removed": _date(fields.get("Date Removed")),
+    }
+
+
+def _antenna(fields: dict[str, str]) -> dict:
+    # The antenna type field holds the IGS antenna code and, optionally, the radome
+    tokens = (_text(fields.get("Antenna Type")) or "").split()
+    radome = _text(fields.get("Antenna Radome Type")) or (tokens[1] if len(tokens) > 1 else "NONE")
+    return {
+        "antenna_type": tokens[0] if tokens else None,
+        "radome": radome,
+        "serial": _text(fields.get("Serial Number")),
+        "arp": _text(fields.get("Antenna Reference Point")),
+        "ecc_up": _float(fields.get("Marker->ARP Up Ecc. (m)")),
+        "ecc_north": _float(fields.get("Marker->ARP North Ecc(m)")),
+        "ecc_east": _float(fields.get("Marker->ARP East Ecc(m)")),
+        "installed": _date(fields.get("Date Installed")),
+        "removed": _date(fields.get("Date Removed")),
+    }
+
+
+def read_igs_sitelog(path: Union[str, Path]) -> SiteLog:
+    """
+    Parses an IGS station log file.
+
+    Site logs document the equipment history of a station: since every receiver
+    or antenna change may introduce biases and position offsets, the history can
+    be used to segment data by hardware period (see `SiteLog.hardware_periods`).
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the site log (e.g., 'grot00ita_20240101.log').
+
+    Returns
+    -------
+    SiteLog
+        Site identification, approximate coordinates, and receiver and antenna
+        histories.
+    """
+    general: dict[str, str] = {}
+    receivers, antennas = [], []
+    section, block = None, None
+
+    for line in Path(path).read_text(encoding="utf-8", errors="replace").splitlines():
+        if match := _SECTION.match(line):
+            section, block = int(match["section"]), None
+            continue
+        if match := _ENTRY.match(line):
+            section = int(match["section"])
+            if match["entry"] == "x":
+                block = None
+            elif section in (3, 4):
+                block = {}
+                (receivers if section == 3 else antennas).append(block)
+            else:
+                block = None
+            line = match["rest"]
+
+        field = _FIELD.match(line)
+        if field is None:
+            continue
+        key, value = field["key"].strip(), field["value"]
+        if section in (3, 4):
+            # Template entries ('3.x') are skipped; continuation lines have no key
+            if block is not None and key not in block:
+                block[key] = value
+        elif section in (1, 2) and key not in general:
+            general[key] = value
+
+    x, y, z = (_float(general.get(f"{c} coordinate (m)")) for c in "XYZ")
+    return SiteLog(
+        site_id=_text(general.get("Nine Character ID") or general.get("Four Character ID")),
+        site_name=_text(general.get("Site Name")),
+        domes=_text(general.get("IERS DOMES Number")),
+        country=_text(general.get("Country or Region") or general.get("Country")),
+        position=(x, y, z) if None not in (x, y, z) else None,
+        lat=_dms(general.get("Latitude (N is +)"), 2),
+        lon=_dms(general.get("Longitude (E is +)"), 3),
+        height=_float(general.get("Elevation (m,ellips.)")),
+        receivers=pl.DataFrame([_receiver(r) for r in receivers], schema=_RECEIVER_COLUMNS),
+        antennas=pl.DataFrame([_antenna(a) for a in antennas], schema=_ANTENNA_COLUMNS),
+    )
@@ -0,0 +1,65 @@
+from datetime import date, datetime
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import UbxDecoder
+from .raw import RawSource, _feed, _reference_nanos, _with_utc
+
+
+def read_ubx(
+    source: RawSource,
+    reference: Optional[Union[date, datetime]] = None,
+    time_scale: str = "GPST",
+    nav_time_scale: Optional[str] = None,
+    time_unit: str = "us",
+) -> tuple[pl.DataFrame, dict[str, pl.DataFrame]]:
+    """
+    Decodes a u-blox UBX log into observation and navigation DataFrames.
+
+    RXM-RAWX raw measurements (pseudorange, carrier phase, Doppler and C/N0) and
+    RXM-SFRBX navigation subframes are mapped onto the schemas of `read_rinex_obs`
+    and `read_rinex_nav`, so that logs of low-cost receivers (e.g., ZED-F9P) can
+    be processed without converting them to RINEX first. Pseudoranges and carrier
+    phases are only kept when flagged as valid by the tracking status.
+
+    Ephemerides are decoded from GPS LNAV subframes 1-3 and Galileo I/NAV words 1-5,
+    once a consistent set (same issue of data) has been received; GLONASS and BeiDou
+    navigation data are not decoded. Messages with a wrong checksum are skipped.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or binary file-like object
+        Path to the UBX file (optionally gzip-compressed, '.gz'), or an open binary
+        stream (e.g., a serial port).
+    reference : datetime.date or datetime.datetime, optional
+        Approximate date of the data, only needed to resolve the week of navigation
+        data recorded before the first measurement epoch. By default, the
+        modification time of the file, or the current time for streams.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the observation epochs, by default 'GPST'.
+    nav_time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the ephemeris epochs; by default, the timescale of each
+        constellation, as `read_rinex_nav`.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' columns, by default microseconds.
+
+    Returns
+    -------
+    tuple
+        - pl.DataFrame: Observations, with the columns of `read_rinex_obs`
+        - dict[str, pl.DataFrame]: Broadcast ephemerides keyed by constellation,
+          with the columns and units of `read_rinex_nav`
+
+    Examples
+    --------
+    >>> obs, nav = read_ubx("rover_20250223.ubx")
+    """
+    decoder = UbxDecoder(_reference_nanos(source, reference))
+    _feed(decoder, source)
+    obs = _with_utc(decoder.take_obs(time_scale, time_unit))
+    nav = {
+        const: _with_utc(df)
+        for const, df in decoder.take_nav(nav_time_scale, time_unit).items()
+    }
+    return obs, nav
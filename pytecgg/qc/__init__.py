@@ -0,0 +1,24 @@
+from .clock_jumps import detect_clock_jumps, repair_clock_jumps
+from .completeness import epoch_completeness
+from .gaps import detect_gaps, fill_gaps
+from .interval import sampling_interval
+from .multipath import estimate_multipath
+from .navigation import check_nav_consistency
+from .report import qc_report, QCReport
+from .snr import snr_from_ssi, snr_statistics, ssi_to_dbhz
+
+__all__ = [
+    "check_nav_consistency",
+    "detect_clock_jumps",
+    "detect_gaps",
+    "epoch_completeness",
+    "estimate_multipath",
+    "fill_gaps",
+    "qc_report",
+    "QCReport",
+    "repair_clock_jumps",
+    "sampling_interval",
+    "snr_from_ssi",
+    "snr_statistics",
+    "ssi_to_dbhz",
+]
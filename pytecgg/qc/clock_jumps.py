@@ -0,0 +1,88 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import clock_jumps as _clock_jumps
+
+
+def _valid_channels(glonass_channels: Optional[dict[str, int]]) -> dict[str, int]:
+    return {sv: k for sv, k in (glonass_channels or {}).items() if k is not None}
+
+
+def detect_clock_jumps(
+    obs_data: pl.DataFrame,
+    glonass_channels: Optional[dict[str, int]] = None,
+    tolerance: float = 1e-4,
+) -> pl.DataFrame:
+    """
+    Detect millisecond jumps of the receiver clock.
+
+    Receivers steering their clock towards GPS time introduce jumps of an integer
+    number of milliseconds, which appear in the code observables but usually not in
+    the carrier phases. Between two epochs, a jump shifts the code-minus-phase
+    difference of every satellite by the same multiple of c * 1 ms (~300 km), so that
+    it would otherwise be mistaken for a cycle slip by the Melbourne-Wübbena test.
+    Receivers applying the jump to the phases instead are detected as well: the
+    observable that jumped is told apart by its epoch-to-epoch change.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; GLONASS satellites without a channel
+        are not used for detection.
+    tolerance : float, optional
+        Maximum deviation (in ms) of the mean jump from a whole number of
+        milliseconds (default: 1e-4, i.e. ~30 m).
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - epoch: First epoch after the jump
+            - jump_ms: Size of the jump of the affected observables in milliseconds
+            - observable: Observables that jumped, 'code' or 'phase'
+            - n_satellites: Number of satellites supporting the detection
+    """
+    jumps, _ = _clock_jumps(
+        obs_data, _valid_channels(glonass_channels), False, tolerance
+    )
+    return jumps
+
+
+def repair_clock_jumps(
+    obs_data: pl.DataFrame,
+    glonass_channels: Optional[dict[str, int]] = None,
+    tolerance: float = 1e-4,
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    """
+    Detect and repair millisecond jumps of the receiver clock.
+
+    Detected jumps are accumulated and removed from the observables that jumped,
+    from the affected epoch onwards: code jumps from the code observables ('C*', and
+    'P*' in RINEX 2, in meters), phase jumps from the carrier phases ('L*', in cycles
+    of their carrier frequency), so that code and phase refer to the same continuous
+    receiver clock. Geometry-free combinations are unaffected either way.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; GLONASS phases without a channel are not
+        repaired.
+    tolerance : float, optional
+        Maximum deviation (in ms) of the mean jump from a whole number of
+        milliseconds (default: 1e-4).
+
+    Returns
+    -------
+    tuple[pl.DataFrame, pl.DataFrame]
+        - Repaired observations, with the same schema as `obs_data`
+        - Detected jumps, as returned by `detect_clock_jumps`
+    """
+    jumps, repaired = _clock_jumps(
+        obs_data, _valid_channels(glonass_channels), True, tolerance
+    )
+    return repaired, jumps
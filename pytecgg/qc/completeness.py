@@ -0,0 +1,48 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import epoch_completeness as _epoch_completeness
+
+
+def epoch_completeness(
+    obs_data: pl.DataFrame,
+    nav: dict[str, pl.DataFrame],
+    rx_position: tuple[float, float, float],
+    min_elevation: float = 10.0,
+    interval: Optional[float] = None,
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    """
+    Compute per-epoch and per-day completeness of the observations.
+
+    For every epoch of the nominal sampling grid, satellite positions are predicted
+    from the broadcast ephemerides and compared with the satellites actually observed:
+    completeness is the percentage of satellites above the elevation mask for which
+    at least one observation was recorded. Only the constellations present in the
+    observations are taken into account, so that e.g. a GPS-only receiver is not
+    penalised for untracked Galileo satellites.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    nav : dict[str, pl.DataFrame]
+        Navigation data keyed by constellation, as returned by `read_rinex_nav`.
+    rx_position : tuple[float, float, float]
+        Receiver ECEF coordinates (in meters).
+    min_elevation : float, optional
+        Elevation mask in degrees (default: 10.0).
+    interval : float, optional
+        Nominal sampling interval in seconds; if not provided, it is inferred as
+        the most frequent spacing between epochs.
+
+    Returns
+    -------
+    tuple[pl.DataFrame, pl.DataFrame]
+        - Per-epoch DataFrame with columns 'epoch', 'n_expected' (satellites above
+          the mask), 'n_observed' (of which observed), 'n_signals' (observables
+          recorded for them) and 'completeness' (in %, null if none is expected)
+        - Per-day DataFrame with columns 'date', 'n_expected', 'n_observed',
+          'completeness'
+    """
+    return _epoch_completeness(obs_data, nav, rx_position, min_elevation, interval)
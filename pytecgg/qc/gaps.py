@@ -0,0 +1,126 @@
+from datetime import timedelta
+from typing import Optional, Sequence
+
+import polars as pl
+
+from .interval import sampling_interval
+from ..pytecgg import detect_gaps as _detect_gaps
+
+
+def detect_gaps(obs_data: pl.DataFrame, interval: Optional[float] = None) -> pl.DataFrame:
+    """
+    Detect data gaps relative to the nominal sampling interval.
+
+    Gaps are reported both at station level (epochs without any observation) and
+    per satellite; a spacing counts as a gap when it exceeds 1.5 times the interval.
+    Note that per-satellite gaps also include the periods in which the satellite
+    is below the horizon.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch' and 'sv' (e.g., as returned by `read_rinex_obs`).
+    interval : float, optional
+        Nominal sampling interval in seconds; if not provided, it is inferred as
+        the most frequent spacing between epochs.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - sv: Satellite identifier (null for station-level gaps)
+            - start: Last epoch before the gap
+            - end: First epoch after the gap
+            - duration: Time elapsed between 'start' and 'end'
+            - n_missing: Number of missing epochs at the nominal interval
+    """
+    return _detect_gaps(obs_data, interval)
+
+
+def fill_gaps(
+    df: pl.DataFrame,
+    max_gap: float = 120.0,
+    columns: Optional[Sequence[str]] = None,
+    arc: str = "id_arc_valid",
+    interval: Optional[float] = None,
+) -> pl.DataFrame:
+    """
+    Fill short data gaps within arcs by linear interpolation in time.
+
+    Missing epochs of the nominal sampling grid are inserted in the gaps of each
+    arc no longer than `max_gap`, and flagged, so that ROTI and spectral analyses
+    see regularly sampled series. Longer gaps, gaps between arcs and rows without
+    an arc are left untouched.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        TEC DataFrame with columns 'epoch' and the arc identifier (e.g., as
+        returned by `calculate_tec` or `compute_tec`).
+    max_gap : float, optional
+        Longest gap filled, in seconds (time between the samples bounding the
+        gap), by default 120 s.
+    columns : sequence of str, optional
+        Columns interpolated in the inserted rows; by default, all the
+        floating-point columns. Other columns (e.g., 'sv') are copied from the
+        sample preceding the gap.
+    arc : str, optional
+        Column of the arc identifiers, by default 'id_arc_valid'.
+    interval : float, optional
+        Nominal sampling interval in seconds; if not provided, it is inferred as
+        the most frequent spacing between epochs.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with the inserted rows, sorted by arc and epoch, and a boolean
+        'interpolated' column flagging them.
+
+    Examples
+    --------
+    >>> df = fill_gaps(compute_tec(obs_path, nav_path), max_gap=90)
+    >>> df = df.with_columns(pl.col("stec").tec.rot(max_gap=30).over("id_arc_valid"))
+    """
+    if columns is None:
+        columns = [
+            name for name, dtype in df.schema.items() if dtype.is_float() and name != arc
+        ]
+    step = timedelta(seconds=interval) if interval is not None else sampling_interval(df)
+    flagged = df.with_columns(pl.lit(False).alias("interpolated"))
+    if step is None or df.is_empty():
+        return flagged
+
+    following = pl.col("epoch").shift(-1).over(arc) - pl.col("epoch")
+    n_missing = (
+        following.dt.total_microseconds() / (step / timedelta(microseconds=1))
+    ).round().cast(pl.Int64) - 1
+    inserted = (
+        flagged.filter(pl.col(arc).is_not_null())
+        .sort(arc, "epoch")
+        .with_columns(
+            pl.when(following <= timedelta(seconds=max_gap))
+            .then(n_missing)
+            .otherwise(0)
+            .alias("_n_missing")
+        )
+        .filter(pl.col("_n_missing") > 0)
+        .with_columns(pl.int_ranges(1, pl.col("_n_missing") + 1).alias("_k"))
+        .explode("_k")
+        .with_columns(
+            (pl.col("epoch") + pl.lit(step) * pl.col("_k")).alias("epoch"),
+            *[pl.lit(None).cast(df.schema[name]).alias(name) for name in columns],
+        )
+        .with_columns(pl.lit(True).alias("interpolated"))
+        .drop("_n_missing", "_k")
+    )
+
+    return (
+        pl.concat([flagged, inserted])
+        .sort(arc, "epoch", nulls_last=True)
+        .with_columns(
+            pl.when(pl.col("interpolated"))
+            .then(pl.col(name).interpolate_by("epoch").over(arc))
+            .otherwise(pl.col(name))
+            for name in columns
+        )
+    )
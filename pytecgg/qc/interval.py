@@ -0,0 +1,38 @@
+from datetime import timedelta
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import sampling_interval as _sampling_interval
+
+
+def sampling_interval(obs_data: pl.DataFrame, resolution: float = 0.001) -> Optional[timedelta]:
+    """
+    Infer the true sampling interval of observations from the spacing of their epochs.
+
+    The 'INTERVAL' header record is optional and frequently missing or wrong (e.g.,
+    after decimation); the interval is instead taken as the most frequent spacing
+    between consecutive distinct epochs, which is unaffected by data gaps. Spacings
+    are rounded to `resolution` before counting, so that receivers stamping epochs
+    slightly off the nominal grid still agree on a single interval.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with an 'epoch' column (e.g., as returned by `read_rinex_obs`).
+    resolution : float, optional
+        Resolution in seconds to which the spacings are rounded, by default 1 ms.
+
+    Returns
+    -------
+    datetime.timedelta or None
+        Sampling interval (the shortest of the most frequent spacings, on ties), or
+        None with less than two distinct epochs.
+
+    Examples
+    --------
+    >>> df, _, _ = read_rinex_obs("ASIR00ITA_R_20242810000_01D_30S_MO.rnx")
+    >>> sampling_interval(df)
+    datetime.timedelta(seconds=30)
+    """
+    return _sampling_interval(obs_data, resolution)
@@ -0,0 +1,67 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import multipath_qc as _multipath_qc
+
+
+def estimate_multipath(
+    obs_data: pl.DataFrame,
+    phase: tuple[str, str],
+    code: tuple[str, str],
+    glonass_channels: Optional[dict[str, int]] = None,
+    max_gap: Optional[float] = None,
+    slip_threshold: float = 5.0,
+    min_arc_length: int = 10,
+    rinex_version: Optional[str] = None,
+) -> tuple[pl.DataFrame, pl.DataFrame]:
+    """
+    Estimate code multipath with the classic code-minus-carrier combinations (MP1/MP2).
+
+    Each combination contains, besides multipath and code noise, a constant bias made of
+    phase ambiguities and hardware delays. Observations are therefore split into arcs
+    (on time gaps and on jumps caused by cycle slips) and the mean of each arc is removed,
+    as done by teqc.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'.
+    phase : tuple[str, str]
+        Phase observables (in cycles) on the two frequencies, e.g. ("L1C", "L2W").
+    code : tuple[str, str]
+        Code observables (in meters) on the two frequencies, e.g. ("C1C", "C2W");
+        MP1 refers to the first one, MP2 to the second one.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV; required for GLONASS FDMA signals.
+    max_gap : float, optional
+        Maximum time gap (in seconds) within an arc; defaults to twice the
+        sampling interval inferred from the data.
+    slip_threshold : float, optional
+        Epoch-to-epoch jump (in meters) of MP1 or MP2 that starts a new arc (default: 5.0).
+    min_arc_length : int, optional
+        Arcs with fewer samples are discarded (default: 10).
+    rinex_version : str, optional
+        RINEX version of the observations (e.g. "3.02"), which sets the BeiDou band
+        naming; RINEX 3.03 and later by default.
+
+    Returns
+    -------
+    tuple[pl.DataFrame, pl.DataFrame]
+        - Bias-removed 'mp1' and 'mp2' (in meters) per 'epoch' and 'sv', with 'arc' ids
+        - RMS statistics with columns 'sv', 'observable', 'mp_rms', 'n_obs', 'n_arcs',
+          'n_slips' (arc breaks caused by jumps rather than by time gaps)
+    """
+    channels = {
+        sv: k for sv, k in (glonass_channels or {}).items() if k is not None
+    }
+    return _multipath_qc(
+        obs_data,
+        phase,
+        code,
+        channels,
+        max_gap,
+        slip_threshold,
+        min_arc_length,
+        rinex_version,
+    )
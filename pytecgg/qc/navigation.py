@@ -0,0 +1,147 @@
+import polars as pl
+
+from pytecgg.context import SUPPORTED_SYSTEMS
+
+# Orbit and clock parameters that identify the content of an ephemeris; transmission
+# times, accuracies and flags legitimately differ between broadcasts of the same upload
+_EPHEMERIS_PARAMS = (
+    "clock_bias",
+    "clock_drift",
+    "clock_drift_rate",
+    "toe",
+    "sqrta",
+    "e",
+    "i0",
+    "omega0",
+    "omega",
+    "m0",
+    "deltaN",
+    "idot",
+    "omegaDot",
+    "cuc",
+    "cus",
+    "crc",
+    "crs",
+    "cic",
+    "cis",
+    "satPosX",
+    "satPosY",
+    "satPosZ",
+    "velX",
+    "velY",
+    "velZ",
+    "accelX",
+    "accelY",
+    "accelZ",
+)
+
+# Issue of data of the orbit, per constellation
+_ISSUE_OF_DATA = {"GPS": "iode", "GALILEO": "iodnav", "BEIDOU": "aode"}
+
+_REPORT_SCHEMA = {
+    "constellation": pl.String,
+    "sv": pl.String,
+    "epoch": pl.Datetime("us", "UTC"),
+    "row": pl.UInt32,
+    "issue": pl.String,
+    "detail": pl.String,
+}
+
+
+def _conflicts(records: pl.DataFrame, key: list[str], params: list[str]) -> pl.DataFrame:
+    """Records sharing a key with at least one record of different content."""
+    return records.filter(
+        (pl.len().over(key) > 1) & (pl.struct(params).n_unique().over(key) > 1)
+    )
+
+
+def check_nav_consistency(nav_frames: dict[str, pl.DataFrame]) -> pl.DataFrame:
+    """
+    Check broadcast ephemerides for inconsistent issues of data and conflicting uploads.
+
+    Three kinds of suspect records are reported:
+
+    - 'iode_iodc_mismatch': GPS records whose IODE differs from the 8 least
+      significant bits of the IODC, i.e. orbit and clock from different uploads
+      (typically at a data cutover).
+    - 'conflicting_duplicate': records of a satellite sharing the same clock reference
+      epoch (and, for Galileo, data source) but not the same orbit or clock parameters.
+    - 'iod_conflict': records of a satellite sharing the same issue of data (IODE,
+      IODnav or AODE) and orbit reference time, but not the same parameters.
+
+    Parameters
+    ----------
+    nav_frames : dict[str, pl.DataFrame]
+        Navigation data keyed by constellation name, as returned by `read_rinex_nav`.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per suspect record and issue, with columns 'constellation', 'sv'
+        (e.g., 'G01'), 'epoch', 'row' (row index in `nav_frames[constellation]`),
+        'issue' and 'detail'; empty if no inconsistency is found.
+    """
+    reports = [pl.DataFrame(schema=_REPORT_SCHEMA)]
+    for const_name, df in nav_frames.items():
+        symbol_ = SUPPORTED_SYSTEMS.get(const_name.upper())
+        if symbol_ is None or df.is_empty():
+            continue
+
+        records = df.with_row_index("row").with_columns(
+            pl.format("{}{}", pl.lit(symbol_), pl.col("sv").cast(pl.String).str.zfill(2)).alias("sv")
+        )
+        params = [c for c in _EPHEMERIS_PARAMS if c in df.columns]
+        # Galileo I/NAV and F/NAV ephemerides of the same epoch carry different clocks
+        source = ["source"] if "source" in df.columns else []
+        found = []
+
+        if {"iode", "iodc"} <= set(df.columns):
+            found.append(
+                records.filter(
+                    pl.col("iode").cast(pl.Int64) != pl.col("iodc").cast(pl.Int64) % 256
+                ).with_columns(
+                    pl.lit("iode_iodc_mismatch").alias("issue"),
+                    pl.format(
+                        "IODE {} / IODC {}",
+                        pl.col("iode").cast(pl.Int64),
+                        pl.col("iodc").cast(pl.Int64),
+                    ).alias("detail"),
+                )
+            )
+
+        if params:
+            found.append(
+                _conflicts(records, ["sv", *source, "epoch"], params).with_columns(
+                    pl.lit("conflicting_duplicate").alias("issue"),
+                    pl.format("{} records at this epoch", pl.len().over("sv", *source, "epoch")).alias(
+                        "detail"
+                    ),
+                )
+            )
+
+        iod = _ISSUE_OF_DATA.get(const_name.upper())
+        if iod in df.columns and "toe" in params:
+            found.append(
+                _conflicts(records, ["sv", *source, iod, "toe"], params).with_columns(
+                    pl.lit("iod_conflict").alias("issue"),
+                    pl.format(
+                        "{} {} shared by records with different parameters",
+                        pl.lit(iod.upper()),
+                        pl.col(iod).cast(pl.Int64),
+                    ).alias("detail"),
+                )
+            )
+
+        for suspect in found:
+            reports.append(
+                suspect.select(
+                    pl.lit(const_name).alias("constellation"),
+                    "sv",
+                    "epoch",
+                    "row",
+                    "issue",
+                    "detail",
+                ).cast(_REPORT_SCHEMA)
+            )
+
+    return pl.concat(reports).sort("constellation", "sv", "epoch", "issue", "row")
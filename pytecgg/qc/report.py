@@ -0,0 +1,267 @@
+from dataclasses import dataclass, field
+from datetime import datetime, timedelta
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+from .gaps import detect_gaps
+from .interval import sampling_interval
+from .multipath import estimate_multipath
+from pytecgg.context import SUPPORTED_SYSTEMS
+from pytecgg.parsing import read_rinex_obs
+from pytecgg.linear_combinations.observables import retrieve_observable_pairs
+
+SNR_PREFIX = "S"
+
+# Temporal columns of the DataFrames of a report, restored by `QCReport.from_dict`
+_DATETIME_COLUMNS = ("epoch", "start", "end")
+_DURATION_COLUMNS = ("duration",)
+
+
+def _json_value(value):
+    if isinstance(value, datetime):
+        return value.isoformat()
+    if isinstance(value, timedelta):
+        return value.total_seconds()
+    return value
+
+
+def _frame_to_dict(df: pl.DataFrame) -> dict[str, list]:
+    return {
+        name: [_json_value(v) for v in values]
+        for name, values in df.to_dict(as_series=False).items()
+    }
+
+
+def _frame_from_dict(columns: dict[str, list]) -> pl.DataFrame:
+    df = pl.DataFrame(columns)
+    restored = [
+        pl.Series(name, [datetime.fromisoformat(v) if v is not None else None for v in values])
+        for name, values in columns.items()
+        if name in _DATETIME_COLUMNS and values
+    ] + [
+        pl.Series(name, [timedelta(seconds=v) if v is not None else None for v in values])
+        for name, values in columns.items()
+        if name in _DURATION_COLUMNS and values
+    ]
+    return df.with_columns(restored)
+
+
+@dataclass
+class QCReport:
+    """
+    teqc/Anubis-style quality summary of a RINEX observation dataset.
+
+    Attributes
+    ----------
+    first_epoch, last_epoch : datetime or None
+        Time span covered by the observations.
+    interval : timedelta or None
+        Nominal sampling interval, inferred as the most frequent epoch spacing.
+    expected_epochs : int
+        Epochs expected between the first and last epoch at the nominal interval.
+    actual_epochs : int
+        Epochs actually present in the data.
+    gaps : pl.DataFrame
+        Station-level and per-satellite data gaps, as returned by `detect_gaps`.
+    counts : pl.DataFrame
+        Number of observations per SV and signal ('sv', 'observable', 'n_obs').
+    snr : pl.DataFrame
+        Mean signal-to-noise ratio per SV and signal ('sv', 'observable', 'mean_snr').
+    multipath : pl.DataFrame
+        MP1/MP2 RMS statistics per SV and code signal, as returned by `estimate_multipath`.
+    n_slips : int
+        Total number of cycle slips detected by the multipath combinations.
+    missing_svs : list[str]
+        Satellites with a broadcast ephemeris that were never observed
+        (only populated when navigation data are provided).
+    """
+
+    first_epoch: Optional[datetime]
+    last_epoch: Optional[datetime]
+    interval: Optional[timedelta]
+    expected_epochs: int
+    actual_epochs: int
+    gaps: pl.DataFrame
+    counts: pl.DataFrame
+    snr: pl.DataFrame
+    multipath: pl.DataFrame
+    n_slips: int
+    missing_svs: list[str] = field(default_factory=list)
+
+    @property
+    def completeness(self) -> float:
+        """Percentage of expected epochs actually present in the data."""
+        if self.expected_epochs == 0:
+            return 0.0
+        return 100.0 * self.actual_epochs / self.expected_epochs
+
+    def to_dict(self) -> dict:
+        """
+        Report as a dictionary of JSON-compatible values, e.g. for logging.
+
+        Datetimes are written as ISO 8601 strings, durations in seconds, and the
+        DataFrames as dictionaries of columns.
+        """
+        return {
+            "first_epoch": _json_value(self.first_epoch),
+            "last_epoch": _json_value(self.last_epoch),
+            "interval": _json_value(self.interval),
+            "expected_epochs": self.expected_epochs,
+            "actual_epochs": self.actual_epochs,
+            "gaps": _frame_to_dict(self.gaps),
+            "counts": _frame_to_dict(self.counts),
+            "snr": _frame_to_dict(self.snr),
+            "multipath": _frame_to_dict(self.multipath),
+            "n_slips": self.n_slips,
+            "missing_svs": list(self.missing_svs),
+        }
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "QCReport":
+        """Report from a dictionary as returned by `to_dict`."""
+        return cls(
+            first_epoch=_parse_datetime(data.get("first_epoch")),
+            last_epoch=_parse_datetime(data.get("last_epoch")),
+            interval=(
+                timedelta(seconds=data["interval"])
+                if data.get("interval") is not None
+                else None
+            ),
+            expected_epochs=data["expected_epochs"],
+            actual_epochs=data["actual_epochs"],
+            gaps=_frame_from_dict(data.get("gaps", {})),
+            counts=_frame_from_dict(data.get("counts", {})),
+            snr=_frame_from_dict(data.get("snr", {})),
+            multipath=_frame_from_dict(data.get("multipath", {})),
+            n_slips=data["n_slips"],
+            missing_svs=list(data.get("missing_svs", [])),
+        )
+
+
+def _parse_datetime(value: Optional[Union[str, datetime]]) -> Optional[datetime]:
+    return datetime.fromisoformat(value) if isinstance(value, str) else value
+
+
+def _glonass_channels(nav: Optional[dict[str, pl.DataFrame]]) -> dict[str, int]:
+    if not nav or "GLONASS" not in nav or "channel" not in nav["GLONASS"].columns:
+        return {}
+    df_glo = nav["GLONASS"].drop_nulls("channel").unique("sv", keep="first")
+    return {
+        f"R{int(sv):02d}": int(k)
+        for sv, k in zip(df_glo["sv"].to_list(), df_glo["channel"].to_list())
+    }
+
+
+def qc_report(
+    source: Union[str, Path, pl.DataFrame],
+    nav: Optional[dict[str, pl.DataFrame]] = None,
+    rinex_version: Optional[str] = None,
+) -> QCReport:
+    """
+    Produce a teqc/Anubis-style quality summary of an observation dataset.
+
+    Parameters
+    ----------
+    source : str, pathlib.Path or pl.DataFrame
+        Path to a RINEX observation file, or observations already parsed by
+        `read_rinex_obs` (columns 'epoch', 'sv', 'observable', 'value').
+    nav : dict[str, pl.DataFrame], optional
+        Navigation data as returned by `read_rinex_nav`; used to provide GLONASS
+        frequency channels for multipath and to list satellites never observed.
+    rinex_version : str, optional
+        RINEX version of the observations, used to select observable pairs;
+        taken from the file when `source` is a path, otherwise inferred from the
+        length of the observable codes.
+
+    Returns
+    -------
+    QCReport
+        Structured summary of counts, epochs, gaps, SNR, multipath and slips.
+    """
+    if isinstance(source, pl.DataFrame):
+        obs = source
+        if rinex_version is None:
+            max_len = obs["observable"].str.len_chars().max()
+            rinex_version = "2" if max_len is not None and max_len <= 2 else "3"
+    else:
+        obs, _, file_version = read_rinex_obs(source)
+        rinex_version = rinex_version or file_version
+
+    epochs = obs["epoch"].unique().sort()
+    interval = sampling_interval(obs)
+
+    first_epoch, last_epoch = epochs.min(), epochs.max()
+    if interval:
+        expected_epochs = int((last_epoch - first_epoch) / interval) + 1
+    else:
+        expected_epochs = len(epochs)
+
+    counts = (
+        obs.group_by("sv", "observable")
+        .agg(pl.col("value").count().alias("n_obs"))
+        .sort("sv", "observable")
+    )
+    snr = (
+        obs.filter(pl.col("observable").str.starts_with(SNR_PREFIX))
+        .group_by("sv", "observable")
+        .agg(pl.col("value").mean().alias("mean_snr"))
+        .sort("sv", "observable")
+    )
+
+    channels = _glonass_channels(nav)
+    mp_stats = []
+    for system_ in obs["sv"].str.slice(0, 1).unique().sort().to_list():
+        df_sys = obs.filter(pl.col("sv").str.starts_with(system_))
+        observables = df_sys["observable"].unique()
+        if not (
+            observables.str.starts_with("L").any()
+            and observables.str.contains("^[CP]").any()
+        ):
+            continue
+        pairs = retrieve_observable_pairs(df_sys, system_, rinex_version)
+        if pairs is None:
+            continue
+        phase, code = pairs
+        _, stats = estimate_multipath(
+            df_sys,
+            phase,
+            code,
+            glonass_channels=channels,
+            rinex_version=rinex_version,
+        )
+        mp_stats.append(stats)
+
+    multipath = pl.concat(mp_stats) if mp_stats else pl.DataFrame()
+    n_slips = (
+        int(multipath.unique("sv")["n_slips"].sum()) if not multipath.is_empty() else 0
+    )
+
+    missing_svs = []
+    if nav:
+        observed = set(obs["sv"].unique().to_list())
+        for const, df_nav in nav.items():
+            prefix = SUPPORTED_SYSTEMS.get(const)
+            if prefix is None:
+                continue
+            for sv in df_nav["sv"].unique().to_list():
+                sv_id = f"{prefix}{int(sv):02d}"
+                if sv_id not in observed:
+                    missing_svs.append(sv_id)
+
+    return QCReport(
+        first_epoch=first_epoch,
+        last_epoch=last_epoch,
+        interval=interval,
+        expected_epochs=expected_epochs,
+        actual_epochs=len(epochs),
+        gaps=detect_gaps(
+            obs, interval.total_seconds() if interval is not None else None
+        ),
+        counts=counts,
+        snr=snr,
+        multipath=multipath,
+        n_slips=n_slips,
+        missing_svs=sorted(missing_svs),
+    )
@@ -0,0 +1,107 @@
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import snr_stats as _snr_stats
+
+
+def snr_statistics(
+    obs_data: pl.DataFrame,
+    nav: Optional[dict[str, pl.DataFrame]] = None,
+    rx_position: Optional[tuple[float, float, float]] = None,
+    bin_width: float = 10.0,
+) -> pl.DataFrame:
+    """
+    Aggregate signal-to-noise ratio observations per satellite and signal.
+
+    When navigation data and the receiver position are provided, statistics are
+    further split into elevation bins, so that a degradation of the antenna or of
+    the RF chain can be told apart from the expected low-elevation attenuation.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        DataFrame with columns 'epoch', 'sv', 'observable', 'value'; only the SNR
+        observables (starting with 'S') are used.
+    nav : dict[str, pl.DataFrame], optional
+        Navigation data keyed by constellation, as returned by `read_rinex_nav`.
+    rx_position : tuple[float, float, float], optional
+        Receiver ECEF coordinates (in meters); required together with `nav`.
+    bin_width : float, optional
+        Width of the elevation bins in degrees (default: 10.0).
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - sv: Satellite identifier
+            - observable: SNR observable (e.g., 'S1C')
+            - elevation_bin: Lower edge of the elevation bin in degrees
+              (only when `nav` and `rx_position` are provided)
+            - n_obs: Number of SNR samples
+            - mean_snr, median_snr, min_snr: SNR statistics (in dB-Hz)
+    """
+    return _snr_stats(obs_data, nav, rx_position, bin_width)
+
+
+def ssi_to_dbhz(ssi: Union[str, pl.Expr] = "ssi") -> pl.Expr:
+    """
+    Approximate SNR (in dB-Hz) of RINEX signal strength indicator digits.
+
+    The RINEX specification relates the digits to SNR ranges of 6 dB-Hz: 1 below
+    12 dB-Hz, 2 from 12 to 17 dB-Hz, and so on up to 9 above 54 dB-Hz. Each digit
+    maps to the middle of its range, i.e. 6 × SSI + 3 dB-Hz (9 dB-Hz for 1, 57 dB-Hz
+    for 9); 0 (unknown) and out-of-range digits map to null.
+
+    Parameters
+    ----------
+    ssi : str or pl.Expr, optional
+        Column name or expression of the SSI digits, by default 'ssi'.
+
+    Returns
+    -------
+    pl.Expr
+        Approximate SNR in dB-Hz (Float64).
+    """
+    ssi = pl.col(ssi) if isinstance(ssi, str) else ssi
+    return pl.when(ssi.is_between(1, 9)).then(ssi.cast(pl.Float64) * 6 + 3)
+
+
+def snr_from_ssi(obs_data: pl.DataFrame) -> pl.DataFrame:
+    """
+    Add SNR observables derived from the signal strength indicator of the carrier
+    phases, for files recording no (or only some) SNR observables.
+
+    Each carrier phase with an SSI digit yields an SNR observable of the same signal
+    (e.g., 'S1C' for 'L1C', 'S1' for 'L1'), approximated with `ssi_to_dbhz`, unless
+    the file records it; SNR-based screening and `snr_statistics` then apply to older
+    files as well, at the 6 dB-Hz resolution of the digits.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations with columns 'epoch', 'sv', 'observable', 'value'
+        and 'ssi', as returned by `read_rinex_obs` with `ReadOptions(ssi=True)`.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with the derived SNR rows appended (same columns).
+
+    Raises
+    ------
+    ValueError
+        If `obs_data` has no 'ssi' column.
+    """
+    if "ssi" not in obs_data.columns:
+        raise ValueError("No 'ssi' column: read the observations with ReadOptions(ssi=True)")
+    keys = ["epoch", "sv", "observable"]
+    derived = (
+        obs_data.filter(pl.col("observable").str.starts_with("L") & ssi_to_dbhz().is_not_null())
+        .with_columns(
+            ("S" + pl.col("observable").str.slice(1)).alias("observable"),
+            ssi_to_dbhz().cast(obs_data.schema["value"]).alias("value"),
+        )
+        .join(obs_data.select(keys), on=keys, how="anti")
+    )
+    return pl.concat([obs_data, derived])
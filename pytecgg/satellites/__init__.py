@@ -1,8 +1,12 @@
 import warnings
 
-from .ephemeris import prepare_ephemeris, Ephem
+from .ephemeris import prepare_ephemeris, select_ephemeris, Ephem
+from .almanac import almanac_to_ephemeris
 from .positions import satellite_coordinates
 from .ipp import calculate_ipp
+from .geometry import annotate_geometry
+from .windup import phase_windup
+from .visibility import predict_visibility
 from .constants import (
     CONSTELLATION_PARAMS,
     EPHEMERIS_FIELDS,
@@ -13,8 +17,13 @@ from .constants import (
 
 __all__ = [
     "prepare_ephemeris",
+    "select_ephemeris",
+    "almanac_to_ephemeris",
     "satellite_coordinates",
     "calculate_ipp",
+    "annotate_geometry",
+    "phase_windup",
+    "predict_visibility",
     "Ephem",
     "CONSTELLATION_PARAMS",
     "EPHEMERIS_FIELDS",
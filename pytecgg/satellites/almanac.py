@@ -0,0 +1,61 @@
+from datetime import datetime, timezone
+
+import polars as pl
+
+from .ephemeris import Ephem, _get_gps_time
+from .kepler.orbits import _gps_to_datetime
+
+# Broadcast GPS weeks roll over every 1024 weeks
+_WEEK_ROLLOVER = 1024
+
+# Perturbation terms of the broadcast ephemerides, absent from the almanacs
+_UNMODELLED = ("deltaN", "idot", "cuc", "cus", "crc", "crs", "cic", "cis")
+
+
+def almanac_to_ephemeris(almanac: pl.DataFrame, epoch: datetime) -> Ephem:
+    """
+    Convert a GPS almanac to ephemerides for coarse orbit propagation.
+
+    The almanac elements are propagated with the Keplerian model of the broadcast
+    ephemerides, without the perturbation terms (harmonic corrections, mean motion
+    difference and inclination rate), which gives positions within a few kilometres
+    over days: enough for visibility prediction and sanity checks, but not for the
+    computation of the ionospheric pierce points of observations.
+
+    Parameters
+    ----------
+    almanac : pl.DataFrame
+        Almanac as returned by `read_yuma_almanac` or `read_sem_almanac`.
+    epoch : datetime
+        Any epoch close (within ten years) to the period of use, resolving the
+        1024-week rollover of the almanac week.
+
+    Returns
+    -------
+    Ephem
+        Dictionary keyed by satellite ID (e.g., 'G01'), to be used with
+        `satellite_coordinates`.
+    """
+    if epoch.tzinfo is None:
+        epoch = epoch.replace(tzinfo=timezone.utc)
+    reference_week, _ = _get_gps_time(epoch)
+
+    ephem_dict: Ephem = {}
+    for row in almanac.to_dicts():
+        week = row["week"] + _WEEK_ROLLOVER * round(
+            (reference_week - row["week"]) / _WEEK_ROLLOVER
+        )
+        ephem_dict[row["sv"]] = {
+            "constellation": "GPS",
+            "sv": row["sv"],
+            "datetime": _gps_to_datetime(week, row["toa"]),
+            "gps_week": week,
+            "gps_seconds": row["toa"],
+            "toe": row["toa"],
+            **{field: row[field] for field in ("sqrta", "m0", "e", "omega", "i0", "omega0", "omegaDot")},
+            **dict.fromkeys(_UNMODELLED, 0.0),
+            "clock_bias": row["af0"],
+            "clock_drift": row["af1"],
+            "health": row["health"],
+        }
+    return ephem_dict
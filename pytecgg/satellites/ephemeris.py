@@ -1,10 +1,10 @@
 from datetime import datetime
-from typing import Any
+from typing import Any, Literal
 
 import polars as pl
 
 from .constants import CONSTELLATION_PARAMS, GPS_EPOCH
-from pytecgg.context import GNSSContext
+from pytecgg.context import GNSSContext, SUPPORTED_SYSTEMS
 
 Ephem = dict[str, dict[str, Any] | list[dict[str, Any]]]
 """Type alias for a dictionary containing processed ephemeris data.
@@ -116,3 +116,95 @@ def prepare_ephemeris(nav: dict[str, pl.DataFrame], ctx: GNSSContext) -> Ephem:
                 ephem_dict[normalised_sat_id] = ephem
 
     return ephem_dict
+
+
+def select_ephemeris(
+    nav_frames: dict[str, pl.DataFrame],
+    epochs: pl.Series,
+    svs: pl.Series,
+    policy: Literal["closest_toe", "latest"] = "closest_toe",
+    healthy_only: bool = True,
+) -> pl.DataFrame:
+    """
+    Select the broadcast ephemeris to use for each (epoch, satellite) pair.
+
+    Only the records whose validity window ('valid_from', 'valid_until' columns of
+    `read_rinex_nav`) contains the epoch are eligible, and unhealthy satellites are
+    skipped by default.
+
+    Parameters
+    ----------
+    nav_frames : dict[str, pl.DataFrame]
+        Navigation data keyed by constellation name, as returned by `read_rinex_nav`.
+    epochs : pl.Series
+        Epochs at which the ephemerides are needed.
+    svs : pl.Series
+        Satellite identifiers (e.g., 'G01', 'E23'), aligned with `epochs`.
+    policy : {'closest_toe', 'latest'}, optional
+        'closest_toe' (default) selects the record whose orbit reference time is the
+        nearest to the epoch (clock reference epoch for GLONASS), as in
+        post-processing; 'latest' selects the last record with a clock reference
+        epoch not after the epoch, as a real-time receiver would.
+    healthy_only : bool, optional
+        If True (default), records with a non-zero 'health' flag are never selected.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per input pair, in the input order, with columns 'epoch', 'sv',
+        'constellation' (key of `nav_frames`), 'row' (row index of the selected
+        record in `nav_frames[constellation]`) and 'toc' (clock reference epoch of
+        the record); 'constellation', 'row' and 'toc' are null when no valid
+        ephemeris is available.
+    """
+    if policy not in ("closest_toe", "latest"):
+        raise ValueError(f"Unknown policy '{policy}', must be 'closest_toe' or 'latest'")
+
+    queries = pl.DataFrame({"epoch": epochs, "sv": svs.cast(pl.String)}).with_row_index("_query")
+    dtype = queries.schema["epoch"]
+
+    candidates = []
+    for const_name, df in nav_frames.items():
+        symbol_ = SUPPORTED_SYSTEMS.get(const_name.upper())
+        if symbol_ is None or df.is_empty():
+            continue
+        reference = "toe_epoch" if policy == "closest_toe" and "toe_epoch" in df.columns else "toc"
+        records = df.with_row_index("row")
+        if healthy_only and "health" in df.columns:
+            records = records.filter(pl.col("health").fill_null(0) == 0)
+        candidates.append(
+            records.select(
+                pl.format("{}{}", pl.lit(symbol_), pl.col("sv").cast(pl.String).str.zfill(2)).alias("sv"),
+                pl.lit(const_name).alias("constellation"),
+                "row",
+                pl.col(reference).cast(dtype).alias("_reference"),
+                *(pl.col(c).cast(dtype) for c in ("toc", "valid_from", "valid_until")),
+            )
+        )
+
+    if not candidates:
+        return queries.select(
+            "epoch",
+            "sv",
+            pl.lit(None, pl.String).alias("constellation"),
+            pl.lit(None, pl.UInt32).alias("row"),
+            pl.lit(None, dtype).alias("toc"),
+        )
+
+    eligible = pl.col("epoch").is_between(pl.col("valid_from"), pl.col("valid_until"))
+    selected = (
+        queries.sort("epoch")
+        .join_asof(
+            pl.concat(candidates).drop_nulls("_reference").sort("_reference"),
+            left_on="epoch",
+            right_on="_reference",
+            by="sv",
+            strategy="nearest" if policy == "closest_toe" else "backward",
+        )
+        .with_columns(
+            pl.when(eligible.fill_null(False)).then(pl.col(c)).alias(c)
+            for c in ("constellation", "row", "toc")
+        )
+        .sort("_query")
+    )
+    return selected.select("epoch", "sv", "constellation", "row", "toc")
@@ -0,0 +1,65 @@
+from typing import Optional
+
+import polars as pl
+
+from ..pytecgg import annotate_geometry as _annotate_geometry
+from pytecgg.settings import get_settings
+
+
+def annotate_geometry(
+    obs_df: pl.DataFrame,
+    nav_frames: dict[str, pl.DataFrame],
+    rx_position: tuple[float, float, float],
+    h_ipp: Optional[float] = None,
+    min_elevation: Optional[float] = None,
+) -> pl.DataFrame:
+    """
+    Annotate every observation with its satellite geometry, in a single pass.
+
+    Fuses `prepare_ephemeris`, `satellite_coordinates` and `calculate_ipp`, and the
+    mapping function of the TEC calibration, in the Rust backend: the geometry is
+    computed once per epoch and satellite, then broadcast to all the observables,
+    without building an intermediate DataFrame per step.
+
+    Parameters
+    ----------
+    obs_df : pl.DataFrame
+        Observations with columns 'epoch' (GPS time, as returned by `read_rinex_obs`)
+        and 'sv' (e.g., 'G01'), in long or wide format.
+    nav_frames : dict[str, pl.DataFrame]
+        Navigation data keyed by constellation, as returned by `read_rinex_nav`.
+    rx_position : tuple[float, float, float]
+        Receiver ECEF coordinates (in meters).
+    h_ipp : float, optional
+        Height of the ionospheric shell in meters; by default, the IPP height of the
+        package settings.
+    min_elevation : float, optional
+        Observations below this elevation (degrees), or without a usable ephemeris,
+        are filtered out. By default, the elevation mask of the package settings
+        (none, unless set with `pytecgg.configure`).
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with added columns 'sat_x', 'sat_y', 'sat_z' (ECEF, meters),
+        'azi', 'ele' (degrees), 'lat_ipp', 'lon_ipp' (degrees) and 'mapping'
+        (mapping function of the package settings, vertical over slant TEC); null
+        where no ephemeris is available.
+    """
+    settings = get_settings()
+    if h_ipp is None:
+        h_ipp = settings.h_ipp
+    annotated = _annotate_geometry(
+        obs_df,
+        nav_frames,
+        tuple(rx_position),
+        h_ipp,
+        settings.mapping_alpha,
+        settings.earth_radius,
+    )
+
+    if min_elevation is None:
+        min_elevation = settings.min_elevation
+    if min_elevation is not None:
+        annotated = annotated.filter(pl.col("ele") >= min_elevation)
+    return annotated
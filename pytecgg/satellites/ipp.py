@@ -4,8 +4,8 @@ import numpy as np
 import polars as pl
 from pymap3d import ecef2geodetic, ecef2aer
 
-from .constants import RE
 from pytecgg.context import GNSSContext
+from pytecgg.settings import get_settings
 
 
 def calculate_ipp(
@@ -23,8 +23,9 @@ def calculate_ipp(
     ctx : GNSSContext
         Context containing receiver position and IPP height.
     min_elevation : float, optional
-        Minimum elevation angle in degrees. If provided, observations below
-        this threshold are filtered out.
+        Minimum elevation angle in degrees: observations below this threshold
+        are filtered out. By default, the elevation mask of the package
+        settings (none, unless set with `pytecgg.configure`).
 
     Returns
     -------
@@ -38,13 +39,14 @@ def calculate_ipp(
     xA, yA, zA = ctx.receiver_pos
     xB, yB, zB = sat_ecef[:, 0], sat_ecef[:, 1], sat_ecef[:, 2]
     h_ipp = ctx.h_ipp
+    earth_radius = get_settings().earth_radius
 
     dx, dy, dz = xB - xA, yB - yA, zB - zA
 
     # Intersection segment-sphere (thin-shell approximation)
     a = dx**2 + dy**2 + dz**2
     b = 2 * (dx * xA + dy * yA + dz * zA)
-    c = xA**2 + yA**2 + zA**2 - (RE + h_ipp) ** 2
+    c = xA**2 + yA**2 + zA**2 - (earth_radius + h_ipp) ** 2
 
     disc = b**2 - 4 * a * c
     mask = disc >= 0
@@ -103,6 +105,8 @@ def calculate_ipp(
         ]
     )
 
+    if min_elevation is None:
+        min_elevation = get_settings().min_elevation
     if min_elevation is not None:
         df_result = df_result.filter(pl.col("ele") >= min_elevation)
 
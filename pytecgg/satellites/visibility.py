@@ -0,0 +1,138 @@
+from datetime import datetime, timedelta, timezone
+from typing import Optional, Union
+
+import numpy as np
+import polars as pl
+from pymap3d import ecef2aer, ecef2geodetic
+
+from .almanac import almanac_to_ephemeris
+from .ephemeris import prepare_ephemeris
+from .positions import satellite_coordinates
+from pytecgg.context import GNSSContext, SUPPORTED_SYSTEMS
+from pytecgg.settings import get_settings
+
+_PASS_SCHEMA = {
+    "sv": pl.String,
+    "rise": pl.Datetime("us", "UTC"),
+    "set": pl.Datetime("us", "UTC"),
+    "duration": pl.Duration("us"),
+    "max_elevation": pl.Float64,
+    "culmination": pl.Datetime("us", "UTC"),
+    "rise_azimuth": pl.Float64,
+    "set_azimuth": pl.Float64,
+}
+
+
+def _utc(t: datetime) -> datetime:
+    return t.replace(tzinfo=timezone.utc) if t.tzinfo is None else t.astimezone(timezone.utc)
+
+
+def predict_visibility(
+    nav_frames: Union[dict[str, pl.DataFrame], pl.DataFrame],
+    site_ecef: tuple[float, float, float],
+    start: datetime,
+    end: datetime,
+    mask: Optional[float] = None,
+    step: timedelta = timedelta(minutes=1),
+) -> pl.DataFrame:
+    """
+    Predict the passes of the satellites above a site from broadcast ephemerides,
+    or from a GPS almanac.
+
+    Satellite positions are propagated on a regular time grid between `start` and
+    `end`, and each run of consecutive epochs above the elevation mask is a pass.
+    Useful to plan campaigns, or to compare the observed tracking with the expected
+    one (e.g., missing passes or late acquisitions).
+
+    Parameters
+    ----------
+    nav_frames : dict[str, pl.DataFrame] or pl.DataFrame
+        Navigation data keyed by constellation name, as returned by `read_rinex_nav`,
+        or a GPS almanac, as returned by `read_yuma_almanac` or `read_sem_almanac`
+        (coarse propagation, see `almanac_to_ephemeris`).
+    site_ecef : tuple[float, float, float]
+        ECEF position of the site in meters.
+    start, end : datetime
+        Time window of the prediction, in the timescale of the navigation data
+        (naive datetimes are taken as UTC-labelled).
+    mask : float, optional
+        Elevation mask in degrees. By default, the elevation mask of the package
+        settings, or the horizon if none is set.
+    step : timedelta, optional
+        Time resolution of the prediction, by default one minute.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per pass, sorted by rise time, with columns 'sv', 'rise', 'set'
+        (first and last epochs above the mask, clipped to the window), 'duration',
+        'max_elevation' (degrees), 'culmination' (epoch of the maximum elevation),
+        'rise_azimuth' and 'set_azimuth' (degrees).
+    """
+    if mask is None:
+        mask = get_settings().min_elevation or 0.0
+
+    epochs = pl.datetime_range(_utc(start), _utc(end), step, eager=True, time_unit="us")
+    if epochs.is_empty():
+        return pl.DataFrame(schema=_PASS_SCHEMA)
+
+    if isinstance(nav_frames, pl.DataFrame):
+        ephem = almanac_to_ephemeris(nav_frames, _utc(start))
+    else:
+        systems = [name for name in nav_frames if name.upper() in SUPPORTED_SYSTEMS]
+        if not systems:
+            return pl.DataFrame(schema=_PASS_SCHEMA)
+        ctx = GNSSContext(
+            receiver_pos=tuple(site_ecef),
+            receiver_name="site",
+            rinex_version="3",
+            systems=systems,
+        )
+        ephem = prepare_ephemeris(nav_frames, ctx)
+    if not ephem:
+        return pl.DataFrame(schema=_PASS_SCHEMA)
+
+    svs = sorted(ephem)
+    coords = satellite_coordinates(
+        pl.Series("sv", np.repeat(svs, epochs.len())),
+        pl.concat([epochs] * len(svs)),
+        ephem,
+    ).sort("sv", "epoch")
+
+    azi, ele, _ = ecef2aer(
+        coords["sat_x"].to_numpy(),
+        coords["sat_y"].to_numpy(),
+        coords["sat_z"].to_numpy(),
+        *ecef2geodetic(*site_ecef),
+        deg=True,
+    )
+    visible = np.nan_to_num(ele, nan=-90.0) >= mask
+
+    passes = (
+        coords.select("sv", "epoch")
+        .with_columns(
+            pl.Series("azi", azi),
+            pl.Series("ele", ele),
+            pl.Series("_visible", visible),
+        )
+        .with_columns(
+            (pl.col("_visible") != pl.col("_visible").shift(1))
+            .fill_null(True)
+            .cum_sum()
+            .over("sv")
+            .alias("_run")
+        )
+        .filter(pl.col("_visible"))
+        .group_by("sv", "_run")
+        .agg(
+            pl.col("epoch").first().alias("rise"),
+            pl.col("epoch").last().alias("set"),
+            pl.col("ele").max().alias("max_elevation"),
+            pl.col("epoch").get(pl.col("ele").arg_max()).alias("culmination"),
+            pl.col("azi").first().alias("rise_azimuth"),
+            pl.col("azi").last().alias("set_azimuth"),
+        )
+        .with_columns((pl.col("set") - pl.col("rise")).alias("duration"))
+        .sort("rise", "sv")
+    )
+    return passes.select(list(_PASS_SCHEMA)).cast(_PASS_SCHEMA)
@@ -0,0 +1,106 @@
+import numpy as np
+import polars as pl
+from pymap3d import ecef2geodetic
+
+from pytecgg.context import GNSSContext
+from pytecgg.geodesy.solar import _subsolar_point
+
+
+def _unit(v: np.ndarray) -> np.ndarray:
+    return v / np.linalg.norm(v, axis=-1, keepdims=True)
+
+
+def _sun_direction(epochs: pl.Series) -> np.ndarray:
+    """ECEF unit vectors pointing to the Sun from the geocenter."""
+    lat, lon = np.radians(_subsolar_point(epochs))
+    return np.column_stack(
+        [np.cos(lat) * np.cos(lon), np.cos(lat) * np.sin(lon), np.sin(lat)]
+    )
+
+
+def _windup_fraction(
+    sat: np.ndarray, sun: np.ndarray, rec: np.ndarray, rec_x: np.ndarray, rec_y: np.ndarray
+) -> np.ndarray:
+    """
+    Fractional phase wind-up (cycles, in [-0.5, 0.5]) of Wu et al. (1993), between
+    nominally yawing satellite antennas and a north-oriented receiver antenna
+    """
+    # Nominal attitude: z axis to the geocenter, y axis normal to the Sun-satellite plane
+    sat_z = _unit(-sat)
+    sat_y = _unit(np.cross(sat_z, _unit(sun * 1.496e11 - sat)))
+    sat_x = np.cross(sat_y, sat_z)
+
+    k = _unit(rec - sat)
+    k_x_sat = np.cross(k, sat_y)
+    k_x_rec = np.cross(k, rec_y)
+    dipole_sat = sat_x - k * np.sum(k * sat_x, axis=1, keepdims=True) - k_x_sat
+    dipole_rec = rec_x - k * np.sum(k * rec_x, axis=1, keepdims=True) + k_x_rec
+
+    cos_phi = np.sum(dipole_sat * dipole_rec, axis=1) / (
+        np.linalg.norm(dipole_sat, axis=1) * np.linalg.norm(dipole_rec, axis=1)
+    )
+    fraction = np.arccos(np.clip(cos_phi, -1.0, 1.0)) / (2 * np.pi)
+    sign = np.sign(np.sum(k * np.cross(dipole_sat, dipole_rec), axis=1))
+    return np.where(sign < 0, -fraction, fraction)
+
+
+def phase_windup(df: pl.DataFrame, ctx: GNSSContext) -> pl.DataFrame:
+    """
+    Calculate the carrier phase wind-up of each satellite.
+
+    The wind-up is the phase rotation due to the relative orientation of the
+    circularly polarized satellite and receiver antennas. Satellites are assumed to
+    follow the nominal yaw attitude (pointing to the geocenter, solar panels
+    orthogonal to the Sun), approximated from their position and a low-precision
+    Sun position; the yaw maneuvers of eclipsing satellites are not modelled. The
+    receiver antenna is assumed static and oriented to the north.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with columns 'sv', 'epoch', 'sat_x', 'sat_y', 'sat_z', as returned
+        by `satellite_coordinates`.
+    ctx : GNSSContext
+        Context containing the receiver position.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with an added 'windup' column (cycles), continuous along the arc
+        of each satellite; null for missing coordinates.
+    """
+    if df.is_empty():
+        return df.with_columns(pl.lit(None, pl.Float64).alias("windup"))
+
+    df = df.with_row_index("_row").sort("sv", "epoch")
+    valid = df.filter(
+        pl.col("sat_x").is_not_nan() & pl.col("sat_y").is_not_nan() & pl.col("sat_z").is_not_nan()
+    )
+
+    rec = np.asarray(ctx.receiver_pos, dtype=float)
+    lat, lon, _ = ecef2geodetic(*rec, deg=False)
+    north = np.array([-np.sin(lat) * np.cos(lon), -np.sin(lat) * np.sin(lon), np.cos(lat)])
+    west = np.array([np.sin(lon), -np.cos(lon), 0.0])
+
+    n = valid.height
+    fraction = _windup_fraction(
+        valid.select("sat_x", "sat_y", "sat_z").to_numpy(),
+        _sun_direction(valid["epoch"]),
+        np.tile(rec, (n, 1)),
+        np.tile(north, (n, 1)),
+        np.tile(west, (n, 1)),
+    )
+
+    # Integer cycles are resolved by continuity along the arc of each satellite
+    windup = np.empty(n)
+    svs = valid["sv"].to_numpy()
+    starts = np.flatnonzero(np.r_[True, svs[1:] != svs[:-1]])
+    for start, end in zip(starts, np.r_[starts[1:], n]):
+        windup[start:end] = np.unwrap(2 * np.pi * fraction[start:end]) / (2 * np.pi)
+
+    result = pl.DataFrame({"_row": valid["_row"], "windup": windup})
+    return (
+        df.join(result, on="_row", how="left")
+        .sort("_row")
+        .drop("_row")
+    )
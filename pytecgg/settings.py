@@ -0,0 +1,133 @@
+from contextlib import contextmanager
+from dataclasses import dataclass, fields, replace
+from typing import Iterator, Optional
+
+# Scaling of the zenith angle of each mapping function: the single-layer (thin-shell)
+# model and the modified single-layer model of the CODE global ionospheric maps
+MAPPING_FUNCTIONS = {"thin_shell": 1.0, "mslm": 0.9782}
+
+
+@dataclass(frozen=True)
+class Settings:
+    """
+    Physical conventions used by default by the TEC helpers of the package.
+
+    Attributes
+    ----------
+    h_ipp : float
+        Height of the ionospheric shell in meters, by default 350 km; used as the
+        default of `GNSSContext.h_ipp` and of the `tec.mapping` expression.
+    earth_radius : float
+        Mean Earth radius in meters of the thin-shell model, by default 6371 km;
+        the shell lies at `earth_radius + h_ipp` from the geocentre, for the pierce
+        points and the mapping function alike.
+    iono_constant : float
+        Ionospheric refraction constant K (m³/s²) of the first-order group delay
+        40.308·TEC/f², by default 40.308.
+    tecu : float
+        Electrons per square meter in one TEC unit, by default 1e16.
+    mapping_function : {'thin_shell', 'mslm'}
+        Slant-to-vertical mapping function: the single-layer (thin-shell) model
+        (default), or the modified single-layer model (MSLM, zenith angle scaled by
+        0.9782, usually paired with `h_ipp=506_700`).
+    h_ipp_equator : float
+        Height of the ionospheric shell in meters at the magnetic equator, by
+        default 450 km, for the modip-aware mapping function of the calibration
+        (`calculate_tec(modip_mapping=True)`): the shell rises from `h_ipp` at the
+        magnetic poles to `h_ipp_equator` at the magnetic equator, following the
+        higher F2 peak of the low latitudes.
+    min_elevation : float or None
+        Default elevation mask in degrees of `calculate_ipp`; None (default)
+        keeps all the observations.
+    """
+
+    h_ipp: float = 350_000
+    earth_radius: float = 6_371_000
+    iono_constant: float = 40.308
+    tecu: float = 1e16
+    mapping_function: str = "thin_shell"
+    h_ipp_equator: float = 450_000
+    min_elevation: Optional[float] = None
+
+    def __post_init__(self):
+        if self.mapping_function not in MAPPING_FUNCTIONS:
+            raise ValueError(
+                f"Unsupported mapping function '{self.mapping_function}': expected one of {list(MAPPING_FUNCTIONS)}"
+            )
+        if min(self.h_ipp, self.h_ipp_equator, self.earth_radius, self.iono_constant, self.tecu) <= 0:
+            raise ValueError(
+                "'h_ipp', 'h_ipp_equator', 'earth_radius', 'iono_constant' and 'tecu' must be positive"
+            )
+
+    @property
+    def mapping_alpha(self) -> float:
+        """Scaling of the zenith angle of the mapping function."""
+        return MAPPING_FUNCTIONS[self.mapping_function]
+
+
+_settings = Settings()
+
+
+def get_settings() -> Settings:
+    """
+    Returns the settings currently in force in the process.
+
+    Returns
+    -------
+    Settings
+        Current settings (immutable: change them with `configure`).
+    """
+    return _settings
+
+
+def configure(**changes) -> Settings:
+    """
+    Changes the physical conventions of the package, once per process.
+
+    Parameters
+    ----------
+    **changes
+        New values of `Settings` attributes (e.g., `h_ipp=450_000`,
+        `mapping_function='mslm'`); the others are kept.
+
+    Returns
+    -------
+    Settings
+        The new settings.
+
+    Examples
+    --------
+    >>> configure(h_ipp=450_000, min_elevation=15)
+    """
+    global _settings
+    names = {f.name for f in fields(Settings)}
+    unknown = set(changes) - names
+    if unknown:
+        raise ValueError(f"Unknown settings {sorted(unknown)}: expected some of {sorted(names)}")
+    _settings = replace(_settings, **changes)
+    return _settings
+
+
+def reset_settings() -> Settings:
+    """Restores the default settings."""
+    global _settings
+    _settings = Settings()
+    return _settings
+
+
+@contextmanager
+def settings_context(**changes) -> Iterator[Settings]:
+    """
+    Applies settings within a `with` block only, restoring the previous ones on exit.
+
+    Examples
+    --------
+    >>> with settings_context(mapping_function="mslm", h_ipp=506_700):
+    ...     df = df.with_columns(pl.col("stec").tec.mapping("ele").alias("vtec"))
+    """
+    global _settings
+    previous = _settings
+    try:
+        yield configure(**changes)
+    finally:
+        _settings = previous
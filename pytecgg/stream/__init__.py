@@ -0,0 +1,4 @@
+from .ntrip import NtripClient, NtripError, ntrip_sourcetable, stream_ntrip
+from .tail import RinexObsTail, tail_rinex_obs
+
+__all__ = ["NtripClient", "NtripError", "ntrip_sourcetable", "stream_ntrip", "RinexObsTail", "tail_rinex_obs"]
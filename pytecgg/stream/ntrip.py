@@ -0,0 +1,331 @@
+import base64
+import logging
+import socket
+import ssl
+import time
+from typing import Iterator, Optional
+
+import polars as pl
+
+from ..pytecgg import RtcmDecoder
+from ..utils.download_rinex import USER_AGENT
+
+logger = logging.getLogger(__name__)
+
+# Fields of the 'STR' records of a caster source table (NTRIP 2.0, section 2.3)
+_STR_FIELDS = [
+    "mountpoint",
+    "identifier",
+    "format",
+    "format_details",
+    "carrier",
+    "nav_system",
+    "network",
+    "country",
+    "lat",
+    "lon",
+    "nmea",
+    "solution",
+    "generator",
+    "compression",
+    "authentication",
+    "fee",
+    "bitrate",
+]
+
+
+class NtripError(ConnectionError):
+    """Request refused by an NTRIP caster (e.g., unknown mountpoint)."""
+
+
+class NtripClient:
+    """
+    Client of an NTRIP caster, reading the raw data stream of a mountpoint.
+
+    Both NTRIP 1.0 ('ICY 200 OK') and NTRIP 2.0 (HTTP, possibly chunked)
+    responses are supported. The client can be used as a context manager.
+
+    Parameters
+    ----------
+    host : str
+        Caster host name.
+    mountpoint : str, optional
+        Mountpoint of the stream; None requests the source table.
+    port : int, optional
+        Caster port, by default 2101.
+    username, password : str, optional
+        Caster credentials, if required by the mountpoint.
+    gga : str, optional
+        NMEA GGA sentence sent after connecting, required by network (VRS)
+        mountpoints to generate the stream for the receiver position.
+    tls : bool, optional
+        If True, the connection is encrypted (e.g., casters on port 443).
+    timeout : float, optional
+        Socket timeout, in seconds.
+    """
+
+    def __init__(
+        self,
+        host: str,
+        mountpoint: Optional[str] = None,
+        port: int = 2101,
+        username: Optional[str] = None,
+        password: Optional[str] = None,
+        gga: Optional[str] = None,
+        tls: bool = False,
+        timeout: float = 10.0,
+    ):
+        self.host = host
+        self.mountpoint = mountpoint
+        self.port = port
+        self.username = username
+        self.password = password
+        self.gga = gga
+        self.tls = tls
+        self.timeout = timeout
+        self._socket: Optional[socket.socket] = None
+        self._initial = b""
+        self._chunked = False
+
+    def __enter__(self) -> "NtripClient":
+        self.connect()
+        return self
+
+    def __exit__(self, *exc) -> None:
+        self.close()
+
+    def _request(self) -> bytes:
+        lines = [
+            f"GET /{self.mountpoint or ''} HTTP/1.1",
+            f"Host: {self.host}:{self.port}",
+            "Ntrip-Version: Ntrip/2.0",
+            f"User-Agent: NTRIP {USER_AGENT}",
+            "Connection: close",
+        ]
+        if self.username is not None:
+            token = base64.b64encode(f"{self.username}:{self.password or ''}".encode()).decode()
+            lines.append(f"Authorization: Basic {token}")
+        return ("\r\n".join(lines) + "\r\n\r\n").encode()
+
+    def connect(self) -> None:
+        """
+        Connect to the caster and read the response header.
+
+        Raises
+        ------
+        PermissionError
+            If the caster rejects the credentials.
+        NtripError
+            If the mountpoint does not exist or the caster refuses the request.
+        """
+        sock = socket.create_connection((self.host, self.port), timeout=self.timeout)
+        if self.tls:
+            sock = ssl.create_default_context().wrap_socket(sock, server_hostname=self.host)
+        sock.sendall(self._request())
+
+        response = b""
+        while b"\r\n\r\n" not in response and not response.startswith(b"ICY 200 OK\r\n"):
+            data = sock.recv(4096)
+            if not data:
+                break
+            response += data
+
+        status, _, rest = response.partition(b"\r\n")
+        if status.startswith(b"ICY 200"):
+            # NTRIP 1.0: the stream follows the status line (and an optional blank line)
+            body = rest[2:] if rest.startswith(b"\r\n") else rest
+            headers = b""
+        else:
+            headers, _, body = rest.partition(b"\r\n\r\n")
+        code = status.split(b" ")[1] if b" " in status else b""
+        is_table = status.startswith(b"SOURCETABLE") or b"gnss/sourcetable" in headers.lower()
+
+        if code == b"401":
+            sock.close()
+            raise PermissionError(f"Unauthorized access to {self.host}/{self.mountpoint}")
+        if code != b"200" or (self.mountpoint and is_table):
+            sock.close()
+            reason = "unknown mountpoint" if is_table else status.decode(errors="replace")
+            raise NtripError(f"NTRIP request to {self.host}/{self.mountpoint} failed: {reason}")
+
+        self._chunked = b"transfer-encoding: chunked" in headers.lower()
+        self._socket, self._initial = sock, body
+        if self.gga:
+            sock.sendall(self.gga.strip().encode() + b"\r\n")
+        logger.info(f"Connected to {self.host}:{self.port}/{self.mountpoint or ''}")
+
+    def close(self) -> None:
+        if self._socket is not None:
+            self._socket.close()
+            self._socket = None
+
+    def _raw(self, size: int) -> Iterator[bytes]:
+        if self._initial:
+            yield self._initial
+            self._initial = b""
+        while self._socket is not None:
+            data = self._socket.recv(size)
+            if not data:
+                return
+            yield data
+
+    def chunks(self, size: int = 4096) -> Iterator[bytes]:
+        """
+        Iterate over the data of the stream, as received.
+
+        Chunked transfer encoding (NTRIP 2.0) is removed. The iteration ends when
+        the caster closes the connection; socket errors (e.g., timeouts) are raised.
+        """
+        if not self._chunked:
+            yield from self._raw(size)
+            return
+        buffer = b""
+        for data in self._raw(size):
+            buffer += data
+            while True:
+                line, sep, rest = buffer.partition(b"\r\n")
+                if not sep:
+                    break
+                length = int(line.split(b";")[0] or b"0", 16)
+                if length == 0:
+                    return
+                if len(rest) < length + 2:
+                    break
+                yield rest[:length]
+                buffer = rest[length + 2 :]
+
+
+def stream_ntrip(
+    host: str,
+    mountpoint: str,
+    port: int = 2101,
+    username: Optional[str] = None,
+    password: Optional[str] = None,
+    gga: Optional[str] = None,
+    tls: bool = False,
+    time_scale: str = "GPST",
+    time_unit: str = "us",
+    glonass_channels: Optional[dict[str, int]] = None,
+    retries: int = 3,
+    timeout: float = 10.0,
+) -> Iterator[pl.DataFrame]:
+    """
+    Stream RTCM 3 observations of an NTRIP mountpoint as DataFrames, in near-real time.
+
+    MSM4 to MSM7 messages are decoded into batches of complete epochs, with the
+    same schema as `read_rinex_obs`, so that live data can be processed (e.g., TEC
+    or ROTI monitoring) as file-based data. Dropped connections are re-established
+    up to `retries` times in a row, with the decoder state (e.g., a frame split
+    across the disconnection) carried over.
+
+    Parameters
+    ----------
+    host : str
+        Caster host name.
+    mountpoint : str
+        Mountpoint of the stream.
+    port : int, optional
+        Caster port, by default 2101.
+    username, password : str, optional
+        Caster credentials, if required by the mountpoint.
+    gga : str, optional
+        NMEA GGA sentence, for network (VRS) mountpoints.
+    tls : bool, optional
+        If True, the connection is encrypted.
+    time_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}, optional
+        Timescale of the 'epoch' column, by default 'GPST'.
+    time_unit : {'us', 'ns'}, optional
+        Precision of the 'epoch' column, by default microseconds.
+    glonass_channels : dict[str, int], optional
+        GLONASS frequency channels per SV (e.g., {'R01': 1}), needed for the
+        GLONASS carrier phase of MSM4/MSM6 streams.
+    retries : int, optional
+        Number of reconnection attempts after a dropped connection.
+    timeout : float, optional
+        Socket timeout, in seconds.
+
+    Yields
+    ------
+    pl.DataFrame
+        Observations of the epochs completed since the previous batch, with
+        columns 'epoch', 'sv', 'observable', 'value', 'epoch_flag' and
+        'rx_clock_offset' (null).
+
+    Examples
+    --------
+    >>> for batch in stream_ntrip("ntrip.example.org", "GRAZ00AUT0", username="u", password="p"):
+    ...     print(batch.height)
+    """
+    # The current time only needs to be within a few days of the data
+    decoder = RtcmDecoder(time.time_ns(), glonass_channels)
+    client = NtripClient(host, mountpoint, port, username, password, gga, tls, timeout)
+    failures = 0
+    while True:
+        try:
+            with client:
+                for data in client.chunks():
+                    decoder.feed(data)
+                    batch = decoder.take_obs(False, time_scale, time_unit)
+                    if batch.height:
+                        failures = 0
+                        yield batch.with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+            logger.warning(f"Connection closed by {host}")
+        except (NtripError, PermissionError):
+            raise
+        except OSError as e:
+            # Network failures: timeouts, resets, TLS errors
+            logger.warning(f"Connection to {host} lost: {e}")
+        failures += 1
+        if failures > retries:
+            return
+        time.sleep(min(2**failures, 60))
+
+
+def ntrip_sourcetable(
+    host: str,
+    port: int = 2101,
+    username: Optional[str] = None,
+    password: Optional[str] = None,
+    tls: bool = False,
+    timeout: float = 10.0,
+) -> pl.DataFrame:
+    """
+    List the streams of an NTRIP caster.
+
+    Parameters
+    ----------
+    host : str
+        Caster host name.
+    port : int, optional
+        Caster port, by default 2101.
+    username, password : str, optional
+        Caster credentials, if required.
+    tls : bool, optional
+        If True, the connection is encrypted.
+    timeout : float, optional
+        Socket timeout, in seconds.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per stream ('STR' record), with columns 'mountpoint',
+        'identifier', 'format' (e.g., 'RTCM 3.3'), 'format_details', 'carrier',
+        'nav_system', 'network', 'country', 'lat', 'lon' (degrees), 'nmea'
+        (whether a GGA sentence is required), 'solution', 'generator',
+        'compression', 'authentication', 'fee' and 'bitrate'.
+    """
+    client = NtripClient(host, None, port, username, password, tls=tls, timeout=timeout)
+    with client:
+        text = b"".join(client.chunks()).decode(errors="replace")
+
+    rows = []
+    for line in text.splitlines():
+        if not line.startswith("STR;"):
+            continue
+        fields = line.split(";")[1 : len(_STR_FIELDS) + 1]
+        rows.append(fields + [None] * (len(_STR_FIELDS) - len(fields)))
+    return pl.DataFrame(rows, schema=_STR_FIELDS, orient="row").with_columns(
+        pl.col("lat", "lon").cast(pl.Float64, strict=False),
+        pl.col("nmea") == "1",
+        pl.col("bitrate").cast(pl.Int64, strict=False),
+    )
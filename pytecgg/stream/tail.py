@@ -0,0 +1,142 @@
+import re
+import tempfile
+import time
+from pathlib import Path
+from typing import Iterator, Optional, Union
+
+import polars as pl
+
+from ..parsing import read_rinex_obs
+
+# Epoch records: '>' in RINEX 3/4, ' yy mm dd hh mm ss.sssssss  f' in RINEX 2
+_EPOCH_V2 = re.compile(rb"^ [ \d]\d( [ \d]\d){4}[ \d]{2}\d\.\d{7}  \d", re.MULTILINE)
+_EPOCH_V3 = re.compile(rb"^>", re.MULTILINE)
+
+_END_OF_HEADER = b"END OF HEADER"
+
+
+class RinexObsTail:
+    """
+    Follower of a RINEX observation file being written by a receiver.
+
+    The file offset is remembered between reads, so that each call of `read` only
+    parses the epochs appended since the previous one; iterating polls the file
+    every `poll_interval` seconds. The last epoch of the file is only returned
+    once the next one starts (or with `flush`), as it may still be incomplete.
+    Compressed (gzip or Hatanaka) files cannot be followed.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the RINEX observation file.
+    poll_interval : float, optional
+        Seconds between two polls of the file when iterating, by default 1.
+    **kwargs
+        Options passed to `read_rinex_obs` (e.g., `time_scale`); `sort` and
+        `duplicates` apply to each batch.
+    """
+
+    def __init__(self, path: Union[str, Path], poll_interval: float = 1.0, **kwargs):
+        self.path = Path(path)
+        name = self.path.name.lower()
+        if name.endswith((".gz", ".z", ".crx")) or re.search(r"\.\d\dd$", name):
+            raise ValueError(f"Cannot follow a compressed file: {self.path.name}")
+        self.poll_interval = poll_interval
+        self.kwargs = kwargs
+        self.offset = 0
+        self._header: Optional[bytes] = None
+
+    def _read_header(self, f) -> bool:
+        data = f.read()
+        end = data.find(_END_OF_HEADER)
+        newline = data.find(b"\n", end)
+        if end < 0 or newline < 0:
+            return False
+        self._header = data[: newline + 1]
+        self.offset = newline + 1
+        return True
+
+    def read(self, flush: bool = False) -> Optional[pl.DataFrame]:
+        """
+        Parses the epochs appended since the previous read.
+
+        Parameters
+        ----------
+        flush : bool, optional
+            If True, the last epoch is returned as well (e.g., once the file has
+            been closed by the receiver).
+
+        Returns
+        -------
+        pl.DataFrame or None
+            Observations of the new epochs, with the columns of `read_rinex_obs`,
+            or None if no complete epoch was appended.
+        """
+        with open(self.path, "rb") as f:
+            if f.seek(0, 2) < self.offset:
+                # Truncated or replaced file: start over
+                self.offset = 0
+                self._header = None
+            f.seek(0)
+            if self._header is None and not self._read_header(f):
+                return None
+            f.seek(self.offset)
+            chunk = f.read()
+
+        # Complete lines only, up to the start of the last epoch unless flushing
+        chunk = chunk[: chunk.rfind(b"\n") + 1]
+        if not flush:
+            version = self._header[:9].strip()
+            pattern = _EPOCH_V2 if version.startswith(b"2") else _EPOCH_V3
+            starts = [m.start() for m in pattern.finditer(chunk)]
+            chunk = chunk[: starts[-1]] if starts else b""
+        if not chunk.strip():
+            return None
+        self.offset += len(chunk)
+
+        # The parser reads files: the header and the new epochs are parsed together
+        with tempfile.TemporaryDirectory() as tmp:
+            path = Path(tmp) / self.path.name
+            path.write_bytes(self._header + chunk)
+            return read_rinex_obs(path, **self.kwargs)[0]
+
+    def __iter__(self) -> Iterator[pl.DataFrame]:
+        while True:
+            batch = self.read()
+            if batch is not None and batch.height:
+                yield batch
+            else:
+                time.sleep(self.poll_interval)
+
+
+def tail_rinex_obs(path: Union[str, Path], poll_interval: float = 1.0, **kwargs) -> RinexObsTail:
+    """
+    Follow a RINEX observation file being appended to, in near-real time.
+
+    Receivers logging to hourly or daily files append epochs as they are
+    observed: the returned follower remembers its file offset and yields only the
+    newly appended epochs, either on each call of its `read` method or by
+    iterating over it (polling the file every `poll_interval` seconds).
+
+    Parameters
+    ----------
+    path : str or pathlib.Path
+        Path to the (uncompressed) RINEX observation file.
+    poll_interval : float, optional
+        Seconds between two polls of the file when iterating, by default 1.
+    **kwargs
+        Options passed to `read_rinex_obs` (e.g., `time_scale`, `geometry_free`).
+
+    Returns
+    -------
+    RinexObsTail
+        Follower of the file.
+
+    Examples
+    --------
+    >>> tail = tail_rinex_obs("GROT00ITA_R_20250600000_01H_01S_MO.rnx")
+    >>> new_epochs = tail.read()
+    >>> for batch in tail_rinex_obs("GROT00ITA_R_20250600000_01H_01S_MO.rnx", poll_interval=5):
+    ...     print(batch["epoch"].max())
+    """
+    return RinexObsTail(path, poll_interval, **kwargs)
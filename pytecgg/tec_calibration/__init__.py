@@ -1,10 +1,16 @@
 from .arcs import extract_arcs
-from .modip import extract_modip
+from .modip import calculate_modip, extract_modip
 from .calibration import calculate_tec, calculate_vertical_equivalent
+from .p1c1 import correct_p1c1_bias
+from .pipeline import TecOptions, compute_tec
 
 __all__ = [
     "extract_arcs",
     "extract_modip",
+    "calculate_modip",
     "calculate_tec",
     "calculate_vertical_equivalent",
+    "correct_p1c1_bias",
+    "compute_tec",
+    "TecOptions",
 ]
@@ -6,6 +6,7 @@ import polars as pl
 from scipy.linalg import qr, solve, solve_triangular
 from scipy.sparse import csr_matrix
 
+from pytecgg.tec_calibration.modip import calculate_modip
 from pytecgg.tec_calibration.calibration_preprocessing import (
     _polynomial_expansion,
     _preprocessing,
@@ -170,6 +171,7 @@ def _estimate_bias(
     max_degree: int,
     n_epochs: int,
     h_ipp: float,
+    modip_mapping: bool = False,
 ) -> dict[str, float]:
     """
     Estimate arc-level TEC biases. The function preprocesses the data, computes vTEC,
@@ -188,13 +190,20 @@ def _estimate_bias(
         Number of epochs per batch for calibration.
     h_ipp : float, optional
         Height of the IPP [m].
+    modip_mapping : bool, optional
+        If True, the mapping function uses the modip-aware shell height.
 
     Returns
     -------
     dict[str, float]
         Dictionary mapping arc identifiers to estimated biases.
     """
-    df_clean = _preprocessing(df, receiver_position=receiver_position, h_ipp=h_ipp)
+    df_clean = _preprocessing(
+        df,
+        receiver_position=receiver_position,
+        h_ipp=h_ipp,
+        modip_mapping=modip_mapping,
+    )
     return _gg_calibration(df_clean, batch_length_mins=n_epochs, max_degree=max_degree)
 
 
@@ -276,6 +285,7 @@ def calculate_tec(
     ctx: GNSSContext,
     max_polynomial_degree: int = 3,
     batch_size_epochs: int = 30,
+    modip_mapping: bool = False,
 ) -> pl.DataFrame:
     """
     Compute slant and vertical TEC (sTEC, vTEC) after per-arc bias estimation.
@@ -293,6 +303,12 @@ def calculate_tec(
         Maximum degree of polynomial expansion used in calibration.
     batch_size_epochs : int
         Number of epochs per batch for calibration.
+    modip_mapping : bool, optional
+        If True, the slant-to-vertical mapping uses a shell whose height depends
+        on the MoDip of the IPP, from `ctx.h_ipp` at the magnetic poles to the
+        `h_ipp_equator` of the settings at the magnetic equator, which better
+        follows the higher ionosphere of the low latitudes. Requires the
+        'lat_ipp' and 'lon_ipp' columns. Default is False.
 
     Returns
     -------
@@ -321,6 +337,7 @@ def calculate_tec(
         max_degree=max_polynomial_degree,
         n_epochs=batch_size_epochs,
         h_ipp=ctx.h_ipp,
+        modip_mapping=modip_mapping,
     )
 
     map_ = pl.DataFrame(
@@ -330,17 +347,23 @@ def calculate_tec(
         }
     )
 
-    return (
-        df.join(
-            map_,
-            on="id_arc_valid",
-            how="left",
+    df_stec = df.join(
+        map_,
+        on="id_arc_valid",
+        how="left",
+    ).with_columns((pl.col("gflc_levelled") - pl.col("bias")).alias("stec"))
+
+    if modip_mapping:
+        modip = calculate_modip(df_stec.select("epoch", "lat_ipp", "lon_ipp"))["modip_ipp"]
+        mapping = _mapping_function(
+            df_stec["ele"].to_numpy(), h_ipp=ctx.h_ipp, modip=modip.to_numpy()
         )
-        .with_columns((pl.col("gflc_levelled") - pl.col("bias")).alias("stec"))
-        .with_columns(
-            (pl.col("stec") * _mapping_function(pl.col("ele"), h_ipp=ctx.h_ipp)).alias(
-                "vtec"
-            )
+        return df_stec.with_columns(
+            (pl.col("stec") * pl.Series(mapping)).alias("vtec")
+        )
+    return df_stec.with_columns(
+        (pl.col("stec") * _mapping_function(pl.col("ele"), h_ipp=ctx.h_ipp)).alias(
+            "vtec"
         )
     )
 
@@ -350,6 +373,7 @@ def calculate_vertical_equivalent(
     ctx: GNSSContext,
     max_polynomial_degree: int = 3,
     batch_size_epochs: int = 30,
+    modip_mapping: bool = False,
 ) -> pl.DataFrame:
     """
     Compute the Vertical Equivalent (VEq) series at the station zenith,
@@ -365,6 +389,8 @@ def calculate_vertical_equivalent(
         Same degree used for calibration. Default is 3.
     batch_size_epochs : int, optional
         Same batch size used for calibration. Default is 30.
+    modip_mapping : bool, optional
+        Same mapping used for calibration. Default is False.
 
     Returns
     -------
@@ -373,7 +399,10 @@ def calculate_vertical_equivalent(
     """
     # Align MoDip and geometry for batch solving
     df_prep = _preprocessing(
-        df_calibrated, receiver_position=ctx.receiver_pos, h_ipp=ctx.h_ipp
+        df_calibrated,
+        receiver_position=ctx.receiver_pos,
+        h_ipp=ctx.h_ipp,
+        modip_mapping=modip_mapping,
     )
 
     veq_dict = _estimate_veq_batches(
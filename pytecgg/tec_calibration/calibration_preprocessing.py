@@ -1,7 +1,10 @@
+from typing import Optional
+
 import numpy as np
 import polars as pl
 from pymap3d import ecef2geodetic
 
+from pytecgg.settings import get_settings
 from pytecgg.tec_calibration.modip import extract_modip
 
 
@@ -54,23 +57,42 @@ def _polynomial_expansion(
     return pterms_matrix
 
 
-def _mapping_function(elevation: np.ndarray, h_ipp: float) -> np.ndarray:
+def _shell_height(modip: np.ndarray, h_ipp: float) -> np.ndarray:
+    """
+    Height of the modip-aware shell [m], from `h_ipp` at the magnetic poles to the
+    equatorial height of the settings at the magnetic equator.
     """
-    Compute the mapping function to convert slant to vertical TEC.
+    h_equator = get_settings().h_ipp_equator
+    return h_ipp + (h_equator - h_ipp) * np.cos(np.radians(modip)) ** 2
+
+
+def _mapping_function(
+    elevation: np.ndarray, h_ipp: float, modip: Optional[np.ndarray] = None
+) -> np.ndarray:
+    """
+    Compute the mapping function to convert slant to vertical TEC, following the
+    mapping function of the package settings (thin-shell by default).
 
     Parameters
     ----------
     elevation : np.ndarray
         Satellite elevation angles [degrees]
     h_ipp : float
-        Height of IPP [km]
+        Height of IPP [m]
+    modip : np.ndarray, optional
+        MoDip of the IPPs [degrees]; if given, the shell height depends on the
+        MoDip (see `_shell_height`)
 
     Returns
     -------
     np.ndarray
     """
+    settings = get_settings()
+    if modip is not None:
+        h_ipp = _shell_height(modip, h_ipp)
+    ratio = settings.earth_radius / (settings.earth_radius + h_ipp)
     return np.cos(
-        np.arcsin((6_371 / (6_371 + h_ipp / 1_000)) * np.cos(np.radians(elevation)))
+        np.arcsin(ratio * np.sin(np.radians(settings.mapping_alpha * (90 - elevation))))
     )
 
 
@@ -78,6 +100,7 @@ def _preprocessing(
     df: pl.DataFrame,
     receiver_position: tuple[float, float, float],
     h_ipp: float,
+    modip_mapping: bool = False,
 ) -> pl.DataFrame:
     """
     Preprocess GNSS data for TEC modeling. Adds mapping function, vTEC, and
@@ -95,6 +118,8 @@ def _preprocessing(
         Receiver position in ECEF coordinates (x, y, z) [meters].
     h_ipp : float
         Height of the ionospheric pierce point [m].
+    modip_mapping : bool, optional
+        If True, the mapping function uses the modip-aware shell height.
 
     Returns
     -------
@@ -115,7 +140,9 @@ def _preprocessing(
     modip_rec = extract_modip(coords=receiver_position, year=year, coord_type="ecef")[0]
     _, lon_rec, _ = ecef2geodetic(*receiver_position)
 
-    mapping = _mapping_function(df["ele"].to_numpy(), h_ipp)
+    mapping = _mapping_function(
+        df["ele"].to_numpy(), h_ipp, modip=modip_ipp if modip_mapping else None
+    )
     gflc_vert = df["gflc_levelled"].to_numpy() * mapping
 
     return df.with_columns(
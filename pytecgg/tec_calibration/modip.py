@@ -5,6 +5,7 @@ from functools import lru_cache
 from typing import Sequence, Union, Literal
 
 import numpy as np
+import polars as pl
 
 from .constants import ALTITUDE_KM, LONGITUDES, LATITUDES
 
@@ -215,3 +216,45 @@ def extract_modip(
     )
 
     return interpolator(points_geo).round(2)
+
+
+def calculate_modip(
+    df: pl.DataFrame,
+    lat: str = "lat_ipp",
+    lon: str = "lon_ipp",
+    alias: str = "modip_ipp",
+) -> pl.DataFrame:
+    """
+    Add the MoDip of geographic coordinates (e.g., the IPPs) to a DataFrame.
+
+    The MoDip grid of the year of each epoch is used, so that multi-year series
+    follow the secular variation of the geomagnetic field.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        DataFrame with an 'epoch' column and the latitude and longitude columns.
+    lat, lon : str, optional
+        Latitude and longitude columns in degrees, by default those of the IPPs
+        ('lat_ipp', 'lon_ipp').
+    alias : str, optional
+        Name of the added column, by default 'modip_ipp'.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with the added MoDip column in degrees, null where the
+        coordinates are missing.
+    """
+    years = df["epoch"].dt.year().to_numpy()
+    lons = df[lon].cast(pl.Float64).fill_null(np.nan).to_numpy()
+    lats = df[lat].cast(pl.Float64).fill_null(np.nan).to_numpy()
+
+    modip = np.full(df.height, np.nan)
+    for year in np.unique(years):
+        rows = (years == year) & ~np.isnan(lons) & ~np.isnan(lats)
+        if rows.any():
+            modip[rows] = extract_modip(
+                coords=(lons[rows], lats[rows]), year=int(year), coord_type="geo"
+            )
+    return df.with_columns(pl.Series(alias, modip).fill_nan(None))
@@ -0,0 +1,84 @@
+from typing import Literal
+
+import polars as pl
+
+from pytecgg.linear_combinations.constants import C
+
+# C/A code observables, and the P2 observables of cross-correlation receivers (X2,
+# reported as P2 in RINEX 2)
+_C1_CODES = ["C1", "C1C"]
+_X2_CODES = ["P2", "C2D", "C2W"]
+
+ReceiverClass = Literal["P1P2", "C1P2", "C1X2"]
+
+
+def correct_p1c1_bias(
+    obs_data: pl.DataFrame,
+    biases: pl.DataFrame,
+    receiver_class: ReceiverClass,
+) -> pl.DataFrame:
+    """
+    Make the code observations of a receiver consistent with P1/P2 using the P1-C1
+    satellite biases.
+
+    Depending on the receiver class, the C/A code replaces P1 and may be involved in
+    the P2 measurement, so that the satellite P1-C1 biases enter the code
+    geometry-free combination, hence code TEC and its levelling, unless corrected:
+
+    * 'P1P2': P1 and P2 are tracked; observations are unchanged.
+    * 'C1P2': C1 is provided instead of P1 (non cross-correlation receivers);
+      C1 is corrected to P1.
+    * 'C1X2': cross-correlation receivers, providing C1 and a P2 derived from it
+      (X2 = C1 + P2 - P1); both are corrected.
+
+    Parameters
+    ----------
+    obs_data : pl.DataFrame
+        Long-format observations, with columns 'sv', 'observable', 'value' (codes
+        in meters).
+    biases : pl.DataFrame
+        P1-C1 satellite biases with columns 'sv' and 'bias' (nanoseconds), as
+        returned by `read_bernese_dcb`; receiver rows (null 'sv') are ignored.
+    receiver_class : {'P1P2', 'C1P2', 'C1X2'}
+        Class of the receiver, from its type (e.g. in the 'REC # / TYPE / VERS'
+        header record) and the receiver class tables of the bias provider.
+
+    Returns
+    -------
+    pl.DataFrame
+        Observations with corrected code values; satellites without a bias are
+        unchanged.
+
+    Raises
+    ------
+    ValueError
+        If the receiver class is not supported.
+    """
+    if receiver_class == "P1P2":
+        return obs_data
+    if receiver_class == "C1P2":
+        corrected = _C1_CODES
+    elif receiver_class == "C1X2":
+        corrected = _C1_CODES + _X2_CODES
+    else:
+        raise ValueError(
+            f"Unsupported receiver class '{receiver_class}': expected 'P1P2', 'C1P2' or 'C1X2'"
+        )
+
+    satellite_biases = (
+        biases.filter(pl.col("sv").is_not_null())
+        .unique("sv", keep="first", maintain_order=True)
+        .select("sv", (pl.col("bias") * 1e-9 * C).alias("_bias"))
+    )
+    return (
+        obs_data.with_row_index("_row")
+        .join(satellite_biases, on="sv", how="left")
+        .sort("_row")
+        .with_columns(
+            pl.when(pl.col("observable").is_in(corrected))
+            .then(pl.col("value") + pl.col("_bias").fill_null(0.0))
+            .otherwise(pl.col("value"))
+            .alias("value")
+        )
+        .drop("_row", "_bias")
+    )
@@ -0,0 +1,263 @@
+import warnings
+from dataclasses import asdict, dataclass
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+from .arcs import extract_arcs
+from .calibration import calculate_tec, calculate_vertical_equivalent
+from .calibration_preprocessing import _mapping_function
+from .modip import calculate_modip
+from pytecgg.context import GNSSContext, SUPPORTED_SYSTEMS
+from pytecgg.linear_combinations import calculate_linear_combinations
+from pytecgg.linear_combinations.constants import C
+from pytecgg.linear_combinations.gflc import _meters_to_tecu
+from pytecgg.parsing import read_bernese_dcb, read_rinex_nav, read_rinex_obs
+from pytecgg.satellites import annotate_geometry, prepare_ephemeris
+from pytecgg.settings import get_settings
+
+# Columns of the DataFrame returned by `compute_tec`, in order
+_OUTPUT_COLUMNS = [
+    "epoch",
+    "sv",
+    "id_arc_valid",
+    "azi",
+    "ele",
+    "lat_ipp",
+    "lon_ipp",
+    "gflc_levelled",
+    "bias",
+    "stec",
+    "vtec",
+]
+
+
+@dataclass
+class TecOptions:
+    """
+    Processing options of `compute_tec`.
+
+    Attributes
+    ----------
+    systems : list[str] or None
+        Constellations to process, as full names or symbols (e.g., ['GPS', 'E']);
+        by default, all the supported constellations of the navigation file.
+    h_ipp : float or None
+        Height of the ionospheric shell in meters; by default, that of the package
+        settings.
+    min_elevation : float or None
+        Elevation mask in degrees; by default, that of the package settings (none,
+        unless set with `pytecgg.configure`).
+    threshold_abs, threshold_std, threshold_jump : float
+        Cycle-slip and jump detection thresholds of `extract_arcs`.
+    min_arc_length : int
+        Minimum number of epochs of a valid arc, by default 30.
+    max_polynomial_degree : int
+        Maximum degree of the polynomial expansion of the calibration, by default 3.
+    batch_size_epochs : int
+        Number of epochs per batch of the calibration, by default 30.
+    vertical_equivalent : bool
+        If True, the vertical equivalent at the station zenith is added as a 'veq'
+        column (see `calculate_vertical_equivalent`).
+    modip_mapping : bool
+        If True, the slant-to-vertical mapping uses the modip-aware shell height
+        (see `calculate_tec`), better suited to low-latitude stations.
+    """
+
+    systems: Optional[list[str]] = None
+    h_ipp: Optional[float] = None
+    min_elevation: Optional[float] = None
+    threshold_abs: float = 5.0
+    threshold_std: float = 5.0
+    threshold_jump: float = 10.0
+    min_arc_length: int = 30
+    max_polynomial_degree: int = 3
+    batch_size_epochs: int = 30
+    vertical_equivalent: bool = False
+    modip_mapping: bool = False
+
+    def to_dict(self) -> dict:
+        """Options as a dictionary of JSON-compatible values."""
+        return asdict(self)
+
+    @classmethod
+    def from_dict(cls, data: dict) -> "TecOptions":
+        """Options from a dictionary as returned by `to_dict`; missing keys take their defaults."""
+        return cls(**data)
+
+
+def _tecu_per_ns(ctx: GNSSContext) -> pl.DataFrame:
+    """Conversion factor of a P1-P2 bias from nanoseconds to TECU, per satellite."""
+    rows = []
+    for symbol_, meta in ctx.freq_meta.items():
+        # GLONASS frequencies depend on the channel of each satellite
+        freqs = meta if isinstance(meta, dict) else {symbol_: meta}
+        for key, (f1, f2) in freqs.items():
+            factor = _meters_to_tecu(pl.lit(f1 * 1e6), pl.lit(f2 * 1e6)) * C * 1e-9
+            rows.append(pl.select(pl.lit(key).alias("_key"), factor.alias("_tecu_per_ns")))
+    if not rows:
+        return pl.DataFrame(schema={"_key": pl.String, "_tecu_per_ns": pl.Float64})
+    return pl.concat(rows)
+
+
+def _apply_biases(
+    df: pl.DataFrame, biases: pl.DataFrame, ctx: GNSSContext
+) -> pl.DataFrame:
+    """Removes known P1-P2 satellite and receiver biases from the levelled GFLC."""
+    satellite_biases = (
+        biases.filter(pl.col("sv").is_not_null())
+        .unique("sv", keep="first", maintain_order=True)
+        .select("sv", pl.col("bias").alias("_sat_bias"))
+    )
+    receiver_biases = (
+        biases.filter(
+            pl.col("sv").is_null()
+            & (pl.col("station").str.slice(0, 4).str.to_lowercase() == ctx.receiver_name)
+        )
+        .unique("system", keep="first", maintain_order=True)
+        .select("system", pl.col("bias").alias("_rx_bias"))
+    )
+    missing = set(ctx.systems) - set(receiver_biases["system"])
+    if missing:
+        warnings.warn(
+            f"No receiver bias of '{ctx.receiver_name}' for {sorted(missing)}: assumed to be zero"
+        )
+
+    factors = _tecu_per_ns(ctx)
+    return (
+        df.with_columns(pl.col("sv").str.slice(0, 1).alias("system"))
+        .join(satellite_biases, on="sv", how="left")
+        .join(receiver_biases, on="system", how="left")
+        .join(factors, left_on="sv", right_on="_key", how="left")
+        .join(
+            factors.rename({"_tecu_per_ns": "_system_factor"}),
+            left_on="system",
+            right_on="_key",
+            how="left",
+        )
+        # The code GFLC (P2 - P1) carries the opposite of the P1-P2 biases
+        .with_columns(
+            (
+                -(pl.col("_sat_bias") + pl.col("_rx_bias").fill_null(0.0))
+                * pl.coalesce("_tecu_per_ns", "_system_factor")
+            ).alias("bias")
+        )
+        .with_columns((pl.col("gflc_levelled") - pl.col("bias")).alias("stec"))
+        .with_columns((pl.col("stec") * pl.col("mapping")).alias("vtec"))
+        .drop("system", "_sat_bias", "_rx_bias", "_tecu_per_ns", "_system_factor")
+    )
+
+
+def compute_tec(
+    obs_path: Union[str, Path],
+    nav_path: Union[str, Path],
+    biases: Optional[Union[str, Path, pl.DataFrame]] = None,
+    options: Optional[TecOptions] = None,
+) -> pl.DataFrame:
+    """
+    Compute calibrated slant and vertical TEC from a pair of RINEX files, in one call.
+
+    Runs the whole pipeline of the package: parsing, satellite geometry
+    (`annotate_geometry`), linear combinations, cycle-slip and arc detection with
+    phase-to-code levelling (`extract_arcs`), bias removal and vertical mapping.
+    Biases are estimated by the calibration of `calculate_tec`, unless known P1-P2
+    differential code biases are given, in which case they are removed instead.
+
+    Parameters
+    ----------
+    obs_path : str or pathlib.Path
+        Path to the RINEX observation file (.rnx, .crx, or .gz).
+    nav_path : str or pathlib.Path
+        Path to the RINEX navigation file covering the observations.
+    biases : str, pathlib.Path or pl.DataFrame, optional
+        P1-P2 differential code biases (nanoseconds), as a file in the Bernese DCB
+        format or as returned by `read_bernese_dcb`. The receiver bias is taken from
+        the rows of the station (first four characters of the marker name), and
+        assumed to be zero, with a warning, if missing.
+    options : TecOptions, optional
+        Processing options; by default, those of `TecOptions()`.
+
+    Returns
+    -------
+    pl.DataFrame
+        One row per epoch and satellite of a valid arc above the elevation mask,
+        with columns 'epoch', 'sv', 'id_arc_valid', 'azi', 'ele', 'lat_ipp',
+        'lon_ipp' (degrees), 'gflc_levelled', 'bias', 'stec' and 'vtec' (TECU),
+        and 'veq' with `options.vertical_equivalent`.
+
+    Examples
+    --------
+    >>> df = compute_tec(
+    ...     "ASIR00ITA_R_20242810000_01D_30S_MO.rnx",
+    ...     "BRDC00WRD_R_20242810000_01D_MN.rnx",
+    ...     options=TecOptions(systems=["GPS", "Galileo"], min_elevation=20),
+    ... )
+    """
+    options = options if options is not None else TecOptions()
+    settings = get_settings()
+    h_ipp = options.h_ipp if options.h_ipp is not None else settings.h_ipp
+    min_elevation = (
+        options.min_elevation
+        if options.min_elevation is not None
+        else settings.min_elevation
+    )
+
+    nav = read_rinex_nav(nav_path)
+    df_obs, rec_pos, rinex_version = read_rinex_obs(obs_path)
+    systems = options.systems or [
+        name for name in nav if name.upper() in SUPPORTED_SYSTEMS
+    ]
+    ctx = GNSSContext(
+        receiver_pos=rec_pos,
+        receiver_name=Path(obs_path).name[:4],
+        rinex_version=rinex_version,
+        h_ipp=h_ipp,
+        systems=systems,
+    )
+    # Fills the GLONASS frequency channels of the context
+    prepare_ephemeris(nav, ctx)
+
+    df_lc = calculate_linear_combinations(
+        df_obs, ctx=ctx, combinations=["gflc_phase", "gflc_code", "mw"]
+    )
+    df_arcs = extract_arcs(
+        df_lc,
+        ctx=ctx,
+        threshold_abs=options.threshold_abs,
+        threshold_std=options.threshold_std,
+        min_arc_length=options.min_arc_length,
+        threshold_jump=options.threshold_jump,
+    ).filter(pl.col("id_arc_valid").is_not_null())
+    df_geom = annotate_geometry(
+        df_arcs, nav, rec_pos, h_ipp=h_ipp, min_elevation=min_elevation
+    ).filter(pl.col("ele").is_not_null())
+
+    if biases is None:
+        df_tec = calculate_tec(
+            df_geom,
+            ctx,
+            max_polynomial_degree=options.max_polynomial_degree,
+            batch_size_epochs=options.batch_size_epochs,
+            modip_mapping=options.modip_mapping,
+        )
+    else:
+        if not isinstance(biases, pl.DataFrame):
+            biases = read_bernese_dcb(biases)
+        if options.modip_mapping:
+            modip = calculate_modip(df_geom)["modip_ipp"].to_numpy()
+            mapping = _mapping_function(df_geom["ele"].to_numpy(), h_ipp, modip=modip)
+            df_geom = df_geom.with_columns(pl.Series("mapping", mapping))
+        df_tec = _apply_biases(df_geom, biases, ctx)
+
+    columns = list(_OUTPUT_COLUMNS)
+    if options.vertical_equivalent:
+        df_tec = calculate_vertical_equivalent(
+            df_tec,
+            ctx,
+            max_polynomial_degree=options.max_polynomial_degree,
+            batch_size_epochs=options.batch_size_epochs,
+            modip_mapping=options.modip_mapping,
+        )
+        columns.append("veq")
+    return df_tec.select(columns).sort("epoch", "sv")
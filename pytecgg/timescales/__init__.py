@@ -0,0 +1,4 @@
+from .conversions import convert_timescale, TIMESCALES
+from .leap_seconds import gpst_utc_offset, leap_seconds
+
+__all__ = ["convert_timescale", "gpst_utc_offset", "leap_seconds", "TIMESCALES"]
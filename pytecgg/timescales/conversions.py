@@ -0,0 +1,35 @@
+import polars as pl
+
+from ..pytecgg import convert_timescale as _convert_timescale
+
+TIMESCALES = ("GPST", "GST", "BDT", "GLONASST", "UTC", "TAI")
+
+
+def convert_timescale(epochs: pl.Series, from_scale: str, to_scale: str) -> pl.Series:
+    """
+    Convert epochs between GNSS timescales.
+
+    Epochs are read as the calendar date and time in `from_scale` (whatever their
+    time zone label) and returned as the calendar date and time of the same
+    instant in `to_scale`, with the same time unit and zone. The offsets are:
+
+    * GST = GPST (nominal), TAI = GPST + 19 s, BDT = GPST - 14 s;
+    * UTC = TAI - leap seconds in force at the epoch;
+    * GLONASST = UTC + 3 h, i.e. UTC(SU) as used by GLONASS.
+
+    This allows, for instance, navigation epochs natively given in BDT or GLONASS
+    time to be reconciled with the GPST observation grid.
+
+    Parameters
+    ----------
+    epochs : pl.Series
+        Datetime Series.
+    from_scale, to_scale : {'GPST', 'GST', 'BDT', 'GLONASST', 'UTC', 'TAI'}
+        Input and output timescales.
+
+    Returns
+    -------
+    pl.Series
+        The converted Datetime Series.
+    """
+    return _convert_timescale(epochs, from_scale, to_scale)
@@ -0,0 +1,68 @@
+from pathlib import Path
+from typing import Optional, Union
+
+import polars as pl
+
+from ..pytecgg import leap_seconds as _leap_seconds
+
+
+def leap_seconds(path: Optional[Union[str, Path]] = None) -> pl.DataFrame:
+    """
+    Leap second table with the effective offsets of TAI and GPS time from UTC.
+
+    The table is based on the IERS leap seconds known to hifitime; when a RINEX
+    file is given, the information of its 'LEAP SECONDS' header record is added:
+    the current offset (in force at the first epoch of the file) and, if
+    announced, the future one at the respective week and day.
+
+    Parameters
+    ----------
+    path : str or pathlib.Path, optional
+        Path to a RINEX file whose 'LEAP SECONDS' record should be reported.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with columns:
+            - epoch: UTC epoch from which the offsets are effective, as datetime[μs, UTC]
+            - tai_utc: TAI - UTC in seconds
+            - gpst_utc: GPST - UTC in seconds (null before the GPS epoch, 1980-01-06)
+            - source: 'hifitime' or 'header'
+    """
+    df = _leap_seconds(None if path is None else str(path))
+    return df.with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+
+
+def gpst_utc_offset(epochs: pl.Series) -> pl.Series:
+    """
+    GPST - UTC offset in force at each UTC epoch, from the built-in leap second table.
+
+    Subtracting the offset from GPS-time epochs (e.g., as returned by
+    `read_rinex_obs`) gives UTC epochs, and adding it to UTC-stamped external data
+    aligns them with the GPST observation grid.
+
+    Parameters
+    ----------
+    epochs : pl.Series
+        Datetime Series of UTC epochs.
+
+    Returns
+    -------
+    pl.Series
+        Series 'gpst_utc' with the offsets in seconds (null before the GPS epoch).
+    """
+    table = leap_seconds().filter(pl.col("source") == "hifitime")
+    frame = pl.DataFrame({"epoch": epochs}).with_row_index("_row")
+    time_zone = frame.schema["epoch"].time_zone
+    table = table.with_columns(
+        pl.col("epoch")
+        .dt.convert_time_zone(time_zone or "UTC")
+        .dt.replace_time_zone(time_zone)
+        .dt.cast_time_unit(frame.schema["epoch"].time_unit)
+    )
+    joined = (
+        frame.sort("epoch")
+        .join_asof(table.select("epoch", "gpst_utc"), on="epoch", strategy="backward")
+        .sort("_row")
+    )
+    return joined["gpst_utc"]
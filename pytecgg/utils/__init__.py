@@ -1,4 +1,5 @@
 from .download_rinex import download_nav_bkg, download_obs_ring
+from .resample import resample
 from .summarise_rinex import summarise_rinex_data
 
-__all__ = ["download_nav_bkg", "download_obs_ring", "summarise_rinex_data"]
+__all__ = ["download_nav_bkg", "download_obs_ring", "resample", "summarise_rinex_data"]
@@ -0,0 +1,105 @@
+from datetime import timedelta
+from typing import Literal, Union
+
+import polars as pl
+
+# Carrier phase columns of TEC DataFrames, only meaningful within an arc
+_PHASE_COLUMNS = ("gflc_phase", "iflc_phase", "mw")
+# Arc identifiers, in order of preference
+_ARC_COLUMNS = ("id_arc_valid", "id_arc")
+
+
+def resample(
+    df: pl.DataFrame,
+    every: Union[str, timedelta] = "30s",
+    agg: Literal["nearest", "mean"] = "nearest",
+) -> pl.DataFrame:
+    """
+    Downsample observation or TEC DataFrames onto a regular grid of epochs.
+
+    Samples are assigned to the nearest epoch of the grid (multiples of `every`),
+    per satellite and, for observations in long format, per observable:
+
+    * 'nearest': the sample closest to each grid epoch is kept as is, i.e. the
+      usual decimation of RINEX files, consistent across all the columns.
+    * 'mean': floating-point columns are averaged over the samples of each grid
+      epoch, other columns (e.g., flags) being those of the nearest sample.
+
+    Averaging carrier phase across a cycle slip gives a meaningless value: with
+    'mean', samples are only averaged within the arc of the nearest sample when
+    the DataFrame has an arc identifier ('id_arc_valid' or 'id_arc'), and carrier
+    phase observables ('L' codes) and phase combinations ('gflc_phase',
+    'iflc_phase', 'mw') of DataFrames without arcs are taken at the nearest sample.
+
+    Parameters
+    ----------
+    df : pl.DataFrame
+        Observations as returned by `read_rinex_obs` (columns 'epoch', 'sv',
+        'observable', 'value'), or TEC DataFrames with columns 'epoch' and 'sv'
+        (e.g., from `calculate_linear_combinations` or `calculate_tec`).
+    every : str or timedelta, optional
+        Interval of the output grid, by default '30s'.
+    agg : {'nearest', 'mean'}, optional
+        Aggregation of the samples of each grid epoch, by default 'nearest'.
+
+    Returns
+    -------
+    pl.DataFrame
+        DataFrame with the columns of `df`, at most one row per grid epoch,
+        satellite (and observable), with 'epoch' on the grid; sorted by epoch.
+
+    Raises
+    ------
+    ValueError
+        If the aggregation is not supported.
+
+    Examples
+    --------
+    >>> df, _, _ = read_rinex_obs("ASIR00ITA_R_20242810000_01D_01S_MO.rnx")
+    >>> df_30s = resample(df, every="30s")
+    """
+    if agg not in ("nearest", "mean"):
+        raise ValueError(f"Unsupported aggregation '{agg}': expected 'nearest' or 'mean'")
+
+    keys = ["sv", "observable"] if "observable" in df.columns else ["sv"]
+    groups = [*keys, "_bin"]
+    binned = df.with_columns(pl.col("epoch").dt.round(every).alias("_bin")).with_columns(
+        (pl.col("epoch") - pl.col("_bin")).abs().alias("_offset")
+    )
+
+    if agg == "nearest":
+        aggregations = [pl.all().sort_by("_offset", "epoch").first()]
+    else:
+        arc = next((c for c in _ARC_COLUMNS if c in df.columns), None)
+        if arc is not None:
+            binned = binned.filter(
+                pl.col(arc).eq_missing(
+                    pl.col(arc).sort_by("_offset", "epoch").first().over(groups)
+                )
+            )
+        averaged = [
+            name
+            for name, dtype in df.schema.items()
+            if dtype.is_float()
+            and name not in keys
+            and (arc is not None or name not in _PHASE_COLUMNS)
+        ]
+        aggregations = [pl.all().exclude(averaged).sort_by("_offset", "epoch").first()]
+        for name in averaged:
+            mean = pl.col(name).mean()
+            if name == "value" and "observable" in keys and arc is None:
+                mean = (
+                    pl.when(pl.col("observable").first().str.starts_with("L"))
+                    .then(pl.col(name).sort_by("_offset", "epoch").first())
+                    .otherwise(mean)
+                )
+            aggregations.append(mean.alias(name))
+
+    return (
+        binned.group_by(groups)
+        .agg(aggregations)
+        .drop("epoch", "_offset")
+        .rename({"_bin": "epoch"})
+        .select(df.columns)
+        .sort("epoch", *keys)
+    )
@@ -0,0 +1,505 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use rinex::prelude::Constellation;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+use crate::combinations::{carrier_frequency, C};
+use crate::records::{self, NavRecord, ObsRow};
+use crate::rtcm::{self, BitReader, GPS_WEEK_ORIGIN_NANOS, WEEK_NANOS};
+use crate::timescales::{self, OutputScale};
+
+/// Synchronization byte of big-endian, forward-readable records with regular CRC
+const SYNC: u8 = 0xE2;
+/// Record identifiers of navigation and observation data
+const NAVIGATION_RECORD: u32 = 0x01;
+const OBSERVATION_RECORD: u32 = 0x7F;
+/// Largest record (identifier, length and message) protected by a 1- or 2-byte checksum;
+/// longer records use CRC-32 or MD5 and are skipped
+const CHECKSUM_1_MAX: usize = 127;
+const CHECKSUM_2_MAX: usize = 4095;
+
+/// Reads an unsigned BINEX integer (1 to 4 bytes), returning its value and size
+fn ubnxi(data: &[u8]) -> Option<(u32, usize)> {
+    let mut value = 0u32;
+    for (i, &byte) in data.iter().take(4).enumerate() {
+        if i == 3 {
+            return Some(((value << 8) | u32::from(byte), 4));
+        }
+        value = (value << 7) | u32::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+/// CRC-16 (CCITT polynomial, zero initial value) of medium-sized records
+pub(crate) fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for &byte in data {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Reads a big-endian IEEE single-precision float
+fn r4(bits: &mut BitReader) -> Option<f64> {
+    Some(f64::from(f32::from_bits(bits.u(32)? as u32)))
+}
+
+/// Reads a big-endian IEEE double-precision float
+fn r8(bits: &mut BitReader) -> Option<f64> {
+    Some(f64::from_bits(bits.u(64)?))
+}
+
+/// Constellation letter of the satellite system field of observation records
+fn obs_system(id: u64) -> Option<char> {
+    match id {
+        0 => Some('G'),
+        1 => Some('R'),
+        2 => Some('S'),
+        3 => Some('E'),
+        4 => Some('C'),
+        _ => None,
+    }
+}
+
+/// RINEX signal code of the tracking code field of 0x7F-05 observation records
+fn obs_signal(system: char, code: u64) -> Option<&'static str> {
+    const GPS: [&str; 27] = [
+        "1C", "1C", "1P", "1W", "1Y", "1M", "1X", "1N", "", "", "2W", "2C", "2D", "2S", "2L", "2X", "2P", "2W",
+        "2Y", "2M", "2N", "", "", "5X", "5I", "5Q", "5X",
+    ];
+    const GLONASS: [&str; 17] = ["1C", "1C", "1P", "", "", "", "", "", "", "", "2C", "2C", "2P", "3X", "3I", "3Q", "3X"];
+    const SBAS: [&str; 10] = ["1C", "1C", "", "", "", "", "5X", "5I", "5Q", "5X"];
+    const GALILEO: [&str; 24] = [
+        "1C", "1A", "1B", "1C", "1X", "1Z", "5X", "5I", "5Q", "5X", "7X", "7I", "7Q", "7X", "8X", "8I", "8Q", "8X",
+        "6X", "6A", "6B", "6C", "6X", "6Z",
+    ];
+    const BEIDOU: [&str; 16] = ["2X", "2I", "2Q", "2X", "7X", "7I", "7Q", "7X", "6X", "6I", "6Q", "6X", "1X", "1S", "1L", "1X"];
+    let table: &[&'static str] = match system {
+        'G' => &GPS,
+        'R' => &GLONASS,
+        'S' => &SBAS,
+        'E' => &GALILEO,
+        'C' => &BEIDOU,
+        _ => return None,
+    };
+    table.get(code as usize).copied().filter(|s| !s.is_empty())
+}
+
+/// Observations of a signal of a 0x7F-05 record, with ranges in meters
+struct Signal {
+    signal: Option<&'static str>,
+    glonass_channel: Option<i32>,
+    pseudorange: f64,
+    phase_range: f64,
+    doppler: Option<f64>,
+    cnr: f64,
+}
+
+/// Decodes the signals of a satellite of a 0x7F-05 (Trimble NetR8) observation record;
+/// pseudoranges and phase ranges of the secondary signals are differences from the
+/// first one
+fn decode_signals(system: char, count: usize, bits: &mut BitReader) -> Option<Vec<Signal>> {
+    let mut signals: Vec<Signal> = Vec::with_capacity(count);
+    for i in 0..count {
+        let extended = bits.u(1)? == 1;
+        // Slip flag
+        bits.skip(2)?;
+        let code = bits.u(5)?;
+        // Flag bytes, each with its index in the two lowest bits
+        let mut flags = [0u8; 4];
+        let mut more = extended;
+        for _ in 0..4 {
+            if !more {
+                break;
+            }
+            let byte = bits.u(8)? as u8;
+            flags[usize::from(byte & 0x03)] = byte & 0x7F;
+            more = byte & 0x80 != 0;
+        }
+        let glonass_channel = (flags[2] != 0).then(|| (((flags[2] >> 2) & 0x0F) as i8) << 4 >> 4).map(i32::from);
+        let wide = flags[0] & 0x40 != 0;
+        let phase_unit = if flags[0] & 0x20 != 0 { 1e-4 } else { 2e-5 };
+
+        let mut cnr = bits.u(8)? as f64 * 0.4;
+        let pseudorange = if i == 0 {
+            cnr += bits.i(2)? as f64 * 0.1;
+            bits.u(32)? as f64 * 0.064 + bits.u(6)? as f64 * 0.001
+        } else if wide {
+            cnr += bits.i(2)? as f64 * 0.1;
+            bits.skip(2)?;
+            signals[0].pseudorange + bits.i(20)? as f64 * 0.001
+        } else {
+            signals[0].pseudorange + bits.i(16)? as f64 * 0.001
+        };
+        let phase_range = if wide {
+            pseudorange + bits.i(24)? as f64 * phase_unit
+        } else {
+            cnr += bits.i(2)? as f64 * 0.1;
+            pseudorange + bits.i(22)? as f64 * phase_unit
+        };
+        let doppler = if flags[0] & 0x04 != 0 { Some(bits.i(24)? as f64 / 256.0) } else { None };
+        if flags[0] & 0x08 != 0 {
+            // Slip counter
+            bits.skip(if flags[0] & 0x10 != 0 { 16 } else { 8 })?;
+        }
+        signals.push(Signal {
+            signal: obs_signal(system, code),
+            glonass_channel,
+            pseudorange,
+            phase_range,
+            doppler,
+            cnr,
+        });
+    }
+    Some(signals)
+}
+
+/// Decoder of BINEX records into observation and navigation DataFrames
+#[pyclass]
+pub(crate) struct BinexDecoder {
+    /// Bytes not yet decoded, e.g. a record split across two reads
+    buffer: Vec<u8>,
+    /// Approximate current time (GPST, Unix-like nanoseconds), resolving the day of
+    /// GLONASS ephemerides; it follows the decoded observation epochs
+    reference: i64,
+    glonass_channels: HashMap<String, i32>,
+    rows: Vec<ObsRow>,
+    /// Broadcast ephemerides by subrecord, PRN and time of clock
+    ephemerides: BTreeMap<(u8, u8, i64), NavRecord>,
+    records: usize,
+    crc_errors: usize,
+}
+
+impl BinexDecoder {
+    /// Extracts and decodes the complete records of the buffer
+    fn decode_records(&mut self) {
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..].iter().position(|b| *b == SYNC) {
+            start += offset;
+            let record = &self.buffer[start..];
+            let Some((id, id_len)) = ubnxi(&record[1..]) else {
+                break;
+            };
+            let Some((length, length_len)) = ubnxi(&record[1 + id_len..]) else {
+                break;
+            };
+            let header = 1 + id_len + length_len;
+            let checked = id_len + length_len + length as usize;
+            let checksum_len = if checked <= CHECKSUM_1_MAX {
+                1
+            } else if checked <= CHECKSUM_2_MAX {
+                2
+            } else {
+                // CRC-32 or MD5 protected records are not supported
+                start += 1;
+                continue;
+            };
+            let total = header + length as usize + checksum_len;
+            if record.len() < total {
+                break;
+            }
+            let data = &record[1..1 + checked];
+            let expected = &record[1 + checked..total];
+            let valid = if checksum_len == 1 {
+                data.iter().fold(0u8, |acc, b| acc ^ b) == expected[0]
+            } else {
+                crc16(data).to_be_bytes() == expected
+            };
+            if !valid {
+                // Not a record boundary (or a corrupted record): resynchronize
+                self.crc_errors += 1;
+                start += 1;
+                continue;
+            }
+            let message = record[header..header + length as usize].to_vec();
+            self.decode_message(id, &message);
+            self.records += 1;
+            start += total;
+        }
+        self.buffer.drain(..start);
+    }
+
+    /// Decodes a record message; unsupported and malformed records are ignored
+    fn decode_message(&mut self, id: u32, message: &[u8]) {
+        let mut bits = BitReader::new(message);
+        let Some(subrecord) = bits.u(8).map(|s| s as u8) else {
+            return;
+        };
+        match id {
+            NAVIGATION_RECORD => {
+                let record = match subrecord {
+                    0x01 => decode_kepler_ephemeris(Constellation::GPS, &mut bits, self.reference),
+                    0x02 => decode_glonass_ephemeris(&mut bits, self.reference),
+                    0x04 => decode_kepler_ephemeris(Constellation::Galileo, &mut bits, self.reference),
+                    _ => None,
+                };
+                if let Some(record) = record {
+                    if let Some((_, channel)) = record.params.iter().find(|(key, _)| *key == "channel") {
+                        self.glonass_channels.insert(format!("R{:02}", record.prn), *channel as i32);
+                    }
+                    self.ephemerides.insert((subrecord, record.prn, record.toc), record);
+                }
+            },
+            OBSERVATION_RECORD if subrecord == 0x05 => {
+                // A malformed record is dropped as a whole
+                if let Some(rows) = self.decode_observations(&mut bits) {
+                    self.rows.extend(rows);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// Decodes a 0x7F-05 observation record (one epoch)
+    fn decode_observations(&mut self, bits: &mut BitReader) -> Option<Vec<ObsRow>> {
+        // Minutes since the GPS epoch and milliseconds of the minute, in GPST
+        let minutes = bits.u(32)? as i64;
+        let millis = bits.u(16)? as i64;
+        let epoch = GPS_WEEK_ORIGIN_NANOS + minutes * 60_000_000_000 + millis * 1_000_000;
+        self.reference = epoch;
+
+        let flags = bits.u(8)?;
+        let satellites = (flags & 0x3F) as usize + 1;
+        let mut clock_offset = None;
+        if flags & 0x80 != 0 {
+            // Clock reset flags, then the offset in nanoseconds
+            bits.skip(2)?;
+            clock_offset = Some(bits.i(22)? as f64 * 1e-9);
+        }
+        if flags & 0x40 != 0 {
+            // Offsets of the system times from the receiver time
+            let systems = bits.u(4)? as usize;
+            bits.skip(4 + 32 * systems)?;
+        }
+
+        let mut rows = Vec::new();
+        for _ in 0..satellites {
+            let prn = bits.u(8)? as u16;
+            bits.skip(1)?;
+            let count = bits.u(3)? as usize;
+            let system = obs_system(bits.u(4)?);
+            let signals = decode_signals(system.unwrap_or('?'), count, bits)?;
+            let Some(system) = system else {
+                continue;
+            };
+            let sv = match system {
+                'S' => format!("S{:02}", prn.saturating_sub(100)),
+                _ => format!("{}{:02}", system, prn),
+            };
+            for s in signals {
+                let Some(signal) = s.signal else {
+                    continue;
+                };
+                let channel = s.glonass_channel.or_else(|| self.glonass_channels.get(&sv).copied());
+                if system == 'R' {
+                    if let Some(channel) = s.glonass_channel {
+                        self.glonass_channels.insert(sv.clone(), channel);
+                    }
+                }
+                let band = signal.chars().next().unwrap_or('0');
+                let wavelength = carrier_frequency(system, band, channel).map(|f| C / f);
+                let mut push = |kind: char, value: Option<f64>| {
+                    if let Some(value) = value {
+                        rows.push(ObsRow {
+                            epoch,
+                            sv: sv.clone(),
+                            observable: format!("{}{}", kind, signal),
+                            value,
+                            clock_offset,
+                        });
+                    }
+                };
+                push('C', Some(s.pseudorange));
+                push('L', wavelength.map(|w| s.phase_range / w));
+                push('D', s.doppler);
+                push('S', Some(s.cnr));
+            }
+        }
+        Some(rows)
+    }
+}
+
+/// Decodes a GPS (0x01-01) or Galileo (0x01-04) decoded ephemeris; the time of clock
+/// is the time of ephemeris, and Galileo weeks are GPS-aligned
+fn decode_kepler_ephemeris(constellation: Constellation, bits: &mut BitReader, reference: i64) -> Option<NavRecord> {
+    let galileo = constellation == Constellation::Galileo;
+    let prn = bits.u(8)? as u8 + 1;
+    let reference_week = (reference - GPS_WEEK_ORIGIN_NANOS).div_euclid(WEEK_NANOS);
+    let week = rtcm::full_week(reference_week, bits.u(16)?, 1024);
+    // Transmission time of week
+    bits.skip(32)?;
+    let toe = bits.i(32)? as f64;
+    let mut params = Vec::with_capacity(28);
+    if galileo {
+        params.push(("bgdE5aE1", r4(bits)?));
+        params.push(("bgdE5bE1", r4(bits)?));
+        params.push(("iodnav", bits.i(32)? as f64));
+    } else {
+        params.push(("tgd", r4(bits)?));
+        params.push(("iodc", bits.i(32)? as f64));
+    }
+    params.push(("clock_drift_rate", r4(bits)?));
+    params.push(("clock_drift", r4(bits)?));
+    params.push(("clock_bias", r4(bits)?));
+    if !galileo {
+        params.push(("iode", bits.i(32)? as f64));
+    }
+    params.extend([
+        ("deltaN", r4(bits)? * PI),
+        ("m0", r8(bits)?),
+        ("e", r8(bits)?),
+        ("sqrta", r8(bits)?),
+        ("cic", r4(bits)?),
+        ("crc", r4(bits)?),
+        ("cis", r4(bits)?),
+        ("crs", r4(bits)?),
+        ("cuc", r4(bits)?),
+        ("cus", r4(bits)?),
+        ("omega0", r8(bits)?),
+        ("omega", r8(bits)?),
+        ("i0", r8(bits)?),
+        ("omegaDot", r4(bits)? * PI),
+        ("idot", r4(bits)? * PI),
+        (if galileo { "sisa" } else { "accuracy" }, r4(bits)?),
+        ("health", bits.u(16)? as f64),
+        ("toe", toe),
+        ("week", week as f64),
+    ]);
+    if galileo {
+        params.push(("source", bits.u(16)? as f64));
+    }
+
+    Some(NavRecord {
+        constellation,
+        prn,
+        toc: GPS_WEEK_ORIGIN_NANOS + week * WEEK_NANOS + (toe * 1e9) as i64,
+        params,
+    })
+}
+
+/// Decodes a GLONASS decoded ephemeris (0x01-02), whose times are seconds of day of
+/// GLONASS time and state vectors are in km
+fn decode_glonass_ephemeris(bits: &mut BitReader, reference: i64) -> Option<NavRecord> {
+    let prn = bits.u(8)? as u8 + 1;
+    // Day number
+    bits.skip(16)?;
+    let tb = bits.u(32)? as i64;
+    let clock_bias = r8(bits)?;
+    let gamma = r8(bits)?;
+    let tk = bits.u(32)? as i64;
+    let mut state = [0.0; 9];
+    for value in state.iter_mut() {
+        *value = r8(bits)?;
+    }
+    let health = (bits.u(8)? & 0x01) as f64;
+    let channel = bits.i(8)? as f64;
+    let age = bits.u(8)? as f64;
+    let (toc, frame_tow) = rtcm::glonass_ephemeris_times(reference, tb, tk);
+
+    Some(NavRecord {
+        constellation: Constellation::Glonass,
+        prn,
+        toc,
+        params: vec![
+            ("clock_bias", clock_bias),
+            ("clock_drift", gamma),
+            ("clock_drift_rate", frame_tow),
+            ("satPosX", state[0]),
+            ("velX", state[1]),
+            ("accelX", state[2]),
+            ("health", health),
+            ("satPosY", state[3]),
+            ("velY", state[4]),
+            ("accelY", state[5]),
+            ("channel", channel),
+            ("satPosZ", state[6]),
+            ("velZ", state[7]),
+            ("accelZ", state[8]),
+            ("ageOp", age),
+        ],
+    })
+}
+
+#[pymethods]
+impl BinexDecoder {
+    /// Decoder of BINEX records (big-endian, forward-readable, sync byte 0xE2): 0x7F-05
+    /// observations and 0x01-01/02/04 GPS, GLONASS and Galileo ephemerides
+    ///
+    /// Parameters:
+    ///     reference (int): Approximate time of the data (Unix-like nanoseconds), resolving
+    ///         the day of GLONASS ephemerides received before any observation
+    ///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+    #[new]
+    #[pyo3(signature = (reference, glonass_channels=None))]
+    fn new(reference: i64, glonass_channels: Option<HashMap<String, i32>>) -> Self {
+        Self {
+            buffer: Vec::new(),
+            reference,
+            glonass_channels: glonass_channels.unwrap_or_default(),
+            rows: Vec::new(),
+            ephemerides: BTreeMap::new(),
+            records: 0,
+            crc_errors: 0,
+        }
+    }
+
+    /// Decodes the complete records of a chunk of data; incomplete records are kept
+    /// until the next chunk
+    ///
+    /// Parameters:
+    ///     data (bytes): Raw bytes
+    ///
+    /// Returns:
+    ///     int: Number of records decoded so far
+    fn feed(&mut self, data: &[u8]) -> usize {
+        self.buffer.extend_from_slice(data);
+        self.decode_records();
+        self.records
+    }
+
+    /// Returns the decoded observations, removing them from the decoder
+    ///
+    /// Parameters:
+    ///     time_scale (str): Timescale of the output epochs (see `read_rinex_obs`)
+    ///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+    ///
+    /// Returns:
+    ///     PyDataFrame: A DataFrame with the columns of `read_rinex_obs`
+    #[pyo3(signature = (time_scale="GPST", time_unit="us"))]
+    fn take_obs(&mut self, time_scale: &str, time_unit: &str) -> PyResult<PyDataFrame> {
+        let scale = OutputScale::parse(time_scale)?;
+        let unit = timescales::parse_time_unit(time_unit)?;
+        records::obs_frame(&std::mem::take(&mut self.rows), scale, unit)
+    }
+
+    /// Returns the decoded broadcast ephemerides, removing them from the decoder
+    ///
+    /// Parameters:
+    ///     time_scale (str, optional): Timescale of the output epochs (see `read_rinex_nav`);
+    ///         None keeps the constellation-native epochs
+    ///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+    ///
+    /// Returns:
+    ///     dict[str, PyDataFrame]: DataFrames keyed by constellation, with the columns and
+    ///     units of `read_rinex_nav`
+    #[pyo3(signature = (time_scale=None, time_unit="us"))]
+    fn take_nav(&mut self, time_scale: Option<&str>, time_unit: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
+        let scale = time_scale.map(OutputScale::parse).transpose()?;
+        let unit = timescales::parse_time_unit(time_unit)?;
+        let records: Vec<NavRecord> = std::mem::take(&mut self.ephemerides).into_values().collect();
+        records::nav_frames(&records, scale, unit)
+    }
+
+    /// Number of candidate records discarded because of a checksum mismatch
+    #[getter]
+    fn crc_errors(&self) -> usize {
+        self.crc_errors
+    }
+}
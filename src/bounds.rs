@@ -0,0 +1,253 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use crate::text::{field, open_text, Lines};
+
+/// Satellites listed on each RINEX 2 epoch line, and observations on each data line
+const V2_SATS_PER_LINE: usize = 12;
+const V2_OBS_PER_LINE: usize = 5;
+
+/// Epoch, flag and number of satellites (or of special records) of an epoch line
+struct EpochLine {
+    epoch: Option<DateTime<Utc>>,
+    flag: u8,
+    count: usize,
+}
+
+/// Parses a RINEX 3/4 ('> yyyy mm dd hh mm ss.sssssss  f nnn') or RINEX 2
+/// (' yy mm dd hh mm ss.sssssss  f nnn') epoch line
+fn parse_epoch_line(line: &[u8], v3: bool) -> EpochLine {
+    let (cols, flag_col, count_cols) = if v3 {
+        ([(2, 6), (7, 9), (10, 12), (13, 15), (16, 18), (18, 29)], 31, (32, 35))
+    } else {
+        ([(1, 3), (4, 6), (7, 9), (10, 12), (13, 15), (15, 26)], 28, (29, 32))
+    };
+    let int = |(a, b): (usize, usize)| field(line, a, b).parse::<u32>().ok();
+    let epoch = (|| {
+        let mut year = int(cols[0])? as i32;
+        if !v3 {
+            year += if year < 80 { 2000 } else { 1900 };
+        }
+        let seconds: f64 = field(line, cols[5].0, cols[5].1).parse().ok()?;
+        let nanos = (seconds.fract() * 1e9).round() as u32;
+        let datetime = NaiveDate::from_ymd_opt(year, int(cols[1])?, int(cols[2])?)?
+            .and_hms_nano_opt(int(cols[3])?, int(cols[4])?, seconds.trunc() as u32, nanos)?;
+        Some(datetime.and_utc())
+    })();
+    EpochLine {
+        epoch,
+        flag: field(line, flag_col, flag_col + 1).parse().unwrap_or(0),
+        count: field(line, count_cols.0, count_cols.1).parse().unwrap_or(0),
+    }
+}
+
+/// Applies a CRINEX text difference to the previous line: spaces keep the previous
+/// character, '&' blanks it, any other character replaces it
+fn apply_text_diff(previous: &mut Vec<u8>, diff: &[u8]) {
+    for (i, &c) in diff.iter().enumerate() {
+        let c = if c == b'&' { b' ' } else { c };
+        match previous.get_mut(i) {
+            Some(p) if diff[i] != b' ' => *p = c,
+            Some(_) => {},
+            None => previous.push(c),
+        }
+    }
+}
+
+/// Width of an observation field of RINEX 2/3 data lines (F14.3, LLI and SSI digits)
+const FIELD_WIDTH: usize = 16;
+
+/// Observation values recorded on a RINEX 2/3 data line: the non-blank values among the
+/// `n` fields starting at column `start`
+fn count_values(line: &[u8], start: usize, n: usize) -> usize {
+    (0..n)
+        .filter(|i| !field(line, start + FIELD_WIDTH * i, start + FIELD_WIDTH * i + 14).is_empty())
+        .count()
+}
+
+/// Observation values recorded on a CRINEX data line: the non-empty fields among the first
+/// `n` space-separated ones (the flags follow)
+fn count_compact_values(line: &[u8], n: usize) -> usize {
+    line.split(|c| *c == b' ').take(n).filter(|f| !f.is_empty()).count()
+}
+
+/// First and last observation epochs of a file, and their number
+#[derive(Default)]
+struct TimeBounds {
+    first: Option<DateTime<Utc>>,
+    last: Option<DateTime<Utc>>,
+    epochs: usize,
+}
+
+impl TimeBounds {
+    fn push(&mut self, epoch: &EpochLine) {
+        // Special events (flags 2 to 5) carry no observations
+        if (2..=5).contains(&epoch.flag) {
+            return;
+        }
+        // Lines that are not epoch lines (e.g. trailing blank lines) have no epoch
+        let Some(epoch) = epoch.epoch else {
+            return;
+        };
+        self.epochs += 1;
+        self.first = Some(self.first.map_or(epoch, |first| first.min(epoch)));
+        self.last = Some(self.last.map_or(epoch, |last| last.max(epoch)));
+    }
+}
+
+/// Scans the epoch lines of an observation file without decoding the observations,
+/// visiting each epoch line with the number of values recorded in the epoch and the byte
+/// offset of the epoch line in the (decompressed) text
+fn scan<R: BufRead>(reader: R, mut visit: impl FnMut(&EpochLine, usize, u64)) -> io::Result<()> {
+    let mut lines = Lines::new(reader);
+    let mut version = 0.0;
+    let mut compact = false;
+    let mut n_types: usize = 0;
+    let mut system_types: HashMap<u8, usize> = HashMap::new();
+    while lines.next()? {
+        match lines.label() {
+            "CRINEX VERS   / TYPE" => compact = true,
+            "RINEX VERSION / TYPE" => version = field(&lines.line, 0, 9).parse().unwrap_or(0.0),
+            "# / TYPES OF OBSERV" if !field(&lines.line, 0, 6).is_empty() => {
+                n_types = field(&lines.line, 0, 6).parse().unwrap_or(0)
+            },
+            "SYS / # / OBS TYPES" => {
+                if let Some(&system) = lines.line.first().filter(|c| **c != b' ') {
+                    system_types.insert(system, field(&lines.line, 3, 6).parse().unwrap_or(0));
+                }
+            },
+            "END OF HEADER" => break,
+            _ => {},
+        }
+    }
+    if version == 0.0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "missing 'RINEX VERSION / TYPE' record"));
+    }
+    let v3 = version >= 3.0;
+    let types = |system: u8| if v3 { system_types.get(&system).copied().unwrap_or(0) } else { n_types };
+
+    let mut previous: Vec<u8> = Vec::with_capacity(256);
+    // RINEX 3 epoch awaiting its data lines, with its values and offset
+    let mut pending: Option<(EpochLine, usize, u64)> = None;
+    while lines.next()? {
+        let offset = lines.offset;
+        if compact {
+            // Epoch line (initialized with '>' or '&', otherwise differenced), clock
+            // offset line, then one line per satellite, listed on the epoch line
+            let marker = if v3 { b'>' } else { b'&' };
+            if lines.line.first() == Some(&marker) {
+                previous.clear();
+                previous.extend_from_slice(&lines.line);
+                if !v3 {
+                    previous[0] = b' ';
+                }
+            } else {
+                apply_text_diff(&mut previous, &lines.line);
+            }
+            let epoch = parse_epoch_line(&previous, v3);
+            if (2..=5).contains(&epoch.flag) {
+                lines.skip(epoch.count)?;
+                visit(&epoch, 0, offset);
+                continue;
+            }
+            lines.skip(1)?;
+            let sats_col = if v3 { 41 } else { 32 };
+            let mut values = 0;
+            for k in 0..epoch.count {
+                if !lines.next()? {
+                    break;
+                }
+                let system = previous.get(sats_col + 3 * k).copied().unwrap_or(b'G');
+                values += count_compact_values(&lines.line, types(system));
+            }
+            visit(&epoch, values, offset);
+        } else if v3 {
+            if lines.line.first() == Some(&b'>') {
+                if let Some((epoch, values, offset)) = pending.take() {
+                    visit(&epoch, values, offset);
+                }
+                let epoch = parse_epoch_line(&lines.line, true);
+                if (2..=5).contains(&epoch.flag) {
+                    lines.skip(epoch.count)?;
+                    visit(&epoch, 0, offset);
+                } else {
+                    pending = Some((epoch, 0, offset));
+                }
+            } else if let Some((_, values, _)) = pending.as_mut() {
+                let system = lines.line.first().copied().unwrap_or(b' ');
+                *values += count_values(&lines.line, 3, types(system));
+            }
+        } else {
+            let epoch = parse_epoch_line(&lines.line, false);
+            if (2..=5).contains(&epoch.flag) {
+                lines.skip(epoch.count)?;
+                visit(&epoch, 0, offset);
+                continue;
+            }
+            lines.skip(epoch.count.div_ceil(V2_SATS_PER_LINE).saturating_sub(1))?;
+            let mut values = 0;
+            'sats: for _ in 0..epoch.count {
+                for first in (0..n_types.max(1)).step_by(V2_OBS_PER_LINE) {
+                    if !lines.next()? {
+                        break 'sats;
+                    }
+                    values += count_values(&lines.line, 0, (n_types - first).min(V2_OBS_PER_LINE));
+                }
+            }
+            visit(&epoch, values, offset);
+        }
+    }
+    if let Some((epoch, values, offset)) = pending {
+        visit(&epoch, values, offset);
+    }
+    Ok(())
+}
+
+/// Observation values recorded in each observation epoch of a file (special events
+/// excluded), with the byte offset of its epoch line in the (decompressed) text
+pub(crate) fn epoch_values(path: &Path) -> io::Result<Vec<(usize, u64)>> {
+    let mut epochs = Vec::new();
+    scan(open_text(path)?, |epoch, values, offset| {
+        if !(2..=5).contains(&epoch.flag) && epoch.epoch.is_some() {
+            epochs.push((values, offset));
+        }
+    })?;
+    Ok(epochs)
+}
+
+/// First epoch, last epoch and number of epochs of `rinex_time_bounds`
+type BoundsOutput = (Option<DateTime<Utc>>, Option<DateTime<Utc>>, usize);
+
+/// Returns the first and last observation epochs of a RINEX observation file and the
+/// number of epochs, by scanning the epoch lines only
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file (.rnx, .crx, or .gz)
+///
+/// Returns:
+///     tuple:
+///         - datetime | None: First epoch, as recorded in the file and labelled as UTC
+///         - datetime | None: Last epoch
+///         - int: Number of observation epochs (special events excluded)
+#[pyfunction]
+pub(crate) fn rinex_time_bounds(py: Python<'_>, path: &str) -> PyResult<BoundsOutput> {
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(
+            format!("File not found: {}", path.display())
+        ));
+    }
+    let bounds = py
+        .allow_threads(|| {
+            let mut bounds = TimeBounds::default();
+            scan(open_text(path)?, |epoch, _, _| bounds.push(epoch))?;
+            Ok::<_, io::Error>(bounds)
+        })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("RINEX scanning error: {}", e)
+        ))?;
+    Ok((bounds.first, bounds.last, bounds.epochs))
+}
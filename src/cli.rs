@@ -0,0 +1,388 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::options::ReadOptions;
+
+/// Option of a subcommand: long name, value placeholder (None for boolean flags) and help
+type OptionSpec = (&'static str, Option<&'static str>, &'static str);
+
+/// Subcommand of the command-line interface
+struct Command {
+    name: &'static str,
+    summary: &'static str,
+    arguments: &'static [(&'static str, &'static str)],
+    options: &'static [OptionSpec],
+}
+
+const COMMANDS: &[Command] = &[
+    Command {
+        name: "convert",
+        summary: "Convert a raw receiver log (UBX, SBF, BINEX, RTCM 3) into Parquet observations",
+        arguments: &[("INPUT", "Receiver log, optionally gzip-compressed"), ("OUTPUT", "Observations (.parquet or .csv)")],
+        options: &[
+            ("format", Some("FORMAT"), "ubx, sbf, binex or rtcm; by default, from the extension of INPUT"),
+            ("date", Some("YYYY-MM-DD"), "Approximate date of the log, resolving the week of UBX, BINEX and RTCM epochs"),
+            ("nav-dir", Some("DIR"), "Directory receiving the navigation data, one Parquet file per constellation"),
+        ],
+    },
+    Command {
+        name: "to-parquet",
+        summary: "Convert a RINEX observation (or navigation) file into Parquet",
+        arguments: &[("INPUT", "RINEX file (.rnx, .crx, or .gz)"), ("OUTPUT", "Parquet file, or directory with --nav")],
+        options: &[
+            ("nav", None, "INPUT is a navigation file, written as one Parquet file per constellation"),
+            ("geometry-free", None, "Write the geometry-free phase and code combinations instead of the observables"),
+            ("time-scale", Some("SCALE"), "Timescale of the epochs (GPST, GST, BDT, GLONASST, UTC or TAI), by default GPST"),
+        ],
+    },
+    Command {
+        name: "qc",
+        summary: "Print a quality summary of a RINEX observation file",
+        arguments: &[("INPUT", "RINEX observation file")],
+        options: &[("nav", Some("FILE"), "RINEX navigation file, listing the satellites never observed")],
+    },
+    Command {
+        name: "tec",
+        summary: "Compute calibrated slant and vertical TEC from a pair of RINEX files",
+        arguments: &[
+            ("OBS", "RINEX observation file"),
+            ("NAV", "RINEX navigation file"),
+            ("OUTPUT", "TEC (.parquet or .csv)"),
+        ],
+        options: &[
+            ("dcb", Some("FILE"), "P1-P2 differential code biases (Bernese DCB), removed instead of estimated"),
+            ("systems", Some("LIST"), "Comma-separated constellations (e.g. G,E); by default, all the supported ones"),
+            ("min-elevation", Some("DEG"), "Elevation mask in degrees"),
+            ("h-ipp", Some("M"), "Height of the ionospheric shell in meters"),
+            ("vertical-equivalent", None, "Add the vertical equivalent at the station zenith ('veq')"),
+            ("modip-mapping", None, "Use the modip-aware shell height in the vertical mapping"),
+        ],
+    },
+];
+
+/// Invalid command line, reported with the usage of the (sub)command
+struct UsageError(String);
+
+/// Positional arguments, option values and flags of a subcommand
+struct Parsed {
+    arguments: Vec<String>,
+    values: HashMap<&'static str, String>,
+    flags: HashSet<&'static str>,
+}
+
+impl Parsed {
+    fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    fn number(&self, name: &str) -> Result<Option<f64>, UsageError> {
+        self.value(name)
+            .map(|v| v.parse().map_err(|_| UsageError(format!("--{name}: invalid number '{v}'"))))
+            .transpose()
+    }
+}
+
+fn parse(command: &Command, argv: &[String]) -> Result<Parsed, UsageError> {
+    let mut parsed = Parsed { arguments: Vec::new(), values: HashMap::new(), flags: HashSet::new() };
+    let mut args = argv.iter();
+    while let Some(arg) = args.next() {
+        let Some(long) = arg.strip_prefix("--") else {
+            parsed.arguments.push(arg.clone());
+            continue;
+        };
+        let (name, inline) = match long.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (long, None),
+        };
+        let Some(&(name, placeholder, _)) = command.options.iter().find(|(n, _, _)| *n == name) else {
+            return Err(UsageError(format!("unknown option '--{name}'")));
+        };
+        match (placeholder, inline) {
+            (None, None) => {
+                parsed.flags.insert(name);
+            },
+            (None, Some(_)) => return Err(UsageError(format!("--{name} takes no value"))),
+            (Some(_), Some(value)) => {
+                parsed.values.insert(name, value);
+            },
+            (Some(placeholder), None) => {
+                let value = args.next().ok_or_else(|| UsageError(format!("--{name} expects {placeholder}")))?;
+                parsed.values.insert(name, value.clone());
+            },
+        }
+    }
+    if parsed.arguments.len() != command.arguments.len() {
+        let expected: Vec<&str> = command.arguments.iter().map(|(a, _)| *a).collect();
+        return Err(UsageError(format!("expected arguments {}", expected.join(" "))));
+    }
+    Ok(parsed)
+}
+
+fn usage() -> String {
+    let mut text = String::from("usage: pytecgg [--version] <command> [options]\n\ncommands:\n");
+    for command in COMMANDS {
+        text.push_str(&format!("  {:<12}{}\n", command.name, command.summary));
+    }
+    text.push_str("\nRun 'pytecgg <command> --help' for the options of a command.\n");
+    text
+}
+
+fn command_usage(command: &Command) -> String {
+    let arguments: Vec<&str> = command.arguments.iter().map(|(a, _)| *a).collect();
+    let mut text = format!(
+        "usage: pytecgg {} [options] {}\n\n{}\n\narguments:\n",
+        command.name,
+        arguments.join(" "),
+        command.summary
+    );
+    for (name, help) in command.arguments {
+        text.push_str(&format!("  {name:<24}{help}\n"));
+    }
+    text.push_str("\noptions:\n");
+    for (name, placeholder, help) in command.options {
+        let option = match placeholder {
+            Some(p) => format!("--{name} {p}"),
+            None => format!("--{name}"),
+        };
+        text.push_str(&format!("  {option:<24}{help}\n"));
+    }
+    text
+}
+
+/// Writes to the Python streams, so that the output follows `sys.stdout` redirections
+fn echo(py: Python<'_>, stream: &str, text: &str) -> PyResult<()> {
+    py.import("sys")?.getattr(stream)?.call_method1("write", (text,))?;
+    Ok(())
+}
+
+fn kwargs<'py>(py: Python<'py>, items: &[(&str, Bound<'py, PyAny>)]) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, value) in items {
+        dict.set_item(key, value)?;
+    }
+    Ok(dict)
+}
+
+/// Writes a DataFrame as CSV or, by default, as Parquet, by the extension of the path
+fn write_frame(df: &Bound<'_, PyAny>, path: &str) -> PyResult<()> {
+    let is_csv = Path::new(path).extension().is_some_and(|e| e.eq_ignore_ascii_case("csv"));
+    df.call_method1(if is_csv { "write_csv" } else { "write_parquet" }, (path,))?;
+    Ok(())
+}
+
+/// Writes navigation data, one Parquet file per constellation
+fn write_nav(py: Python<'_>, nav: &Bound<'_, PyAny>, dir: &str) -> PyResult<()> {
+    std::fs::create_dir_all(dir).map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    for item in nav.call_method0("items")?.try_iter()? {
+        let (name, df): (String, Bound<'_, PyAny>) = item?.extract()?;
+        let path = Path::new(dir).join(format!("{name}.parquet"));
+        write_frame(&df, &path.to_string_lossy())?;
+        echo(py, "stdout", &format!("{}\n", path.display()))?;
+    }
+    Ok(())
+}
+
+fn convert(py: Python<'_>, args: &Parsed) -> Result<(), CliError> {
+    let (input, output) = (&args.arguments[0], &args.arguments[1]);
+    let format = match args.value("format") {
+        Some(format) => format.to_ascii_lowercase(),
+        None => {
+            let name = input.to_ascii_lowercase();
+            let name = name.strip_suffix(".gz").unwrap_or(&name);
+            let extension = Path::new(name).extension().map(|e| e.to_string_lossy().into_owned());
+            match extension.as_deref() {
+                Some("ubx") => "ubx".into(),
+                Some("sbf") => "sbf".into(),
+                Some("bnx" | "binex") => "binex".into(),
+                Some("rtcm" | "rtcm3") => "rtcm".into(),
+                _ => return Err(UsageError(format!("cannot infer the format of '{input}': use --format")).into()),
+            }
+        },
+    };
+    let reader = match format.as_str() {
+        "ubx" | "sbf" | "binex" | "rtcm" => format!("read_{format}"),
+        _ => return Err(UsageError(format!("--format: expected ubx, sbf, binex or rtcm, got '{format}'")).into()),
+    };
+
+    let parsing = py.import("pytecgg.parsing")?;
+    let mut options = Vec::new();
+    if let Some(date) = args.value("date") {
+        if format == "sbf" {
+            return Err(UsageError("--date does not apply to SBF logs, whose epochs are complete".into()).into());
+        }
+        let date = py
+            .import("datetime")?
+            .getattr("date")?
+            .call_method1("fromisoformat", (date,))
+            .map_err(|_| UsageError(format!("--date: invalid date '{date}'")))?;
+        options.push(("reference", date));
+    }
+    let decoded = parsing.getattr(reader.as_str())?.call((input,), Some(&kwargs(py, &options)?))?;
+    write_frame(&decoded.get_item(0)?, output)?;
+    if let Some(dir) = args.value("nav-dir") {
+        write_nav(py, &decoded.get_item(1)?, dir)?;
+    }
+    Ok(())
+}
+
+fn to_parquet(py: Python<'_>, args: &Parsed) -> Result<(), CliError> {
+    let (input, output) = (&args.arguments[0], &args.arguments[1]);
+    let parsing = py.import("pytecgg.parsing")?;
+    let mut options = Vec::new();
+    if let Some(scale) = args.value("time-scale") {
+        options.push(("time_scale", scale.into_bound_py_any(py)?));
+    }
+    if args.flags.contains("nav") {
+        if args.flags.contains("geometry-free") {
+            return Err(UsageError("--geometry-free does not apply to navigation files".into()).into());
+        }
+        let nav = parsing.getattr("read_rinex_nav")?.call((input,), Some(&kwargs(py, &options)?))?;
+        return Ok(write_nav(py, &nav, output)?);
+    }
+    let read_options = ReadOptions {
+        geometry_free: args.flags.contains("geometry-free"),
+        time_scale: args.value("time-scale").unwrap_or("GPST").to_string(),
+        ..ReadOptions::default()
+    };
+    let options = [("options", Py::new(py, read_options)?.into_bound_py_any(py)?)];
+    let parsed = parsing.getattr("read_rinex_obs")?.call((input,), Some(&kwargs(py, &options)?))?;
+    let df = parsed.get_item(0)?;
+    write_frame(&df, output)?;
+    Ok(())
+}
+
+fn qc(py: Python<'_>, args: &Parsed) -> Result<(), CliError> {
+    let nav = match args.value("nav") {
+        Some(path) => py.import("pytecgg.parsing")?.getattr("read_rinex_nav")?.call1((path,))?,
+        None => py.None().into_bound(py),
+    };
+    let report = py.import("pytecgg.qc")?.getattr("qc_report")?.call1((&args.arguments[0], nav))?;
+    let field = |name: &str| -> PyResult<String> { Ok(report.getattr(name)?.str()?.to_string()) };
+
+    let gaps = report.getattr("gaps")?;
+    let station_gaps: usize = gaps
+        .call_method1("filter", (py.import("polars")?.getattr("col")?.call1(("sv",))?.call_method0("is_null")?,))?
+        .getattr("height")?
+        .extract()?;
+    let missing: Vec<String> = report.getattr("missing_svs")?.extract()?;
+    let completeness: f64 = report.getattr("completeness")?.extract()?;
+    let lines = [
+        ("first epoch", field("first_epoch")?),
+        ("last epoch", field("last_epoch")?),
+        ("interval", field("interval")?),
+        (
+            "epochs",
+            format!("{} / {} ({completeness:.1} %)", field("actual_epochs")?, field("expected_epochs")?),
+        ),
+        ("station gaps", station_gaps.to_string()),
+        ("cycle slips", field("n_slips")?),
+        ("missing SVs", if missing.is_empty() { "-".into() } else { missing.join(", ") }),
+    ];
+    let text: String = lines.iter().map(|(name, value)| format!("{name:<14}{value}\n")).collect();
+    echo(py, "stdout", &text)?;
+    Ok(())
+}
+
+fn tec(py: Python<'_>, args: &Parsed) -> Result<(), CliError> {
+    let (obs, nav, output) = (&args.arguments[0], &args.arguments[1], &args.arguments[2]);
+    let calibration = py.import("pytecgg.tec_calibration")?;
+
+    let mut options = Vec::new();
+    if let Some(systems) = args.value("systems") {
+        let systems: Vec<&str> = systems.split(',').map(str::trim).filter(|s| !s.is_empty()).collect();
+        options.push(("systems", systems.into_bound_py_any(py)?));
+    }
+    for (option, field) in [("min-elevation", "min_elevation"), ("h-ipp", "h_ipp")] {
+        if let Some(value) = args.number(option)? {
+            options.push((field, value.into_bound_py_any(py)?));
+        }
+    }
+    for (flag, field) in [("vertical-equivalent", "vertical_equivalent"), ("modip-mapping", "modip_mapping")] {
+        if args.flags.contains(flag) {
+            options.push((field, true.into_bound_py_any(py)?));
+        }
+    }
+    let options = calibration.getattr("TecOptions")?.call((), Some(&kwargs(py, &options)?))?;
+    let biases = match args.value("dcb") {
+        Some(path) => path.into_bound_py_any(py)?,
+        None => py.None().into_bound(py),
+    };
+    let df = calibration.getattr("compute_tec")?.call1((obs, nav, biases, options))?;
+    write_frame(&df, output)?;
+    Ok(())
+}
+
+/// Failure of a subcommand: invalid command line, or error raised while processing
+enum CliError {
+    Usage(UsageError),
+    Python(PyErr),
+}
+
+impl From<UsageError> for CliError {
+    fn from(err: UsageError) -> Self {
+        Self::Usage(err)
+    }
+}
+
+impl From<PyErr> for CliError {
+    fn from(err: PyErr) -> Self {
+        Self::Python(err)
+    }
+}
+
+/// Runs the `pytecgg` command-line interface
+///
+/// Parameters:
+///     argv (list[str]): Command-line arguments, without the program name
+///
+/// Returns:
+///     int: Exit status, 0 on success, 1 if processing failed and 2 for an invalid
+///     command line
+#[pyfunction]
+pub(crate) fn run_cli(py: Python<'_>, argv: Vec<String>) -> PyResult<i32> {
+    let Some((name, rest)) = argv.split_first() else {
+        echo(py, "stderr", &usage())?;
+        return Ok(2);
+    };
+    match name.as_str() {
+        "-h" | "--help" => {
+            echo(py, "stdout", &usage())?;
+            return Ok(0);
+        },
+        "--version" => {
+            echo(py, "stdout", &format!("pytecgg {}\n", env!("CARGO_PKG_VERSION")))?;
+            return Ok(0);
+        },
+        _ => {},
+    }
+    let Some(command) = COMMANDS.iter().find(|c| c.name == name) else {
+        echo(py, "stderr", &format!("pytecgg: error: unknown command '{name}'\n\n{}", usage()))?;
+        return Ok(2);
+    };
+    if rest.iter().any(|a| a == "-h" || a == "--help") {
+        echo(py, "stdout", &command_usage(command))?;
+        return Ok(0);
+    }
+
+    let outcome = parse(command, rest).map_err(CliError::from).and_then(|args| match command.name {
+        "convert" => convert(py, &args),
+        "to-parquet" => to_parquet(py, &args),
+        "qc" => qc(py, &args),
+        _ => tec(py, &args),
+    });
+    match outcome {
+        Ok(()) => Ok(0),
+        Err(CliError::Usage(UsageError(message))) => {
+            let text = format!("pytecgg {}: error: {message}\n\n{}", command.name, command_usage(command));
+            echo(py, "stderr", &text)?;
+            Ok(2)
+        },
+        Err(CliError::Python(err)) => {
+            echo(py, "stderr", &format!("pytecgg {}: error: {}\n", command.name, err.value(py)))?;
+            Ok(1)
+        },
+    }
+}
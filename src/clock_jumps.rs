@@ -0,0 +1,265 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::combinations::{carrier_frequency, C};
+use crate::frames::polars_err;
+
+/// Range equivalent of one millisecond of receiver clock (m)
+const MS_RANGE: f64 = C * 1e-3;
+/// Minimum number of satellites for a jump to be told apart from a single-satellite outlier
+const MIN_SATELLITES: usize = 2;
+
+/// Observables affected by a receiver clock jump
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JumpKind {
+    /// Code observables (C*, and P* in RINEX 2)
+    Code,
+    /// Carrier phase observables (L*)
+    Phase,
+}
+
+impl JumpKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            JumpKind::Code => "code",
+            JumpKind::Phase => "phase",
+        }
+    }
+}
+
+/// Receiver clock jumps detected between consecutive epochs
+pub(crate) struct ClockJump {
+    /// First epoch after the jump (physical units)
+    pub epoch: i64,
+    /// Size of the jump of the affected observables in whole milliseconds
+    pub milliseconds: i64,
+    /// Observables affected by the jump
+    pub kind: JumpKind,
+    /// Number of satellites supporting the detection
+    pub n_satellites: u32,
+}
+
+/// Code and phase ranges (m) per SV and signal suffix, at a single epoch
+type EpochRanges = BTreeMap<String, BTreeMap<String, (f64, f64)>>;
+
+/// Code and phase ranges (m) per (epoch, sv), one pair per signal with both code and
+/// phase on the same band and attribute (e.g. 'C1C'/'L1C', or 'P1'/'L1' in RINEX 2)
+fn code_and_phase(
+    df: &DataFrame,
+    glonass_channels: &HashMap<String, i32>,
+) -> PolarsResult<BTreeMap<i64, EpochRanges>> {
+    let epoch_phys = df.column("epoch")?.to_physical_repr();
+    let value_col = df.column("value")?.cast(&DataType::Float64)?;
+
+    type Signals = BTreeMap<String, (Option<f64>, Option<f64>)>;
+    let mut signals: BTreeMap<(i64, String), Signals> = BTreeMap::new();
+    let rows = epoch_phys
+        .i64()?
+        .into_iter()
+        .zip(df.column("sv")?.str()?)
+        .zip(df.column("observable")?.str()?)
+        .zip(value_col.f64()?);
+    for (((epoch, sv), code), value) in rows {
+        let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) else {
+            continue;
+        };
+        let (kind, suffix) = code.split_at(1);
+        let entry = signals.entry((epoch, sv.to_string())).or_default().entry(suffix.to_string()).or_default();
+        match kind {
+            "C" | "P" if entry.0.is_none() => entry.0 = Some(value),
+            "L" if entry.1.is_none() => entry.1 = Some(value),
+            _ => {},
+        }
+    }
+
+    let mut out: BTreeMap<i64, EpochRanges> = BTreeMap::new();
+    for ((epoch, sv), per_signal) in signals {
+        let system = sv.chars().next().unwrap_or_default();
+        let channel = glonass_channels.get(&sv).copied();
+        let ranges: BTreeMap<String, (f64, f64)> = per_signal
+            .into_iter()
+            .filter_map(|(suffix, (code, phase))| {
+                let f = carrier_frequency(system, suffix.chars().next()?, channel)?;
+                Some((suffix, (code?, phase? * C / f)))
+            })
+            .collect();
+        if !ranges.is_empty() {
+            out.entry(epoch).or_default().insert(sv, ranges);
+        }
+    }
+    Ok(out)
+}
+
+/// Detects millisecond jumps of the receiver clock (clock steering): between two epochs,
+/// every satellite shows the same jump of the code-minus-phase difference, equal to a
+/// whole number of milliseconds of range. Jumps of the phase alone are detected as well:
+/// the observable that jumped is the one whose epoch-to-epoch change holds the jump, the
+/// geometric range changing by far less than a millisecond (~300 km) between epochs.
+pub(crate) fn detect_jumps(
+    df: &DataFrame,
+    glonass_channels: &HashMap<String, i32>,
+    tolerance: f64,
+) -> PolarsResult<Vec<ClockJump>> {
+    let ranges = code_and_phase(df, glonass_channels)?;
+
+    let mut jumps = Vec::new();
+    let mut previous: Option<&EpochRanges> = None;
+    for (epoch, current) in &ranges {
+        let Some(prev) = previous.replace(current) else {
+            continue;
+        };
+
+        // Epoch-to-epoch changes of code and phase (m), one signal per satellite
+        let deltas: Vec<(f64, f64)> = current
+            .iter()
+            .filter_map(|(sv, signals)| {
+                let before = prev.get(sv)?;
+                signals.iter().find_map(|(suffix, (code, phase))| {
+                    before.get(suffix).map(|(c, p)| (code - c, phase - p))
+                })
+            })
+            .collect();
+
+        // A jump must affect all the satellites in view, with at least half a millisecond
+        let differences: Vec<f64> = deltas.iter().map(|(code, phase)| code - phase).collect();
+        if differences.len() < MIN_SATELLITES || differences.iter().any(|d| d.abs() < 0.5 * MS_RANGE) {
+            continue;
+        }
+        let mean = differences.iter().sum::<f64>() / differences.len() as f64 / MS_RANGE;
+        if (mean - mean.round()).abs() > tolerance {
+            continue;
+        }
+        // The code-minus-phase difference jumps with the code, and against the phase
+        let code_jumps = deltas.iter().filter(|(code, phase)| code.abs() > phase.abs()).count();
+        let (kind, milliseconds) = if 2 * code_jumps >= deltas.len() {
+            (JumpKind::Code, mean.round() as i64)
+        } else {
+            (JumpKind::Phase, -mean.round() as i64)
+        };
+        jumps.push(ClockJump {
+            epoch: *epoch,
+            milliseconds,
+            kind,
+            n_satellites: deltas.len() as u32,
+        });
+    }
+    Ok(jumps)
+}
+
+/// Cumulative jump (ms) in force from each jump of the given kind onwards
+fn cumulative_jumps(jumps: &[ClockJump], kind: JumpKind) -> Vec<(i64, i64)> {
+    let mut total = 0;
+    jumps
+        .iter()
+        .filter(|jump| jump.kind == kind)
+        .map(|jump| {
+            total += jump.milliseconds;
+            (jump.epoch, total)
+        })
+        .collect()
+}
+
+/// Cumulative jump (ms) in force at an epoch
+fn jump_at(cumulative: &[(i64, i64)], epoch: i64) -> i64 {
+    match cumulative.partition_point(|(start, _)| *start <= epoch) {
+        0 => 0,
+        n => cumulative[n - 1].1,
+    }
+}
+
+/// Removes the detected jumps from the observables that jumped: code jumps from the
+/// code observables (C*, and P* in RINEX 2, in meters), phase jumps from the carrier
+/// phases (L*, in cycles), so that code and phase refer to the same continuous receiver
+/// clock. GLONASS phases without a frequency channel are left untouched.
+pub(crate) fn repair_jumps(
+    df: &DataFrame,
+    jumps: &[ClockJump],
+    glonass_channels: &HashMap<String, i32>,
+) -> PolarsResult<DataFrame> {
+    let epoch_phys = df.column("epoch")?.to_physical_repr();
+    let value_col = df.column("value")?.cast(&DataType::Float64)?;
+
+    let code_jumps = cumulative_jumps(jumps, JumpKind::Code);
+    let phase_jumps = cumulative_jumps(jumps, JumpKind::Phase);
+
+    let repaired: Float64Chunked = epoch_phys
+        .i64()?
+        .into_iter()
+        .zip(df.column("sv")?.str()?)
+        .zip(df.column("observable")?.str()?)
+        .zip(value_col.f64()?)
+        .map(|(((epoch, sv), code), value)| {
+            let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) else {
+                return value;
+            };
+            let mut chars = code.chars();
+            Some(match chars.next() {
+                Some('C' | 'P') => value - jump_at(&code_jumps, epoch) as f64 * MS_RANGE,
+                Some('L') => {
+                    let jump = jump_at(&phase_jumps, epoch);
+                    let system = sv.chars().next().unwrap_or_default();
+                    let channel = glonass_channels.get(sv).copied();
+                    match chars.next().and_then(|band| carrier_frequency(system, band, channel)) {
+                        // One millisecond of range is f * 1e-3 cycles
+                        Some(f) if jump != 0 => value - jump as f64 * f * 1e-3,
+                        _ => value,
+                    }
+                }
+                _ => value,
+            })
+        })
+        .collect();
+
+    let mut out = df.clone();
+    out.with_column(repaired.with_name("value".into()).into_series())?;
+    Ok(out)
+}
+
+/// Detects (and optionally repairs) millisecond jumps of the receiver clock
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     repair (bool): If true, also return the observations with the jumps removed from the
+///         observables that jumped
+///     tolerance (float): Maximum deviation (in ms) of the jump from a whole millisecond
+///
+/// Returns:
+///     tuple:
+///         - PyDataFrame: Jumps with columns 'epoch' (first epoch after the jump),
+///           'jump_ms', 'observable' ('code' or 'phase', the observables that jumped),
+///           'n_satellites'
+///         - PyDataFrame or None: Repaired observations, if `repair` is true
+#[pyfunction]
+#[pyo3(signature = (df, glonass_channels=None, repair=false, tolerance=1e-4))]
+pub(crate) fn clock_jumps(
+    df: PyDataFrame,
+    glonass_channels: Option<HashMap<String, i32>>,
+    repair: bool,
+    tolerance: f64,
+) -> PyResult<(PyDataFrame, Option<PyDataFrame>)> {
+    let df = df.0;
+    let glonass_channels = glonass_channels.unwrap_or_default();
+    let jumps = detect_jumps(&df, &glonass_channels, tolerance).map_err(polars_err)?;
+
+    let epoch_dtype = df.column("epoch").map_err(polars_err)?.dtype().clone();
+    let table = DataFrame::new(vec![
+        Series::new("epoch".into(), jumps.iter().map(|j| j.epoch).collect::<Vec<_>>())
+            .cast(&epoch_dtype)
+            .map_err(polars_err)?
+            .into(),
+        Series::new("jump_ms".into(), jumps.iter().map(|j| j.milliseconds).collect::<Vec<_>>()).into(),
+        Series::new("observable".into(), jumps.iter().map(|j| j.kind.as_str()).collect::<Vec<_>>()).into(),
+        Series::new("n_satellites".into(), jumps.iter().map(|j| j.n_satellites).collect::<Vec<_>>()).into(),
+    ])
+    .map_err(polars_err)?;
+
+    let repaired = if repair {
+        Some(PyDataFrame(repair_jumps(&df, &jumps, &glonass_channels).map_err(polars_err)?))
+    } else {
+        None
+    };
+    Ok((PyDataFrame(table), repaired))
+}
@@ -0,0 +1,554 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use rinex::prelude::{Constellation, Observable, Version};
+use std::collections::HashMap;
+
+use crate::frames::{keyed_frame, pivot_observables, polars_err};
+
+/// Speed of light in vacuum (m/s)
+pub(crate) const C: f64 = 299_792_458.0;
+
+/// Returns the carrier frequency (Hz) of a RINEX frequency band for a given constellation.
+/// GLONASS FDMA bands (1 and 2) also require the satellite frequency channel number.
+pub(crate) fn carrier_frequency(system: char, band: char, glonass_channel: Option<i32>) -> Option<f64> {
+    let mhz = match (system, band) {
+        ('G' | 'E' | 'J' | 'S' | 'C', '1') => 1575.42,
+        ('G' | 'J', '2') => 1227.60,
+        ('C', '2') => 1561.098,
+        ('G' | 'E' | 'J' | 'S' | 'C' | 'I', '5') => 1176.45,
+        ('E' | 'J', '6') => 1278.75,
+        ('C', '6') => 1268.52,
+        ('E' | 'C', '7') => 1207.14,
+        ('E' | 'C', '8') => 1191.795,
+        ('I', '9') => 2492.028,
+        ('R', '1') => 1602.0 + f64::from(glonass_channel?) * 0.5625,
+        ('R', '2') => 1246.0 + f64::from(glonass_channel?) * 0.4375,
+        ('R', '3') => 1202.025,
+        ('R', '4') => 1600.995,
+        ('R', '6') => 1248.06,
+        _ => return None,
+    };
+    Some(mhz * 1e6)
+}
+
+/// Naming of the BeiDou frequency bands, which depends on the RINEX version: before 3.03,
+/// B1I is recorded in band 1 (e.g. C1I/L1I); from 3.03 on, in band 2, band 1 being B1C
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum BandNaming {
+    /// RINEX 3.03 and later
+    #[default]
+    Current,
+    /// RINEX 2 and 3.00-3.02
+    Legacy,
+}
+
+impl BandNaming {
+    /// Band naming of a RINEX version
+    pub(crate) fn from_version(version: Version) -> Self {
+        if (version.major, version.minor) < (3, 3) {
+            Self::Legacy
+        } else {
+            Self::Current
+        }
+    }
+
+    /// Parses an optional RINEX version (e.g. '3.02'), the current naming by default; a bare
+    /// major version (e.g. '3') stands for its latest revision
+    pub(crate) fn parse(version: Option<&str>) -> PyResult<Self> {
+        let Some(version) = version.map(str::trim) else {
+            return Ok(Self::Current);
+        };
+        let parsed = version.parse::<Version>().map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid RINEX version '{}'", version))
+        })?;
+        if version.contains('.') {
+            Ok(Self::from_version(parsed))
+        } else {
+            Ok(Self::from_version(Version::new(parsed.major, u8::MAX)))
+        }
+    }
+}
+
+/// Carrier frequency (Hz) of a RINEX frequency band, following the band naming of the file
+pub(crate) fn band_frequency(system: char, band: char, glonass_channel: Option<i32>, naming: BandNaming) -> Option<f64> {
+    match (naming, system, band) {
+        (BandNaming::Legacy, 'C', '1') => Some(1561.098e6),
+        _ => carrier_frequency(system, band, glonass_channel),
+    }
+}
+
+/// Single-letter RINEX code of a constellation (SBAS augmentation systems collapse to 'S')
+pub(crate) fn system_letter(constellation: Constellation) -> Option<char> {
+    match constellation {
+        Constellation::GPS => Some('G'),
+        Constellation::Glonass => Some('R'),
+        Constellation::Galileo => Some('E'),
+        Constellation::BeiDou => Some('C'),
+        Constellation::QZSS => Some('J'),
+        Constellation::IRNSS => Some('I'),
+        c if c.is_sbas() => Some('S'),
+        _ => None,
+    }
+}
+
+/// Constellation name of a RINEX system letter, as keying the DataFrames of
+/// `read_rinex_nav` (e.g. 'GPS', 'GALILEO')
+pub(crate) fn constellation_name(system: char) -> Option<&'static str> {
+    match system {
+        'G' => Some("GPS"),
+        'R' => Some("GLONASS"),
+        'E' => Some("GALILEO"),
+        'C' => Some("BEIDOU"),
+        'J' => Some("QZSS"),
+        'I' => Some("IRNSS"),
+        'S' => Some("SBAS"),
+        _ => None,
+    }
+}
+
+/// Unit of the carrier phases returned by `read_rinex_obs`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PhaseUnit {
+    Cycles,
+    Meters,
+}
+
+impl PhaseUnit {
+    /// Parses a unit name ('cycles' or 'meters')
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "cycles" => Ok(Self::Cycles),
+            "meters" => Ok(Self::Meters),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported phase unit '{}': expected 'cycles' or 'meters'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Converts the carrier phases ('L' observables) of long-format observations from cycles
+/// into meters, with the wavelength of each satellite; phases of unknown wavelength (e.g.
+/// GLONASS FDMA bands without frequency channel) become null
+pub(crate) fn phases_to_meters(
+    mut df: DataFrame,
+    glonass_channels: &HashMap<String, i32>,
+    naming: BandNaming,
+) -> PolarsResult<DataFrame> {
+    let values: Float64Chunked = {
+        let svs = df.column("sv")?.str()?;
+        let codes = df.column("observable")?.str()?;
+        let values = df.column("value")?.f64()?;
+        svs.into_iter()
+            .zip(codes)
+            .zip(values)
+            .map(|((sv, code), value)| {
+                let (Some(sv), Some(code), Some(value)) = (sv, code, value) else {
+                    return value;
+                };
+                if !code.starts_with('L') {
+                    return Some(value);
+                }
+                let system = sv.chars().next()?;
+                let frequency = band_frequency(system, code.chars().nth(1)?, glonass_channels.get(sv).copied(), naming)?;
+                Some(value * C / frequency)
+            })
+            .collect()
+    };
+    df.with_column(values.with_name("value".into()).into_series())?;
+    Ok(df)
+}
+
+/// Preferred (first, second) frequency bands per constellation, in order of priority
+fn band_priority(system: char, naming: BandNaming) -> &'static [(char, char)] {
+    match system {
+        'G' | 'J' => &[('1', '2'), ('1', '5')],
+        'R' => &[('1', '2')],
+        'E' => &[('1', '5'), ('1', '7'), ('1', '8')],
+        // B1I first, then B1C (RINEX 3.03 on)
+        'C' if naming == BandNaming::Current => {
+            &[('2', '7'), ('2', '6'), ('1', '7'), ('1', '6'), ('2', '5'), ('1', '5')]
+        },
+        // B1I in band 1 (or in band 2 as in RINEX 3.01)
+        'C' => &[('1', '7'), ('1', '6'), ('2', '7'), ('2', '6'), ('1', '5'), ('2', '5')],
+        'I' => &[('5', '9')],
+        'S' => &[('1', '5')],
+        _ => &[],
+    }
+}
+
+/// Priority of the tracking channel attributes (third character of RINEX 3 codes)
+pub(crate) const ATTRIBUTE_PRIORITY: [char; 8] = ['C', 'L', 'S', 'I', 'Q', 'W', 'X', 'P'];
+
+/// Picks the best phase (or code) observable pair among the available ones, following the
+/// band priorities of the constellation and then the tracking channel priorities
+pub(crate) fn preferred_pair(
+    system: char,
+    available: &[String],
+    phase: bool,
+    naming: BandNaming,
+) -> Option<(String, String)> {
+    let kinds: &[char] = if phase { &['L'] } else { &['C', 'P'] };
+    let best = |band: char| {
+        available
+            .iter()
+            .filter(|o| {
+                let mut chars = o.chars();
+                chars.next().is_some_and(|k| kinds.contains(&k)) && chars.next() == Some(band)
+            })
+            .min_by_key(|o| {
+                let attribute = o.chars().nth(2).map_or(0, |a| {
+                    ATTRIBUTE_PRIORITY.iter().position(|p| *p == a).map_or(ATTRIBUTE_PRIORITY.len(), |i| i + 1)
+                });
+                let kind = kinds.iter().position(|k| o.starts_with(*k));
+                (attribute, kind)
+            })
+            .cloned()
+    };
+    band_priority(system, naming)
+        .iter()
+        .find_map(|&(b1, b2)| Some((best(b1)?, best(b2)?)))
+}
+
+/// Frequencies (Hz) of the given observables as tracked by a specific satellite
+pub(crate) fn observable_frequencies(
+    sv: &str,
+    observables: &[&str],
+    glonass_channels: &HashMap<String, i32>,
+    naming: BandNaming,
+) -> Vec<Option<f64>> {
+    let system = sv.chars().next().unwrap_or_default();
+    let channel = glonass_channels.get(sv).copied();
+    observables
+        .iter()
+        .map(|obs| band_frequency(system, obs.chars().nth(1)?, channel, naming))
+        .collect()
+}
+
+/// Combination of observable values, given their frequencies (Hz)
+pub(crate) type CombinationFn = fn(&[f64], &[f64]) -> f64;
+
+/// Output column of a linear combination, computed from a subset of the pivoted observables
+pub(crate) struct Combination<'a> {
+    pub name: &'a str,
+    /// Indices of the observables entering the combination
+    pub slots: Vec<usize>,
+    pub f: CombinationFn,
+}
+
+/// Computes linear combinations per (epoch, sv) from a long-format observation DataFrame;
+/// a combination is null where one of its observables is missing or has an unknown frequency
+pub(crate) fn combine(
+    df: &DataFrame,
+    observables: &[&str],
+    glonass_channels: &HashMap<String, i32>,
+    naming: BandNaming,
+    combinations: &[Combination],
+) -> PolarsResult<DataFrame> {
+    let pivoted = pivot_observables(df, observables)?;
+
+    let mut freq_cache: HashMap<String, Vec<Option<f64>>> = HashMap::new();
+    let mut keys = Vec::with_capacity(pivoted.rows.len());
+    let mut outputs: Vec<Vec<Option<f64>>> =
+        vec![Vec::with_capacity(pivoted.rows.len()); combinations.len()];
+
+    for ((epoch, sv), slots) in pivoted.rows {
+        let freqs = freq_cache
+            .entry(sv.clone())
+            .or_insert_with(|| observable_frequencies(&sv, observables, glonass_channels, naming));
+
+        for (comb, out) in combinations.iter().zip(outputs.iter_mut()) {
+            let values: Option<Vec<f64>> = comb.slots.iter().map(|&i| slots[i]).collect();
+            let comb_freqs: Option<Vec<f64>> = comb.slots.iter().map(|&i| freqs[i]).collect();
+            out.push(match (values, comb_freqs) {
+                (Some(v), Some(f)) => Some((comb.f)(&v, &f)),
+                _ => None,
+            });
+        }
+        keys.push((epoch, sv));
+    }
+
+    let columns = combinations.iter().map(|c| c.name).zip(outputs).collect();
+    keyed_frame(&pivoted.epoch_dtype, keys, columns)
+}
+
+/// Converts a pair of carrier phases from cycles to meters
+fn phase_in_meters(v: &[f64], f: &[f64]) -> (f64, f64) {
+    (v[0] * C / f[0], v[1] * C / f[1])
+}
+
+/// Computes a phase and/or a code two-frequency combination, depending on which
+/// observable pairs are provided
+fn phase_code_combination(
+    df: &DataFrame,
+    phase: Option<(String, String)>,
+    code: Option<(String, String)>,
+    glonass_channels: Option<HashMap<String, i32>>,
+    rinex_version: Option<&str>,
+    (phase_name, phase_f): (&str, CombinationFn),
+    (code_name, code_f): (&str, CombinationFn),
+) -> PyResult<PyDataFrame> {
+    let mut observables: Vec<&str> = Vec::with_capacity(4);
+    let mut combinations = Vec::with_capacity(2);
+
+    if let Some((o1, o2)) = &phase {
+        combinations.push(Combination {
+            name: phase_name,
+            slots: vec![observables.len(), observables.len() + 1],
+            f: phase_f,
+        });
+        observables.extend([o1.as_str(), o2.as_str()]);
+    }
+    if let Some((o1, o2)) = &code {
+        combinations.push(Combination {
+            name: code_name,
+            slots: vec![observables.len(), observables.len() + 1],
+            f: code_f,
+        });
+        observables.extend([o1.as_str(), o2.as_str()]);
+    }
+    if combinations.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "At least one of 'phase' and 'code' must be provided",
+        ));
+    }
+
+    let naming = BandNaming::parse(rinex_version)?;
+    let channels = glonass_channels.unwrap_or_default();
+    combine(df, &observables, &channels, naming, &combinations)
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
+
+/// Computes the Melbourne-Wübbena combination per (epoch, sv), as the difference between
+/// the wide-lane phase and the narrow-lane code combinations
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     phase (tuple[str, str]): Phase observables (in cycles) on the two frequencies
+///     code (tuple[str, str]): Code observables (in meters) on the two frequencies
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     rinex_version (str, optional): RINEX version of the observations, which sets the
+///         BeiDou band naming (3.03 and later by default)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'mw' (in meters)
+#[pyfunction]
+#[pyo3(signature = (df, phase, code, glonass_channels=None, rinex_version=None))]
+pub(crate) fn melbourne_wubbena(
+    df: PyDataFrame,
+    phase: (String, String),
+    code: (String, String),
+    glonass_channels: Option<HashMap<String, i32>>,
+    rinex_version: Option<&str>,
+) -> PyResult<PyDataFrame> {
+    let observables = [phase.0.as_str(), phase.1.as_str(), code.0.as_str(), code.1.as_str()];
+    let mw = Combination {
+        name: "mw",
+        slots: vec![0, 1, 2, 3],
+        f: |v, f| {
+            let (f1, f2) = (f[0], f[1]);
+            // Phase converted to meters before forming the wide-lane
+            let wide_lane = (f1 * v[0] * (C / f1) - f2 * v[1] * (C / f2)) / (f1 - f2);
+            let narrow_lane = (f1 * v[2] + f2 * v[3]) / (f1 + f2);
+            wide_lane - narrow_lane
+        },
+    };
+    let naming = BandNaming::parse(rinex_version)?;
+    let channels = glonass_channels.unwrap_or_default();
+    combine(&df.0, &observables, &channels, naming, &[mw])
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
+
+/// Computes the ionosphere-free combinations (L3/P3) per (epoch, sv), using the
+/// frequencies of the observed bands for each constellation
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     phase (tuple[str, str], optional): Phase observables (in cycles) on the two frequencies
+///     code (tuple[str, str], optional): Code observables (in meters) on the two frequencies
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     rinex_version (str, optional): RINEX version of the observations, which sets the
+///         BeiDou band naming (3.03 and later by default)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', and 'iflc_phase' and/or
+///     'iflc_code' (in meters), depending on the observables provided
+#[pyfunction]
+#[pyo3(signature = (df, phase=None, code=None, glonass_channels=None, rinex_version=None))]
+pub(crate) fn ionosphere_free(
+    df: PyDataFrame,
+    phase: Option<(String, String)>,
+    code: Option<(String, String)>,
+    glonass_channels: Option<HashMap<String, i32>>,
+    rinex_version: Option<&str>,
+) -> PyResult<PyDataFrame> {
+    fn iono_free(x1: f64, x2: f64, f1: f64, f2: f64) -> f64 {
+        (f1 * f1 * x1 - f2 * f2 * x2) / (f1 * f1 - f2 * f2)
+    }
+    phase_code_combination(
+        &df.0,
+        phase,
+        code,
+        glonass_channels,
+        rinex_version,
+        ("iflc_phase", |v, f| {
+            let (p1, p2) = phase_in_meters(v, f);
+            iono_free(p1, p2, f[0], f[1])
+        }),
+        ("iflc_code", |v, f| iono_free(v[0], v[1], f[0], f[1])),
+    )
+}
+
+/// Computes the wide-lane phase and/or code combinations per (epoch, sv)
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     phase (tuple[str, str], optional): Phase observables (in cycles) on the two frequencies
+///     code (tuple[str, str], optional): Code observables (in meters) on the two frequencies
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     rinex_version (str, optional): RINEX version of the observations, which sets the
+///         BeiDou band naming (3.03 and later by default)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', and 'wl_phase' and/or
+///     'wl_code' (in meters), depending on the observables provided
+#[pyfunction]
+#[pyo3(signature = (df, phase=None, code=None, glonass_channels=None, rinex_version=None))]
+pub(crate) fn wide_lane(
+    df: PyDataFrame,
+    phase: Option<(String, String)>,
+    code: Option<(String, String)>,
+    glonass_channels: Option<HashMap<String, i32>>,
+    rinex_version: Option<&str>,
+) -> PyResult<PyDataFrame> {
+    fn wide(x1: f64, x2: f64, f1: f64, f2: f64) -> f64 {
+        (f1 * x1 - f2 * x2) / (f1 - f2)
+    }
+    phase_code_combination(
+        &df.0,
+        phase,
+        code,
+        glonass_channels,
+        rinex_version,
+        ("wl_phase", |v, f| {
+            let (p1, p2) = phase_in_meters(v, f);
+            wide(p1, p2, f[0], f[1])
+        }),
+        ("wl_code", |v, f| wide(v[0], v[1], f[0], f[1])),
+    )
+}
+
+/// Computes the narrow-lane phase and/or code combinations per (epoch, sv)
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     phase (tuple[str, str], optional): Phase observables (in cycles) on the two frequencies
+///     code (tuple[str, str], optional): Code observables (in meters) on the two frequencies
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     rinex_version (str, optional): RINEX version of the observations, which sets the
+///         BeiDou band naming (3.03 and later by default)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', and 'nl_phase' and/or
+///     'nl_code' (in meters), depending on the observables provided
+#[pyfunction]
+#[pyo3(signature = (df, phase=None, code=None, glonass_channels=None, rinex_version=None))]
+pub(crate) fn narrow_lane(
+    df: PyDataFrame,
+    phase: Option<(String, String)>,
+    code: Option<(String, String)>,
+    glonass_channels: Option<HashMap<String, i32>>,
+    rinex_version: Option<&str>,
+) -> PyResult<PyDataFrame> {
+    fn narrow(x1: f64, x2: f64, f1: f64, f2: f64) -> f64 {
+        (f1 * x1 + f2 * x2) / (f1 + f2)
+    }
+    phase_code_combination(
+        &df.0,
+        phase,
+        code,
+        glonass_channels,
+        rinex_version,
+        ("nl_phase", |v, f| {
+            let (p1, p2) = phase_in_meters(v, f);
+            narrow(p1, p2, f[0], f[1])
+        }),
+        ("nl_code", |v, f| narrow(v[0], v[1], f[0], f[1])),
+    )
+}
+
+/// Converts a geometry-free combination (in meters) into TEC units, given the two frequencies
+pub(crate) fn meters_to_tecu(f1: f64, f2: f64) -> f64 {
+    (1.0 / 40.308) * (f1 * f1 * f2 * f2) / (f1 * f1 - f2 * f2) / 1e16
+}
+
+/// Computes the geometry-free phase (L4) and code (P4) combinations, in TEC units, for every
+/// constellation declared in the header, pairing signals according to the default priorities
+pub(crate) fn geometry_free_from_header(
+    df: &DataFrame,
+    codes: &HashMap<Constellation, Vec<Observable>>,
+    glonass_channels: &HashMap<String, i32>,
+    naming: BandNaming,
+) -> PolarsResult<DataFrame> {
+    let mut observables: Vec<String> = Vec::new();
+    let mut slots: HashMap<char, [usize; 4]> = HashMap::new();
+
+    for (constellation, declared) in codes {
+        let Some(system) = system_letter(*constellation) else {
+            continue;
+        };
+        let available: Vec<String> = declared.iter().map(|o| o.to_string()).collect();
+        let (Some(phase), Some(code)) = (
+            preferred_pair(system, &available, true, naming),
+            preferred_pair(system, &available, false, naming),
+        ) else {
+            continue;
+        };
+        let mut idx = [0; 4];
+        for (i, obs) in [phase.0, phase.1, code.0, code.1].into_iter().enumerate() {
+            idx[i] = observables.iter().position(|o| *o == obs).unwrap_or_else(|| {
+                observables.push(obs);
+                observables.len() - 1
+            });
+        }
+        slots.insert(system, idx);
+    }
+
+    let refs: Vec<&str> = observables.iter().map(String::as_str).collect();
+    let pivoted = pivot_observables(df, &refs)?;
+
+    let mut keys = Vec::with_capacity(pivoted.rows.len());
+    let mut gf_phase = Vec::with_capacity(pivoted.rows.len());
+    let mut gf_code = Vec::with_capacity(pivoted.rows.len());
+
+    for ((epoch, sv), values) in pivoted.rows {
+        let Some(idx) = sv.chars().next().and_then(|s| slots.get(&s)) else {
+            continue;
+        };
+        let pair: Vec<&str> = idx.iter().map(|&i| refs[i]).collect();
+        let freqs = observable_frequencies(&sv, &pair, glonass_channels, naming);
+        let (phase, code) = match (freqs[0], freqs[1]) {
+            (Some(f1), Some(f2)) => {
+                let k = meters_to_tecu(f1, f2);
+                let phase = values[idx[0]]
+                    .zip(values[idx[1]])
+                    .map(|(l1, l2)| (l1 * C / f1 - l2 * C / f2) * k);
+                let code = values[idx[2]].zip(values[idx[3]]).map(|(p1, p2)| (p2 - p1) * k);
+                (phase, code)
+            },
+            _ => (None, None),
+        };
+        keys.push((epoch, sv));
+        gf_phase.push(phase);
+        gf_code.push(code);
+    }
+
+    keyed_frame(
+        &pivoted.epoch_dtype,
+        keys,
+        vec![("gflc_phase", gf_phase), ("gflc_code", gf_code)],
+    )
+}
@@ -0,0 +1,235 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use crate::frames::{polars_err, units_per_second};
+
+/// Directory name of null partition values, as used by Hive and Polars
+const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// What to do with partitions that already contain Parquet files
+#[derive(Clone, Copy, PartialEq)]
+enum ExistingPolicy {
+    Error,
+    Overwrite,
+    Append,
+}
+
+impl ExistingPolicy {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "overwrite" => Ok(Self::Overwrite),
+            "append" => Ok(Self::Append),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid existing policy '{}': expected 'error', 'overwrite' or 'append'",
+                name
+            ))),
+        }
+    }
+}
+
+fn parse_compression(name: &str) -> PyResult<ParquetCompression> {
+    match name.to_ascii_lowercase().as_str() {
+        "zstd" => Ok(ParquetCompression::Zstd(None)),
+        "snappy" => Ok(ParquetCompression::Snappy),
+        "lz4" => Ok(ParquetCompression::Lz4Raw),
+        "gzip" => Ok(ParquetCompression::Gzip(None)),
+        "uncompressed" => Ok(ParquetCompression::Uncompressed),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid compression '{}': expected 'zstd', 'snappy', 'lz4', 'gzip' or 'uncompressed'",
+            name
+        ))),
+    }
+}
+
+fn io_err(e: std::io::Error) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string())
+}
+
+/// Converts days since 1970-01-01 into the (proleptic Gregorian) year and day of year
+fn year_and_doy(days: i64) -> (i32, i16) {
+    // Civil-from-days algorithm by H. Hinnant, with years starting on March 1st
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy_march = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let march_based_month = (5 * doy_march + 2) / 153;
+
+    if march_based_month < 10 {
+        let year = yoe + era * 400;
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        (year as i32, (doy_march + 60 + i64::from(leap)) as i16)
+    } else {
+        ((yoe + era * 400 + 1) as i32, (doy_march - 305) as i16)
+    }
+}
+
+/// Adds the 'year' and 'doy' (UTC) columns derived from 'epoch', when requested as
+/// partitions but missing from the DataFrame
+fn add_date_columns(df: &mut DataFrame, partition_by: &[String]) -> PolarsResult<()> {
+    let missing: Vec<&str> = ["year", "doy"]
+        .into_iter()
+        .filter(|c| partition_by.iter().any(|p| p == c) && df.column(c).is_err())
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let epoch_col = df.column("epoch")?;
+    let units_per_day = units_per_second(epoch_col.dtype()) * 86_400;
+    let epochs = epoch_col.to_physical_repr();
+    let (years, doys): (Vec<Option<i32>>, Vec<Option<i16>>) = epochs
+        .i64()?
+        .into_iter()
+        .map(|t| match t {
+            Some(t) => {
+                let (year, doy) = year_and_doy(t.div_euclid(units_per_day));
+                (Some(year), Some(doy))
+            },
+            None => (None, None),
+        })
+        .unzip();
+
+    if missing.contains(&"year") {
+        df.with_column(Series::new("year".into(), years))?;
+    }
+    if missing.contains(&"doy") {
+        df.with_column(Series::new("doy".into(), doys))?;
+    }
+    Ok(())
+}
+
+/// Escapes the characters that would break a 'key=value' directory name
+fn escape_partition_value(value: Option<&str>) -> String {
+    match value {
+        None => NULL_PARTITION.to_string(),
+        Some(v) => v
+            .replace('%', "%25")
+            .replace('/', "%2F")
+            .replace('\\', "%5C")
+            .replace('=', "%3D"),
+    }
+}
+
+/// Lists the Parquet files of a partition directory
+fn parquet_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "parquet") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Writes a DataFrame as a Hive-partitioned Parquet dataset
+///
+/// Parameters:
+///     df (PyDataFrame): DataFrame to write
+///     root (str): Root directory of the dataset
+///     partition_by (list[str]): Partition columns, nested in this order; 'year' and
+///         'doy' are derived from the 'epoch' column (UTC) if missing
+///     existing (str): Policy for partitions that already contain Parquet files:
+///         'error', 'overwrite' (replace them) or 'append' (add a new file)
+///     compression (str): 'zstd', 'snappy', 'lz4', 'gzip' or 'uncompressed'
+///
+/// Returns:
+///     list[str]: Paths of the written files
+#[pyfunction]
+#[pyo3(signature = (df, root, partition_by, existing="overwrite", compression="zstd"))]
+pub(crate) fn write_dataset(
+    df: PyDataFrame,
+    root: &str,
+    partition_by: Vec<String>,
+    existing: &str,
+    compression: &str,
+) -> PyResult<Vec<String>> {
+    let policy = ExistingPolicy::parse(existing)?;
+    let compression = parse_compression(compression)?;
+    if partition_by.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "At least one partition column is required",
+        ));
+    }
+
+    let mut df = df.0;
+    add_date_columns(&mut df, &partition_by).map_err(polars_err)?;
+
+    // Row indices of each partition, keyed by the escaped partition values
+    let keys = partition_by
+        .iter()
+        .map(|c| df.column(c)?.cast(&DataType::String))
+        .collect::<PolarsResult<Vec<_>>>()
+        .map_err(polars_err)?;
+    let keys = keys
+        .iter()
+        .map(|c| c.str().cloned())
+        .collect::<PolarsResult<Vec<_>>>()
+        .map_err(polars_err)?;
+    let mut partitions: BTreeMap<Vec<String>, Vec<IdxSize>> = BTreeMap::new();
+    for row in 0..df.height() {
+        let values = keys.iter().map(|k| escape_partition_value(k.get(row))).collect();
+        partitions.entry(values).or_default().push(row as IdxSize);
+    }
+
+    let dirs: Vec<PathBuf> = partitions
+        .keys()
+        .map(|values| {
+            partition_by
+                .iter()
+                .zip(values)
+                .fold(PathBuf::from(root), |dir, (k, v)| dir.join(format!("{}={}", k, v)))
+        })
+        .collect();
+
+    // Refuse before writing anything, so that the dataset is never left half-updated
+    if policy == ExistingPolicy::Error {
+        for dir in &dirs {
+            if !parquet_files(dir).map_err(io_err)?.is_empty() {
+                return Err(PyErr::new::<pyo3::exceptions::PyFileExistsError, _>(format!(
+                    "Partition already exists: {}",
+                    dir.display()
+                )));
+            }
+        }
+    }
+
+    let mut written = Vec::with_capacity(dirs.len());
+    for (dir, rows) in dirs.iter().zip(partitions.into_values()) {
+        fs::create_dir_all(dir).map_err(io_err)?;
+        let existing_files = parquet_files(dir).map_err(io_err)?;
+        if policy == ExistingPolicy::Overwrite {
+            for file in &existing_files {
+                fs::remove_file(file).map_err(io_err)?;
+            }
+        }
+
+        let path = (0..)
+            .map(|i| dir.join(format!("part-{}.parquet", i)))
+            .find(|p| !p.exists())
+            .expect("unbounded file index");
+
+        let mut part = df
+            .take(&IdxCa::from_vec("".into(), rows))
+            .map(|p| p.drop_many(&partition_by))
+            .map_err(polars_err)?;
+        let file = File::create(&path).map_err(io_err)?;
+        ParquetWriter::new(file)
+            .with_compression(compression)
+            .with_statistics(StatisticsOptions::full())
+            .finish(&mut part)
+            .map_err(polars_err)?;
+        written.push(path.display().to_string());
+    }
+
+    Ok(written)
+}
@@ -0,0 +1,232 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::frames::polars_err;
+
+/// Values of long-format observations keyed by (physical epoch, sv, observable); the
+/// first occurrence wins on duplicated entries
+fn keyed_values<'a>(
+    epochs: &Int64Chunked,
+    svs: &'a StringChunked,
+    codes: &'a StringChunked,
+    values: &Float64Chunked,
+) -> HashMap<(i64, &'a str, &'a str), f64> {
+    let mut keyed = HashMap::with_capacity(epochs.len());
+    for (((epoch, sv), code), value) in epochs.into_iter().zip(svs).zip(codes).zip(values) {
+        if let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) {
+            keyed.entry((epoch, sv, code)).or_insert(value);
+        }
+    }
+    keyed
+}
+
+/// Forms the between-station single differences of two long-format observation frames
+pub(crate) fn difference(a: &DataFrame, b: &DataFrame) -> PolarsResult<DataFrame> {
+    let epoch_dtype = a.column("epoch")?.dtype().clone();
+    let epochs_a = a.column("epoch")?.to_physical_repr();
+    // Epochs of the second station in the time unit of the first one
+    let epochs_b = b.column("epoch")?.cast(&epoch_dtype)?.to_physical_repr();
+    let values_a = a.column("value")?.cast(&DataType::Float64)?;
+    let values_b = b.column("value")?.cast(&DataType::Float64)?;
+
+    let (svs_b, codes_b) = (b.column("sv")?.str()?, b.column("observable")?.str()?);
+    let reference = keyed_values(epochs_b.i64()?, svs_b, codes_b, values_b.f64()?);
+
+    let mut epochs = Vec::new();
+    let mut svs = Vec::new();
+    let mut codes = Vec::new();
+    let mut differences = Vec::new();
+    let rows = epochs_a
+        .i64()?
+        .into_iter()
+        .zip(a.column("sv")?.str()?)
+        .zip(a.column("observable")?.str()?)
+        .zip(values_a.f64()?);
+    for (((epoch, sv), code), value) in rows {
+        let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) else {
+            continue;
+        };
+        if let Some(other) = reference.get(&(epoch, sv, code)) {
+            epochs.push(epoch);
+            svs.push(sv);
+            codes.push(code);
+            differences.push(value - other);
+        }
+    }
+
+    DataFrame::new(vec![
+        Series::new("epoch".into(), epochs).cast(&epoch_dtype)?.into(),
+        Series::new("sv".into(), svs).into(),
+        Series::new("observable".into(), codes).into(),
+        Series::new("value".into(), differences).into(),
+    ])
+}
+
+/// Observations of one epoch and constellation: value per (sv, observable)
+type EpochGroup<'a> = BTreeMap<&'a str, BTreeMap<&'a str, f64>>;
+
+/// Elevation of each satellite keyed by (physical epoch, sv)
+fn elevation_table<'a>(
+    epochs: &Int64Chunked,
+    svs: &'a StringChunked,
+    values: &Float64Chunked,
+) -> HashMap<(i64, &'a str), f64> {
+    let mut elevation = HashMap::new();
+    for ((epoch, sv), ele) in epochs.into_iter().zip(svs).zip(values) {
+        if let (Some(epoch), Some(sv), Some(ele)) = (epoch, sv, ele) {
+            if !ele.is_nan() {
+                elevation.entry((epoch, sv)).or_insert(ele);
+            }
+        }
+    }
+    elevation
+}
+
+/// Forms between-satellite differences of long-format observations (or of single
+/// differences) across a reference satellite per epoch and constellation: the fixed one
+/// of the constellation when it is observed, the highest one otherwise
+pub(crate) fn satellite_difference(
+    sd: &DataFrame,
+    elevations: Option<&DataFrame>,
+    fixed: &HashMap<char, String>,
+) -> PolarsResult<DataFrame> {
+    let epoch_dtype = sd.column("epoch")?.dtype().clone();
+    let epochs_sd = sd.column("epoch")?.to_physical_repr();
+    let values_sd = sd.column("value")?.cast(&DataType::Float64)?;
+
+    let (epochs_ele, values_ele);
+    let mut elevation = HashMap::new();
+    if let Some(e) = elevations {
+        epochs_ele = e.column("epoch")?.cast(&epoch_dtype)?.to_physical_repr();
+        values_ele = e.column("ele")?.cast(&DataType::Float64)?;
+        elevation = elevation_table(epochs_ele.i64()?, e.column("sv")?.str()?, values_ele.f64()?);
+    }
+
+    let mut groups: BTreeMap<(i64, char), EpochGroup> = BTreeMap::new();
+    let rows = epochs_sd
+        .i64()?
+        .into_iter()
+        .zip(sd.column("sv")?.str()?)
+        .zip(sd.column("observable")?.str()?)
+        .zip(values_sd.f64()?);
+    for (((epoch, sv), code), value) in rows {
+        let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) else {
+            continue;
+        };
+        let system = sv.chars().next().unwrap_or(' ');
+        groups.entry((epoch, system)).or_default().entry(sv).or_default().entry(code).or_insert(value);
+    }
+
+    let mut epochs = Vec::new();
+    let mut svs = Vec::new();
+    let mut references = Vec::new();
+    let mut codes = Vec::new();
+    let mut differences = Vec::new();
+    for ((epoch, system), group) in &groups {
+        let fixed_reference = fixed.get(system).and_then(|sv| group.get_key_value(sv.as_str())).map(|(sv, _)| *sv);
+        // Otherwise the highest satellite with a known elevation, the first in name order on ties
+        let Some(reference) = fixed_reference.or_else(|| {
+            group
+                .keys()
+                .filter_map(|sv| Some((*sv, *elevation.get(&(*epoch, *sv))?)))
+                .fold(None, |best: Option<(&str, f64)>, (sv, ele)| match best {
+                    Some((_, top)) if top >= ele => best,
+                    _ => Some((sv, ele)),
+                })
+                .map(|(sv, _)| sv)
+        }) else {
+            continue;
+        };
+        let reference_values = &group[reference];
+        for (sv, values) in group.iter().filter(|(sv, _)| **sv != reference) {
+            for (code, value) in values {
+                if let Some(reference_value) = reference_values.get(code) {
+                    epochs.push(*epoch);
+                    svs.push(*sv);
+                    references.push(reference);
+                    codes.push(*code);
+                    differences.push(value - reference_value);
+                }
+            }
+        }
+    }
+
+    DataFrame::new(vec![
+        Series::new("epoch".into(), epochs).cast(&epoch_dtype)?.into(),
+        Series::new("sv".into(), svs).into(),
+        Series::new("reference_sv".into(), references).into(),
+        Series::new("observable".into(), codes).into(),
+        Series::new("value".into(), differences).into(),
+    ])
+}
+
+/// Computes the between-station single differences of observations, aligned on
+/// (epoch, sv, observable)
+///
+/// Parameters:
+///     df (PyDataFrame): Observations of the first station, with columns 'epoch', 'sv',
+///         'observable', 'value'
+///     reference (PyDataFrame): Observations of the second (reference) station
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'observable', 'value' (first
+///     minus reference station), in the row order of the first station and restricted to
+///     the observations common to both
+#[pyfunction]
+pub(crate) fn single_differences(df: PyDataFrame, reference: PyDataFrame) -> PyResult<PyDataFrame> {
+    difference(&df.0, &reference.0)
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
+
+/// Computes double differences of between-station single differences, across a reference
+/// satellite chosen per epoch and constellation as the highest one
+///
+/// Parameters:
+///     df (PyDataFrame): Single differences with columns 'epoch', 'sv', 'observable', 'value'
+///     elevations (PyDataFrame): Satellite elevations with columns 'epoch', 'sv', 'ele'
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'reference_sv', 'observable',
+///     'value' (satellite minus reference satellite), sorted by epoch, constellation, sv
+///     and observable
+#[pyfunction]
+pub(crate) fn double_differences(df: PyDataFrame, elevations: PyDataFrame) -> PyResult<PyDataFrame> {
+    satellite_difference(&df.0, Some(&elevations.0), &HashMap::new())
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
+
+/// Computes between-satellite single differences of the observations of one receiver,
+/// across a reference satellite per epoch and constellation
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     elevations (PyDataFrame, optional): Satellite elevations with columns 'epoch', 'sv',
+///         'ele', to select the highest satellite as the reference
+///     reference (dict[str, str], optional): Fixed reference satellite per constellation
+///         (e.g. {'G': 'G05'}), used at the epochs where it is observed
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'reference_sv', 'observable',
+///     'value' (satellite minus reference satellite), sorted by epoch, constellation, sv
+///     and observable
+#[pyfunction]
+#[pyo3(signature = (df, elevations=None, reference=None))]
+pub(crate) fn between_satellite_differences(
+    df: PyDataFrame,
+    elevations: Option<PyDataFrame>,
+    reference: Option<HashMap<char, String>>,
+) -> PyResult<PyDataFrame> {
+    let fixed = reference.unwrap_or_default();
+    if elevations.is_none() && fixed.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "At least one of 'elevations' and 'reference' must be provided",
+        ));
+    }
+    satellite_difference(&df.0, elevations.as_ref().map(|e| &e.0), &fixed)
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
@@ -0,0 +1,152 @@
+use flate2::read::GzDecoder;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use rinex::prelude::{Epoch, TimeScale};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::frames::polars_err;
+use crate::gpst_micros;
+
+/// Special event record of an observation file (epoch flag 2 to 5), which the RINEX
+/// parser skips together with the header lines that may follow it
+pub(crate) struct EventRecord {
+    pub epoch: Option<i64>,
+    pub flag: u8,
+    pub records: Vec<String>,
+}
+
+/// Human readable description of an epoch flag
+fn event_name(flag: u8) -> &'static str {
+    match flag {
+        2 => "antenna_moving",
+        3 => "new_site_occupation",
+        4 => "header_information",
+        _ => "external_event",
+    }
+}
+
+/// Parses the (optional) date fields of an epoch line into Unix-like GPST microseconds;
+/// two-digit years are those of RINEX 2 (80-99 mapped to 19xx)
+fn parse_event_epoch(fields: &[&str]) -> Option<i64> {
+    let [y, m, d, hh, mm, ss] = fields else {
+        return None;
+    };
+    let mut year: i32 = y.parse().ok()?;
+    if year < 100 {
+        year += if year < 80 { 2000 } else { 1900 };
+    }
+    let (secs, frac) = ss.split_once('.').unwrap_or((ss, ""));
+    let nanos: u32 = format!("{:0<9}", frac).get(..9)?.parse().ok()?;
+    let epoch = Epoch::from_gregorian(
+        year,
+        m.parse().ok()?,
+        d.parse().ok()?,
+        hh.parse().ok()?,
+        mm.parse().ok()?,
+        secs.parse().ok()?,
+        nanos,
+        TimeScale::GPST,
+    );
+    Some(gpst_micros(epoch))
+}
+
+/// Recognises the epoch line of a special event, returning its epoch, flag and number
+/// of records that follow. Event lines only hold the (possibly blank) date, the flag and
+/// the record count, which tells them apart from observation lines.
+fn parse_event_line(line: &str, v3: bool) -> Option<(Option<i64>, u8, usize)> {
+    let body = match line.chars().next()? {
+        // CRINEX copies event lines verbatim, behind its own markers
+        '>' | '&' => &line[1..],
+        _ if v3 => return None,
+        _ => line,
+    };
+    let fields: Vec<&str> = body.split_whitespace().collect();
+    let (date, flag, count) = match fields.as_slice() {
+        [date @ .., flag, count] if date.is_empty() || date.len() == 6 => (date, flag, count),
+        _ => return None,
+    };
+    let flag: u8 = flag.parse().ok().filter(|f| (2..=5).contains(f))?;
+    let count: usize = count.parse().ok()?;
+    let epoch = parse_event_epoch(date);
+    if !date.is_empty() && epoch.is_none() {
+        return None;
+    }
+    Some((epoch, flag, count))
+}
+
+/// Scans the body of an observation file for special event records
+fn scan_events<R: BufRead>(reader: R) -> std::io::Result<Vec<EventRecord>> {
+    let mut lines = reader.lines();
+    let mut v3 = false;
+
+    for line in lines.by_ref() {
+        let line = line?;
+        if line.contains("RINEX VERSION / TYPE") && !line.contains("CRINEX") {
+            v3 = line.get(..9).and_then(|v| v.trim().parse::<f64>().ok()).is_some_and(|v| v >= 3.0);
+        }
+        if line.contains("END OF HEADER") {
+            break;
+        }
+    }
+
+    let mut events = Vec::new();
+    while let Some(line) = lines.next() {
+        let line = line?;
+        let Some((epoch, flag, count)) = parse_event_line(&line, v3) else {
+            continue;
+        };
+        let mut records = Vec::with_capacity(count);
+        for _ in 0..count {
+            match lines.next() {
+                Some(line) => records.push(line?.trim_end().to_string()),
+                None => break,
+            }
+        }
+        events.push(EventRecord { epoch, flag, records });
+    }
+    Ok(events)
+}
+
+/// Reads the special event records of a (plain, gzipped or CRINEX) observation file
+pub(crate) fn read_events(path: &Path) -> PyResult<Vec<EventRecord>> {
+    let file = File::open(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(format!("{}: {}", path.display(), e)))?;
+
+    let reader: Box<dyn Read> = if path.extension().is_some_and(|ext| ext == "gz") {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    scan_events(BufReader::new(reader)).map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+}
+
+/// Parses the special event records (epoch flags 2 to 5) of a RINEX observation file:
+/// antenna being moved, new site occupation, header information inserted in the body
+/// and external events
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file (plain, gzipped or CRINEX)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch' (null where not given), 'flag',
+///     'event', 'records' (the lines following the event, newline-separated)
+#[pyfunction]
+pub(crate) fn read_rinex_events(path: &str) -> PyResult<PyDataFrame> {
+    let events = read_events(Path::new(path))?;
+
+    let df = DataFrame::new(vec![
+        Series::new("epoch".into(), events.iter().map(|e| e.epoch).collect::<Vec<_>>())
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .map_err(polars_err)?
+            .into(),
+        Series::new("flag".into(), events.iter().map(|e| e.flag as u32).collect::<Vec<_>>()).into(),
+        Series::new("event".into(), events.iter().map(|e| event_name(e.flag)).collect::<Vec<_>>()).into(),
+        Series::new("records".into(), events.iter().map(|e| e.records.join("\n")).collect::<Vec<_>>()).into(),
+    ])
+    .map_err(polars_err)?;
+    Ok(PyDataFrame(df))
+}
+
@@ -0,0 +1,308 @@
+use polars::prelude::*;
+use pyo3_polars::derive::polars_expr;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::combinations::carrier_frequency;
+use crate::frames::units_per_second;
+
+fn same_length(a: &Series, b: &Series) -> PolarsResult<()> {
+    polars_ensure!(a.len() == b.len(), ShapeMismatch: "input Series must have the same length");
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct FrequencyKwargs {
+    glonass_channels: Option<HashMap<String, i32>>,
+}
+
+/// Carrier frequencies of observables, as tracked by the corresponding satellites
+///
+/// Inputs:
+///     observable: RINEX observable codes (e.g. 'L1C', 'C5Q')
+///     sv: Satellite identifiers (e.g. 'G01')
+///
+/// Kwargs:
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///
+/// Returns:
+///     Frequencies (Hz), null for unknown bands or GLONASS channels
+#[polars_expr(output_type=Float64)]
+fn gnss_frequency(inputs: &[Series], kwargs: FrequencyKwargs) -> PolarsResult<Series> {
+    let (observable, sv) = (&inputs[0], &inputs[1]);
+    same_length(observable, sv)?;
+    let channels = kwargs.glonass_channels.unwrap_or_default();
+    let observables = observable.str()?;
+    let svs = sv.str()?;
+
+    let out: Float64Chunked = observables
+        .into_iter()
+        .zip(svs)
+        .map(|(obs, sv)| {
+            let (obs, sv) = (obs?, sv?);
+            let system = sv.chars().next()?;
+            carrier_frequency(system, obs.chars().nth(1)?, channels.get(sv).copied())
+        })
+        .collect();
+    Ok(out.with_name(observable.name().clone()).into_series())
+}
+
+#[derive(Deserialize)]
+struct RateKwargs {
+    max_gap: Option<f64>,
+}
+
+/// Rate of change of TEC between consecutive epochs (ROT)
+///
+/// Inputs:
+///     tec: TEC values (TECU), in time order
+///     epoch: Epochs (Datetime)
+///
+/// Kwargs:
+///     max_gap (float, optional): Largest time step (s) over which the rate is
+///         computed; null across longer data gaps
+///
+/// Returns:
+///     ROT in TECU/min, null for the first epoch
+#[polars_expr(output_type=Float64)]
+fn tec_rate(inputs: &[Series], kwargs: RateKwargs) -> PolarsResult<Series> {
+    let (tec, epoch) = (&inputs[0], &inputs[1]);
+    same_length(tec, epoch)?;
+    let per_minute = 60.0 * units_per_second(epoch.dtype()) as f64;
+    let values = tec.cast(&DataType::Float64)?;
+    let values = values.f64()?;
+    let epochs = epoch.to_physical_repr();
+    let epochs = epochs.i64()?;
+
+    let mut previous: Option<(i64, f64)> = None;
+    let mut out = Vec::with_capacity(values.len());
+    for (t, v) in epochs.into_iter().zip(values) {
+        let current = t.zip(v);
+        let rot = previous.zip(current).and_then(|((t0, v0), (t1, v1))| {
+            let minutes = (t1 - t0) as f64 / per_minute;
+            let within_gap = kwargs.max_gap.is_none_or(|g| minutes * 60.0 <= g);
+            (minutes > 0.0 && within_gap).then(|| (v1 - v0) / minutes)
+        });
+        out.push(rot);
+        previous = current;
+    }
+    Ok(Series::new(tec.name().clone(), out))
+}
+
+#[derive(Deserialize)]
+struct MappingKwargs {
+    h_ipp: f64,
+    alpha: f64,
+    earth_radius: f64,
+}
+
+/// Single-layer ionospheric mapping function, converting slant TEC into vertical TEC
+///
+/// Inputs:
+///     elevation: Satellite elevation (degrees)
+///
+/// Kwargs:
+///     h_ipp (float): Height of the ionospheric shell (m)
+///     alpha (float): Scaling of the zenith angle, 1 for the thin-shell model and 0.9782
+///         for the modified single-layer model
+///     earth_radius (float): Mean Earth radius (m)
+///
+/// Returns:
+///     Slant-to-vertical factor, i.e. the cosine of the zenith angle at the IPP
+#[polars_expr(output_type=Float64)]
+fn tec_mapping(inputs: &[Series], kwargs: MappingKwargs) -> PolarsResult<Series> {
+    let MappingKwargs { h_ipp, alpha, earth_radius } = kwargs;
+    let ratio = earth_radius / (earth_radius + h_ipp);
+    let elevation = inputs[0].cast(&DataType::Float64)?;
+    let out: Float64Chunked = elevation
+        .f64()?
+        .apply_values(|el| (ratio * (alpha * (90.0 - el)).to_radians().sin()).asin().cos());
+    Ok(out.with_name("mapping".into()).into_series())
+}
+
+#[derive(Clone, Copy)]
+enum RollingStatistic {
+    Mean,
+    Std,
+    Detrend,
+}
+
+impl RollingStatistic {
+    fn parse(name: &str) -> PolarsResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "mean" => Ok(Self::Mean),
+            "std" => Ok(Self::Std),
+            "detrend" => Ok(Self::Detrend),
+            _ => polars_bail!(
+                InvalidOperation: "invalid rolling statistic '{}': expected 'mean', 'std' or 'detrend'",
+                name
+            ),
+        }
+    }
+}
+
+/// Value at the origin of the least-squares polynomial through the points, if determined
+fn polynomial_at_origin(x: &[f64], y: &[f64], degree: usize) -> Option<f64> {
+    let n = degree + 1;
+    if x.len() < n {
+        return None;
+    }
+    // Normal equations, augmented with the right-hand side
+    let mut system = vec![vec![0.0; n + 1]; n];
+    for (&xi, &yi) in x.iter().zip(y) {
+        let mut powers = vec![1.0; 2 * n - 1];
+        for k in 1..powers.len() {
+            powers[k] = powers[k - 1] * xi;
+        }
+        for (r, row) in system.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().take(n).enumerate() {
+                *cell += powers[r + c];
+            }
+            row[n] += powers[r] * yi;
+        }
+    }
+    // Gaussian elimination with partial pivoting
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&a, &b| system[a][col].abs().total_cmp(&system[b][col].abs()))?;
+        if system[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        system.swap(col, pivot);
+        let (upper, lower) = system.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (cell, &p) in row.iter_mut().zip(pivot_row).skip(col) {
+                *cell -= factor * p;
+            }
+        }
+    }
+    let mut coefficients = vec![0.0; n];
+    for r in (0..n).rev() {
+        let tail: f64 = (r + 1..n).map(|c| system[r][c] * coefficients[c]).sum();
+        coefficients[r] = (system[r][n] - tail) / system[r][r];
+    }
+    Some(coefficients[0])
+}
+
+/// Rolling window, with its extent before and after each epoch in physical epoch units
+struct Rolling {
+    before: i64,
+    after: i64,
+    center: bool,
+    statistic: RollingStatistic,
+    degree: usize,
+    ddof: usize,
+    min_samples: usize,
+}
+
+/// Rolling statistic over the time-ordered samples of a single arc
+fn roll_arc(epochs: &[i64], values: &[f64], rolling: &Rolling) -> Vec<Option<f64>> {
+    let Rolling { before, after, center, statistic, degree, ddof, min_samples } = *rolling;
+    // Sums are taken relative to the first value, limiting the cancellation of the variance
+    let offset = values.first().copied().unwrap_or(0.0);
+    let (mut lo, mut hi) = (0, 0);
+    let (mut sum, mut sum_sq) = (0.0, 0.0);
+    let mut out = Vec::with_capacity(values.len());
+    for (i, &t) in epochs.iter().enumerate() {
+        while hi < epochs.len() && epochs[hi] <= t + after {
+            let v = values[hi] - offset;
+            sum += v;
+            sum_sq += v * v;
+            hi += 1;
+        }
+        // Trailing windows exclude their left edge, centered windows include both edges
+        while epochs[lo] < t - before || (!center && epochs[lo] == t - before) {
+            let v = values[lo] - offset;
+            sum -= v;
+            sum_sq -= v * v;
+            lo += 1;
+        }
+        let count = hi - lo;
+        if count < min_samples.max(1) {
+            out.push(None);
+            continue;
+        }
+        let n = count as f64;
+        out.push(match statistic {
+            RollingStatistic::Mean => Some(sum / n + offset),
+            RollingStatistic::Std => (count > ddof)
+                .then(|| ((sum_sq - sum * sum / n) / (n - ddof as f64)).max(0.0).sqrt()),
+            RollingStatistic::Detrend => {
+                // Times scaled by the window, keeping the normal equations well conditioned
+                let scale = (before + after).max(1) as f64;
+                let x: Vec<f64> = epochs[lo..hi].iter().map(|&e| (e - t) as f64 / scale).collect();
+                polynomial_at_origin(&x, &values[lo..hi], degree).map(|fit| values[i] - fit)
+            },
+        });
+    }
+    out
+}
+
+#[derive(Deserialize)]
+struct RollingKwargs {
+    window: f64,
+    statistic: String,
+    degree: usize,
+    ddof: usize,
+    min_samples: usize,
+    center: bool,
+}
+
+/// Rolling statistics of time series within arcs, never mixing samples of different arcs
+///
+/// Inputs:
+///     values: Values (e.g. ROT, or phase), in any order
+///     epoch: Epochs (Datetime)
+///     arc: Arc identifiers; rows with a null identifier are left out
+///
+/// Kwargs:
+///     window (float): Length of the window (s), trailing (ending at each epoch) or centered
+///     statistic (str): 'mean', 'std' or 'detrend' (residual of a polynomial fit)
+///     degree (int): Degree of the polynomial of 'detrend'
+///     ddof (int): Delta degrees of freedom of 'std'
+///     min_samples (int): Smallest number of samples in a window giving a value
+///     center (bool): Whether the window is centered on each epoch
+///
+/// Returns:
+///     Rolling statistic, null for null values or arcs, or short windows
+#[polars_expr(output_type=Float64)]
+fn arc_rolling(inputs: &[Series], kwargs: RollingKwargs) -> PolarsResult<Series> {
+    let (values, epoch, arc) = (&inputs[0], &inputs[1], &inputs[2]);
+    same_length(values, epoch)?;
+    same_length(values, arc)?;
+    let RollingKwargs { window, statistic, degree, ddof, min_samples, center } = kwargs;
+    let statistic = RollingStatistic::parse(&statistic)?;
+    polars_ensure!(window > 0.0, InvalidOperation: "the rolling window must be positive");
+    let span = (window * units_per_second(epoch.dtype()) as f64).round() as i64;
+    let (before, after) = if center { (span / 2, span - span / 2) } else { (span, 0) };
+    let rolling = Rolling { before, after, center, statistic, degree, ddof, min_samples };
+
+    let data = values.cast(&DataType::Float64)?;
+    let data = data.f64()?;
+    let epochs = epoch.to_physical_repr();
+    let epochs = epochs.i64()?;
+    let arcs = arc.cast(&DataType::String)?;
+    let arcs = arcs.str()?;
+
+    // Rows of each arc with a valid value and epoch, in time order
+    let mut rows: HashMap<&str, Vec<(i64, usize)>> = HashMap::new();
+    for (i, ((a, t), v)) in arcs.into_iter().zip(epochs).zip(data).enumerate() {
+        if let (Some(a), Some(t), Some(v)) = (a, t, v) {
+            if v.is_finite() {
+                rows.entry(a).or_default().push((t, i));
+            }
+        }
+    }
+
+    let mut out: Vec<Option<f64>> = vec![None; data.len()];
+    for mut group in rows.into_values() {
+        group.sort_unstable();
+        let times: Vec<i64> = group.iter().map(|&(t, _)| t).collect();
+        let samples: Vec<f64> = group.iter().map(|&(_, i)| data.get(i).unwrap_or(f64::NAN)).collect();
+        for ((_, i), value) in group.into_iter().zip(roll_arc(&times, &samples, &rolling)) {
+            out[i] = value;
+        }
+    }
+    Ok(Series::new(values.name().clone(), out))
+}
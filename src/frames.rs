@@ -0,0 +1,300 @@
+use pyo3::prelude::*;
+use polars::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+use crate::combinations::constellation_name;
+
+/// Maps a Polars error onto a Python RuntimeError
+pub(crate) fn polars_err(e: PolarsError) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string())
+}
+
+/// Observations in long format ('epoch', 'sv', 'observable', 'value') pivoted
+/// on (epoch, sv) for a fixed list of observables
+pub(crate) struct PivotedObs {
+    /// Data type of the source epoch column, restored on output
+    pub epoch_dtype: DataType,
+    /// Physical epoch and SV, with one slot per requested observable
+    pub rows: BTreeMap<(i64, String), Vec<Option<f64>>>,
+}
+
+/// Pivots a long-format observation DataFrame on (epoch, sv), keeping only the
+/// requested observables; the first occurrence wins on duplicated entries
+pub(crate) fn pivot_observables(df: &DataFrame, observables: &[&str]) -> PolarsResult<PivotedObs> {
+    let epoch_col = df.column("epoch")?;
+    let epoch_dtype = epoch_col.dtype().clone();
+    let epoch_phys = epoch_col.to_physical_repr();
+    let value_col = df.column("value")?.cast(&DataType::Float64)?;
+
+    let epochs = epoch_phys.i64()?;
+    let svs = df.column("sv")?.str()?;
+    let codes = df.column("observable")?.str()?;
+    let values = value_col.f64()?;
+
+    let mut rows: BTreeMap<(i64, String), Vec<Option<f64>>> = BTreeMap::new();
+    for (((epoch, sv), code), value) in epochs.into_iter().zip(svs).zip(codes).zip(values) {
+        let (Some(epoch), Some(sv), Some(code)) = (epoch, sv, code) else {
+            continue;
+        };
+        let Some(slot) = observables.iter().position(|o| *o == code) else {
+            continue;
+        };
+        let entry = rows
+            .entry((epoch, sv.to_string()))
+            .or_insert_with(|| vec![None; observables.len()]);
+        if entry[slot].is_none() {
+            entry[slot] = value;
+        }
+    }
+
+    Ok(PivotedObs { epoch_dtype, rows })
+}
+
+/// Builds an ('epoch', 'sv', <name>) DataFrame from values keyed by (epoch, sv)
+pub(crate) fn keyed_frame(
+    epoch_dtype: &DataType,
+    keys: Vec<(i64, String)>,
+    columns: Vec<(&str, Vec<Option<f64>>)>,
+) -> PolarsResult<DataFrame> {
+    let (epochs, svs): (Vec<i64>, Vec<String>) = keys.into_iter().unzip();
+    let epoch_series = Series::new("epoch".into(), epochs).cast(epoch_dtype)?;
+
+    let mut cols: Vec<Column> = vec![epoch_series.into(), Series::new("sv".into(), svs).into()];
+    for (name, values) in columns {
+        cols.push(Series::new(name.into(), values).into());
+    }
+    DataFrame::new(cols)
+}
+
+/// Datetime column of physical epochs, with the logical type attached instead of
+/// casting an Int64 column (which would copy it)
+pub(crate) fn datetime_series(name: &str, epochs: Vec<i64>, unit: TimeUnit) -> Series {
+    Int64Chunked::from_vec(name.into(), epochs).into_datetime(unit, None).into_series()
+}
+
+/// Replaces the Datetime 'epoch' column by its physical Int64 timestamps
+pub(crate) fn physical_epochs(mut df: DataFrame) -> PolarsResult<DataFrame> {
+    let epochs = df.column("epoch")?.to_physical_repr();
+    df.with_column(epochs)?;
+    Ok(df)
+}
+
+/// Splits long-format observations by constellation, keyed by constellation name (the
+/// first letter of the satellite for unknown systems); rows keep their order
+pub(crate) fn partition_constellations(df: &DataFrame) -> PolarsResult<BTreeMap<String, DataFrame>> {
+    let svs = df.column("sv")?.str()?;
+    let mut partitions: BTreeMap<String, Vec<IdxSize>> = BTreeMap::new();
+    for (row, sv) in svs.into_iter().enumerate() {
+        let system = sv.and_then(|sv| sv.chars().next()).unwrap_or(' ');
+        let key = constellation_name(system).map_or_else(|| system.to_string(), String::from);
+        partitions.entry(key).or_default().push(row as IdxSize);
+    }
+    partitions
+        .into_iter()
+        .map(|(key, rows)| Ok((key, df.take(&IdxCa::from_vec("".into(), rows))?)))
+        .collect()
+}
+
+/// Duration column of physical time differences, built like `datetime_series`
+pub(crate) fn duration_series(name: &str, durations: Vec<i64>, unit: TimeUnit) -> Series {
+    Int64Chunked::from_vec(name.into(), durations).into_duration(unit).into_series()
+}
+
+/// Number of physical epoch units in one second, for a Datetime (or Duration) data type
+pub(crate) fn units_per_second(dtype: &DataType) -> i64 {
+    match dtype {
+        DataType::Datetime(TimeUnit::Nanoseconds, _) | DataType::Duration(TimeUnit::Nanoseconds) => {
+            1_000_000_000
+        },
+        DataType::Datetime(TimeUnit::Milliseconds, _) | DataType::Duration(TimeUnit::Milliseconds) => {
+            1_000
+        },
+        _ => 1_000_000,
+    }
+}
+
+/// Infers the nominal sampling interval (in physical epoch units) as the most frequent
+/// positive spacing between consecutive distinct epochs; ties favour the shortest spacing
+pub(crate) fn infer_interval(epochs: &[i64]) -> Option<i64> {
+    infer_interval_rounded(epochs, 1)
+}
+
+/// Like `infer_interval`, with the spacings rounded to a multiple of `resolution` (in
+/// physical epoch units) before counting, so that jittered epochs agree on their spacing
+pub(crate) fn infer_interval_rounded(epochs: &[i64], resolution: i64) -> Option<i64> {
+    let resolution = resolution.max(1);
+    let mut sorted: Vec<i64> = epochs.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    let mut counts: BTreeMap<i64, usize> = BTreeMap::new();
+    for w in sorted.windows(2) {
+        let dt = (w[1] - w[0] + resolution / 2) / resolution * resolution;
+        if dt > 0 {
+            *counts.entry(dt).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(dt, _)| dt)
+}
+
+/// What to do with repeated (epoch, sv, observable) entries of long-format observations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DuplicatePolicy {
+    Error,
+    First,
+    Last,
+    Keep,
+}
+
+impl DuplicatePolicy {
+    /// Parses a policy name ('error', 'first', 'last' or 'keep')
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "error" => Ok(Self::Error),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "keep" => Ok(Self::Keep),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported duplicates policy '{}': expected 'error', 'first', 'last' or 'keep'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Applies a duplicates policy to long-format observations, preserving the row order;
+/// with `Error`, a ValueError reports the number of repeated entries
+pub(crate) fn handle_duplicates(df: DataFrame, policy: DuplicatePolicy) -> PyResult<DataFrame> {
+    let subset = ["epoch".to_string(), "sv".to_string(), "observable".to_string()];
+    let keep = match policy {
+        DuplicatePolicy::Keep => return Ok(df),
+        DuplicatePolicy::Last => UniqueKeepStrategy::Last,
+        DuplicatePolicy::Error | DuplicatePolicy::First => UniqueKeepStrategy::First,
+    };
+    let unique = df.unique_stable(Some(&subset), keep, None).map_err(polars_err)?;
+    if policy == DuplicatePolicy::Error && unique.height() < df.height() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Found {} duplicated (epoch, sv, observable) entries",
+            df.height() - unique.height()
+        )));
+    }
+    Ok(unique)
+}
+
+/// Table of the distinct labels of a repeated key (e.g., satellites or observables):
+/// rows store the index of their label, so that string columns are built without
+/// formatting and allocating a string per row
+pub(crate) struct Interner<K> {
+    indices: HashMap<K, IdxSize>,
+    labels: Vec<String>,
+}
+
+impl<K: Hash + Eq + Clone + Display> Interner<K> {
+    pub(crate) fn new() -> Self {
+        Self { indices: HashMap::new(), labels: Vec::new() }
+    }
+
+    /// Index of the label of a key, formatted on its first occurrence
+    pub(crate) fn index(&mut self, key: &K) -> IdxSize {
+        if let Some(&index) = self.indices.get(key) {
+            return index;
+        }
+        let index = self.labels.len() as IdxSize;
+        self.labels.push(key.to_string());
+        self.indices.insert(key.clone(), index);
+        index
+    }
+
+    /// String column of the labels of the given indices
+    pub(crate) fn column(&self, name: &str, indices: Vec<IdxSize>) -> PolarsResult<Series> {
+        Series::new(name.into(), &self.labels).take(&IdxCa::from_vec("".into(), indices))
+    }
+}
+
+/// Approximate peak memory per long-format observation row while the DataFrame is
+/// built: the intermediate vectors and the columns
+pub(crate) const OBS_ROW_BYTES: usize = 128;
+
+/// What to do when a file exceeds the row or memory limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LimitPolicy {
+    Error,
+    Truncate,
+}
+
+impl LimitPolicy {
+    /// Parses a policy name ('error' or 'truncate')
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "error" => Ok(Self::Error),
+            "truncate" => Ok(Self::Truncate),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported limit policy '{}': expected 'error' or 'truncate'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Maximum number of rows allowed by a row limit and a memory limit (in MB, at
+/// `row_bytes` per row), or None without limits
+pub(crate) fn row_budget(max_rows: Option<usize>, max_memory_mb: Option<f64>, row_bytes: usize) -> Option<usize> {
+    let memory_rows = max_memory_mb.map(|mb| (mb.max(0.0) * 1_048_576.0 / row_bytes as f64) as usize);
+    match (max_rows, memory_rows) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Number of leading epochs whose rows fit in the budget: all of them if the file is
+/// within the limits, otherwise a ValueError with `Error` or a warning with `Truncate`
+pub(crate) fn epochs_within_budget(
+    py: Python<'_>,
+    rows_per_epoch: impl Iterator<Item = usize>,
+    budget: usize,
+    policy: LimitPolicy,
+    source: &str,
+) -> PyResult<usize> {
+    let counts: Vec<usize> = rows_per_epoch.collect();
+    let total: usize = counts.iter().sum();
+    if total <= budget {
+        return Ok(counts.len());
+    }
+    if policy == LimitPolicy::Error {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "{} has {} observations, exceeding the limit of {} rows",
+            source, total, budget
+        )));
+    }
+    let mut rows = 0;
+    let epochs = counts.iter().take_while(|&&n| {
+        rows += n;
+        rows <= budget
+    }).count();
+    let kept: usize = counts[..epochs].iter().sum();
+    let message = format!(
+        "{} truncated to its first {} epochs ({} of {} rows) to stay within the limits",
+        source, epochs, kept, total
+    );
+    let message = std::ffi::CString::new(message).unwrap_or_default();
+    PyErr::warn(py, &py.get_type::<pyo3::exceptions::PyUserWarning>(), &message, 1)?;
+    Ok(epochs)
+}
+
+/// Sorts a DataFrame by the given key columns (stable), flagging the leading epoch
+/// column as sorted
+pub(crate) fn sort_by_keys(df: DataFrame, keys: &[&str]) -> PolarsResult<DataFrame> {
+    let keys: Vec<&str> = keys.iter().copied().filter(|k| df.get_column_index(k).is_some()).collect();
+    let mut sorted = df.sort(keys, SortMultipleOptions::default().with_maintain_order(true))?;
+    if let Some(idx) = sorted.get_column_index("epoch") {
+        let mut epoch = sorted.get_columns()[idx].clone();
+        epoch.set_sorted_flag(polars::series::IsSorted::Ascending);
+        sorted.replace_column(idx, epoch)?;
+    }
+    Ok(sorted)
+}
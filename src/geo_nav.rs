@@ -0,0 +1,75 @@
+use rinex::prelude::*;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::text::{field, open_text, Lines};
+
+/// Type and system of the RINEX 3 header the records are rewritten under
+const V3_HEADER: &str = "     3.04           N: GNSS NAV DATA    S: SBAS             RINEX VERSION / TYPE";
+const END_OF_HEADER: &str = "END OF HEADER";
+
+/// Rewrites the first line of a RINEX 2 GEO record (I2,5(1X,I2),F5.1,3D19.12) in the
+/// RINEX 3 SBAS layout (A1,I2.2,1X,I4,5(1X,I2.2),3D19.12), or None if it is malformed
+fn v3_record_line(line: &[u8]) -> Option<String> {
+    let prn: u8 = field(line, 0, 2).parse().ok()?;
+    let mut date = [0u32; 5];
+    for (i, value) in date.iter_mut().enumerate() {
+        *value = field(line, 3 + 3 * i, 5 + 3 * i).parse().ok()?;
+    }
+    let seconds: f64 = field(line, 17, 22).parse().ok()?;
+    // Two-digit years: 80-99 are 1980-1999, 00-79 are 2000-2079
+    let year = if date[0] < 80 { 2000 + date[0] } else { 1900 + date[0] };
+    let clock = std::str::from_utf8(line.get(22..)?).ok()?;
+    Some(format!(
+        "S{prn:02} {year:04} {:02} {:02} {:02} {:02} {:02}{clock}",
+        date[1],
+        date[2],
+        date[3],
+        date[4],
+        seconds.round() as u32
+    ))
+}
+
+/// Parses a RINEX 2 GEO navigation file ('H: GEO NAV MSG DATA'), which the rinex crate
+/// does not identify, by rewriting its records as the equivalent RINEX 3 SBAS records
+/// (same broadcast parameters, GPS time epochs); returns None for other files
+pub(crate) fn parse_geo_nav(path: &Path) -> Option<Result<Rinex, ParsingError>> {
+    let mut lines = Lines::new(open_text(path).ok()?);
+    if !lines.next().ok()? || lines.label() != "RINEX VERSION / TYPE" {
+        return None;
+    }
+    let version: f64 = field(&lines.line, 0, 9).parse().ok()?;
+    if version >= 3.0 || field(&lines.line, 20, 21) != "H" {
+        return None;
+    }
+
+    let mut text = format!("{V3_HEADER}\n{:60}{END_OF_HEADER}\n", "");
+    let mut in_header = true;
+    // Continuation lines of a malformed record are dropped along with it
+    let mut skipping = false;
+    while lines.next().ok()? {
+        if in_header {
+            in_header = lines.label() != END_OF_HEADER;
+            continue;
+        }
+        if field(&lines.line, 0, 3).is_empty() {
+            if !skipping {
+                text.push(' ');
+                text.push_str(std::str::from_utf8(&lines.line).unwrap_or(""));
+                text.push('\n');
+            }
+            continue;
+        }
+        match v3_record_line(&lines.line) {
+            Some(record) => {
+                skipping = false;
+                text.push_str(&record);
+                text.push('\n');
+            },
+            None => skipping = true,
+        }
+    }
+
+    let mut reader = BufReader::new(text.as_bytes());
+    Some(Rinex::parse(&mut reader))
+}
@@ -0,0 +1,231 @@
+use pyo3::prelude::*;
+use pyo3_polars::PySeries;
+use polars::prelude::*;
+
+use crate::frames::polars_err;
+
+/// WGS-84 semi-major axis (m)
+pub(crate) const WGS84_A: f64 = 6_378_137.0;
+/// WGS-84 flattening
+pub(crate) const WGS84_F: f64 = 1.0 / 298.257_223_563;
+
+/// Converts ECEF coordinates (m) into WGS-84 geodetic latitude, longitude (radians)
+/// and ellipsoidal height (m), using Bowring's method refined by fixed-point iteration
+pub(crate) fn ecef_to_geodetic(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let lon = y.atan2(x);
+    let p = x.hypot(y);
+
+    if p < 1e-9 {
+        let b = WGS84_A * (1.0 - WGS84_F);
+        let lat = std::f64::consts::FRAC_PI_2.copysign(z);
+        return (lat, lon, z.abs() - b);
+    }
+
+    let mut lat = z.atan2(p * (1.0 - e2));
+    let mut h = 0.0;
+    for _ in 0..10 {
+        let sin_lat = lat.sin();
+        let n = WGS84_A / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+        h = p / lat.cos() - n;
+        let next = z.atan2(p * (1.0 - e2 * n / (n + h)));
+        let converged = (next - lat).abs() < 1e-12;
+        lat = next;
+        if converged {
+            break;
+        }
+    }
+    (lat, lon, h)
+}
+
+/// Converts WGS-84 geodetic latitude, longitude (radians) and ellipsoidal height (m)
+/// into ECEF coordinates (m)
+pub(crate) fn geodetic_to_ecef(lat: f64, lon: f64, h: f64) -> (f64, f64, f64) {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let (sin_lon, cos_lon) = lon.sin_cos();
+    let n = WGS84_A / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+    (
+        (n + h) * cos_lat * cos_lon,
+        (n + h) * cos_lat * sin_lon,
+        (n * (1.0 - e2) + h) * sin_lat,
+    )
+}
+
+/// Rotates an ECEF difference vector into the local East-North-Up frame of a site,
+/// given its geodetic latitude and longitude (radians)
+pub(crate) fn ecef_to_enu(dx: f64, dy: f64, dz: f64, lat: f64, lon: f64) -> (f64, f64, f64) {
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let (sin_lon, cos_lon) = lon.sin_cos();
+    let e = -sin_lon * dx + cos_lon * dy;
+    let n = -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz;
+    let u = cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz;
+    (e, n, u)
+}
+
+/// Rotates a local East-North-Up vector of a site into an ECEF difference vector,
+/// given its geodetic latitude and longitude (radians)
+pub(crate) fn enu_to_ecef(e: f64, n: f64, u: f64, lat: f64, lon: f64) -> (f64, f64, f64) {
+    let (sin_lat, cos_lat) = lat.sin_cos();
+    let (sin_lon, cos_lon) = lon.sin_cos();
+    let dx = -sin_lon * e - sin_lat * cos_lon * n + cos_lat * cos_lon * u;
+    let dy = cos_lon * e - sin_lat * sin_lon * n + cos_lat * sin_lon * u;
+    let dz = cos_lat * n + sin_lat * u;
+    (dx, dy, dz)
+}
+
+/// Observation geometry of a target seen from a site: receiver geodetic coordinates
+/// are precomputed once, since they are shared by every satellite and epoch
+pub(crate) struct Site {
+    pub ecef: (f64, f64, f64),
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl Site {
+    pub(crate) fn new(ecef: (f64, f64, f64)) -> Self {
+        let (lat, lon, _) = ecef_to_geodetic(ecef.0, ecef.1, ecef.2);
+        Self { ecef, lat, lon }
+    }
+
+    /// Azimuth and elevation (degrees) of an ECEF target, azimuth clockwise from north
+    pub(crate) fn azimuth_elevation(&self, target: [f64; 3]) -> (f64, f64) {
+        let (e, n, u) = ecef_to_enu(
+            target[0] - self.ecef.0,
+            target[1] - self.ecef.1,
+            target[2] - self.ecef.2,
+            self.lat,
+            self.lon,
+        );
+        let azimuth = e.atan2(n).to_degrees().rem_euclid(360.0);
+        let elevation = u.atan2(e.hypot(n)).to_degrees();
+        (azimuth, elevation)
+    }
+}
+
+/// Reads three Series as Float64 arrays of equal length
+fn float_triplet(a: &Series, b: &Series, c: &Series) -> PyResult<[Float64Chunked; 3]> {
+    if a.len() != b.len() || a.len() != c.len() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Coordinate Series must have the same length",
+        ));
+    }
+    let cast = |s: &Series| -> PyResult<Float64Chunked> {
+        Ok(s.cast(&DataType::Float64).map_err(polars_err)?.f64().map_err(polars_err)?.clone())
+    };
+    Ok([cast(a)?, cast(b)?, cast(c)?])
+}
+
+/// Applies a point-wise coordinate transformation to three Float64 arrays, propagating
+/// nulls, and returns three named Series
+fn transform_triplet(
+    inputs: [Float64Chunked; 3],
+    names: [&str; 3],
+    f: impl Fn(f64, f64, f64) -> (f64, f64, f64),
+) -> (PySeries, PySeries, PySeries) {
+    let mut out: [Vec<Option<f64>>; 3] = Default::default();
+    for ((a, b), c) in inputs[0].into_iter().zip(&inputs[1]).zip(&inputs[2]) {
+        let r = match (a, b, c) {
+            (Some(a), Some(b), Some(c)) => Some(f(a, b, c)),
+            _ => None,
+        };
+        out[0].push(r.map(|r| r.0));
+        out[1].push(r.map(|r| r.1));
+        out[2].push(r.map(|r| r.2));
+    }
+    let [a, b, c] = out;
+    (
+        PySeries(Series::new(names[0].into(), a)),
+        PySeries(Series::new(names[1].into(), b)),
+        PySeries(Series::new(names[2].into(), c)),
+    )
+}
+
+/// Converts ECEF coordinates into WGS-84 geodetic coordinates
+///
+/// Parameters:
+///     x, y, z (PySeries): ECEF coordinates (m)
+///
+/// Returns:
+///     tuple[PySeries, PySeries, PySeries]: 'lat', 'lon' (degrees) and 'height' (m)
+#[pyfunction]
+#[pyo3(name = "ecef_to_geodetic")]
+pub(crate) fn py_ecef_to_geodetic(x: PySeries, y: PySeries, z: PySeries) -> PyResult<(PySeries, PySeries, PySeries)> {
+    let inputs = float_triplet(&x.0, &y.0, &z.0)?;
+    Ok(transform_triplet(inputs, ["lat", "lon", "height"], |x, y, z| {
+        let (lat, lon, h) = ecef_to_geodetic(x, y, z);
+        (lat.to_degrees(), lon.to_degrees(), h)
+    }))
+}
+
+/// Converts WGS-84 geodetic coordinates into ECEF coordinates
+///
+/// Parameters:
+///     lat, lon (PySeries): Geodetic latitude and longitude (degrees)
+///     height (PySeries): Ellipsoidal height (m)
+///
+/// Returns:
+///     tuple[PySeries, PySeries, PySeries]: 'x', 'y', 'z' ECEF coordinates (m)
+#[pyfunction]
+#[pyo3(name = "geodetic_to_ecef")]
+pub(crate) fn py_geodetic_to_ecef(lat: PySeries, lon: PySeries, height: PySeries) -> PyResult<(PySeries, PySeries, PySeries)> {
+    let inputs = float_triplet(&lat.0, &lon.0, &height.0)?;
+    Ok(transform_triplet(inputs, ["x", "y", "z"], |lat, lon, h| {
+        geodetic_to_ecef(lat.to_radians(), lon.to_radians(), h)
+    }))
+}
+
+/// Converts ECEF coordinates into the local East-North-Up frame of a reference site
+///
+/// Parameters:
+///     x, y, z (PySeries): ECEF coordinates (m), or ECEF velocities (m/s) with `velocity`
+///     reference (tuple[float, float, float]): ECEF position of the reference site (m)
+///     velocity (bool): If true, the inputs are vectors (e.g. velocities) that are only
+///         rotated into the local frame, without subtracting the site position
+///
+/// Returns:
+///     tuple[PySeries, PySeries, PySeries]: 'east', 'north', 'up' components
+#[pyfunction]
+#[pyo3(name = "ecef_to_enu", signature = (x, y, z, reference, velocity=false))]
+pub(crate) fn py_ecef_to_enu(
+    x: PySeries,
+    y: PySeries,
+    z: PySeries,
+    reference: (f64, f64, f64),
+    velocity: bool,
+) -> PyResult<(PySeries, PySeries, PySeries)> {
+    let inputs = float_triplet(&x.0, &y.0, &z.0)?;
+    let site = Site::new(reference);
+    let origin = if velocity { (0.0, 0.0, 0.0) } else { reference };
+    Ok(transform_triplet(inputs, ["east", "north", "up"], |x, y, z| {
+        ecef_to_enu(x - origin.0, y - origin.1, z - origin.2, site.lat, site.lon)
+    }))
+}
+
+/// Converts local East-North-Up components of a reference site into ECEF coordinates
+///
+/// Parameters:
+///     east, north, up (PySeries): Local components (m), or velocities (m/s) with `velocity`
+///     reference (tuple[float, float, float]): ECEF position of the reference site (m)
+///     velocity (bool): If true, the inputs are only rotated into the ECEF frame, without
+///         adding the site position
+///
+/// Returns:
+///     tuple[PySeries, PySeries, PySeries]: 'x', 'y', 'z' ECEF components
+#[pyfunction]
+#[pyo3(name = "enu_to_ecef", signature = (east, north, up, reference, velocity=false))]
+pub(crate) fn py_enu_to_ecef(
+    east: PySeries,
+    north: PySeries,
+    up: PySeries,
+    reference: (f64, f64, f64),
+    velocity: bool,
+) -> PyResult<(PySeries, PySeries, PySeries)> {
+    let inputs = float_triplet(&east.0, &north.0, &up.0)?;
+    let site = Site::new(reference);
+    let origin = if velocity { (0.0, 0.0, 0.0) } else { reference };
+    Ok(transform_triplet(inputs, ["x", "y", "z"], |e, n, u| {
+        let (dx, dy, dz) = enu_to_ecef(e, n, u, site.lat, site.lon);
+        (origin.0 + dx, origin.1 + dy, origin.2 + dz)
+    }))
+}
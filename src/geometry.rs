@@ -0,0 +1,135 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use std::collections::HashMap;
+
+use crate::frames::{polars_err, units_per_second};
+use crate::geodesy::{ecef_to_geodetic, Site};
+use crate::orbits::Ephemerides;
+
+/// Geometry of a satellite seen from the receiver at one epoch
+#[derive(Clone, Copy)]
+struct Geometry {
+    position: [f64; 3],
+    azimuth: f64,
+    elevation: f64,
+    /// IPP latitude and longitude (degrees), if the line of sight crosses the shell
+    ipp: Option<(f64, f64)>,
+    mapping: f64,
+}
+
+/// Thin-shell model of the ionosphere
+#[derive(Clone, Copy)]
+pub(crate) struct Shell {
+    /// Height of the shell above the mean Earth radius (m)
+    pub(crate) height: f64,
+    /// Mean Earth radius (m)
+    pub(crate) earth_radius: f64,
+    /// Scaling of the zenith angle of the mapping function
+    pub(crate) mapping_alpha: f64,
+}
+
+/// Intersection of the receiver-satellite segment with the ionospheric shell, closest to
+/// the receiver
+fn pierce_point(site: &Site, target: [f64; 3], shell_radius: f64) -> Option<[f64; 3]> {
+    let origin = [site.ecef.0, site.ecef.1, site.ecef.2];
+    let d = [target[0] - origin[0], target[1] - origin[1], target[2] - origin[2]];
+    let a = d.iter().map(|v| v * v).sum::<f64>();
+    let b = 2.0 * d.iter().zip(&origin).map(|(v, o)| v * o).sum::<f64>();
+    let c = origin.iter().map(|o| o * o).sum::<f64>() - shell_radius * shell_radius;
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 || a == 0.0 {
+        return None;
+    }
+    let sqrt_disc = discriminant.sqrt();
+    let t = [(-b - sqrt_disc) / (2.0 * a), (-b + sqrt_disc) / (2.0 * a)]
+        .into_iter()
+        .filter(|t| (0.0..=1.0).contains(t))
+        .reduce(f64::min)?;
+    Some([origin[0] + d[0] * t, origin[1] + d[1] * t, origin[2] + d[2] * t])
+}
+
+/// Appends the satellite position, azimuth, elevation, IPP coordinates and mapping function
+/// to every row of long-format observations, computing the geometry once per (epoch, sv)
+pub(crate) fn annotate(
+    df: &DataFrame,
+    ephemerides: &Ephemerides,
+    site: &Site,
+    shell: Shell,
+) -> PolarsResult<DataFrame> {
+    let epoch_col = df.column("epoch")?;
+    let scale = units_per_second(epoch_col.dtype()) as f64;
+    let epochs = epoch_col.to_physical_repr();
+    let shell_radius = shell.earth_radius + shell.height;
+
+    let mut cache: HashMap<(i64, &str), Option<Geometry>> = HashMap::new();
+    let rows = epochs.i64()?.into_iter().zip(df.column("sv")?.str()?);
+    let geometries: Vec<Option<Geometry>> = rows
+        .map(|(epoch, sv)| {
+            let (epoch, sv) = (epoch?, sv?);
+            *cache.entry((epoch, sv)).or_insert_with(|| {
+                let position = ephemerides.position(sv, epoch as f64 / scale)?;
+                let (azimuth, elevation) = site.azimuth_elevation(position);
+                let ipp = pierce_point(site, position, shell_radius).map(|p| {
+                    let (lat, lon, _) = ecef_to_geodetic(p[0], p[1], p[2]);
+                    (lat.to_degrees(), lon.to_degrees())
+                });
+                // Thin-shell (or modified single-layer) mapping function, as cos(z')
+                let zenith = (shell.mapping_alpha * (90.0 - elevation)).to_radians();
+                let mapping = (shell.earth_radius / shell_radius * zenith.sin()).asin().cos();
+                Some(Geometry { position, azimuth, elevation, ipp, mapping })
+            })
+        })
+        .collect();
+
+    let column = |name: &str, value: &dyn Fn(&Geometry) -> Option<f64>| -> Column {
+        Series::new(name.into(), geometries.iter().map(|g| g.as_ref().and_then(value)).collect::<Vec<_>>())
+            .into()
+    };
+    let mut out = df.clone();
+    out.hstack_mut(&[
+        column("sat_x", &|g| Some(g.position[0])),
+        column("sat_y", &|g| Some(g.position[1])),
+        column("sat_z", &|g| Some(g.position[2])),
+        column("azi", &|g| Some(g.azimuth)),
+        column("ele", &|g| Some(g.elevation)),
+        column("lat_ipp", &|g| g.ipp.map(|(lat, _)| lat)),
+        column("lon_ipp", &|g| g.ipp.map(|(_, lon)| lon)),
+        column("mapping", &|g| Some(g.mapping)),
+    ])?;
+    Ok(out)
+}
+
+/// Annotates observations with their geometry from broadcast ephemerides, in one pass
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch' (GPST) and 'sv'
+///     nav (dict[str, PyDataFrame]): Navigation data keyed by constellation, as from `read_rinex_nav`
+///     rx_position (tuple[float, float, float]): Receiver ECEF coordinates (in meters)
+///     h_ipp (float): Height of the ionospheric shell (in meters)
+///     mapping_alpha (float): Scaling of the zenith angle of the mapping function (1 for
+///         the thin-shell model)
+///     earth_radius (float): Mean Earth radius (in meters)
+///
+/// Returns:
+///     PyDataFrame: The observations with added columns 'sat_x', 'sat_y', 'sat_z' (m),
+///     'azi', 'ele' (degrees), 'lat_ipp', 'lon_ipp' (degrees) and 'mapping', null where
+///     no ephemeris is available (and for the IPP, where the line of sight does not
+///     cross the shell)
+#[pyfunction]
+pub(crate) fn annotate_geometry(
+    df: PyDataFrame,
+    nav: HashMap<String, PyDataFrame>,
+    rx_position: (f64, f64, f64),
+    h_ipp: f64,
+    mapping_alpha: f64,
+    earth_radius: f64,
+) -> PyResult<PyDataFrame> {
+    let nav: HashMap<String, DataFrame> = nav.into_iter().map(|(k, v)| (k, v.0)).collect();
+    let ephemerides = Ephemerides::from_frames(&nav).map_err(polars_err)?;
+    let site = Site::new(rx_position);
+    let shell = Shell { height: h_ipp, earth_radius, mapping_alpha };
+    annotate(&df.0, &ephemerides, &site, shell)
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
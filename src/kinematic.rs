@@ -0,0 +1,175 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use rinex::prelude::*;
+use std::path::Path;
+
+use crate::events::{read_events, EventRecord};
+use crate::frames::{polars_err, units_per_second};
+use crate::{_parse_file, gpst_micros};
+
+/// Marker position in force from a given epoch onwards, as set by the event records
+struct PositionChange {
+    epoch: i64,
+    position: Option<(f64, f64, f64)>,
+    kinematic: bool,
+}
+
+/// Extracts the 'APPROX POSITION XYZ' record among the lines following an event
+fn approx_position(records: &[String]) -> Option<(f64, f64, f64)> {
+    let line = records.iter().find(|l| l.contains("APPROX POSITION XYZ"))?;
+    let values: Vec<f64> = line
+        .get(..42)
+        .unwrap_or(line)
+        .split_whitespace()
+        .map(|v| v.parse().ok())
+        .collect::<Option<_>>()?;
+    match values.as_slice() {
+        [x, y, z] => Some((*x, *y, *z)),
+        _ => None,
+    }
+}
+
+/// Position changes implied by the event records: an antenna being moved (flag 2) starts
+/// a kinematic segment with unknown position, which ends at the next new site occupation
+/// (flag 3); the new position, as well as any position inserted in the body as header
+/// information (flag 4), is taken from the 'APPROX POSITION XYZ' record. Events without
+/// an epoch cannot be placed in time and are ignored.
+fn position_changes(events: &[EventRecord]) -> Vec<PositionChange> {
+    let mut changes = Vec::new();
+    let mut kinematic = false;
+    for event in events {
+        let Some(epoch) = event.epoch else {
+            continue;
+        };
+        let position = approx_position(&event.records);
+        match event.flag {
+            2 => kinematic = true,
+            3 => kinematic = false,
+            4 if position.is_some() => {},
+            _ => continue,
+        }
+        changes.push(PositionChange {
+            epoch,
+            position: if event.flag == 2 { None } else { position },
+            kinematic,
+        });
+    }
+    changes
+}
+
+/// Linearly interpolates an external trajectory, sorted by epoch, at a given epoch;
+/// epochs outside the trajectory span give no position
+fn interpolate(trajectory: &[(i64, [f64; 3])], epoch: i64) -> Option<(f64, f64, f64)> {
+    let i = trajectory.partition_point(|(t, _)| *t < epoch);
+    let (t1, p1) = trajectory.get(i)?;
+    if *t1 == epoch {
+        return Some((p1[0], p1[1], p1[2]));
+    }
+    let (t0, p0) = trajectory.get(i.checked_sub(1)?)?;
+    let w = (epoch - t0) as f64 / (t1 - t0) as f64;
+    let lerp = |k: usize| p0[k] + w * (p1[k] - p0[k]);
+    Some((lerp(0), lerp(1), lerp(2)))
+}
+
+/// Reads an external trajectory with columns 'epoch', 'x', 'y', 'z' (ECEF, meters) into
+/// Unix-like GPST microseconds, sorted by epoch
+fn read_trajectory(df: &DataFrame) -> PolarsResult<Vec<(i64, [f64; 3])>> {
+    let epoch_col = df.column("epoch")?;
+    let scale = units_per_second(epoch_col.dtype());
+    let epochs = epoch_col.to_physical_repr();
+    let coords = ["x", "y", "z"]
+        .iter()
+        .map(|c| df.column(c)?.cast(&DataType::Float64))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let (x, y, z) = (coords[0].f64()?, coords[1].f64()?, coords[2].f64()?);
+
+    let mut trajectory: Vec<(i64, [f64; 3])> = epochs
+        .i64()?
+        .into_iter()
+        .zip(x)
+        .zip(y)
+        .zip(z)
+        .filter_map(|(((t, x), y), z)| Some((t? * 1_000_000 / scale, [x?, y?, z?])))
+        .collect();
+    trajectory.sort_by_key(|(t, _)| *t);
+    Ok(trajectory)
+}
+
+/// Returns the marker position at every observation epoch of a RINEX file, for kinematic
+/// (e.g. shipborne or airborne) as well as static occupations
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file
+///     trajectory (PyDataFrame, optional): External positions with columns 'epoch', 'x',
+///         'y', 'z' (ECEF, meters), linearly interpolated at the observation epochs
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch', 'x', 'y', 'z' (null where unknown),
+///     'kinematic' (the antenna is being moved) and 'source' ('header', 'event' or
+///     'trajectory')
+#[pyfunction]
+#[pyo3(signature = (path, trajectory=None))]
+pub(crate) fn read_rinex_positions(path: &str, trajectory: Option<PyDataFrame>) -> PyResult<PyDataFrame> {
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(
+            format!("File not found: {}", path.display())
+        ));
+    }
+
+    let rinex = _parse_file(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("RINEX parsing error: {}", e)
+        ))?;
+    let Record::ObsRecord(obs_data) = &rinex.record else {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Not an OBS file"));
+    };
+
+    let mut epochs: Vec<i64> = obs_data.keys().map(|k| gpst_micros(k.epoch)).collect();
+    epochs.sort_unstable();
+    epochs.dedup();
+
+    let changes = position_changes(&read_events(path)?);
+    let trajectory = match trajectory {
+        Some(df) => read_trajectory(&df.0).map_err(polars_err)?,
+        None => Vec::new(),
+    };
+
+    let header = rinex.header.rx_position;
+    let n = epochs.len();
+    let (mut xs, mut ys, mut zs) = (Vec::with_capacity(n), Vec::with_capacity(n), Vec::with_capacity(n));
+    let mut kinematic = Vec::with_capacity(n);
+    let mut sources = Vec::with_capacity(n);
+
+    for &epoch in &epochs {
+        let applied = changes.partition_point(|c| c.epoch <= epoch);
+        let (event_position, moving, source) = match applied {
+            0 => (header, false, "header"),
+            i => (changes[i - 1].position, changes[i - 1].kinematic, "event"),
+        };
+        let (position, source) = match interpolate(&trajectory, epoch) {
+            Some(p) => (Some(p), "trajectory"),
+            None => (event_position, source),
+        };
+        xs.push(position.map(|p| p.0));
+        ys.push(position.map(|p| p.1));
+        zs.push(position.map(|p| p.2));
+        kinematic.push(moving);
+        sources.push(source);
+    }
+
+    let df = DataFrame::new(vec![
+        Series::new("epoch".into(), epochs)
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .map_err(polars_err)?
+            .into(),
+        Series::new("x".into(), xs).into(),
+        Series::new("y".into(), ys).into(),
+        Series::new("z".into(), zs).into(),
+        Series::new("kinematic".into(), kinematic).into(),
+        Series::new("source".into(), sources).into(),
+    ])
+    .map_err(polars_err)?;
+    Ok(PyDataFrame(df))
+}
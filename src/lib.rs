@@ -1,45 +1,274 @@
 use pyo3::prelude::*;
 use pyo3_polars::PyDataFrame;
 use rinex::prelude::*;
+use rinex::observation::{EpochFlag, ObsKey, Observations, SNR};
+use rayon::prelude::*;
 use polars::prelude::*;
 use std::path::Path;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::Instant;
 
-/// Constant offset between J1900 (hifitime default) and Unix Epoch (1970) in microseconds,
+mod binex;
+mod bounds;
+mod cli;
+mod clock_jumps;
+mod combinations;
+mod dataset;
+mod differences;
+mod events;
+mod expressions;
+mod frames;
+mod geo_nav;
+mod geodesy;
+mod geometry;
+mod kinematic;
+mod options;
+mod orbits;
+mod phase_shift;
+mod qc;
+mod records;
+mod rtcm;
+mod sbf;
+mod scale_factor;
+mod signals;
+mod site;
+mod stats;
+mod tasks;
+mod text;
+mod timescales;
+mod ubx;
+
+/// Allocations go through the allocator of Polars, which releases the Series returned by
+/// the expression plugins; the test harness, without an interpreter, keeps the system one
+#[cfg(not(test))]
+#[global_allocator]
+static ALLOC: pyo3_polars::PolarsAllocator = pyo3_polars::PolarsAllocator::new();
+
+/// Constant offset between J1900 (hifitime default) and Unix Epoch (1970) in nanoseconds,
 /// including the 19s constant offset between TAI and GPST.
 /// This ensures RINEX epochs align with the "round" 00/30s grid in Polars/Unix time.
-const UNIX_GPST_OFFSET_MICROS: i64 = 2_208_988_819_000_000;
+const UNIX_GPST_OFFSET_NANOS: i128 = 2_208_988_819_000_000_000;
+
+/// Converts an epoch into Unix-like GPST microseconds, bypassing UTC leap second
+/// adjustments to preserve the original GPST grid; sub-microsecond digits are truncated
+pub(crate) fn gpst_micros(epoch: Epoch) -> i64 {
+    gpst_nanos(epoch).div_euclid(1_000)
+}
+
+/// Converts an epoch into Unix-like GPST nanoseconds with integer arithmetic, preserving
+/// the full precision of the epoch
+pub(crate) fn gpst_nanos(epoch: Epoch) -> i64 {
+    (epoch.to_duration_since_j1900().total_nanoseconds() - UNIX_GPST_OFFSET_NANOS) as i64
+}
 
 /// Helper function to read a RINEX file (supports regular, compressed, and gzipped RINEX files)
-fn _parse_file<P: AsRef<Path>>(path: P) -> Result<Rinex, ParsingError> {
+pub(crate) fn _parse_file<P: AsRef<Path>>(path: P) -> Result<Rinex, ParsingError> {
     let path = path.as_ref();
 
     // 1. Try with gzip, if file has .gz extension
-    if path.extension().map_or(false, |ext| ext == "gz") {
+    if path.extension().is_some_and(|ext| ext == "gz") {
         if let Ok(rinex) = Rinex::from_gzip_file(path) {
             return Ok(rinex);
         }
         // If gzip fails, continue to try regular parsing
     }
 
-    // 2. Try regular file parsing (works for both .rnx and .crx)
+    // 2. RINEX 2 GEO navigation files are not identified by the rinex crate: their
+    //    records are rewritten as RINEX 3 SBAS records
+    if let Some(rinex) = geo_nav::parse_geo_nav(path) {
+        return rinex;
+    }
+
+    // 3. Try regular file parsing (works for both .rnx and .crx)
     Rinex::from_file(path)
 }
 
+/// Parses the header and leading epochs of an observation file: the first `bytes` of its
+/// (decompressed) text
+fn parse_prefix(path: &Path, bytes: u64) -> std::io::Result<Result<Rinex, ParsingError>> {
+    let mut reader = std::io::BufReader::new(std::io::Read::take(text::open_text(path)?, bytes));
+    Ok(Rinex::parse(&mut reader))
+}
+
+
+/// Numeric RINEX epoch flag (0: OK, 1: power failure, 2-5: events, 6: cycle slip records)
+fn epoch_flag_value(flag: EpochFlag) -> u32 {
+    match flag {
+        EpochFlag::Ok => 0,
+        EpochFlag::PowerFailure => 1,
+        EpochFlag::AntennaBeingMoved => 2,
+        EpochFlag::NewSiteOccupation => 3,
+        EpochFlag::HeaderInformationFollows => 4,
+        EpochFlag::ExternalEvent => 5,
+        EpochFlag::CycleSlip => 6,
+    }
+}
+
+/// RINEX signal strength indicator digit (1: minimum, 5: threshold of a good S/N,
+/// 9: maximum), or None for 0 (unknown)
+fn ssi_digit(snr: SNR) -> Option<u32> {
+    match snr {
+        SNR::DbHz0 => None,
+        SNR::DbHz12 => Some(1),
+        SNR::DbHz12_17 => Some(2),
+        SNR::DbHz18_23 => Some(3),
+        SNR::DbHz24_29 => Some(4),
+        SNR::DbHz30_35 => Some(5),
+        SNR::DbHz36_41 => Some(6),
+        SNR::DbHz42_47 => Some(7),
+        SNR::DbHz48_53 => Some(8),
+        SNR::DbHz54 => Some(9),
+    }
+}
+
+/// Typical number of satellites tracked at once, per constellation
+fn tracked_satellites(constellation: Constellation) -> usize {
+    match constellation {
+        Constellation::GPS => 10,
+        Constellation::Glonass | Constellation::Galileo => 8,
+        Constellation::BeiDou => 12,
+        Constellation::Mixed => 30,
+        _ => 3,
+    }
+}
+
+/// Estimates the number of observation rows from the header: epochs spanned at the
+/// sampling interval (at most `n_epochs`, the epochs actually parsed), times the
+/// observables declared for the satellites typically tracked in each constellation
+fn estimate_obs_rows(header: &Header, n_epochs: usize) -> usize {
+    let Some(obs) = header.obs.as_ref() else {
+        return 0;
+    };
+    let spanned = match (obs.timeof_first_obs, obs.timeof_last_obs, header.sampling_interval) {
+        (Some(first), Some(last), Some(interval)) if interval.total_nanoseconds() > 0 => {
+            ((last - first).total_nanoseconds() / interval.total_nanoseconds()).max(0) as usize + 1
+        },
+        _ => n_epochs,
+    };
+    let per_epoch: usize = obs
+        .codes
+        .iter()
+        .map(|(constellation, codes)| codes.len() * tracked_satellites(*constellation))
+        .sum();
+    spanned.min(n_epochs) * per_epoch
+}
+
+/// Flattens a run of observation epochs into a long-format DataFrame ('epoch', 'sv',
+/// 'observable', 'value', 'epoch_flag', 'rx_clock_offset' and, when snapping, 'snap_offset',
+/// with `ssi`, 'ssi')
+#[allow(clippy::too_many_arguments)]
+fn flatten_obs(
+    entries: &[(&ObsKey, &Observations)],
+    scale: timescales::OutputScale,
+    unit: TimeUnit,
+    snap_step: Option<i64>,
+    ssi: bool,
+    filter: &options::SignalFilter,
+    capacity: usize,
+) -> PolarsResult<DataFrame> {
+    let mut epochs = Vec::with_capacity(capacity);
+    let mut prns = Vec::with_capacity(capacity);
+    let mut codes = Vec::with_capacity(capacity);
+    let mut values = Vec::with_capacity(capacity);
+    let mut flags = Vec::with_capacity(capacity);
+    let mut clock_offsets = Vec::with_capacity(capacity);
+    let mut sv_labels = frames::Interner::new();
+    let mut observable_labels = frames::Interner::new();
+    let mut snap_offsets = Vec::with_capacity(if snap_step.is_some() { capacity } else { 0 });
+    let mut ssi_digits = Vec::with_capacity(if ssi { capacity } else { 0 });
+
+    for (obs_key, observations) in entries {
+        let mut ts = timescales::epoch_timestamp(obs_key.epoch, scale, unit);
+        let mut snap_offset = 0;
+        if let Some(step) = snap_step {
+            (ts, snap_offset) = timescales::snap_to_grid(ts, step);
+        }
+        let flag = epoch_flag_value(obs_key.flag);
+        let clock_offset = observations.clock.as_ref().map(|clock| clock.offset_s);
+
+        for signal in observations.signals.iter().filter(|signal| filter.keep_signal(signal)) {
+            epochs.push(ts);
+            prns.push(sv_labels.index(&signal.sv));
+            codes.push(observable_labels.index(&signal.observable));
+            values.push(signal.value);
+            flags.push(flag);
+            clock_offsets.push(clock_offset);
+            if snap_step.is_some() {
+                snap_offsets.push(snap_offset);
+            }
+            if ssi {
+                ssi_digits.push(signal.snr.and_then(ssi_digit));
+            }
+        }
+    }
+
+    let mut df = DataFrame::new(vec![
+        frames::datetime_series("epoch", epochs, unit).into(),
+        sv_labels.column("sv", prns)?.into(),
+        observable_labels.column("observable", codes)?.into(),
+        Series::new("value".into(), values).into(),
+        Series::new("epoch_flag".into(), flags).into(),
+        Series::new("rx_clock_offset".into(), clock_offsets).into(),
+    ])?;
+    if snap_step.is_some() {
+        df.with_column(frames::duration_series("snap_offset", snap_offsets, unit))?;
+    }
+    if ssi {
+        df.with_column(Series::new("ssi".into(), ssi_digits))?;
+    }
+    Ok(df)
+}
+
+/// Observations, receiver position, RINEX version and parse statistics of `read_rinex_obs`
+type ObsOutput = (PyDataFrame, (f64, f64, f64), String, stats::ParseStats);
 
 /// Parses a RINEX observation file and returns the extracted observation data as a DataFrame
 ///
 /// Parameters:
 ///     path (str): Path to the RINEX observation file
+///     options (ReadOptions, optional): Output, filter and limit options (see `ReadOptions`);
+///         with `geometry_free`, signals are paired internally and the geometry-free
+///         phase (L4) and code (P4) combinations are returned instead of the raw observables
 ///
 /// Returns:
 ///     tuple:
-///         - PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'observable', 'value'
+///         - PyDataFrame: A DataFrame with columns 'epoch', 'sv', 'observable', 'value',
+///           'epoch_flag', 'rx_clock_offset' (in seconds, null if not recorded), with
+///           `ssi`, 'ssi' (signal strength indicator digit, null if not recorded)
+///           (or 'epoch', 'sv', 'gflc_phase', 'gflc_code' in TECu, with `geometry_free`)
 ///         - tuple[float, float, float]: Receiver's position in ECEF coordinates (in meters)
 ///         - str: RINEX version
+///         - ParseStats: Statistics of the epochs and signals read
 #[pyfunction]
-#[pyo3(text_signature = "(path, /)")]
-fn read_rinex_obs(path: &str) -> PyResult<(PyDataFrame, (f64, f64, f64), String)> {
+#[pyo3(signature = (path, options=None))]
+fn read_rinex_obs(
+    py: Python<'_>,
+    path: &str,
+    options: Option<options::ReadOptions>,
+) -> PyResult<ObsOutput> {
+    let started = Instant::now();
+    let options = options.unwrap_or_default();
+    let duplicates = frames::DuplicatePolicy::parse(&options.duplicates)?;
+    let on_limit = frames::LimitPolicy::parse(&options.on_limit)?;
+    let scale = timescales::OutputScale::parse(&options.time_scale)?;
+    // Integer epoch types fix the unit of their timestamps
+    let integer_epochs = timescales::parse_epoch_dtype(&options.epoch_dtype)?;
+    let unit = match integer_epochs {
+        Some(unit) => unit,
+        None => timescales::parse_time_unit(&options.time_unit)?,
+    };
+    let value_dtype = options::parse_value_dtype(&options.value_dtype)?;
+    let phase_shift_policy = phase_shift::PhaseShiftPolicy::parse(&options.phase_shift)?;
+    let phase_unit = combinations::PhaseUnit::parse(&options.phase_unit)?;
+    let snap_step = options
+        .snap_to
+        .as_deref()
+        .map(|interval| timescales::parse_interval(interval).map(|nanos| match unit {
+            TimeUnit::Nanoseconds => nanos,
+            _ => (nanos / 1_000).max(1),
+        }))
+        .transpose()?;
+    let filter = options::SignalFilter::new(&options, unit)?;
     let path = Path::new(path);
     
     if !path.exists() {
@@ -48,7 +277,33 @@ fn read_rinex_obs(path: &str) -> PyResult<(PyDataFrame, (f64, f64, f64), String)
         ));
     }
 
-    let rinex = _parse_file(path)
+    // The record of the whole file is held in memory while parsing, before the filters:
+    // with limits, the values of each epoch are counted first, so that files exceeding
+    // them are rejected, or only their leading epochs within the limits are parsed
+    let row_budget = frames::row_budget(options.max_rows, options.max_memory_mb, frames::OBS_ROW_BYTES);
+    let mut prefix_bytes = None;
+    if let Some(budget) = row_budget {
+        let epochs = py
+            .allow_threads(|| bounds::epoch_values(path))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("RINEX parsing error: {}", e)))?;
+        let n_epochs = frames::epochs_within_budget(
+            py,
+            epochs.iter().map(|(values, _)| *values),
+            budget,
+            on_limit,
+            &path.display().to_string(),
+        )?;
+        prefix_bytes = epochs.get(n_epochs).map(|(_, offset)| *offset);
+    }
+
+    // Parsing dominates the cost and touches no Python objects: release the GIL,
+    // so that several files can be read concurrently from Python threads
+    let rinex = py
+        .allow_threads(|| match prefix_bytes {
+            Some(bytes) => parse_prefix(path, bytes),
+            None => Ok(_parse_file(path)),
+        })
+        .and_then(|parsed| parsed.map_err(std::io::Error::other))
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
             format!("RINEX parsing error: {}", e)
         ))?;
@@ -59,44 +314,152 @@ fn read_rinex_obs(path: &str) -> PyResult<(PyDataFrame, (f64, f64, f64), String)
 
     let (x, y, z) = rinex.header.rx_position.unwrap_or((f64::NAN, f64::NAN, f64::NAN));
     let version = rinex.header.version.to_string();
+    let naming = combinations::BandNaming::from_version(rinex.header.version);
 
-    let est_capacity = 250_000;
-    let mut epochs = Vec::with_capacity(est_capacity);
-    let mut prns = Vec::with_capacity(est_capacity);
-    let mut codes = Vec::with_capacity(est_capacity);
-    let mut values = Vec::with_capacity(est_capacity);
-
-    match &rinex.record {
-        Record::ObsRecord(obs_data) => {
-            for (obs_key, observations) in obs_data.iter() {
-                // Bypass UTC leap second adjustments to preserve original GPST grid.
-                let total_micros = (obs_key.epoch.to_duration_since_j1900().to_seconds() * 1_000_000.0) as i64;
-                let ts = total_micros - UNIX_GPST_OFFSET_MICROS;
-
-                for signal in &observations.signals {
-                    epochs.push(ts);
-                    prns.push(signal.sv.to_string());
-                    codes.push(signal.observable.to_string());
-                    values.push(signal.value);
-                }
-            }
-        },
+    let obs_data = match &rinex.record {
+        Record::ObsRecord(obs_data) => obs_data,
         _ => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("No obs data")),
+    };
+    let mut entries: Vec<(&ObsKey, &Observations)> = obs_data
+        .iter()
+        .filter(|(obs_key, _)| filter.keep_epoch(timescales::epoch_timestamp(obs_key.epoch, scale, unit)))
+        .collect();
+    if let Some(budget) = row_budget {
+        let n_epochs = frames::epochs_within_budget(
+            py,
+            entries.iter().map(|(_, observations)| {
+                observations.signals.iter().filter(|signal| filter.keep_signal(signal)).count()
+            }),
+            budget,
+            on_limit,
+            &path.display().to_string(),
+        )?;
+        entries.truncate(n_epochs);
     }
 
-    let epoch_series = Series::new("epoch".into(), epochs)
-        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    // Epochs are flattened in parallel runs, each with its own builders, then stacked
+    let n_epochs = entries.len();
+    let rows_per_epoch = estimate_obs_rows(&rinex.header, n_epochs) / n_epochs.max(1);
+    let run_len = n_epochs.div_ceil(4 * rayon::current_num_threads()).max(1);
+    let df = py
+        .allow_threads(|| {
+            let runs = entries
+                .par_chunks(run_len)
+                .map(|run| {
+                    flatten_obs(run, scale, unit, snap_step, options.ssi, &filter, rows_per_epoch * run.len())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut runs = runs.into_iter();
+            let mut df = match runs.next() {
+                Some(df) => df,
+                None => flatten_obs(&[], scale, unit, snap_step, options.ssi, &filter, 0)?,
+            };
+            for run in runs {
+                df.vstack_mut_owned(run)?;
+            }
+            df.as_single_chunk_par();
+            Ok(df)
+        })
+        .map_err(frames::polars_err)?;
+    let mut df = frames::handle_duplicates(df, duplicates)?;
+    // Recorded values are the observations multiplied by the declared scale factors
+    let scale_factors = scale_factor::read_scale_factors(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("RINEX parsing error: {}", e)))?;
+    let (scaled_df, scaled_observables) =
+        scale_factor::apply_scale_factors(df, &scale_factors).map_err(frames::polars_err)?;
+    df = scaled_df;
+    if phase_shift_policy != phase_shift::PhaseShiftPolicy::Keep {
+        let shifts = phase_shift::read_phase_shifts(path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("RINEX parsing error: {}", e)))?;
+        df = phase_shift::apply_phase_shifts(df, &shifts, phase_shift_policy).map_err(frames::polars_err)?;
+    }
+
+    let mut svs: HashSet<SV> = HashSet::new();
+    for (_, observations) in &entries {
+        svs.extend(observations.signals.iter().filter(|signal| filter.keep_signal(signal)).map(|signal| signal.sv));
+    }
+    let mut svs_per_constellation: BTreeMap<String, usize> = BTreeMap::new();
+    for sv in svs {
+        let key = combinations::system_letter(sv.constellation)
+            .map_or_else(|| sv.constellation.to_string(), String::from);
+        *svs_per_constellation.entry(key).or_default() += 1;
+    }
+    let epochs: Vec<i64> = entries
+        .iter()
+        .map(|(obs_key, _)| timescales::epoch_timestamp(obs_key.epoch, scale, unit))
+        .collect();
+    let bytes = std::fs::metadata(path).map(|m| m.len()).unwrap_or_default();
+    let signals = df.height();
+    let header_interval = rinex
+        .header
+        .sampling_interval
+        .map(|dt| chrono::TimeDelta::nanoseconds(dt.total_nanoseconds() as i64));
+    let parse_stats = || {
+        stats::ParseStats::new(
+            &epochs,
+            unit,
+            header_interval,
+            signals,
+            svs_per_constellation,
+            bytes,
+            started.elapsed().as_secs_f64(),
+        )
+        .with_scaled_observables(scaled_observables)
+    };
+
+    let glonass_channels: HashMap<String, i32> = rinex
+        .header
+        .glo_channels
+        .iter()
+        .map(|(sv, k)| (sv.to_string(), i32::from(*k)))
+        .collect();
+    if options.geometry_free {
+        let codes = rinex.header.obs.as_ref().map(|obs| obs.codes.clone()).unwrap_or_default();
+        let mut gf = combinations::geometry_free_from_header(&df, &codes, &glonass_channels, naming)
+            .map_err(frames::polars_err)?;
+        if options.sort {
+            gf = frames::sort_by_keys(gf, &["epoch", "sv"]).map_err(frames::polars_err)?;
+        }
+        if integer_epochs.is_some() {
+            gf = frames::physical_epochs(gf).map_err(frames::polars_err)?;
+        }
+        return Ok((PyDataFrame(gf), (x, y, z), version, parse_stats()));
+    }
+
+    if phase_unit == combinations::PhaseUnit::Meters {
+        df = combinations::phases_to_meters(df, &glonass_channels, naming).map_err(frames::polars_err)?;
+    }
+    let mut df = if options.sort {
+        frames::sort_by_keys(df, &["epoch", "sv", "observable"]).map_err(frames::polars_err)?
+    } else {
+        df
+    };
+    if value_dtype != DataType::Float64 {
+        let values = df.column("value").and_then(|c| c.cast(&value_dtype)).map_err(frames::polars_err)?;
+        df.with_column(values).map_err(frames::polars_err)?;
+    }
+    if integer_epochs.is_some() {
+        df = frames::physical_epochs(df).map_err(frames::polars_err)?;
+    }
+    Ok((PyDataFrame(df), (x, y, z), version, parse_stats()))
+}
 
-    let df = DataFrame::new(vec![
-        epoch_series.into(),
-        Series::new("sv".into(), prns).into(),
-        Series::new("observable".into(), codes).into(),
-        Series::new("value".into(), values).into(),
-    ])
-    .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
 
-    Ok((PyDataFrame(df), (x, y, z), version))
+/// Splits long-format observations into a dictionary of DataFrames, one per constellation
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with an 'sv' column, as returned by `read_rinex_obs`
+///
+/// Returns:
+///     dict[str, PyDataFrame]: Observations keyed by constellation name, as the
+///     DataFrames of `read_rinex_nav` ('GPS', 'GLONASS', 'GALILEO', 'BEIDOU', plus
+///     'QZSS', 'IRNSS' and 'SBAS'), with the rows in their original order
+#[pyfunction]
+fn split_constellations(py: Python<'_>, df: PyDataFrame) -> PyResult<BTreeMap<String, PyDataFrame>> {
+    let parts = py
+        .allow_threads(|| frames::partition_constellations(&df.0))
+        .map_err(frames::polars_err)?;
+    Ok(parts.into_iter().map(|(key, part)| (key, PyDataFrame(part))).collect())
 }
 
 
@@ -105,19 +468,42 @@ fn read_rinex_obs(path: &str) -> PyResult<(PyDataFrame, (f64, f64, f64), String)
 ///
 /// Parameters:
 ///     path (str): Path to the RINEX navigation file
+///     time_scale (str, optional): Timescale of the output epochs (see `read_rinex_obs`);
+///         by default, epochs are given in the timescale of each constellation
+///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+///     sort (bool): If true, sort each DataFrame by epoch and SV
 ///
 /// Returns:
 ///     dict[str, PyDataFrame]: A dictionary where keys are GNSS constellation names
-///     (e.g., "GPS", "Galileo") and values are DataFrames containing navigation parameters
+///     (e.g., "GPS", "Galileo", "SBAS") and values are DataFrames containing navigation
+///     parameters, along with the continuous GPS week and time of week ('gps_week', 'tow')
+///     and their constellation-native counterparts ('native_week', 'native_tow': GST and
+///     BDT weeks, null for GLONASS and SBAS), the decoded broadcast accuracy ('ura_index', 'ura') and the
+///     validity window of each record ('fit_interval', 'valid_from', 'valid_until'), and
+///     its clock and orbit reference times ('toc', 'toe_epoch')
 #[pyfunction]
-#[pyo3(text_signature = "(path, /)")]
-fn read_rinex_nav(path: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
+#[pyo3(signature = (path, time_scale=None, time_unit="us", sort=false))]
+fn read_rinex_nav(
+    py: Python<'_>,
+    path: &str,
+    time_scale: Option<&str>,
+    time_unit: &str,
+    sort: bool,
+) -> PyResult<BTreeMap<String, PyDataFrame>> {
+    let scale = time_scale.map(timescales::OutputScale::parse).transpose()?;
+    let unit = timescales::parse_time_unit(time_unit)?;
     let path_obj = Path::new(path);
-    let rinex = _parse_file(path_obj).map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("{}", e)))?;
+    // Release the GIL while parsing, as read_rinex_obs
+    let rinex = py
+        .allow_threads(|| _parse_file(path_obj))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("{}", e)))?;
 
     let mut storage: BTreeMap<String, BTreeMap<String, Vec<Option<f64>>>> = BTreeMap::new();
     let mut constellation_times: BTreeMap<String, Vec<i64>> = BTreeMap::new();
     let mut constellation_svs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    // GPS week, TOW and (if any) native week and TOW of each record
+    type WeekTimes = Vec<(i64, f64, Option<(i64, f64)>)>;
+    let mut constellation_weeks: BTreeMap<String, WeekTimes> = BTreeMap::new();
 
     for (nav_key, ephemeris) in rinex.nav_ephemeris_frames_iter() {
         let constel = match nav_key.sv.constellation {
@@ -127,15 +513,24 @@ fn read_rinex_nav(path: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
             Constellation::BeiDou => "BEIDOU",
             // Constellation::QZSS => "QZSS",
             // Constellation::IRNSS => "IRNSS",
-            // Constellation::SBAS => "SBAS",
+            c if c.is_sbas() => "SBAS",
             // _ => "OTHER", // Uncomment to include other constellations
             _ => continue, // Skip unsupported constellations
         }.to_string();
         
         let (y, m, d, hh, mm, ss, ns) = nav_key.epoch.to_gregorian(nav_key.epoch.time_scale);
         let forced_epoch = Epoch::from_gregorian(y, m, d, hh, mm, ss, ns, TimeScale::GPST);
-        let total_micros = (forced_epoch.to_duration_since_j1900().to_seconds() * 1_000_000.0) as i64;
-        let ts = total_micros - UNIX_GPST_OFFSET_MICROS;
+        let native_ts = gpst_micros(forced_epoch);
+        let ts = match scale {
+            Some(scale) => timescales::epoch_timestamp(nav_key.epoch, scale, unit),
+            None => timescales::epoch_timestamp(forced_epoch, timescales::OutputScale::Gpst, unit),
+        };
+
+        // GPS week and TOW refer to true GPST (e.g. BDT and GLONASS UTC are shifted),
+        // the native ones to the constellation's own timescale
+        let (gps_week, tow) = timescales::gps_week_and_tow(gpst_micros(nav_key.epoch.to_time_scale(TimeScale::GPST)));
+        let native = timescales::native_week_and_tow(native_ts, nav_key.sv.constellation);
+        constellation_weeks.entry(constel.clone()).or_default().push((gps_week, tow, native));
 
         constellation_times.entry(constel.clone()).or_default().push(ts);
         constellation_svs.entry(constel.clone()).or_default().push(nav_key.sv.prn.to_string());
@@ -158,31 +553,49 @@ fn read_rinex_nav(path: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
         }
     }
 
-    let mut result = BTreeMap::new();
-    for (constel, columns) in storage {
-        let times = constellation_times.remove(&constel).unwrap();
-        let svs = constellation_svs.remove(&constel).unwrap();
+    // DataFrames of the constellations are assembled in parallel
+    let constellations: Vec<_> = storage
+        .into_iter()
+        .map(|(constel, columns)| {
+            let times = constellation_times.remove(&constel).unwrap();
+            let svs = constellation_svs.remove(&constel).unwrap();
+            let weeks = constellation_weeks.remove(&constel).unwrap();
+            (constel, columns, times, svs, weeks)
+        })
+        .collect();
+    let result = constellations
+        .into_par_iter()
+        .map(|(constel, columns, times, svs, weeks)| {
+            let epoch_series = frames::datetime_series("epoch", times, unit);
 
-        let epoch_series = Series::new("epoch".into(), times)
-            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        
-        let mut df = DataFrame::new(vec![
-            epoch_series.into(),
-            Series::new("sv".into(), svs).into(),
-        ]).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-
-        for (name, values) in columns {
-            let mut final_values = values;
-            while final_values.len() < df.height() {
-                final_values.push(None);
+            let mut df = DataFrame::new(vec![
+                epoch_series.into(),
+                Series::new("sv".into(), svs).into(),
+                Series::new("gps_week".into(), weeks.iter().map(|w| w.0).collect::<Vec<_>>()).into(),
+                Series::new("tow".into(), weeks.iter().map(|w| w.1).collect::<Vec<_>>()).into(),
+                Series::new("native_week".into(), weeks.iter().map(|w| w.2.map(|n| n.0)).collect::<Vec<_>>()).into(),
+                Series::new("native_tow".into(), weeks.iter().map(|w| w.2.map(|n| n.1)).collect::<Vec<_>>()).into(),
+            ])?;
+
+            for (name, values) in columns {
+                let mut final_values = values;
+                while final_values.len() < df.height() {
+                    final_values.push(None);
+                }
+                let s = Series::new(name.into(), final_values);
+                df.with_column(s)?;
             }
-            let s = Series::new(name.into(), final_values);
-            df.with_column(s).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-        }
 
-        result.insert(constel, PyDataFrame(df));
-    }
+            if sort {
+                df = frames::sort_by_keys(df, &["epoch", "sv"])?;
+            }
+            let df = records::with_state_vector_columns(df, &constel)?;
+            let df = records::with_validity_columns(records::with_accuracy_columns(df)?, &constel)?;
+            let df = records::with_reference_time_columns(df)?;
+            Ok((constel, PyDataFrame(df)))
+        })
+        .collect::<PolarsResult<BTreeMap<_, _>>>()
+        .map_err(frames::polars_err)?;
 
     Ok(result)
 }
@@ -192,5 +605,42 @@ fn read_rinex_nav(path: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
 fn pytecgg(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(read_rinex_obs, m)?)?;
     m.add_function(wrap_pyfunction!(read_rinex_nav, m)?)?;
+    m.add_function(wrap_pyfunction!(split_constellations, m)?)?;
+    m.add_function(wrap_pyfunction!(events::read_rinex_events, m)?)?;
+    m.add_function(wrap_pyfunction!(kinematic::read_rinex_positions, m)?)?;
+    m.add_function(wrap_pyfunction!(site::read_rinex_site, m)?)?;
+    m.add_function(wrap_pyfunction!(bounds::rinex_time_bounds, m)?)?;
+    m.add_function(wrap_pyfunction!(phase_shift::read_rinex_phase_shifts, m)?)?;
+    m.add_function(wrap_pyfunction!(scale_factor::read_rinex_scale_factors, m)?)?;
+    m.add_function(wrap_pyfunction!(signals::observable_table, m)?)?;
+    m.add_function(wrap_pyfunction!(timescales::leap_seconds, m)?)?;
+    m.add_function(wrap_pyfunction!(timescales::convert_timescale, m)?)?;
+    m.add_function(wrap_pyfunction!(geodesy::py_ecef_to_geodetic, m)?)?;
+    m.add_function(wrap_pyfunction!(geodesy::py_geodetic_to_ecef, m)?)?;
+    m.add_function(wrap_pyfunction!(geodesy::py_ecef_to_enu, m)?)?;
+    m.add_function(wrap_pyfunction!(geodesy::py_enu_to_ecef, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::melbourne_wubbena, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::ionosphere_free, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::wide_lane, m)?)?;
+    m.add_function(wrap_pyfunction!(combinations::narrow_lane, m)?)?;
+    m.add_function(wrap_pyfunction!(geometry::annotate_geometry, m)?)?;
+    m.add_function(wrap_pyfunction!(differences::single_differences, m)?)?;
+    m.add_function(wrap_pyfunction!(differences::double_differences, m)?)?;
+    m.add_function(wrap_pyfunction!(differences::between_satellite_differences, m)?)?;
+    m.add_function(wrap_pyfunction!(qc::multipath_qc, m)?)?;
+    m.add_function(wrap_pyfunction!(qc::detect_gaps, m)?)?;
+    m.add_function(wrap_pyfunction!(qc::sampling_interval, m)?)?;
+    m.add_function(wrap_pyfunction!(qc::epoch_completeness, m)?)?;
+    m.add_function(wrap_pyfunction!(qc::snr_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(clock_jumps::clock_jumps, m)?)?;
+    m.add_function(wrap_pyfunction!(dataset::write_dataset, m)?)?;
+    m.add_function(wrap_pyfunction!(tasks::spawn_blocking, m)?)?;
+    m.add_function(wrap_pyfunction!(cli::run_cli, m)?)?;
+    m.add_class::<options::ReadOptions>()?;
+    m.add_class::<stats::ParseStats>()?;
+    m.add_class::<rtcm::RtcmDecoder>()?;
+    m.add_class::<binex::BinexDecoder>()?;
+    m.add_class::<ubx::UbxDecoder>()?;
+    m.add_class::<sbf::SbfDecoder>()?;
     Ok(())
 }
@@ -0,0 +1,410 @@
+use chrono::{DateTime, FixedOffset, NaiveDateTime};
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3::types::{PyDateTime, PyDict, PyString};
+use rinex::observation::SignalObservation;
+use rinex::prelude::{Constellation, Observable, SV};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::combinations::system_letter;
+
+/// Options of the RINEX observation reader, reusable across calls
+///
+/// Parameters:
+///     geometry_free (bool): If true, return the geometry-free combinations (see
+///         `read_rinex_obs`) instead of the raw observables
+///     time_scale (str): Timescale of the output epochs ('GPST', 'GST', 'BDT', 'GLONASST',
+///         'UTC' or 'TAI')
+///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+///     epoch_dtype (str): Type of the output epochs: 'datetime' (in `time_unit`), or raw
+///         Int64 timestamps since the Unix epoch, 'i64_us' or 'i64_ns'
+///     snap_to (str, optional): Nominal sampling interval to which epochs are rounded
+///     duplicates (str): Policy for repeated (epoch, sv, observable) entries
+///     sort (bool): If true, sort the output by epoch, SV and observable
+///     max_rows (int, optional): Maximum number of observation rows, checked against the
+///         values recorded in the file before it is parsed
+///     max_memory_mb (float, optional): Maximum estimated memory of the observations, in MB
+///     on_limit (str): 'error' or 'truncate', when a limit is exceeded
+///     start (datetime, optional): First epoch to read (inclusive), in the output timescale
+///     end (datetime, optional): Last epoch to read (exclusive), in the output timescale
+///     constellations (list[str], optional): Constellations to read, as RINEX letters
+///         (e.g. ['G', 'E'])
+///     observables (list[str], optional): Observables to read (e.g. ['L1C', 'L5Q'])
+///     exclude_sv (list[str], optional): Satellites to mask out (e.g. ['G04'])
+///     value_dtype (str): Data type of the 'value' column ('float64' or 'float32')
+///     phase_shift (str): 'keep', 'apply' or 'remove' the carrier phase shifts declared
+///         in the header ('SYS / PHASE SHIFT')
+///     phase_unit (str): Unit of the carrier phases, 'cycles' (as recorded) or 'meters'
+///     strict (bool): If true, reject files whose header violates the RINEX specification
+///     normalize_doppler (bool): If true, convert the Doppler observables to the RINEX
+///         convention (Hz, positive for approaching satellites), detected from the phases
+///     ssi (bool): If true, add the signal strength indicator digit of each observation
+///         ('ssi', 1-9, null if not recorded)
+#[pyclass(module = "pytecgg")]
+#[derive(Clone, Debug)]
+pub(crate) struct ReadOptions {
+    #[pyo3(get, set)]
+    pub(crate) geometry_free: bool,
+    #[pyo3(get, set)]
+    pub(crate) time_scale: String,
+    #[pyo3(get, set)]
+    pub(crate) time_unit: String,
+    #[pyo3(get, set)]
+    pub(crate) epoch_dtype: String,
+    #[pyo3(get, set)]
+    pub(crate) snap_to: Option<String>,
+    #[pyo3(get, set)]
+    pub(crate) duplicates: String,
+    #[pyo3(get, set)]
+    pub(crate) sort: bool,
+    #[pyo3(get, set)]
+    pub(crate) max_rows: Option<usize>,
+    #[pyo3(get, set)]
+    pub(crate) max_memory_mb: Option<f64>,
+    #[pyo3(get, set)]
+    pub(crate) on_limit: String,
+    pub(crate) start: Option<NaiveDateTime>,
+    pub(crate) end: Option<NaiveDateTime>,
+    #[pyo3(get, set)]
+    pub(crate) constellations: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub(crate) observables: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub(crate) exclude_sv: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub(crate) value_dtype: String,
+    #[pyo3(get, set)]
+    pub(crate) phase_shift: String,
+    #[pyo3(get, set)]
+    pub(crate) phase_unit: String,
+    #[pyo3(get, set)]
+    pub(crate) strict: bool,
+    #[pyo3(get, set)]
+    pub(crate) normalize_doppler: bool,
+    #[pyo3(get, set)]
+    pub(crate) ssi: bool,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            geometry_free: false,
+            time_scale: "GPST".into(),
+            time_unit: "us".into(),
+            epoch_dtype: "datetime".into(),
+            snap_to: None,
+            duplicates: "keep".into(),
+            sort: false,
+            max_rows: None,
+            max_memory_mb: None,
+            on_limit: "error".into(),
+            start: None,
+            end: None,
+            constellations: None,
+            observables: None,
+            exclude_sv: None,
+            value_dtype: "float64".into(),
+            phase_shift: "keep".into(),
+            phase_unit: "cycles".into(),
+            strict: false,
+            normalize_doppler: false,
+            ssi: false,
+        }
+    }
+}
+
+/// Naive datetime of a Python datetime, converted to UTC if timezone-aware
+fn naive_datetime(value: Option<&Bound<'_, PyDateTime>>) -> PyResult<Option<NaiveDateTime>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    if value.getattr("tzinfo")?.is_none() {
+        return value.extract().map(Some);
+    }
+    Ok(Some(value.extract::<DateTime<FixedOffset>>()?.naive_utc()))
+}
+
+/// Naive datetime of a Python datetime or an ISO 8601 string (as written by `to_dict`)
+fn naive_datetime_or_iso(value: &Bound<'_, PyAny>) -> PyResult<Option<NaiveDateTime>> {
+    if value.is_none() {
+        return Ok(None);
+    }
+    if let Ok(text) = value.downcast::<PyString>() {
+        let text = text.to_cow()?;
+        return NaiveDateTime::from_str(&text).map(Some).map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid datetime '{}'", text))
+        });
+    }
+    naive_datetime(Some(value.downcast::<PyDateTime>()?))
+}
+
+fn iso_datetime(value: Option<NaiveDateTime>) -> Option<String> {
+    value.map(|dt| dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+}
+
+#[pymethods]
+impl ReadOptions {
+    #[new]
+    #[pyo3(signature = (
+        *,
+        geometry_free=false,
+        time_scale="GPST".to_string(),
+        time_unit="us".to_string(),
+        epoch_dtype="datetime".to_string(),
+        snap_to=None,
+        duplicates="keep".to_string(),
+        sort=false,
+        max_rows=None,
+        max_memory_mb=None,
+        on_limit="error".to_string(),
+        start=None,
+        end=None,
+        constellations=None,
+        observables=None,
+        exclude_sv=None,
+        value_dtype="float64".to_string(),
+        phase_shift="keep".to_string(),
+        phase_unit="cycles".to_string(),
+        strict=false,
+        normalize_doppler=false,
+        ssi=false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        geometry_free: bool,
+        time_scale: String,
+        time_unit: String,
+        epoch_dtype: String,
+        snap_to: Option<String>,
+        duplicates: String,
+        sort: bool,
+        max_rows: Option<usize>,
+        max_memory_mb: Option<f64>,
+        on_limit: String,
+        start: Option<&Bound<'_, PyDateTime>>,
+        end: Option<&Bound<'_, PyDateTime>>,
+        constellations: Option<Vec<String>>,
+        observables: Option<Vec<String>>,
+        exclude_sv: Option<Vec<String>>,
+        value_dtype: String,
+        phase_shift: String,
+        phase_unit: String,
+        strict: bool,
+        normalize_doppler: bool,
+        ssi: bool,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            geometry_free,
+            time_scale,
+            time_unit,
+            epoch_dtype,
+            snap_to,
+            duplicates,
+            sort,
+            max_rows,
+            max_memory_mb,
+            on_limit,
+            start: naive_datetime(start)?,
+            end: naive_datetime(end)?,
+            constellations,
+            observables,
+            exclude_sv,
+            value_dtype,
+            phase_shift,
+            phase_unit,
+            strict,
+            normalize_doppler,
+            ssi,
+        })
+    }
+
+    #[getter]
+    fn get_start(&self) -> Option<NaiveDateTime> {
+        self.start
+    }
+
+    #[setter]
+    fn set_start(&mut self, value: Option<&Bound<'_, PyDateTime>>) -> PyResult<()> {
+        self.start = naive_datetime(value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn get_end(&self) -> Option<NaiveDateTime> {
+        self.end
+    }
+
+    #[setter]
+    fn set_end(&mut self, value: Option<&Bound<'_, PyDateTime>>) -> PyResult<()> {
+        self.end = naive_datetime(value)?;
+        Ok(())
+    }
+
+    /// Copy of the options, e.g. to override some of them for a single call
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    /// Options as a dictionary of JSON-compatible values (datetimes as ISO 8601 strings)
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("geometry_free", self.geometry_free)?;
+        dict.set_item("time_scale", &self.time_scale)?;
+        dict.set_item("time_unit", &self.time_unit)?;
+        dict.set_item("epoch_dtype", &self.epoch_dtype)?;
+        dict.set_item("snap_to", &self.snap_to)?;
+        dict.set_item("duplicates", &self.duplicates)?;
+        dict.set_item("sort", self.sort)?;
+        dict.set_item("max_rows", self.max_rows)?;
+        dict.set_item("max_memory_mb", self.max_memory_mb)?;
+        dict.set_item("on_limit", &self.on_limit)?;
+        dict.set_item("start", iso_datetime(self.start))?;
+        dict.set_item("end", iso_datetime(self.end))?;
+        dict.set_item("constellations", &self.constellations)?;
+        dict.set_item("observables", &self.observables)?;
+        dict.set_item("exclude_sv", &self.exclude_sv)?;
+        dict.set_item("value_dtype", &self.value_dtype)?;
+        dict.set_item("phase_shift", &self.phase_shift)?;
+        dict.set_item("phase_unit", &self.phase_unit)?;
+        dict.set_item("strict", self.strict)?;
+        dict.set_item("normalize_doppler", self.normalize_doppler)?;
+        dict.set_item("ssi", self.ssi)?;
+        Ok(dict)
+    }
+
+    /// Options from a dictionary as returned by `to_dict`; missing keys take their defaults
+    #[staticmethod]
+    fn from_dict(data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut options = Self::default();
+        for (key, value) in data.iter() {
+            let key: String = key.extract()?;
+            match key.as_str() {
+                "geometry_free" => options.geometry_free = value.extract()?,
+                "time_scale" => options.time_scale = value.extract()?,
+                "time_unit" => options.time_unit = value.extract()?,
+                "epoch_dtype" => options.epoch_dtype = value.extract()?,
+                "snap_to" => options.snap_to = value.extract()?,
+                "duplicates" => options.duplicates = value.extract()?,
+                "sort" => options.sort = value.extract()?,
+                "max_rows" => options.max_rows = value.extract()?,
+                "max_memory_mb" => options.max_memory_mb = value.extract()?,
+                "on_limit" => options.on_limit = value.extract()?,
+                "start" => options.start = naive_datetime_or_iso(&value)?,
+                "end" => options.end = naive_datetime_or_iso(&value)?,
+                "constellations" => options.constellations = value.extract()?,
+                "observables" => options.observables = value.extract()?,
+                "exclude_sv" => options.exclude_sv = value.extract()?,
+                "value_dtype" => options.value_dtype = value.extract()?,
+                "phase_shift" => options.phase_shift = value.extract()?,
+                "phase_unit" => options.phase_unit = value.extract()?,
+                "strict" => options.strict = value.extract()?,
+                "normalize_doppler" => options.normalize_doppler = value.extract()?,
+                "ssi" => options.ssi = value.extract()?,
+                _ => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Unknown read option '{}'",
+                        key
+                    )))
+                },
+            }
+        }
+        Ok(options)
+    }
+
+    /// Pickles the options through their dictionary, e.g. for multiprocessing pools
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyDict>,))> {
+        let data = slf.borrow().to_dict(slf.py())?;
+        Ok((slf.get_type().getattr("from_dict")?, (data,)))
+    }
+}
+
+/// Data type of the 'value' column ('float64' or 'float32')
+pub(crate) fn parse_value_dtype(name: &str) -> PyResult<DataType> {
+    match name.to_ascii_lowercase().as_str() {
+        "float64" | "f64" => Ok(DataType::Float64),
+        "float32" | "f32" => Ok(DataType::Float32),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unsupported value dtype '{}': expected 'float64' or 'float32'",
+            name
+        ))),
+    }
+}
+
+/// Epochs and signals selected by the filters of `ReadOptions`, with the time window in
+/// output timestamps
+pub(crate) struct SignalFilter {
+    start: Option<i64>,
+    end: Option<i64>,
+    systems: Option<HashSet<char>>,
+    observables: Option<HashSet<Observable>>,
+    excluded: HashSet<SV>,
+}
+
+impl SignalFilter {
+    /// Builds the filter of the options, with the time window in the given unit
+    pub(crate) fn new(options: &ReadOptions, unit: TimeUnit) -> PyResult<Self> {
+        let timestamp = |dt: NaiveDateTime| {
+            let nanos = dt.and_utc().timestamp_nanos_opt().ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Out of range epoch: {}", dt))
+            })?;
+            Ok::<_, PyErr>(if unit == TimeUnit::Nanoseconds { nanos } else { nanos.div_euclid(1_000) })
+        };
+        let value_error = |kind: &str, name: &str| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid {} '{}'", kind, name))
+        };
+        let systems = options
+            .constellations
+            .as_ref()
+            .map(|names| {
+                names
+                    .iter()
+                    .map(|name| {
+                        Constellation::from_str(name)
+                            .ok()
+                            .and_then(system_letter)
+                            .ok_or_else(|| value_error("constellation", name))
+                    })
+                    .collect::<PyResult<HashSet<_>>>()
+            })
+            .transpose()?;
+        let observables = options
+            .observables
+            .as_ref()
+            .map(|names| {
+                names
+                    .iter()
+                    .map(|name| Observable::from_str(name).map_err(|_| value_error("observable", name)))
+                    .collect::<PyResult<HashSet<_>>>()
+            })
+            .transpose()?;
+        let excluded = options
+            .exclude_sv
+            .iter()
+            .flatten()
+            .map(|name| SV::from_str(name).map_err(|_| value_error("satellite", name)))
+            .collect::<PyResult<HashSet<_>>>()?;
+        Ok(Self {
+            start: options.start.map(timestamp).transpose()?,
+            end: options.end.map(timestamp).transpose()?,
+            systems,
+            observables,
+            excluded,
+        })
+    }
+
+    /// Whether an epoch (output timestamp) falls within the time window
+    pub(crate) fn keep_epoch(&self, ts: i64) -> bool {
+        self.start.is_none_or(|start| ts >= start) && self.end.is_none_or(|end| ts < end)
+    }
+
+    /// Whether a signal passes the constellation, observable and satellite filters
+    pub(crate) fn keep_signal(&self, signal: &SignalObservation) -> bool {
+        !self.excluded.contains(&signal.sv)
+            && self.observables.as_ref().is_none_or(|o| o.contains(&signal.observable))
+            && self
+                .systems
+                .as_ref()
+                .is_none_or(|s| system_letter(signal.sv.constellation).is_some_and(|c| s.contains(&c)))
+    }
+}
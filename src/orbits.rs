@@ -0,0 +1,335 @@
+use polars::prelude::*;
+use rinex::prelude::Epoch;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::frames::units_per_second;
+
+/// Seconds in a GNSS week
+const WEEK_SECONDS: f64 = 604_800.0;
+/// Seconds in a day
+const DAY_SECONDS: f64 = 86_400.0;
+/// Constant offset between BeiDou time and GPS time (BDT = GPST - 14 s)
+const BDT_GPST_OFFSET: f64 = 14.0;
+/// Inclination of the reference frame used by BeiDou GEO broadcast orbits
+const BDS_GEO_TILT_DEG: f64 = -5.0;
+/// Maximum distance from the reference epoch for Keplerian ephemerides (s)
+const KEPLER_MAX_AGE: f64 = 4.0 * 3600.0;
+/// Maximum distance from the reference epoch for GLONASS state vectors (s)
+const GLONASS_MAX_AGE: f64 = 45.0 * 60.0;
+/// Runge-Kutta integration step for GLONASS orbits (s)
+const GLONASS_STEP: f64 = 60.0;
+
+/// PZ-90 constants for GLONASS orbit integration
+const GLO_GM: f64 = 3.986_004_4e14;
+const GLO_AE: f64 = 6_378_136.0;
+const GLO_C20: f64 = -1_082.63e-6;
+const GLO_OMEGA_E: f64 = 7.292_115e-5;
+
+/// Keplerian broadcast parameters (GPS, Galileo, BeiDou)
+struct Kepler {
+    sqrt_a: f64,
+    delta_n: f64,
+    m0: f64,
+    e: f64,
+    omega: f64,
+    cuc: f64,
+    cus: f64,
+    crc: f64,
+    crs: f64,
+    cic: f64,
+    cis: f64,
+    i0: f64,
+    idot: f64,
+    omega0: f64,
+    omega_dot: f64,
+    /// Time of ephemeris, in seconds of the week of its own time scale
+    toe: f64,
+    /// Time of ephemeris as absolute GPST (Unix-like seconds)
+    toe_gpst: f64,
+    gm: f64,
+    omega_e: f64,
+    beidou_geo: bool,
+}
+
+/// GLONASS broadcast state vector in PZ-90, at the reference epoch (SI units)
+struct StateVector {
+    position: [f64; 3],
+    velocity: [f64; 3],
+    acceleration: [f64; 3],
+}
+
+enum Orbit {
+    Kepler(Kepler),
+    Glonass(StateVector),
+}
+
+/// A broadcast ephemeris record with its reference epoch as absolute GPST (Unix-like seconds)
+struct Ephemeris {
+    reference: f64,
+    orbit: Orbit,
+}
+
+/// Broadcast ephemerides of every satellite, used to predict ECEF positions
+pub(crate) struct Ephemerides {
+    records: BTreeMap<String, Vec<Ephemeris>>,
+}
+
+/// Offset GPST - UTC (s) at a given UTC instant, as Unix seconds
+fn gpst_minus_utc(unix_seconds: f64) -> f64 {
+    Epoch::from_unix_seconds(unix_seconds)
+        .leap_seconds(true)
+        .map_or(18.0, |tai_utc| tai_utc - 19.0)
+}
+
+/// Start of the (Sunday-aligned) week containing a Unix-like instant, in seconds
+fn week_start(seconds: f64) -> f64 {
+    let days = (seconds / DAY_SECONDS).floor();
+    // 1970-01-01 was a Thursday
+    let weekday = (days + 4.0).rem_euclid(7.0);
+    (days - weekday) * DAY_SECONDS
+}
+
+/// Solves Kepler's equation for the eccentric anomaly
+fn eccentric_anomaly(mean_anomaly: f64, e: f64) -> f64 {
+    let mut ea = mean_anomaly;
+    for _ in 0..20 {
+        let delta = (ea - e * ea.sin() - mean_anomaly) / (1.0 - e * ea.cos());
+        ea -= delta;
+        if delta.abs() < 1e-13 {
+            break;
+        }
+    }
+    ea
+}
+
+impl Kepler {
+    /// Satellite ECEF position (m) at an absolute GPST instant, following IS-GPS-200
+    /// (and the BeiDou ICD for GEO satellites)
+    fn position(&self, t: f64) -> [f64; 3] {
+        let tk = t - self.toe_gpst;
+        let a = self.sqrt_a * self.sqrt_a;
+        let n = (self.gm / a.powi(3)).sqrt() + self.delta_n;
+        let ea = eccentric_anomaly(self.m0 + n * tk, self.e);
+
+        let nu = ((1.0 - self.e * self.e).sqrt() * ea.sin()).atan2(ea.cos() - self.e);
+        let phi = nu + self.omega;
+        let (sin2, cos2) = (2.0 * phi).sin_cos();
+
+        let u = phi + self.cus * sin2 + self.cuc * cos2;
+        let r = a * (1.0 - self.e * ea.cos()) + self.crs * sin2 + self.crc * cos2;
+        let i = self.i0 + self.idot * tk + self.cis * sin2 + self.cic * cos2;
+        let (xp, yp) = (r * u.cos(), r * u.sin());
+
+        if self.beidou_geo {
+            let omega = self.omega0 + self.omega_dot * tk - self.omega_e * self.toe;
+            let (x, y, z) = (
+                xp * omega.cos() - yp * i.cos() * omega.sin(),
+                xp * omega.sin() + yp * i.cos() * omega.cos(),
+                yp * i.sin(),
+            );
+            let (sin_t, cos_t) = BDS_GEO_TILT_DEG.to_radians().sin_cos();
+            let (sin_z, cos_z) = (self.omega_e * tk).sin_cos();
+            let (y1, z1) = (y * cos_t + z * sin_t, -y * sin_t + z * cos_t);
+            return [x * cos_z + y1 * sin_z, -x * sin_z + y1 * cos_z, z1];
+        }
+
+        let omega = self.omega0 + (self.omega_dot - self.omega_e) * tk - self.omega_e * self.toe;
+        [
+            xp * omega.cos() - yp * i.cos() * omega.sin(),
+            xp * omega.sin() + yp * i.cos() * omega.cos(),
+            yp * i.sin(),
+        ]
+    }
+}
+
+/// Right-hand side of the GLONASS equations of motion in the rotating PZ-90 frame
+/// (GLONASS ICD, Appendix A.3.1.2), with constant luni-solar accelerations
+fn glonass_derivatives(state: &[f64; 6], acceleration: &[f64; 3]) -> [f64; 6] {
+    let [x, y, z, vx, vy, vz] = *state;
+    let r2 = x * x + y * y + z * z;
+    let r = r2.sqrt();
+    let mu_r3 = GLO_GM / (r2 * r);
+    let j2 = 1.5 * GLO_C20 * GLO_GM * GLO_AE * GLO_AE / (r2 * r2 * r);
+    let z2_r2 = 5.0 * z * z / r2;
+    let w2 = GLO_OMEGA_E * GLO_OMEGA_E;
+
+    [
+        vx,
+        vy,
+        vz,
+        -mu_r3 * x + j2 * x * (1.0 - z2_r2) + w2 * x + 2.0 * GLO_OMEGA_E * vy + acceleration[0],
+        -mu_r3 * y + j2 * y * (1.0 - z2_r2) + w2 * y - 2.0 * GLO_OMEGA_E * vx + acceleration[1],
+        -mu_r3 * z + j2 * z * (3.0 - z2_r2) + acceleration[2],
+    ]
+}
+
+impl StateVector {
+    /// Satellite ECEF position (m) after `dt` seconds, by fourth-order Runge-Kutta
+    fn position(&self, dt: f64) -> [f64; 3] {
+        let [x, y, z] = self.position;
+        let [vx, vy, vz] = self.velocity;
+        let mut state = [x, y, z, vx, vy, vz];
+        let mut remaining = dt;
+
+        while remaining.abs() > 1e-9 {
+            let h = remaining.signum() * remaining.abs().min(GLONASS_STEP);
+            let step = |s: &[f64; 6], k: &[f64; 6], f: f64| -> [f64; 6] {
+                std::array::from_fn(|j| s[j] + f * k[j])
+            };
+            let k1 = glonass_derivatives(&state, &self.acceleration);
+            let k2 = glonass_derivatives(&step(&state, &k1, h / 2.0), &self.acceleration);
+            let k3 = glonass_derivatives(&step(&state, &k2, h / 2.0), &self.acceleration);
+            let k4 = glonass_derivatives(&step(&state, &k3, h), &self.acceleration);
+            for j in 0..6 {
+                state[j] += h / 6.0 * (k1[j] + 2.0 * k2[j] + 2.0 * k3[j] + k4[j]);
+            }
+            remaining -= h;
+        }
+        [state[0], state[1], state[2]]
+    }
+}
+
+/// Reads a Float64 column of a navigation DataFrame, if present
+fn nav_column(df: &DataFrame, name: &str) -> PolarsResult<Option<Vec<Option<f64>>>> {
+    match df.column(name) {
+        Ok(col) => Ok(Some(col.cast(&DataType::Float64)?.f64()?.into_iter().collect())),
+        Err(_) => Ok(None),
+    }
+}
+
+impl Ephemerides {
+    /// Collects the broadcast ephemerides of the supported constellations, from the
+    /// navigation DataFrames returned by `read_rinex_nav` (keyed by constellation name)
+    pub(crate) fn from_frames(nav: &HashMap<String, DataFrame>) -> PolarsResult<Self> {
+        let mut records: BTreeMap<String, Vec<Ephemeris>> = BTreeMap::new();
+
+        for (constellation, df) in nav {
+            let (system, gm, omega_e) = match constellation.as_str() {
+                "GPS" => ('G', 3.986_005e14, 7.292_115_146_7e-5),
+                "GALILEO" => ('E', 3.986_004_418e14, 7.292_115_146_7e-5),
+                "BEIDOU" => ('C', 3.986_004_418e14, 7.292_115e-5),
+                "GLONASS" => ('R', GLO_GM, GLO_OMEGA_E),
+                _ => continue,
+            };
+
+            let epoch_col = df.column("epoch")?;
+            let scale = units_per_second(epoch_col.dtype()) as f64;
+            let epochs: Vec<Option<i64>> = epoch_col.to_physical_repr().i64()?.into_iter().collect();
+            let svs: Vec<Option<String>> = df
+                .column("sv")?
+                .cast(&DataType::String)?
+                .str()?
+                .into_iter()
+                .map(|s| s.map(str::to_string))
+                .collect();
+
+            let names: &[&str] = if system == 'R' {
+                &["satPosX", "satPosY", "satPosZ", "velX", "velY", "velZ", "accelX", "accelY", "accelZ"]
+            } else {
+                &[
+                    "sqrta", "deltaN", "m0", "e", "omega", "cuc", "cus", "crc", "crs", "cic", "cis", "i0",
+                    "idot", "omega0", "omegaDot", "toe",
+                ]
+            };
+            let mut columns = Vec::with_capacity(names.len());
+            for name in names {
+                match nav_column(df, name)? {
+                    Some(values) => columns.push(values),
+                    None => break,
+                }
+            }
+            if columns.len() < names.len() {
+                continue;
+            }
+
+            for (row, (epoch, sv)) in epochs.iter().zip(&svs).enumerate() {
+                let (Some(epoch), Some(sv)) = (epoch, sv) else {
+                    continue;
+                };
+                let Ok(prn) = sv.trim().trim_start_matches(system).parse::<u8>() else {
+                    continue;
+                };
+                let Some(p) = columns.iter().map(|c| c[row]).collect::<Option<Vec<f64>>>() else {
+                    continue;
+                };
+                // Epochs are labelled in the time scale of each constellation
+                let toc = *epoch as f64 / scale;
+
+                let (reference, orbit) = if system == 'R' {
+                    let km = |v: &[f64]| [v[0] * 1e3, v[1] * 1e3, v[2] * 1e3];
+                    let orbit = Orbit::Glonass(StateVector {
+                        position: km(&p[0..3]),
+                        velocity: km(&p[3..6]),
+                        acceleration: km(&p[6..9]),
+                    });
+                    (toc + gpst_minus_utc(toc), orbit)
+                } else {
+                    let toe = p[15];
+                    let tow = toc - week_start(toc);
+                    let mut toe_abs = week_start(toc) + toe;
+                    if toe - tow > WEEK_SECONDS / 2.0 {
+                        toe_abs -= WEEK_SECONDS;
+                    } else if toe - tow < -WEEK_SECONDS / 2.0 {
+                        toe_abs += WEEK_SECONDS;
+                    }
+                    let offset = if system == 'C' { BDT_GPST_OFFSET } else { 0.0 };
+                    let orbit = Orbit::Kepler(Kepler {
+                        sqrt_a: p[0],
+                        delta_n: p[1],
+                        m0: p[2],
+                        e: p[3],
+                        omega: p[4],
+                        cuc: p[5],
+                        cus: p[6],
+                        crc: p[7],
+                        crs: p[8],
+                        cic: p[9],
+                        cis: p[10],
+                        i0: p[11],
+                        idot: p[12],
+                        omega0: p[13],
+                        omega_dot: p[14],
+                        toe,
+                        toe_gpst: toe_abs + offset,
+                        gm,
+                        omega_e,
+                        beidou_geo: system == 'C' && (prn <= 5 || (59..=63).contains(&prn)),
+                    });
+                    (toc + offset, orbit)
+                };
+
+                records
+                    .entry(format!("{system}{prn:02}"))
+                    .or_default()
+                    .push(Ephemeris { reference, orbit });
+            }
+        }
+
+        for ephemerides in records.values_mut() {
+            ephemerides.sort_by(|a, b| a.reference.total_cmp(&b.reference));
+        }
+        Ok(Self { records })
+    }
+
+    /// Satellites with at least one ephemeris, as RINEX 3 identifiers (e.g. 'G01')
+    pub(crate) fn satellites(&self) -> impl Iterator<Item = &str> {
+        self.records.keys().map(String::as_str)
+    }
+
+    /// ECEF position (m) of a satellite at an absolute GPST instant (Unix-like seconds),
+    /// from the ephemeris closest in time; None if no ephemeris is close enough
+    pub(crate) fn position(&self, sv: &str, t: f64) -> Option<[f64; 3]> {
+        let ephemerides = self.records.get(sv)?;
+        let nearest = ephemerides
+            .iter()
+            .min_by(|a, b| (a.reference - t).abs().total_cmp(&(b.reference - t).abs()))?;
+
+        match &nearest.orbit {
+            Orbit::Kepler(kepler) if (t - kepler.toe_gpst).abs() <= KEPLER_MAX_AGE => Some(kepler.position(t)),
+            Orbit::Glonass(state) if (t - nearest.reference).abs() <= GLONASS_MAX_AGE => {
+                Some(state.position(t - nearest.reference))
+            },
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use crate::frames::polars_err;
+use crate::text::{field, open_text, Lines};
+
+/// Satellites listed on each 'SYS / PHASE SHIFT' line
+const SATS_PER_LINE: usize = 10;
+
+/// Carrier phase shift declared by a 'SYS / PHASE SHIFT' header record
+#[derive(Clone, Debug)]
+pub(crate) struct PhaseShift {
+    pub system: char,
+    pub observable: String,
+    /// Correction in cycles
+    pub cycles: f64,
+    /// Satellites concerned, all of the system if empty
+    pub svs: Vec<String>,
+}
+
+/// Reads the 'SYS / PHASE SHIFT' records of a RINEX 3/4 observation header; records
+/// without an observable or a correction (no shift declared) are skipped
+pub(crate) fn read_phase_shifts(path: &Path) -> io::Result<Vec<PhaseShift>> {
+    let mut lines = Lines::new(open_text(path)?);
+    let mut shifts: Vec<PhaseShift> = Vec::new();
+    // Whether the continuation lines belong to the last kept record
+    let mut continued = false;
+    while lines.next()? {
+        match lines.label() {
+            "SYS / PHASE SHIFT" => {},
+            "END OF HEADER" => break,
+            _ => continue,
+        }
+        // A1,1X,A3,1X,F8.5,2X,I2.2,10(1X,A3); continuation lines: 18X,10(1X,A3)
+        let line = &lines.line;
+        let svs = (0..SATS_PER_LINE)
+            .map(|i| field(line, 19 + 4 * i, 22 + 4 * i))
+            .filter(|sv| !sv.is_empty())
+            .map(str::to_string);
+        match line.first() {
+            Some(b' ') => {
+                if let Some(shift) = shifts.last_mut().filter(|_| continued) {
+                    shift.svs.extend(svs);
+                }
+            },
+            Some(&system) => {
+                let observable = field(line, 2, 5);
+                continued = false;
+                if let (false, Ok(cycles)) = (observable.is_empty(), field(line, 6, 14).parse::<f64>()) {
+                    shifts.push(PhaseShift {
+                        system: system as char,
+                        observable: observable.to_string(),
+                        cycles,
+                        svs: svs.collect(),
+                    });
+                    continued = true;
+                }
+            },
+            None => {},
+        }
+    }
+    Ok(shifts)
+}
+
+/// What to do with the phase shifts declared in the header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PhaseShiftPolicy {
+    Keep,
+    Apply,
+    Remove,
+}
+
+impl PhaseShiftPolicy {
+    /// Parses a policy name ('keep', 'apply' or 'remove')
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "keep" => Ok(Self::Keep),
+            "apply" => Ok(Self::Apply),
+            "remove" => Ok(Self::Remove),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported phase shift policy '{}': expected 'keep', 'apply' or 'remove'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Adds (`Apply`) or subtracts (`Remove`) the declared phase shifts to the carrier
+/// phases of long-format observations
+pub(crate) fn apply_phase_shifts(
+    df: DataFrame,
+    shifts: &[PhaseShift],
+    policy: PhaseShiftPolicy,
+) -> PolarsResult<DataFrame> {
+    let sign = match policy {
+        PhaseShiftPolicy::Keep => return Ok(df),
+        PhaseShiftPolicy::Apply => 1.0,
+        PhaseShiftPolicy::Remove => -1.0,
+    };
+    if shifts.is_empty() {
+        return Ok(df);
+    }
+    let mut by_signal: HashMap<(char, &str), Vec<&PhaseShift>> = HashMap::new();
+    for shift in shifts {
+        by_signal.entry((shift.system, shift.observable.as_str())).or_default().push(shift);
+    }
+
+    let values: Float64Chunked = {
+        let svs = df.column("sv")?.str()?;
+        let codes = df.column("observable")?.str()?;
+        let values = df.column("value")?.f64()?;
+        svs.into_iter()
+            .zip(codes)
+            .zip(values)
+            .map(|((sv, code), value)| {
+                let (Some(sv), Some(code), Some(value)) = (sv, code, value) else {
+                    return value;
+                };
+                let system = sv.chars().next().unwrap_or(' ');
+                let shift = by_signal
+                    .get(&(system, code))
+                    .and_then(|candidates| {
+                        candidates.iter().find(|s| s.svs.is_empty() || s.svs.iter().any(|listed| listed == sv))
+                    })
+                    .map_or(0.0, |s| s.cycles);
+                Some(value + sign * shift)
+            })
+            .collect()
+    };
+    let mut df = df;
+    df.with_column(values.with_name("value".into()).into_series())?;
+    Ok(df)
+}
+
+/// Reads the carrier phase shifts declared in the header of a RINEX 3/4 observation file
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file (.rnx, .crx, or .gz)
+///
+/// Returns:
+///     PyDataFrame: One row per declared shift, with columns 'system' (RINEX letter),
+///     'observable', 'shift' (cycles) and 'sv' (null if the shift applies to all the
+///     satellites of the system)
+#[pyfunction]
+pub(crate) fn read_rinex_phase_shifts(path: &str) -> PyResult<PyDataFrame> {
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(
+            format!("File not found: {}", path.display())
+        ));
+    }
+    let shifts = read_phase_shifts(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("RINEX parsing error: {}", e)))?;
+
+    let mut systems = Vec::new();
+    let mut observables = Vec::new();
+    let mut cycles = Vec::new();
+    let mut svs: Vec<Option<String>> = Vec::new();
+    for shift in &shifts {
+        let targets: Vec<Option<String>> = if shift.svs.is_empty() {
+            vec![None]
+        } else {
+            shift.svs.iter().cloned().map(Some).collect()
+        };
+        for sv in targets {
+            systems.push(shift.system.to_string());
+            observables.push(shift.observable.clone());
+            cycles.push(shift.cycles);
+            svs.push(sv);
+        }
+    }
+    DataFrame::new(vec![
+        Series::new("system".into(), systems).into(),
+        Series::new("observable".into(), observables).into(),
+        Series::new("shift".into(), cycles).into(),
+        Series::new("sv".into(), svs).into(),
+    ])
+    .map(PyDataFrame)
+    .map_err(polars_err)
+}
@@ -0,0 +1,624 @@
+use chrono::TimeDelta;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::combinations::{observable_frequencies, BandNaming, C};
+use crate::frames::{infer_interval, infer_interval_rounded, pivot_observables, polars_err, units_per_second};
+use crate::geodesy::Site;
+use crate::orbits::Ephemerides;
+
+/// Code-minus-carrier multipath series of a single satellite, split into continuous arcs
+struct MultipathTrack {
+    epochs: Vec<i64>,
+    mp1: Vec<Option<f64>>,
+    mp2: Vec<Option<f64>>,
+    arcs: Vec<u32>,
+    /// Arc breaks caused by jumps of the combinations rather than by time gaps
+    slips: u32,
+}
+
+/// Multipath combinations per (epoch, sv) with per-arc bias removal, along with the
+/// RMS statistics per SV and per code signal
+pub(crate) struct Multipath {
+    pub series: DataFrame,
+    pub stats: DataFrame,
+}
+
+/// Parameters of the arc segmentation used for multipath bias removal
+pub(crate) struct ArcSettings {
+    /// Maximum time gap (in seconds) within an arc; defaults to twice the sampling interval
+    pub max_gap: Option<f64>,
+    /// Epoch-to-epoch jump (in meters) of either combination that starts a new arc
+    pub slip_threshold: f64,
+    /// Arcs with fewer samples are discarded
+    pub min_arc_length: usize,
+}
+
+/// Removes the mean of each arc in place, dropping arcs shorter than the minimum length
+fn remove_arc_bias(values: &mut [Option<f64>], arcs: &[u32], min_arc_length: usize) {
+    let mut sums: HashMap<u32, (f64, usize)> = HashMap::new();
+    for (v, arc) in values.iter().zip(arcs) {
+        if let Some(v) = v {
+            let entry = sums.entry(*arc).or_default();
+            entry.0 += v;
+            entry.1 += 1;
+        }
+    }
+    for (v, arc) in values.iter_mut().zip(arcs) {
+        // Arcs without any value (e.g. a code not tracked) have no bias to remove
+        *v = match (*v, sums.get(arc)) {
+            (Some(x), Some(&(sum, n))) if n >= min_arc_length => Some(x - sum / n as f64),
+            _ => None,
+        };
+    }
+}
+
+/// Computes the MP1/MP2 multipath combinations from a long-format observation DataFrame.
+/// Arcs are broken on time gaps and on jumps of the combinations (cycle slips), then the
+/// ambiguity and hardware-delay bias is removed by subtracting the mean of each arc.
+pub(crate) fn multipath(
+    df: &DataFrame,
+    phase: (&str, &str),
+    code: (&str, &str),
+    glonass_channels: &HashMap<String, i32>,
+    naming: BandNaming,
+    settings: &ArcSettings,
+) -> PolarsResult<Multipath> {
+    let observables = [phase.0, phase.1, code.0, code.1];
+    let pivoted = pivot_observables(df, &observables)?;
+    let scale = units_per_second(&pivoted.epoch_dtype);
+
+    let epochs: Vec<i64> = pivoted.rows.keys().map(|(epoch, _)| *epoch).collect();
+    let max_gap = match settings.max_gap {
+        Some(gap) => (gap * scale as f64) as i64,
+        None => infer_interval(&epochs).map_or(i64::MAX, |dt| 2 * dt),
+    };
+
+    let mut tracks: BTreeMap<String, MultipathTrack> = BTreeMap::new();
+    let mut freq_cache: HashMap<String, Vec<Option<f64>>> = HashMap::new();
+
+    for ((epoch, sv), values) in pivoted.rows {
+        let freqs = freq_cache
+            .entry(sv.clone())
+            .or_insert_with(|| observable_frequencies(&sv, &observables, glonass_channels, naming));
+        let (Some(f1), Some(f2), Some(l1), Some(l2)) = (freqs[0], freqs[1], values[0], values[1]) else {
+            continue;
+        };
+
+        // Carrier phases in meters
+        let (phi1, phi2) = (l1 * C / f1, l2 * C / f2);
+        let alpha = (f1 / f2).powi(2);
+        let mp1 = values[2].map(|p1| p1 - (1.0 + 2.0 / (alpha - 1.0)) * phi1 + (2.0 / (alpha - 1.0)) * phi2);
+        let mp2 = values[3]
+            .map(|p2| p2 - (2.0 * alpha / (alpha - 1.0)) * phi1 + (2.0 * alpha / (alpha - 1.0) - 1.0) * phi2);
+
+        let track = tracks.entry(sv).or_insert_with(|| MultipathTrack {
+            epochs: Vec::new(),
+            mp1: Vec::new(),
+            mp2: Vec::new(),
+            arcs: Vec::new(),
+            slips: 0,
+        });
+
+        let arc = match track.epochs.last() {
+            None => 0,
+            Some(last) => {
+                let jump = |prev: Option<&Option<f64>>, curr: Option<f64>| match (prev, curr) {
+                    (Some(Some(a)), Some(b)) => (b - a).abs() > settings.slip_threshold,
+                    _ => false,
+                };
+                let prev_arc = *track.arcs.last().unwrap_or(&0);
+                if epoch - last > max_gap {
+                    prev_arc + 1
+                } else if jump(track.mp1.last(), mp1) || jump(track.mp2.last(), mp2) {
+                    track.slips += 1;
+                    prev_arc + 1
+                } else {
+                    prev_arc
+                }
+            },
+        };
+
+        track.epochs.push(epoch);
+        track.mp1.push(mp1);
+        track.mp2.push(mp2);
+        track.arcs.push(arc);
+    }
+
+    let mut out_epochs = Vec::new();
+    let mut out_svs = Vec::new();
+    let mut out_mp1 = Vec::new();
+    let mut out_mp2 = Vec::new();
+    let mut out_arcs = Vec::new();
+
+    let mut stat_svs = Vec::new();
+    let mut stat_codes = Vec::new();
+    let mut stat_rms = Vec::new();
+    let mut stat_counts = Vec::new();
+    let mut stat_arcs = Vec::new();
+    let mut stat_slips = Vec::new();
+
+    for (sv, mut track) in tracks {
+        remove_arc_bias(&mut track.mp1, &track.arcs, settings.min_arc_length);
+        remove_arc_bias(&mut track.mp2, &track.arcs, settings.min_arc_length);
+
+        for (code, values) in [(code.0, &track.mp1), (code.1, &track.mp2)] {
+            let valid: Vec<(f64, u32)> = values
+                .iter()
+                .zip(&track.arcs)
+                .filter_map(|(v, arc)| v.map(|v| (v, *arc)))
+                .collect();
+            if valid.is_empty() {
+                continue;
+            }
+            let mut arcs: Vec<u32> = valid.iter().map(|(_, arc)| *arc).collect();
+            arcs.dedup();
+            let rms = (valid.iter().map(|(v, _)| v * v).sum::<f64>() / valid.len() as f64).sqrt();
+
+            stat_svs.push(sv.clone());
+            stat_codes.push(code.to_string());
+            stat_rms.push(rms);
+            stat_counts.push(valid.len() as u32);
+            stat_arcs.push(arcs.len() as u32);
+            stat_slips.push(track.slips);
+        }
+
+        out_svs.extend(std::iter::repeat_n(sv, track.epochs.len()));
+        out_epochs.extend(track.epochs);
+        out_mp1.extend(track.mp1);
+        out_mp2.extend(track.mp2);
+        out_arcs.extend(track.arcs);
+    }
+
+    let series = DataFrame::new(vec![
+        Series::new("epoch".into(), out_epochs).cast(&pivoted.epoch_dtype)?.into(),
+        Series::new("sv".into(), out_svs).into(),
+        Series::new("mp1".into(), out_mp1).into(),
+        Series::new("mp2".into(), out_mp2).into(),
+        Series::new("arc".into(), out_arcs).into(),
+    ])?
+    .sort(["epoch", "sv"], Default::default())?;
+
+    let stats = DataFrame::new(vec![
+        Series::new("sv".into(), stat_svs).into(),
+        Series::new("observable".into(), stat_codes).into(),
+        Series::new("mp_rms".into(), stat_rms).into(),
+        Series::new("n_obs".into(), stat_counts).into(),
+        Series::new("n_arcs".into(), stat_arcs).into(),
+        Series::new("n_slips".into(), stat_slips).into(),
+    ])?;
+
+    Ok(Multipath { series, stats })
+}
+
+/// Estimates code multipath with the classic code-minus-carrier combinations (MP1/MP2)
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     phase (tuple[str, str]): Phase observables (in cycles) on the two frequencies
+///     code (tuple[str, str]): Code observables (in meters) on the two frequencies
+///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV
+///     max_gap (float, optional): Maximum time gap (in seconds) within an arc
+///     slip_threshold (float): Jump (in meters) of MP1/MP2 which starts a new arc
+///     min_arc_length (int): Minimum number of samples for an arc to be retained
+///     rinex_version (str, optional): RINEX version of the observations, which sets the
+///         BeiDou band naming (3.03 and later by default)
+///
+/// Returns:
+///     tuple:
+///         - PyDataFrame: Bias-removed 'mp1', 'mp2' (in meters) per 'epoch' and 'sv', with 'arc' ids
+///         - PyDataFrame: RMS statistics with columns 'sv', 'observable', 'mp_rms', 'n_obs', 'n_arcs', 'n_slips'
+#[pyfunction]
+#[pyo3(signature = (
+    df, phase, code, glonass_channels=None, max_gap=None, slip_threshold=5.0, min_arc_length=10,
+    rinex_version=None
+))]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn multipath_qc(
+    df: PyDataFrame,
+    phase: (String, String),
+    code: (String, String),
+    glonass_channels: Option<HashMap<String, i32>>,
+    max_gap: Option<f64>,
+    slip_threshold: f64,
+    min_arc_length: usize,
+    rinex_version: Option<&str>,
+) -> PyResult<(PyDataFrame, PyDataFrame)> {
+    let settings = ArcSettings { max_gap, slip_threshold, min_arc_length };
+    let mp = multipath(
+        &df.0,
+        (&phase.0, &phase.1),
+        (&code.0, &code.1),
+        &glonass_channels.unwrap_or_default(),
+        BandNaming::parse(rinex_version)?,
+        &settings,
+    )
+    .map_err(polars_err)?;
+    Ok((PyDataFrame(mp.series), PyDataFrame(mp.stats)))
+}
+
+/// Gaps in a sorted, deduplicated epoch sequence, as (start, end) pairs of the epochs
+/// bracketing each spacing longer than the nominal interval (with half an interval tolerance)
+fn find_gaps(epochs: &[i64], interval: i64) -> Vec<(i64, i64)> {
+    let tolerance = interval + interval / 2;
+    epochs
+        .windows(2)
+        .filter(|w| w[1] - w[0] > tolerance)
+        .map(|w| (w[0], w[1]))
+        .collect()
+}
+
+/// Detects data gaps relative to the nominal sampling interval, both for the whole
+/// station (epochs without any observation) and for each satellite
+pub(crate) fn gaps(df: &DataFrame, interval: Option<f64>) -> PolarsResult<DataFrame> {
+    let epoch_col = df.column("epoch")?;
+    let epoch_dtype = epoch_col.dtype().clone();
+    let epoch_phys = epoch_col.to_physical_repr();
+    let scale = units_per_second(&epoch_dtype);
+
+    let mut per_sv: BTreeMap<&str, Vec<i64>> = BTreeMap::new();
+    let mut station: Vec<i64> = Vec::with_capacity(df.height());
+    for (epoch, sv) in epoch_phys.i64()?.into_iter().zip(df.column("sv")?.str()?) {
+        if let (Some(epoch), Some(sv)) = (epoch, sv) {
+            per_sv.entry(sv).or_default().push(epoch);
+            station.push(epoch);
+        }
+    }
+    station.sort_unstable();
+    station.dedup();
+
+    let interval = match interval {
+        Some(seconds) => Some((seconds * scale as f64) as i64),
+        None => infer_interval(&station),
+    };
+
+    let mut svs: Vec<Option<String>> = Vec::new();
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut missing = Vec::new();
+
+    if let Some(interval) = interval.filter(|dt| *dt > 0) {
+        let mut push = |sv: Option<&str>, epochs: &[i64]| {
+            for (start, end) in find_gaps(epochs, interval) {
+                svs.push(sv.map(str::to_string));
+                starts.push(start);
+                ends.push(end);
+                missing.push(((end - start + interval / 2) / interval - 1) as u32);
+            }
+        };
+        push(None, &station);
+        for (sv, mut epochs) in per_sv {
+            epochs.sort_unstable();
+            epochs.dedup();
+            push(Some(sv), &epochs);
+        }
+    }
+
+    let durations: Vec<i64> = starts.iter().zip(&ends).map(|(s, e)| e - s).collect();
+    let duration_dtype = match &epoch_dtype {
+        DataType::Datetime(unit, _) => DataType::Duration(*unit),
+        _ => DataType::Duration(TimeUnit::Microseconds),
+    };
+
+    DataFrame::new(vec![
+        Series::new("sv".into(), svs).into(),
+        Series::new("start".into(), starts).cast(&epoch_dtype)?.into(),
+        Series::new("end".into(), ends).cast(&epoch_dtype)?.into(),
+        Series::new("duration".into(), durations).cast(&duration_dtype)?.into(),
+        Series::new("n_missing".into(), missing).into(),
+    ])
+}
+
+/// Detects data gaps relative to the nominal sampling interval, for the whole station
+/// and for each satellite
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch' and 'sv'
+///     interval (float, optional): Nominal sampling interval (in seconds); inferred
+///         from the most frequent epoch spacing if not provided
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'sv' (null for station-level gaps), 'start',
+///     'end' (last epoch before and first epoch after the gap), 'duration', 'n_missing'
+#[pyfunction]
+#[pyo3(signature = (df, interval=None))]
+pub(crate) fn detect_gaps(df: PyDataFrame, interval: Option<f64>) -> PyResult<PyDataFrame> {
+    gaps(&df.0, interval).map(PyDataFrame).map_err(polars_err)
+}
+
+/// Infers the sampling interval of observations from the spacing of their epochs
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with an 'epoch' column
+///     resolution (float): Resolution in seconds to which the spacings are rounded
+///
+/// Returns:
+///     timedelta | None: Most frequent spacing between consecutive distinct epochs
+///     (the shortest one on ties), or None with less than two epochs
+#[pyfunction]
+#[pyo3(signature = (df, resolution=0.001))]
+pub(crate) fn sampling_interval(df: PyDataFrame, resolution: f64) -> PyResult<Option<TimeDelta>> {
+    let epoch_col = df.0.column("epoch").map_err(polars_err)?;
+    let scale = units_per_second(epoch_col.dtype());
+    let epoch_phys = epoch_col.to_physical_repr();
+    let epochs: Vec<i64> = epoch_phys.i64().map_err(polars_err)?.into_iter().flatten().collect();
+    let resolution = (resolution * scale as f64).round() as i64;
+    Ok(infer_interval_rounded(&epochs, resolution).map(|dt| TimeDelta::nanoseconds(dt * (1_000_000_000 / scale))))
+}
+
+/// Completeness of the observations against the satellites predicted to be visible
+pub(crate) struct Completeness {
+    pub per_epoch: DataFrame,
+    pub per_day: DataFrame,
+}
+
+/// Compares, for each epoch of the nominal grid, the satellites actually observed with
+/// those predicted above the elevation mask by the broadcast ephemerides; only the
+/// constellations present in the observations are taken into account
+pub(crate) fn completeness(
+    df: &DataFrame,
+    ephemerides: &Ephemerides,
+    site: &Site,
+    min_elevation: f64,
+    interval: Option<f64>,
+) -> PolarsResult<Completeness> {
+    let epoch_col = df.column("epoch")?;
+    let epoch_dtype = epoch_col.dtype().clone();
+    let epoch_phys = epoch_col.to_physical_repr();
+    let scale = units_per_second(&epoch_dtype);
+    let value_col = df.column("value")?.cast(&DataType::Float64)?;
+
+    // Number of recorded signals per (epoch, sv)
+    let mut observed: HashMap<i64, HashMap<&str, u32>> = HashMap::new();
+    for ((epoch, sv), value) in epoch_phys.i64()?.into_iter().zip(df.column("sv")?.str()?).zip(value_col.f64()?) {
+        if let (Some(epoch), Some(sv), Some(_)) = (epoch, sv, value) {
+            *observed.entry(epoch).or_default().entry(sv).or_default() += 1;
+        }
+    }
+
+    let mut epochs: Vec<i64> = observed.keys().copied().collect();
+    epochs.sort_unstable();
+    let interval = match interval {
+        Some(seconds) => Some((seconds * scale as f64) as i64),
+        None => infer_interval(&epochs),
+    };
+
+    let systems: Vec<char> = observed
+        .values()
+        .flat_map(|svs| svs.keys().filter_map(|sv| sv.chars().next()))
+        .collect::<std::collections::BTreeSet<char>>()
+        .into_iter()
+        .collect();
+    let satellites: Vec<&str> = ephemerides
+        .satellites()
+        .filter(|sv| sv.chars().next().is_some_and(|s| systems.contains(&s)))
+        .collect();
+
+    let grid: Vec<i64> = match (epochs.first(), epochs.last(), interval.filter(|dt| *dt > 0)) {
+        (Some(first), Some(last), Some(dt)) => (0..=(last - first) / dt).map(|k| first + k * dt).collect(),
+        _ => epochs.clone(),
+    };
+
+    let mut n_expected = Vec::with_capacity(grid.len());
+    let mut n_observed = Vec::with_capacity(grid.len());
+    let mut n_signals = Vec::with_capacity(grid.len());
+    let mut ratios = Vec::with_capacity(grid.len());
+    let mut days: BTreeMap<i32, (u32, u32)> = BTreeMap::new();
+    let empty = HashMap::new();
+
+    for &epoch in &grid {
+        let seconds = epoch as f64 / scale as f64;
+        let at_epoch = observed.get(&epoch).unwrap_or(&empty);
+
+        let (mut expected, mut seen, mut signals) = (0u32, 0u32, 0u32);
+        for sv in &satellites {
+            let Some(position) = ephemerides.position(sv, seconds) else {
+                continue;
+            };
+            if site.azimuth_elevation(position).1 < min_elevation {
+                continue;
+            }
+            expected += 1;
+            if let Some(count) = at_epoch.get(sv) {
+                seen += 1;
+                signals += count;
+            }
+        }
+
+        n_expected.push(expected);
+        n_observed.push(seen);
+        n_signals.push(signals);
+        ratios.push((expected > 0).then(|| 100.0 * seen as f64 / expected as f64));
+
+        let day = days.entry((seconds / 86_400.0).floor() as i32).or_default();
+        day.0 += expected;
+        day.1 += seen;
+    }
+
+    let per_epoch = DataFrame::new(vec![
+        Series::new("epoch".into(), grid).cast(&epoch_dtype)?.into(),
+        Series::new("n_expected".into(), n_expected).into(),
+        Series::new("n_observed".into(), n_observed).into(),
+        Series::new("n_signals".into(), n_signals).into(),
+        Series::new("completeness".into(), ratios).into(),
+    ])?;
+
+    let dates: Vec<i32> = days.keys().copied().collect();
+    let (expected, seen): (Vec<u32>, Vec<u32>) = days.values().copied().unzip();
+    let day_ratios: Vec<Option<f64>> = expected
+        .iter()
+        .zip(&seen)
+        .map(|(e, s)| (*e > 0).then(|| 100.0 * *s as f64 / *e as f64))
+        .collect();
+    let per_day = DataFrame::new(vec![
+        Series::new("date".into(), dates).cast(&DataType::Date)?.into(),
+        Series::new("n_expected".into(), expected).into(),
+        Series::new("n_observed".into(), seen).into(),
+        Series::new("completeness".into(), day_ratios).into(),
+    ])?;
+
+    Ok(Completeness { per_epoch, per_day })
+}
+
+/// Computes per-epoch and per-day completeness of the observations, as the percentage of
+/// satellites predicted above the elevation mask (from broadcast ephemerides) actually observed
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     nav (dict[str, PyDataFrame]): Navigation data keyed by constellation, as from `read_rinex_nav`
+///     rx_position (tuple[float, float, float]): Receiver ECEF coordinates (in meters)
+///     min_elevation (float): Elevation mask (in degrees)
+///     interval (float, optional): Nominal sampling interval (in seconds); inferred
+///         from the most frequent epoch spacing if not provided
+///
+/// Returns:
+///     tuple:
+///         - PyDataFrame: Columns 'epoch', 'n_expected', 'n_observed', 'n_signals', 'completeness'
+///         - PyDataFrame: Columns 'date', 'n_expected', 'n_observed', 'completeness'
+#[pyfunction]
+#[pyo3(signature = (df, nav, rx_position, min_elevation=10.0, interval=None))]
+pub(crate) fn epoch_completeness(
+    df: PyDataFrame,
+    nav: HashMap<String, PyDataFrame>,
+    rx_position: (f64, f64, f64),
+    min_elevation: f64,
+    interval: Option<f64>,
+) -> PyResult<(PyDataFrame, PyDataFrame)> {
+    let nav: HashMap<String, DataFrame> = nav.into_iter().map(|(k, v)| (k, v.0)).collect();
+    let ephemerides = Ephemerides::from_frames(&nav).map_err(polars_err)?;
+    let site = Site::new(rx_position);
+    let result = completeness(&df.0, &ephemerides, &site, min_elevation, interval).map_err(polars_err)?;
+    Ok((PyDataFrame(result.per_epoch), PyDataFrame(result.per_day)))
+}
+
+/// Aggregates signal-to-noise ratio observations ('S*' observables) per SV, per signal and,
+/// when satellite positions are available, per elevation bin
+pub(crate) fn snr_statistics(
+    df: &DataFrame,
+    geometry: Option<(&Ephemerides, &Site)>,
+    bin_width: f64,
+) -> PolarsResult<DataFrame> {
+    let epoch_col = df.column("epoch")?;
+    let scale = units_per_second(epoch_col.dtype()) as f64;
+    let epoch_phys = epoch_col.to_physical_repr();
+    let value_col = df.column("value")?.cast(&DataType::Float64)?;
+
+    let mut elevations: HashMap<(i64, &str), Option<f64>> = HashMap::new();
+    let mut groups: BTreeMap<(&str, &str, Option<i64>), Vec<f64>> = BTreeMap::new();
+
+    let rows = epoch_phys
+        .i64()?
+        .into_iter()
+        .zip(df.column("sv")?.str()?)
+        .zip(df.column("observable")?.str()?)
+        .zip(value_col.f64()?);
+    for (((epoch, sv), code), value) in rows {
+        let (Some(epoch), Some(sv), Some(code), Some(value)) = (epoch, sv, code, value) else {
+            continue;
+        };
+        if !code.starts_with('S') {
+            continue;
+        }
+        let bin = match geometry {
+            None => None,
+            Some((ephemerides, site)) => {
+                let elevation = *elevations.entry((epoch, sv)).or_insert_with(|| {
+                    ephemerides
+                        .position(sv, epoch as f64 / scale)
+                        .map(|position| site.azimuth_elevation(position).1)
+                });
+                // Observations without a usable ephemeris cannot be binned
+                match elevation {
+                    Some(elevation) if elevation >= 0.0 => Some((elevation / bin_width).floor() as i64),
+                    _ => continue,
+                }
+            },
+        };
+        groups.entry((sv, code, bin)).or_default().push(value);
+    }
+
+    let mut svs = Vec::with_capacity(groups.len());
+    let mut codes = Vec::with_capacity(groups.len());
+    let mut bins = Vec::with_capacity(groups.len());
+    let mut counts = Vec::with_capacity(groups.len());
+    let mut means = Vec::with_capacity(groups.len());
+    let mut medians = Vec::with_capacity(groups.len());
+    let mut mins = Vec::with_capacity(groups.len());
+
+    for ((sv, code, bin), mut values) in groups {
+        values.sort_unstable_by(f64::total_cmp);
+        let n = values.len();
+        let median = if n % 2 == 1 {
+            values[n / 2]
+        } else {
+            (values[n / 2 - 1] + values[n / 2]) / 2.0
+        };
+
+        svs.push(sv);
+        codes.push(code);
+        bins.push(bin.map(|b| b as f64 * bin_width));
+        counts.push(n as u32);
+        means.push(values.iter().sum::<f64>() / n as f64);
+        medians.push(median);
+        mins.push(values[0]);
+    }
+
+    let mut columns: Vec<Column> = vec![
+        Series::new("sv".into(), svs).into(),
+        Series::new("observable".into(), codes).into(),
+    ];
+    if geometry.is_some() {
+        columns.push(Series::new("elevation_bin".into(), bins).into());
+    }
+    columns.extend([
+        Series::new("n_obs".into(), counts).into(),
+        Series::new("mean_snr".into(), means).into(),
+        Series::new("median_snr".into(), medians).into(),
+        Series::new("min_snr".into(), mins).into(),
+    ]);
+    DataFrame::new(columns)
+}
+
+/// Computes mean, median and minimum SNR per SV and per signal, optionally split into
+/// elevation bins when navigation data and the receiver position are provided
+///
+/// Parameters:
+///     df (PyDataFrame): Observations with columns 'epoch', 'sv', 'observable', 'value'
+///     nav (dict[str, PyDataFrame], optional): Navigation data keyed by constellation
+///     rx_position (tuple[float, float, float], optional): Receiver ECEF coordinates (in meters)
+///     bin_width (float): Width of the elevation bins (in degrees)
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'sv', 'observable', ['elevation_bin',] 'n_obs',
+///     'mean_snr', 'median_snr', 'min_snr'
+#[pyfunction]
+#[pyo3(signature = (df, nav=None, rx_position=None, bin_width=10.0))]
+pub(crate) fn snr_stats(
+    df: PyDataFrame,
+    nav: Option<HashMap<String, PyDataFrame>>,
+    rx_position: Option<(f64, f64, f64)>,
+    bin_width: f64,
+) -> PyResult<PyDataFrame> {
+    if bin_width <= 0.0 {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "bin_width must be positive",
+        ));
+    }
+    let geometry = match (nav, rx_position) {
+        (Some(nav), Some(rx_position)) => {
+            let nav: HashMap<String, DataFrame> = nav.into_iter().map(|(k, v)| (k, v.0)).collect();
+            Some((Ephemerides::from_frames(&nav).map_err(polars_err)?, Site::new(rx_position)))
+        },
+        (None, None) => None,
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "nav and rx_position must be provided together",
+            ))
+        },
+    };
+    snr_statistics(&df.0, geometry.as_ref().map(|(e, s)| (e, s)), bin_width)
+        .map(PyDataFrame)
+        .map_err(polars_err)
+}
@@ -0,0 +1,330 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use polars::prelude::*;
+use rinex::prelude::Constellation;
+use std::collections::BTreeMap;
+
+use crate::frames::{self, polars_err};
+use crate::rtcm::{sisa_meters, URA_METERS};
+use crate::timescales::{self, OutputScale};
+
+/// An observation decoded from a raw receiver or stream format, in the long format of
+/// `read_rinex_obs`
+pub(crate) struct ObsRow {
+    /// GPST, Unix-like nanoseconds
+    pub(crate) epoch: i64,
+    pub(crate) sv: String,
+    pub(crate) observable: String,
+    pub(crate) value: f64,
+    /// Receiver clock offset of the epoch (s), if reported
+    pub(crate) clock_offset: Option<f64>,
+}
+
+/// Builds the observation DataFrame of `read_rinex_obs` from decoded rows
+pub(crate) fn obs_frame(rows: &[ObsRow], scale: OutputScale, unit: TimeUnit) -> PyResult<PyDataFrame> {
+    let divisor = if unit == TimeUnit::Nanoseconds { 1 } else { 1_000 };
+    let epochs: Vec<i64> = rows
+        .iter()
+        .map(|r| scale.tai_to_scale(OutputScale::Gpst.scale_to_tai(r.epoch)).div_euclid(divisor))
+        .collect();
+    let df = DataFrame::new(vec![
+        frames::datetime_series("epoch", epochs, unit).into(),
+        Series::new("sv".into(), rows.iter().map(|r| r.sv.as_str()).collect::<Vec<_>>()).into(),
+        Series::new(
+            "observable".into(),
+            rows.iter().map(|r| r.observable.as_str()).collect::<Vec<_>>(),
+        )
+        .into(),
+        Series::new("value".into(), rows.iter().map(|r| r.value).collect::<Vec<_>>()).into(),
+        Series::new("epoch_flag".into(), vec![0u32; rows.len()]).into(),
+        Series::new(
+            "rx_clock_offset".into(),
+            rows.iter().map(|r| r.clock_offset).collect::<Vec<_>>(),
+        )
+        .into(),
+    ])
+    .map_err(polars_err)?;
+    Ok(PyDataFrame(df))
+}
+
+/// A broadcast ephemeris decoded from a raw receiver or stream format, with the
+/// parameter names and units of RINEX navigation records
+pub(crate) struct NavRecord {
+    pub(crate) constellation: Constellation,
+    pub(crate) prn: u8,
+    /// Time of clock, Unix-like nanoseconds labelled with the calendar fields of the
+    /// constellation's timescale (UTC for GLONASS), as `read_rinex_nav` epochs
+    pub(crate) toc: i64,
+    /// 'clock_bias', 'clock_drift', 'clock_drift_rate' and the orbit parameters
+    pub(crate) params: Vec<(&'static str, f64)>,
+}
+
+/// Name of the `read_rinex_nav` frame and timescale of the epochs of a constellation
+fn constellation_frame(constellation: Constellation) -> Option<(&'static str, OutputScale)> {
+    match constellation {
+        Constellation::GPS => Some(("GPS", OutputScale::Gpst)),
+        Constellation::Glonass => Some(("GLONASS", OutputScale::Utc)),
+        Constellation::Galileo => Some(("GALILEO", OutputScale::Gst)),
+        Constellation::BeiDou => Some(("BEIDOU", OutputScale::Bdt)),
+        _ => None,
+    }
+}
+
+/// URA index of a GPS or BeiDou accuracy in meters, written as the upper bound of its
+/// interval (e.g. 2.4) or as its nominal value (e.g. 2.0)
+fn ura_index(meters: f64) -> Option<u32> {
+    if !meters.is_finite() || meters < 0.0 {
+        return None;
+    }
+    let index = URA_METERS.iter().position(|bound| meters <= bound * (1.0 + 1e-6));
+    Some(index.unwrap_or(URA_METERS.len() - 1) as u32)
+}
+
+/// SISA index of a Galileo accuracy in meters; negative values (no accuracy prediction)
+/// are index 255
+fn sisa_index(meters: f64) -> Option<u32> {
+    if !meters.is_finite() {
+        return None;
+    }
+    let index = match meters {
+        m if m < 0.0 => 255.0,
+        m if m < 0.5 => (m / 0.01).round(),
+        m if m < 1.0 => 50.0 + ((m - 0.5) / 0.02).round(),
+        m if m < 2.0 => 75.0 + ((m - 1.0) / 0.04).round(),
+        m if m <= 6.0 => 100.0 + ((m - 2.0) / 0.16).round(),
+        _ => 255.0,
+    };
+    Some(index as u32)
+}
+
+/// Adds the decoded broadcast accuracy of the ephemerides to a navigation DataFrame:
+/// 'ura_index' (GPS and BeiDou URA index, or Galileo SISA index) and 'ura' (meters,
+/// upper bound of the URA interval or SISA value, null without accuracy prediction)
+pub(crate) fn with_accuracy_columns(mut df: DataFrame) -> PolarsResult<DataFrame> {
+    // SBAS broadcast the URA index itself
+    if df.get_column_index("accuracyCode").is_some() {
+        let codes = df.column("accuracyCode")?.cast(&DataType::Float64)?;
+        let (indices, ura): (Vec<Option<u32>>, Vec<Option<f64>>) = codes
+            .f64()?
+            .into_iter()
+            .map(|code| {
+                let index = code.filter(|c| (0.0..16.0).contains(c)).map(|c| c as u32);
+                (index, index.filter(|i| *i < 15).map(|i| URA_METERS[i as usize]))
+            })
+            .unzip();
+        df.with_column(Series::new("ura_index".into(), indices))?;
+        df.with_column(Series::new("ura".into(), ura))?;
+        return Ok(df);
+    }
+    let (source, galileo) = if df.get_column_index("sisa").is_some() {
+        ("sisa", true)
+    } else if df.get_column_index("accuracy").is_some() {
+        ("accuracy", false)
+    } else {
+        return Ok(df);
+    };
+    let meters = df.column(source)?.cast(&DataType::Float64)?;
+    let (indices, ura): (Vec<Option<u32>>, Vec<Option<f64>>) = meters
+        .f64()?
+        .into_iter()
+        .map(|value| {
+            let index = if galileo { value.and_then(sisa_index) } else { value.and_then(ura_index) };
+            let ura = index.and_then(|i| match (galileo, i) {
+                (true, 255) | (false, 15) => None,
+                (true, i) => Some(sisa_meters(u64::from(i))),
+                (false, i) => Some(URA_METERS[i as usize]),
+            });
+            (index, ura)
+        })
+        .unzip();
+    df.with_column(Series::new("ura_index".into(), indices))?;
+    df.with_column(Series::new("ura".into(), ura))?;
+    Ok(df)
+}
+
+/// Nominal validity (hours) of the ephemerides of a constellation, when no fit interval
+/// is broadcast: 4 hours for GPS and Galileo, 2 hours for the hourly BeiDou ephemerides,
+/// 30 minutes for the GLONASS state vectors and 6 minutes for the SBAS ones
+fn nominal_validity_hours(constellation: &str) -> f64 {
+    match constellation {
+        "BEIDOU" => 2.0,
+        "GLONASS" => 0.5,
+        "SBAS" => 0.1,
+        _ => 4.0,
+    }
+}
+
+/// Adds the validity window of the ephemerides to a navigation DataFrame: 'fit_interval'
+/// (the broadcast GPS fit interval, or the nominal validity of the constellation) and
+/// 'valid_from', 'valid_until', centred on the epoch of each record
+pub(crate) fn with_validity_columns(mut df: DataFrame, constellation: &str) -> PolarsResult<DataFrame> {
+    let epoch_dtype = df.column("epoch")?.dtype().clone();
+    let unit = match epoch_dtype {
+        DataType::Datetime(unit, _) => unit,
+        _ => TimeUnit::Microseconds,
+    };
+    let units_per_hour = (frames::units_per_second(&epoch_dtype) * 3_600) as f64;
+    let nominal = nominal_validity_hours(constellation);
+
+    // Zero (or a missing) fit interval stands for the 4-hour default of GPS
+    let hours: Vec<f64> = match df.get_column_index("fitInt") {
+        Some(_) => df
+            .column("fitInt")?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .map(|h| h.filter(|h| *h > 0.0).unwrap_or(nominal))
+            .collect(),
+        None => vec![nominal; df.height()],
+    };
+    let durations: Vec<i64> = hours.iter().map(|h| (h * units_per_hour).round() as i64).collect();
+
+    let epochs = df.column("epoch")?.to_physical_repr();
+    let (valid_from, valid_until): (Vec<Option<i64>>, Vec<Option<i64>>) = epochs
+        .i64()?
+        .into_iter()
+        .zip(&durations)
+        .map(|(epoch, duration)| (epoch.map(|e| e - duration / 2), epoch.map(|e| e + duration - duration / 2)))
+        .unzip();
+
+    df.with_column(frames::duration_series("fit_interval", durations, unit))?;
+    for (name, values) in [("valid_from", valid_from), ("valid_until", valid_until)] {
+        df.with_column(Int64Chunked::new(name.into(), values).into_datetime(unit, None).into_series())?;
+    }
+    Ok(df)
+}
+
+/// Broadcast parameters of the GLONASS and SBAS state-vector ephemerides, common to
+/// RINEX 2 ('G' and 'H' files) and RINEX 3/4 records
+const GLONASS_PARAMS: [&str; 11] = [
+    "satPosX", "satPosY", "satPosZ", "velX", "velY", "velZ", "accelX", "accelY", "accelZ", "channel", "ageOp",
+];
+const SBAS_PARAMS: [&str; 11] = [
+    "satPosX", "satPosY", "satPosZ", "velX", "velY", "velZ", "accelX", "accelY", "accelZ", "accuracyCode", "iodn",
+];
+
+/// Completes the GLONASS and SBAS DataFrames with all the parameters of their
+/// state-vector ephemerides: the rinex crate omits zero values (e.g. null luni-solar
+/// accelerations or frequency channel 0), which are restored, so that the columns do not
+/// depend on the RINEX version or on the records of a file
+pub(crate) fn with_state_vector_columns(mut df: DataFrame, constellation: &str) -> PolarsResult<DataFrame> {
+    let params: &[&str] = match constellation {
+        "GLONASS" => &GLONASS_PARAMS,
+        "SBAS" => &SBAS_PARAMS,
+        _ => return Ok(df),
+    };
+    for name in params {
+        let values = match df.get_column_index(name) {
+            Some(_) => df.column(name)?.cast(&DataType::Float64)?.fill_null(FillNullStrategy::Zero)?,
+            None => Column::new((*name).into(), vec![0.0f64; df.height()]),
+        };
+        df.with_column(values)?;
+    }
+    Ok(df)
+}
+
+/// Adds explicit reference times to a navigation DataFrame: 'toc' (clock reference
+/// epoch, i.e. the record epoch) and, for the Keplerian constellations, 'toe_epoch' (orbit
+/// reference time), the 'toe' seconds of the native week nearest to the clock epoch
+pub(crate) fn with_reference_time_columns(mut df: DataFrame) -> PolarsResult<DataFrame> {
+    let toc = df.column("epoch")?.as_materialized_series().clone().with_name("toc".into());
+    df.with_column(toc)?;
+    if df.get_column_index("toe").is_none() {
+        return Ok(df);
+    }
+
+    let epoch_dtype = df.column("epoch")?.dtype().clone();
+    let unit = match epoch_dtype {
+        DataType::Datetime(unit, _) => unit,
+        _ => TimeUnit::Microseconds,
+    };
+    let units_per_second = frames::units_per_second(&epoch_dtype) as f64;
+    let week_seconds = 604_800.0;
+
+    let epochs = df.column("epoch")?.to_physical_repr();
+    let toe = df.column("toe")?.cast(&DataType::Float64)?;
+    let tow = df.column("native_tow")?.cast(&DataType::Float64)?;
+    let toe_epochs: Vec<Option<i64>> = epochs
+        .i64()?
+        .into_iter()
+        .zip(toe.f64()?)
+        .zip(tow.f64()?)
+        .map(|((epoch, toe), tow)| {
+            let (epoch, toe, tow) = (epoch?, toe?, tow?);
+            // Offset from the clock epoch, wrapped into half a week on either side
+            let offset = toe - tow;
+            let offset = offset - week_seconds * (offset / week_seconds).round();
+            Some(epoch + (offset * units_per_second).round() as i64)
+        })
+        .collect();
+    df.with_column(Int64Chunked::new("toe_epoch".into(), toe_epochs).into_datetime(unit, None).into_series())?;
+    Ok(df)
+}
+
+/// Builds the per-constellation DataFrames of `read_rinex_nav` from decoded records;
+/// `scale` converts the epochs into a timescale, None keeps the native labels
+pub(crate) fn nav_frames(
+    records: &[NavRecord],
+    scale: Option<OutputScale>,
+    unit: TimeUnit,
+) -> PyResult<BTreeMap<String, PyDataFrame>> {
+    let divisor = if unit == TimeUnit::Nanoseconds { 1 } else { 1_000 };
+    let mut groups: BTreeMap<&str, Vec<(&NavRecord, OutputScale)>> = BTreeMap::new();
+    for record in records {
+        if let Some((name, native)) = constellation_frame(record.constellation) {
+            groups.entry(name).or_default().push((record, native));
+        }
+    }
+
+    let mut result = BTreeMap::new();
+    for (name, group) in groups {
+        let mut epochs = Vec::with_capacity(group.len());
+        let mut weeks = Vec::with_capacity(group.len());
+        let mut columns: BTreeMap<&str, Vec<Option<f64>>> = BTreeMap::new();
+        for (i, (record, native)) in group.iter().enumerate() {
+            let tai = native.scale_to_tai(record.toc);
+            let ts = match scale {
+                Some(scale) => scale.tai_to_scale(tai),
+                None => record.toc,
+            };
+            epochs.push(ts.div_euclid(divisor));
+
+            let gpst = OutputScale::Gpst.tai_to_scale(tai).div_euclid(1_000);
+            let (gps_week, tow) = timescales::gps_week_and_tow(gpst);
+            let native_week = timescales::native_week_and_tow(record.toc.div_euclid(1_000), record.constellation);
+            weeks.push((gps_week, tow, native_week));
+
+            for (key, value) in &record.params {
+                let column = columns.entry(key).or_default();
+                column.resize(i, None);
+                column.push(Some(*value));
+            }
+        }
+
+        let mut df = DataFrame::new(vec![
+            frames::datetime_series("epoch", epochs, unit).into(),
+            Series::new(
+                "sv".into(),
+                group.iter().map(|(r, _)| r.prn.to_string()).collect::<Vec<_>>(),
+            )
+            .into(),
+            Series::new("gps_week".into(), weeks.iter().map(|w| w.0).collect::<Vec<_>>()).into(),
+            Series::new("tow".into(), weeks.iter().map(|w| w.1).collect::<Vec<_>>()).into(),
+            Series::new("native_week".into(), weeks.iter().map(|w| w.2.map(|n| n.0)).collect::<Vec<_>>()).into(),
+            Series::new("native_tow".into(), weeks.iter().map(|w| w.2.map(|n| n.1)).collect::<Vec<_>>()).into(),
+        ])
+        .map_err(polars_err)?;
+        for (key, mut values) in columns {
+            values.resize(group.len(), None);
+            df.with_column(Series::new(key.into(), values)).map_err(polars_err)?;
+        }
+        let df = frames::sort_by_keys(df, &["epoch", "sv"])
+            .and_then(|df| with_state_vector_columns(df, name))
+            .and_then(with_accuracy_columns)
+            .and_then(|df| with_validity_columns(df, name))
+            .and_then(with_reference_time_columns)
+            .map_err(polars_err)?;
+        result.insert(name.to_string(), PyDataFrame(df));
+    }
+    Ok(result)
+}
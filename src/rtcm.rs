@@ -0,0 +1,919 @@
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use rinex::prelude::Constellation;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+use crate::combinations::{carrier_frequency, C};
+use crate::records::{self, NavRecord, ObsRow};
+use crate::timescales::{self, OutputScale};
+
+/// Preamble of RTCM 3 frames
+const PREAMBLE: u8 = 0xD3;
+/// Frame header (preamble and length) and CRC sizes, in bytes
+const HEADER_LEN: usize = 3;
+const CRC_LEN: usize = 3;
+/// Distance travelled by light in one millisecond (m)
+const MS_RANGE: f64 = C * 1e-3;
+pub(crate) const DAY_NANOS: i64 = 86_400_000_000_000;
+pub(crate) const WEEK_NANOS: i64 = 7 * DAY_NANOS;
+/// Start of GPS week 0 (1980-01-06) in Unix-like nanoseconds
+pub(crate) const GPS_WEEK_ORIGIN_NANOS: i64 = 315_964_800_000_000_000;
+/// Constant offset between GPST and BDT in milliseconds
+const GPST_BDT_MILLIS: i64 = 14_000;
+/// Start of BDT week 0 (2006-01-01) in Unix-like nanoseconds
+const BDT_WEEK_ORIGIN_NANOS: i64 = 1_136_073_600_000_000_000;
+/// Offset of GLONASS time (UTC(SU) + 3h) from UTC in nanoseconds
+const GLONASST_UTC_NANOS: i64 = 3 * 3_600_000_000_000;
+/// GPS and BeiDou user range accuracy (m) of the URA index
+pub(crate) const URA_METERS: [f64; 16] = [
+    2.4, 3.4, 4.85, 6.85, 9.65, 13.65, 24.0, 48.0, 96.0, 192.0, 384.0, 768.0, 1536.0, 3072.0, 6144.0, 6144.0,
+];
+
+/// CRC-24Q checksum of RTCM 3 frames
+fn crc24q(data: &[u8]) -> u32 {
+    let mut crc = 0u32;
+    for &byte in data {
+        crc ^= u32::from(byte) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x100_0000 != 0 {
+                crc ^= 0x186_4CFB;
+            }
+        }
+    }
+    crc & 0xFF_FFFF
+}
+
+/// Big-endian bit reader over a message payload
+pub(crate) struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// Reads an unsigned field of up to 64 bits, None past the end of the payload
+    pub(crate) fn u(&mut self, bits: usize) -> Option<u64> {
+        if self.pos + bits > self.data.len() * 8 {
+            return None;
+        }
+        let mut value = 0u64;
+        for i in self.pos..self.pos + bits {
+            let bit = (self.data[i / 8] >> (7 - i % 8)) & 1;
+            value = (value << 1) | u64::from(bit);
+        }
+        self.pos += bits;
+        Some(value)
+    }
+
+    /// Reads a two's complement signed field
+    pub(crate) fn i(&mut self, bits: usize) -> Option<i64> {
+        let value = self.u(bits)? as i64;
+        let shift = 64 - bits;
+        Some((value << shift) >> shift)
+    }
+
+    /// Reads a sign-magnitude field (GLONASS ephemeris)
+    pub(crate) fn sm(&mut self, bits: usize) -> Option<i64> {
+        let value = self.u(bits)?;
+        let magnitude = (value & ((1 << (bits - 1)) - 1)) as i64;
+        Some(if value >> (bits - 1) == 1 { -magnitude } else { magnitude })
+    }
+
+    pub(crate) fn skip(&mut self, bits: usize) -> Option<()> {
+        self.u(bits).map(|_| ())
+    }
+}
+
+/// Smallest value of a signed field, used by RTCM as 'invalid' marker
+fn invalid(bits: usize) -> i64 {
+    -(1 << (bits - 1))
+}
+
+/// Constellation letter of an MSM message number (1071-1077 for GPS, etc.)
+fn msm_system(message: u16) -> Option<char> {
+    match message / 10 {
+        107 => Some('G'),
+        108 => Some('R'),
+        109 => Some('E'),
+        110 => Some('S'),
+        111 => Some('J'),
+        112 => Some('C'),
+        _ => None,
+    }
+}
+
+/// RINEX signal code (band and attribute) of an MSM signal mask position (1-32)
+fn msm_signal(system: char, id: u8) -> Option<&'static str> {
+    let code = match (system, id) {
+        ('G', 2) => "1C",
+        ('G', 3) => "1P",
+        ('G', 4) => "1W",
+        ('G', 8) => "2C",
+        ('G', 9) => "2P",
+        ('G', 10) => "2W",
+        ('G', 15) => "2S",
+        ('G', 16) => "2L",
+        ('G', 17) => "2X",
+        ('G', 22) => "5I",
+        ('G', 23) => "5Q",
+        ('G', 24) => "5X",
+        ('G', 30) => "1S",
+        ('G', 31) => "1L",
+        ('G', 32) => "1X",
+        ('R', 2) => "1C",
+        ('R', 3) => "1P",
+        ('R', 8) => "2C",
+        ('R', 9) => "2P",
+        ('E', 2) => "1C",
+        ('E', 3) => "1A",
+        ('E', 4) => "1B",
+        ('E', 5) => "1X",
+        ('E', 6) => "1Z",
+        ('E', 8) => "6C",
+        ('E', 9) => "6A",
+        ('E', 10) => "6B",
+        ('E', 11) => "6X",
+        ('E', 12) => "6Z",
+        ('E', 14) => "7I",
+        ('E', 15) => "7Q",
+        ('E', 16) => "7X",
+        ('E', 18) => "8I",
+        ('E', 19) => "8Q",
+        ('E', 20) => "8X",
+        ('E', 22) => "5I",
+        ('E', 23) => "5Q",
+        ('E', 24) => "5X",
+        ('S', 2) => "1C",
+        ('S', 22) => "5I",
+        ('S', 23) => "5Q",
+        ('S', 24) => "5X",
+        ('J', 2) => "1C",
+        ('J', 9) => "6S",
+        ('J', 10) => "6L",
+        ('J', 11) => "6X",
+        ('J', 15) => "2S",
+        ('J', 16) => "2L",
+        ('J', 17) => "2X",
+        ('J', 22) => "5I",
+        ('J', 23) => "5Q",
+        ('J', 24) => "5X",
+        ('J', 30) => "1S",
+        ('J', 31) => "1L",
+        ('J', 32) => "1X",
+        ('C', 2) => "2I",
+        ('C', 3) => "2Q",
+        ('C', 4) => "2X",
+        ('C', 8) => "6I",
+        ('C', 9) => "6Q",
+        ('C', 10) => "6X",
+        ('C', 14) => "7I",
+        ('C', 15) => "7Q",
+        ('C', 16) => "7X",
+        ('C', 22) => "5D",
+        ('C', 23) => "5P",
+        ('C', 24) => "5X",
+        ('C', 25) => "7D",
+        ('C', 30) => "1D",
+        ('C', 31) => "1P",
+        ('C', 32) => "1X",
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// RINEX satellite identifier of an MSM satellite mask position (1-64)
+fn msm_sv(system: char, id: u8) -> String {
+    match system {
+        // SBAS PRNs start at 120, i.e. 'S20' in RINEX
+        'S' => format!("S{:02}", u16::from(id) + 19),
+        _ => format!("{}{:02}", system, id),
+    }
+}
+
+/// Nearest timestamp to `reference` of the form `origin + n * period + offset`
+pub(crate) fn nearest(reference: i64, origin: i64, period: i64, offset: i64) -> i64 {
+    let start = origin + (reference - origin).div_euclid(period) * period;
+    let t = start + offset;
+    if t - reference > period / 2 {
+        t - period
+    } else if reference - t > period / 2 {
+        t + period
+    } else {
+        t
+    }
+}
+
+/// Scales an integer field by a power of two
+pub(crate) fn pow2(value: i64, exponent: i32) -> f64 {
+    value as f64 * 2f64.powi(exponent)
+}
+
+/// Scales an angle field by a power of two, from semicircles to radians
+fn semicircles(value: i64, exponent: i32) -> f64 {
+    pow2(value, exponent) * PI
+}
+
+/// Continuous week number of a truncated week field, nearest to the reference week
+pub(crate) fn full_week(reference_week: i64, week: u64, rollover: i64) -> i64 {
+    let week = week as i64;
+    week + ((reference_week - week) as f64 / rollover as f64).round() as i64 * rollover
+}
+
+/// Galileo signal-in-space accuracy (m) of the SISA index; 255 (no accuracy prediction) is -1
+pub(crate) fn sisa_meters(index: u64) -> f64 {
+    let n = index as f64;
+    match index {
+        0..=49 => n * 0.01,
+        50..=74 => 0.5 + (n - 50.0) * 0.02,
+        75..=99 => 1.0 + (n - 75.0) * 0.04,
+        100..=125 => 2.0 + (n - 100.0) * 0.16,
+        _ => -1.0,
+    }
+}
+
+/// Streaming decoder of RTCM 3 messages
+#[pyclass]
+pub(crate) struct RtcmDecoder {
+    /// Bytes not yet decoded, e.g. a frame split across two reads
+    buffer: Vec<u8>,
+    /// Approximate current time (GPST, Unix-like nanoseconds), resolving the week
+    /// (or day, for GLONASS) of the message epochs; it follows the decoded epochs
+    reference: i64,
+    glonass_channels: HashMap<String, i32>,
+    /// Observations of the epoch being received, and of the completed epochs
+    pending: Vec<ObsRow>,
+    pending_epoch: Option<i64>,
+    completed: Vec<ObsRow>,
+    station_id: Option<u16>,
+    position: Option<(f64, f64, f64)>,
+    /// Broadcast ephemerides by message number, PRN and time of clock; repeated
+    /// broadcasts of an ephemeris replace each other
+    ephemerides: BTreeMap<(u16, u8, i64), NavRecord>,
+    frames: usize,
+    crc_errors: usize,
+}
+
+impl RtcmDecoder {
+    /// Extracts and decodes the complete frames of the buffer
+    fn decode_frames(&mut self) {
+        let mut start = 0;
+        while let Some(offset) = self.buffer[start..].iter().position(|b| *b == PREAMBLE) {
+            start += offset;
+            let frame = &self.buffer[start..];
+            if frame.len() < HEADER_LEN {
+                break;
+            }
+            // The 6 bits following the preamble are reserved (zero)
+            if frame[1] & 0xFC != 0 {
+                start += 1;
+                continue;
+            }
+            let length = (usize::from(frame[1] & 0x03) << 8) | usize::from(frame[2]);
+            let total = HEADER_LEN + length + CRC_LEN;
+            if frame.len() < total {
+                break;
+            }
+            let crc = (u32::from(frame[total - 3]) << 16)
+                | (u32::from(frame[total - 2]) << 8)
+                | u32::from(frame[total - 1]);
+            if crc24q(&frame[..total - CRC_LEN]) != crc {
+                // Not a frame boundary (or a corrupted frame): resynchronize
+                self.crc_errors += 1;
+                start += 1;
+                continue;
+            }
+            let payload = frame[HEADER_LEN..HEADER_LEN + length].to_vec();
+            self.decode_message(&payload);
+            self.frames += 1;
+            start += total;
+        }
+        self.buffer.drain(..start);
+    }
+
+    /// Decodes a message payload; unsupported and malformed messages are ignored
+    fn decode_message(&mut self, payload: &[u8]) {
+        let mut bits = BitReader::new(payload);
+        let Some(message) = bits.u(12).map(|m| m as u16) else {
+            return;
+        };
+        match message {
+            1005 | 1006 => {
+                if let Some((station, position)) = decode_station(&mut bits) {
+                    self.station_id = Some(station);
+                    self.position = Some(position);
+                }
+            },
+            1019 | 1020 | 1042 | 1045 | 1046 => {
+                if let Some(record) = self.decode_ephemeris(message, &mut bits) {
+                    self.ephemerides.insert((message, record.prn, record.toc), record);
+                }
+            },
+            _ => {
+                if let Some(system) = msm_system(message) {
+                    self.decode_msm(message, system, &mut bits);
+                }
+            },
+        }
+    }
+
+    /// Resolves the epoch field of an MSM header into a GPST timestamp (nanoseconds)
+    fn msm_epoch(&mut self, system: char, raw: u64) -> i64 {
+        let t = match system {
+            'R' => {
+                // Day of week (3 bits, unused) and milliseconds of day in GLONASS time
+                let tod = (raw & 0x7FF_FFFF) as i64 * 1_000_000;
+                let glonass_reference = OutputScale::Glonasst
+                    .tai_to_scale(OutputScale::Gpst.scale_to_tai(self.reference));
+                let t = nearest(glonass_reference, 0, DAY_NANOS, tod);
+                OutputScale::Gpst.tai_to_scale(OutputScale::Glonasst.scale_to_tai(t))
+            },
+            'C' => {
+                let tow = (raw as i64 + GPST_BDT_MILLIS) * 1_000_000;
+                nearest(self.reference, GPS_WEEK_ORIGIN_NANOS, WEEK_NANOS, tow)
+            },
+            _ => nearest(self.reference, GPS_WEEK_ORIGIN_NANOS, WEEK_NANOS, raw as i64 * 1_000_000),
+        };
+        self.reference = t;
+        t
+    }
+
+    fn decode_msm(&mut self, message: u16, system: char, bits: &mut BitReader) {
+        let kind = (message % 10) as u8;
+        if !(4..=7).contains(&kind) {
+            return;
+        }
+        let Some(msm) = decode_msm_body(system, kind, bits) else {
+            return;
+        };
+        self.station_id = Some(msm.station);
+
+        let epoch = self.msm_epoch(system, msm.epoch);
+        if self.pending_epoch.is_some_and(|t| t != epoch) {
+            self.completed.append(&mut self.pending);
+        }
+        self.pending_epoch = Some(epoch);
+
+        for cell in msm.cells {
+            let sv = msm_sv(system, cell.sat);
+            let channel = cell.glonass_channel.or_else(|| self.glonass_channels.get(&sv).copied());
+            let band = cell.signal.chars().next().unwrap_or('0');
+            let wavelength = carrier_frequency(system, band, channel).map(|f| C / f);
+
+            let mut push = |kind: char, value: Option<f64>| {
+                if let Some(value) = value {
+                    self.pending.push(ObsRow {
+                        epoch,
+                        sv: sv.clone(),
+                        observable: format!("{}{}", kind, cell.signal),
+                        value,
+                        clock_offset: None,
+                    });
+                }
+            };
+            push('C', cell.pseudorange);
+            push('L', cell.phase_range.zip(wavelength).map(|(r, w)| r / w));
+            push('D', cell.range_rate.zip(wavelength).map(|(r, w)| -r / w));
+            push('S', cell.cnr);
+        }
+
+        if !msm.multiple {
+            self.completed.append(&mut self.pending);
+            self.pending_epoch = None;
+        }
+    }
+    /// Decodes a broadcast ephemeris message; the GLONASS ones also provide the
+    /// frequency channel of the SV for the carrier phase of MSM4/MSM6 messages
+    fn decode_ephemeris(&mut self, message: u16, bits: &mut BitReader) -> Option<NavRecord> {
+        let record = match message {
+            1019 => decode_gps_ephemeris(bits, self.reference)?,
+            1020 => decode_glonass_ephemeris(bits, self.reference)?,
+            1042 => decode_beidou_ephemeris(bits, self.reference)?,
+            _ => decode_galileo_ephemeris(bits, self.reference, message == 1046)?,
+        };
+        if record.constellation == Constellation::Glonass {
+            if let Some((_, channel)) = record.params.iter().find(|(key, _)| *key == "channel") {
+                self.glonass_channels.insert(format!("R{:02}", record.prn), *channel as i32);
+            }
+        }
+        Some(record)
+    }
+}
+
+/// Decodes a GPS ephemeris (message 1019)
+fn decode_gps_ephemeris(bits: &mut BitReader, reference: i64) -> Option<NavRecord> {
+    let prn = bits.u(6)? as u8;
+    let reference_week = (reference - GPS_WEEK_ORIGIN_NANOS).div_euclid(WEEK_NANOS);
+    let week = full_week(reference_week, bits.u(10)?, 1024);
+    let accuracy = URA_METERS[bits.u(4)? as usize];
+    let l2_codes = bits.u(2)? as f64;
+    let idot = semicircles(bits.i(14)?, -43);
+    let iode = bits.u(8)? as f64;
+    let toc = bits.u(16)? as i64 * 16;
+    let af2 = pow2(bits.i(8)?, -55);
+    let af1 = pow2(bits.i(16)?, -43);
+    let af0 = pow2(bits.i(22)?, -31);
+    let iodc = bits.u(10)? as f64;
+    let crs = pow2(bits.i(16)?, -5);
+    let delta_n = semicircles(bits.i(16)?, -43);
+    let m0 = semicircles(bits.i(32)?, -31);
+    let cuc = pow2(bits.i(16)?, -29);
+    let e = pow2(bits.u(32)? as i64, -33);
+    let cus = pow2(bits.i(16)?, -29);
+    let sqrta = pow2(bits.u(32)? as i64, -19);
+    let toe = bits.u(16)? as f64 * 16.0;
+    let cic = pow2(bits.i(16)?, -29);
+    let omega0 = semicircles(bits.i(32)?, -31);
+    let cis = pow2(bits.i(16)?, -29);
+    let i0 = semicircles(bits.i(32)?, -31);
+    let crc = pow2(bits.i(16)?, -5);
+    let omega = semicircles(bits.i(32)?, -31);
+    let omega_dot = semicircles(bits.i(24)?, -43);
+    let tgd = pow2(bits.i(8)?, -31);
+    let health = bits.u(6)? as f64;
+    let l2p = bits.u(1)? as f64;
+    // Fit interval flag: 4 hours, or more than 4 hours
+    let fit_interval = if bits.u(1)? == 0 { 4.0 } else { 6.0 };
+
+    Some(NavRecord {
+        constellation: Constellation::GPS,
+        prn,
+        toc: nearest(reference, GPS_WEEK_ORIGIN_NANOS, WEEK_NANOS, toc * 1_000_000_000),
+        params: vec![
+            ("clock_bias", af0),
+            ("clock_drift", af1),
+            ("clock_drift_rate", af2),
+            ("iode", iode),
+            ("crs", crs),
+            ("deltaN", delta_n),
+            ("m0", m0),
+            ("cuc", cuc),
+            ("e", e),
+            ("cus", cus),
+            ("sqrta", sqrta),
+            ("toe", toe),
+            ("cic", cic),
+            ("omega0", omega0),
+            ("cis", cis),
+            ("i0", i0),
+            ("crc", crc),
+            ("omega", omega),
+            ("omegaDot", omega_dot),
+            ("idot", idot),
+            ("l2Codes", l2_codes),
+            ("week", week as f64),
+            ("l2p", l2p),
+            ("accuracy", accuracy),
+            ("health", health),
+            ("tgd", tgd),
+            ("iodc", iodc),
+            ("fitInt", fit_interval),
+        ],
+    })
+}
+
+/// Decodes a Galileo F/NAV (message 1045) or I/NAV (message 1046) ephemeris
+fn decode_galileo_ephemeris(bits: &mut BitReader, reference: i64, inav: bool) -> Option<NavRecord> {
+    let prn = bits.u(6)? as u8;
+    // Galileo week 0 is GPS week 1024; RINEX uses the GPS-aligned week number
+    let reference_week = (reference - GPS_WEEK_ORIGIN_NANOS).div_euclid(WEEK_NANOS) - 1024;
+    let week = full_week(reference_week, bits.u(12)?, 4096) + 1024;
+    let iodnav = bits.u(10)? as f64;
+    let sisa = sisa_meters(bits.u(8)?);
+    let idot = semicircles(bits.i(14)?, -43);
+    let toc = bits.u(14)? as i64 * 60;
+    let af2 = pow2(bits.i(6)?, -59);
+    let af1 = pow2(bits.i(21)?, -46);
+    let af0 = pow2(bits.i(31)?, -34);
+    let crs = pow2(bits.i(16)?, -5);
+    let delta_n = semicircles(bits.i(16)?, -43);
+    let m0 = semicircles(bits.i(32)?, -31);
+    let cuc = pow2(bits.i(16)?, -29);
+    let e = pow2(bits.u(32)? as i64, -33);
+    let cus = pow2(bits.i(16)?, -29);
+    let sqrta = pow2(bits.u(32)? as i64, -19);
+    let toe = bits.u(14)? as f64 * 60.0;
+    let cic = pow2(bits.i(16)?, -29);
+    let omega0 = semicircles(bits.i(32)?, -31);
+    let cis = pow2(bits.i(16)?, -29);
+    let i0 = semicircles(bits.i(32)?, -31);
+    let crc = pow2(bits.i(16)?, -5);
+    let omega = semicircles(bits.i(32)?, -31);
+    let omega_dot = semicircles(bits.i(24)?, -43);
+    let bgd_e5a = pow2(bits.i(10)?, -32);
+
+    // RINEX health bits: E1-B DVS (0) and HS (1-2), E5a DVS (3) and HS (4-5), E5b DVS
+    // (6) and HS (7-8); data sources: I/NAV E1-B and E5b with E5b/E1 clocks, or F/NAV
+    // with E5a/E1 clocks
+    let mut params = Vec::with_capacity(26);
+    let (health, source) = if inav {
+        let bgd_e5b = pow2(bits.i(10)?, -32);
+        let e5b_health = bits.u(2)?;
+        let e5b_validity = bits.u(1)?;
+        let e1_health = bits.u(2)?;
+        let e1_validity = bits.u(1)?;
+        params.push(("bgdE5bE1", bgd_e5b));
+        (e1_validity | e1_health << 1 | e5b_validity << 6 | e5b_health << 7, 517.0)
+    } else {
+        let e5a_health = bits.u(2)?;
+        let e5a_validity = bits.u(1)?;
+        (e5a_validity << 3 | e5a_health << 4, 258.0)
+    };
+    params.extend([
+        ("clock_bias", af0),
+        ("clock_drift", af1),
+        ("clock_drift_rate", af2),
+        ("iodnav", iodnav),
+        ("crs", crs),
+        ("deltaN", delta_n),
+        ("m0", m0),
+        ("cuc", cuc),
+        ("e", e),
+        ("cus", cus),
+        ("sqrta", sqrta),
+        ("toe", toe),
+        ("cic", cic),
+        ("omega0", omega0),
+        ("cis", cis),
+        ("i0", i0),
+        ("crc", crc),
+        ("omega", omega),
+        ("omegaDot", omega_dot),
+        ("idot", idot),
+        ("source", source),
+        ("week", week as f64),
+        ("sisa", sisa),
+        ("health", health as f64),
+        ("bgdE5aE1", bgd_e5a),
+    ]);
+
+    Some(NavRecord {
+        constellation: Constellation::Galileo,
+        prn,
+        toc: nearest(reference, GPS_WEEK_ORIGIN_NANOS, WEEK_NANOS, toc * 1_000_000_000),
+        params,
+    })
+}
+
+/// Decodes a BeiDou D1/D2 ephemeris (message 1042)
+fn decode_beidou_ephemeris(bits: &mut BitReader, reference: i64) -> Option<NavRecord> {
+    let prn = bits.u(6)? as u8;
+    let reference = reference - GPST_BDT_MILLIS * 1_000_000;
+    let reference_week = (reference - BDT_WEEK_ORIGIN_NANOS).div_euclid(WEEK_NANOS);
+    let week = full_week(reference_week, bits.u(13)?, 8192);
+    let accuracy = URA_METERS[bits.u(4)? as usize];
+    let idot = semicircles(bits.i(14)?, -43);
+    let aode = bits.u(5)? as f64;
+    let toc = bits.u(17)? as i64 * 8;
+    let a2 = pow2(bits.i(11)?, -66);
+    let a1 = pow2(bits.i(22)?, -50);
+    let a0 = pow2(bits.i(24)?, -33);
+    let aodc = bits.u(5)? as f64;
+    let crs = pow2(bits.i(18)?, -6);
+    let delta_n = semicircles(bits.i(16)?, -43);
+    let m0 = semicircles(bits.i(32)?, -31);
+    let cuc = pow2(bits.i(18)?, -31);
+    let e = pow2(bits.u(32)? as i64, -33);
+    let cus = pow2(bits.i(18)?, -31);
+    let sqrta = pow2(bits.u(32)? as i64, -19);
+    let toe = bits.u(17)? as f64 * 8.0;
+    let cic = pow2(bits.i(18)?, -31);
+    let omega0 = semicircles(bits.i(32)?, -31);
+    let cis = pow2(bits.i(18)?, -31);
+    let i0 = semicircles(bits.i(32)?, -31);
+    let crc = pow2(bits.i(18)?, -6);
+    let omega = semicircles(bits.i(32)?, -31);
+    let omega_dot = semicircles(bits.i(24)?, -43);
+    // Group delays in units of 0.1 ns
+    let tgd1 = bits.i(10)? as f64 * 1e-10;
+    let tgd2 = bits.i(10)? as f64 * 1e-10;
+    let health = bits.u(1)? as f64;
+
+    Some(NavRecord {
+        constellation: Constellation::BeiDou,
+        prn,
+        toc: nearest(reference, BDT_WEEK_ORIGIN_NANOS, WEEK_NANOS, toc * 1_000_000_000),
+        params: vec![
+            ("clock_bias", a0),
+            ("clock_drift", a1),
+            ("clock_drift_rate", a2),
+            ("aode", aode),
+            ("crs", crs),
+            ("deltaN", delta_n),
+            ("m0", m0),
+            ("cuc", cuc),
+            ("e", e),
+            ("cus", cus),
+            ("sqrta", sqrta),
+            ("toe", toe),
+            ("cic", cic),
+            ("omega0", omega0),
+            ("cis", cis),
+            ("i0", i0),
+            ("crc", crc),
+            ("omega", omega),
+            ("omegaDot", omega_dot),
+            ("idot", idot),
+            ("week", week as f64),
+            ("accuracy", accuracy),
+            ("health", health),
+            ("tgd1b1b3", tgd1),
+            ("tgd2b2b3", tgd2),
+            ("aodc", aodc),
+        ],
+    })
+}
+
+/// Resolves the reference time and message frame time of a GLONASS ephemeris, given in
+/// seconds of day of GLONASS time (UTC(SU) + 3h), into the UTC-labelled time of clock
+/// (nanoseconds) and the frame time in seconds of the UTC week, as in RINEX
+pub(crate) fn glonass_ephemeris_times(reference: i64, tb: i64, tk: i64) -> (i64, f64) {
+    let glonass_reference = OutputScale::Glonasst.tai_to_scale(OutputScale::Gpst.scale_to_tai(reference));
+    let toc = nearest(glonass_reference, 0, DAY_NANOS, tb * 1_000_000_000);
+    let day = toc.div_euclid(DAY_NANOS) * DAY_NANOS;
+    let frame_time = day + tk * 1_000_000_000 - GLONASST_UTC_NANOS;
+    let frame_tow = (frame_time - GPS_WEEK_ORIGIN_NANOS).rem_euclid(WEEK_NANOS) as f64 * 1e-9;
+    (toc - GLONASST_UTC_NANOS, frame_tow)
+}
+
+/// Decodes a GLONASS ephemeris (message 1020), with the RINEX conventions: the clock
+/// bias is -tau_n, the clock drift gamma_n and the 'clock drift rate' the message frame
+/// time (seconds of the UTC week)
+fn decode_glonass_ephemeris(bits: &mut BitReader, reference: i64) -> Option<NavRecord> {
+    let prn = bits.u(6)? as u8;
+    let channel = bits.u(5)? as f64 - 7.0;
+    // Almanac health and its availability, P1
+    bits.skip(4)?;
+    let tk = (bits.u(5)? * 3600 + bits.u(6)? * 60 + bits.u(1)? * 30) as i64;
+    let health = bits.u(1)? as f64;
+    // P2
+    bits.skip(1)?;
+    let tb = bits.u(7)? as i64 * 900;
+    let mut state = || -> Option<(f64, f64, f64)> {
+        let velocity = pow2(bits.sm(24)?, -20);
+        let position = pow2(bits.sm(27)?, -11);
+        let acceleration = pow2(bits.sm(5)?, -30);
+        Some((position, velocity, acceleration))
+    };
+    let (x, vx, ax) = state()?;
+    let (y, vy, ay) = state()?;
+    let (z, vz, az) = state()?;
+    // P3
+    bits.skip(1)?;
+    let gamma = pow2(bits.sm(11)?, -40);
+    // P, ln
+    bits.skip(3)?;
+    let tau = pow2(bits.sm(22)?, -30);
+    // Delta tau_n
+    bits.skip(5)?;
+    let age = bits.u(5)? as f64;
+
+    let (toc, frame_tow) = glonass_ephemeris_times(reference, tb, tk);
+
+    Some(NavRecord {
+        constellation: Constellation::Glonass,
+        prn,
+        toc,
+        params: vec![
+            ("clock_bias", -tau),
+            ("clock_drift", gamma),
+            ("clock_drift_rate", frame_tow),
+            ("satPosX", x),
+            ("velX", vx),
+            ("accelX", ax),
+            ("health", health),
+            ("satPosY", y),
+            ("velY", vy),
+            ("accelY", ay),
+            ("channel", channel),
+            ("satPosZ", z),
+            ("velZ", vz),
+            ("accelZ", az),
+            ("ageOp", age),
+        ],
+    })
+}
+
+/// Decodes the station identifier and ARP position (ECEF, m) of messages 1005/1006
+fn decode_station(bits: &mut BitReader) -> Option<(u16, (f64, f64, f64))> {
+    let station = bits.u(12)? as u16;
+    // ITRF realization year, GPS/GLONASS/Galileo indicators, reference station indicator
+    bits.skip(10)?;
+    let x = bits.i(38)? as f64 * 1e-4;
+    // Single receiver oscillator indicator, reserved
+    bits.skip(2)?;
+    let y = bits.i(38)? as f64 * 1e-4;
+    // Quarter cycle indicator
+    bits.skip(2)?;
+    let z = bits.i(38)? as f64 * 1e-4;
+    Some((station, (x, y, z)))
+}
+
+/// A signal of an MSM message, with ranges in meters
+struct MsmCell {
+    sat: u8,
+    signal: &'static str,
+    glonass_channel: Option<i32>,
+    pseudorange: Option<f64>,
+    phase_range: Option<f64>,
+    range_rate: Option<f64>,
+    cnr: Option<f64>,
+}
+
+struct Msm {
+    station: u16,
+    epoch: u64,
+    multiple: bool,
+    cells: Vec<MsmCell>,
+}
+
+/// Decodes an MSM4 to MSM7 message (after the message number)
+fn decode_msm_body(system: char, kind: u8, bits: &mut BitReader) -> Option<Msm> {
+    let station = bits.u(12)? as u16;
+    let epoch = bits.u(30)?;
+    let multiple = bits.u(1)? == 1;
+    // IODS, reserved, clock steering, external clock, smoothing indicator and interval
+    bits.skip(3 + 7 + 2 + 2 + 1 + 3)?;
+    let sat_mask = bits.u(64)?;
+    let sig_mask = bits.u(32)?;
+    let sats: Vec<u8> = (1..=64).filter(|i| sat_mask >> (64 - i) & 1 == 1).collect();
+    let sigs: Vec<u8> = (1..=32).filter(|i| sig_mask >> (32 - i) & 1 == 1).collect();
+    if sats.len() * sigs.len() > 64 {
+        return None;
+    }
+    let mut cells = Vec::new();
+    for &sat in &sats {
+        for &sig in &sigs {
+            if bits.u(1)? == 1 {
+                cells.push((sat, sig));
+            }
+        }
+    }
+
+    let extended = kind == 5 || kind == 7;
+    let n = sats.len();
+    let rough_ms: Vec<Option<f64>> = (0..n)
+        .map(|_| bits.u(8).map(|v| (v != 255).then_some(v as f64)))
+        .collect::<Option<_>>()?;
+    let info: Vec<u64> = if extended {
+        (0..n).map(|_| bits.u(4)).collect::<Option<_>>()?
+    } else {
+        vec![15; n]
+    };
+    let rough_mod: Vec<f64> = (0..n)
+        .map(|_| bits.u(10).map(|v| v as f64 / 1024.0))
+        .collect::<Option<_>>()?;
+    let rough_rate: Vec<Option<f64>> = if extended {
+        (0..n)
+            .map(|_| bits.i(14).map(|v| (v != invalid(14)).then_some(v as f64)))
+            .collect::<Option<_>>()?
+    } else {
+        vec![None; n]
+    };
+
+    // Field widths and scales (ms) of the signal data: MSM4/5 or high-resolution MSM6/7
+    let high = kind >= 6;
+    let (pr_bits, pr_scale) = if high { (20, 2f64.powi(-29)) } else { (15, 2f64.powi(-24)) };
+    let (cp_bits, cp_scale) = if high { (24, 2f64.powi(-31)) } else { (22, 2f64.powi(-29)) };
+    let (lock_bits, cnr_bits, cnr_scale) = if high { (10, 10, 0.0625) } else { (4, 6, 1.0) };
+
+    let m = cells.len();
+    let fine = |bits: &mut BitReader, width: usize, scale: f64| -> Option<Vec<Option<f64>>> {
+        (0..m)
+            .map(|_| bits.i(width).map(|v| (v != invalid(width)).then_some(v as f64 * scale)))
+            .collect()
+    };
+    let pr = fine(bits, pr_bits, pr_scale)?;
+    let cp = fine(bits, cp_bits, cp_scale)?;
+    // Lock time and half-cycle ambiguity indicators
+    bits.skip(m * (lock_bits + 1))?;
+    let cnr: Vec<Option<f64>> = (0..m)
+        .map(|_| bits.u(cnr_bits).map(|v| (v != 0).then_some(v as f64 * cnr_scale)))
+        .collect::<Option<_>>()?;
+    let rate = if extended { fine(bits, 15, 1e-4)? } else { vec![None; m] };
+
+    let mut out = Vec::with_capacity(m);
+    for (k, &(sat, sig)) in cells.iter().enumerate() {
+        let Some(signal) = msm_signal(system, sig) else {
+            continue;
+        };
+        let s = sats.iter().position(|x| *x == sat)?;
+        let rough = rough_ms[s].map(|ms| ms + rough_mod[s]);
+        // GLONASS frequency channels are sent with an offset of 7 (valid: 0 to 13)
+        let glonass_channel = (system == 'R' && info[s] <= 13).then(|| info[s] as i32 - 7);
+        out.push(MsmCell {
+            sat,
+            signal,
+            glonass_channel,
+            pseudorange: rough.zip(pr[k]).map(|(r, f)| (r + f) * MS_RANGE),
+            phase_range: rough.zip(cp[k]).map(|(r, f)| (r + f) * MS_RANGE),
+            range_rate: rough_rate[s].zip(rate[k]).map(|(r, f)| r + f),
+            cnr: cnr[k],
+        });
+    }
+    Some(Msm { station, epoch, multiple, cells: out })
+}
+
+#[pymethods]
+impl RtcmDecoder {
+    /// Streaming decoder of RTCM 3 messages into observation DataFrames
+    ///
+    /// Parameters:
+    ///     reference (int): Approximate current time (Unix-like nanoseconds), within a few
+    ///         days of the data, used to resolve the week of the message epochs
+    ///     glonass_channels (dict[str, int], optional): GLONASS frequency channels per SV,
+    ///         needed for the carrier phase of MSM4/MSM6 messages
+    #[new]
+    #[pyo3(signature = (reference, glonass_channels=None))]
+    fn new(reference: i64, glonass_channels: Option<HashMap<String, i32>>) -> Self {
+        Self {
+            buffer: Vec::new(),
+            reference,
+            glonass_channels: glonass_channels.unwrap_or_default(),
+            pending: Vec::new(),
+            pending_epoch: None,
+            completed: Vec::new(),
+            station_id: None,
+            position: None,
+            ephemerides: BTreeMap::new(),
+            frames: 0,
+            crc_errors: 0,
+        }
+    }
+
+    /// Decodes the complete frames of a chunk of the stream; incomplete frames are
+    /// kept until the next chunk
+    ///
+    /// Parameters:
+    ///     data (bytes): Raw stream bytes
+    ///
+    /// Returns:
+    ///     int: Number of frames decoded so far
+    fn feed(&mut self, data: &[u8]) -> usize {
+        self.buffer.extend_from_slice(data);
+        self.decode_frames();
+        self.frames
+    }
+
+    /// Returns the observations of the completed epochs, removing them from the decoder
+    ///
+    /// Parameters:
+    ///     flush (bool): If true, the epoch being received is returned too
+    ///     time_scale (str): Timescale of the output epochs (see `read_rinex_obs`)
+    ///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+    ///
+    /// Returns:
+    ///     PyDataFrame: A DataFrame with the columns of `read_rinex_obs`: 'epoch', 'sv',
+    ///     'observable', 'value', 'epoch_flag' and 'rx_clock_offset' (null)
+    #[pyo3(signature = (flush=false, time_scale="GPST", time_unit="us"))]
+    fn take_obs(&mut self, flush: bool, time_scale: &str, time_unit: &str) -> PyResult<PyDataFrame> {
+        let scale = OutputScale::parse(time_scale)?;
+        let unit = timescales::parse_time_unit(time_unit)?;
+        if flush {
+            self.completed.append(&mut self.pending);
+            self.pending_epoch = None;
+        }
+        records::obs_frame(&std::mem::take(&mut self.completed), scale, unit)
+    }
+
+    /// Returns the broadcast ephemerides decoded so far (messages 1019, 1020, 1042, 1045
+    /// and 1046), removing them from the decoder
+    ///
+    /// Parameters:
+    ///     time_scale (str, optional): Timescale of the output epochs (see `read_rinex_nav`);
+    ///         None keeps the constellation-native epochs
+    ///     time_unit (str): Precision of the output epochs ('us' or 'ns')
+    ///
+    /// Returns:
+    ///     dict[str, PyDataFrame]: DataFrames keyed by constellation ('GPS', 'GLONASS',
+    ///     'GALILEO', 'BEIDOU'), with the columns and units of `read_rinex_nav`
+    #[pyo3(signature = (time_scale=None, time_unit="us"))]
+    fn take_nav(&mut self, time_scale: Option<&str>, time_unit: &str) -> PyResult<BTreeMap<String, PyDataFrame>> {
+        let scale = time_scale.map(OutputScale::parse).transpose()?;
+        let unit = timescales::parse_time_unit(time_unit)?;
+        let records: Vec<NavRecord> = std::mem::take(&mut self.ephemerides).into_values().collect();
+        records::nav_frames(&records, scale, unit)
+    }
+
+    /// Reference station identifier, from the last MSM or station message
+    #[getter]
+    fn station_id(&self) -> Option<u16> {
+        self.station_id
+    }
+
+    /// Antenna reference point (ECEF, m) from messages 1005/1006, if received
+    #[getter]
+    fn position(&self) -> Option<(f64, f64, f64)> {
+        self.position
+    }
+
+    /// Number of candidate frames discarded because of a CRC mismatch
+    #[getter]
+    fn crc_errors(&self) -> usize {
+        self.crc_errors
+    }
+}
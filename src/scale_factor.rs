@@ -0,0 +1,159 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::Path;
+
+use crate::frames::polars_err;
+use crate::text::{field, open_text, Lines};
+
+/// Observation types listed on each 'SYS / SCALE FACTOR' line
+const TYPES_PER_LINE: usize = 12;
+
+/// Scale factor declared by a 'SYS / SCALE FACTOR' header record: the recorded values
+/// are the observations multiplied by the factor
+#[derive(Clone, Debug)]
+pub(crate) struct ScaleFactor {
+    pub system: char,
+    pub factor: u32,
+    /// Observation types concerned, all of the system if empty
+    pub observables: Vec<String>,
+}
+
+/// Reads the 'SYS / SCALE FACTOR' records of a RINEX 3/4 observation header
+pub(crate) fn read_scale_factors(path: &Path) -> io::Result<Vec<ScaleFactor>> {
+    let mut lines = Lines::new(open_text(path)?);
+    let mut factors: Vec<ScaleFactor> = Vec::new();
+    while lines.next()? {
+        match lines.label() {
+            "SYS / SCALE FACTOR" => {},
+            "END OF HEADER" => break,
+            _ => continue,
+        }
+        // A1,1X,I4,2X,I2,12(1X,A3); continuation lines: 10X,12(1X,A3)
+        let line = &lines.line;
+        let observables = (0..TYPES_PER_LINE)
+            .map(|i| field(line, 11 + 4 * i, 14 + 4 * i))
+            .filter(|code| !code.is_empty())
+            .map(str::to_string);
+        match line.first() {
+            Some(b' ') => {
+                if let Some(factor) = factors.last_mut() {
+                    factor.observables.extend(observables);
+                }
+            },
+            Some(&system) => {
+                if let Ok(factor) = field(line, 2, 6).parse::<u32>() {
+                    factors.push(ScaleFactor { system: system as char, factor, observables: observables.collect() });
+                }
+            },
+            None => {},
+        }
+    }
+    Ok(factors)
+}
+
+/// Factor of each (system, observable) pair, with the observable set to None for the
+/// factors applying to all the observation types of a system
+fn factor_table(factors: &[ScaleFactor]) -> HashMap<(char, Option<&str>), f64> {
+    let mut table = HashMap::new();
+    for factor in factors.iter().filter(|f| f.factor > 1) {
+        if factor.observables.is_empty() {
+            table.insert((factor.system, None), factor.factor as f64);
+        }
+        for code in &factor.observables {
+            table.insert((factor.system, Some(code.as_str())), factor.factor as f64);
+        }
+    }
+    table
+}
+
+/// Divides the values of long-format observations by their declared scale factors,
+/// returning the observables scaled per system
+pub(crate) fn apply_scale_factors(
+    df: DataFrame,
+    factors: &[ScaleFactor],
+) -> PolarsResult<(DataFrame, BTreeMap<String, Vec<String>>)> {
+    let table = factor_table(factors);
+    let mut scaled: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    if table.is_empty() {
+        return Ok((df, scaled));
+    }
+
+    let values: Float64Chunked = {
+        let svs = df.column("sv")?.str()?;
+        let codes = df.column("observable")?.str()?;
+        let values = df.column("value")?.f64()?;
+        svs.into_iter()
+            .zip(codes)
+            .zip(values)
+            .map(|((sv, code), value)| {
+                let (Some(sv), Some(code), Some(value)) = (sv, code, value) else {
+                    return value;
+                };
+                let system = sv.chars().next().unwrap_or(' ');
+                match table.get(&(system, Some(code))).or_else(|| table.get(&(system, None))) {
+                    Some(factor) => {
+                        let codes = scaled.entry(system.to_string()).or_default();
+                        if !codes.iter().any(|c| c == code) {
+                            codes.push(code.to_string());
+                        }
+                        Some(value / factor)
+                    },
+                    None => Some(value),
+                }
+            })
+            .collect()
+    };
+    let mut df = df;
+    df.with_column(values.with_name("value".into()).into_series())?;
+    for codes in scaled.values_mut() {
+        codes.sort();
+    }
+    Ok((df, scaled))
+}
+
+/// Reads the scale factors declared in the header of a RINEX 3/4 observation file
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file (.rnx, .crx, or .gz)
+///
+/// Returns:
+///     PyDataFrame: One row per scaled observation type, with columns 'system' (RINEX
+///     letter), 'observable' (null if the factor applies to all the observation types
+///     of the system) and 'factor'
+#[pyfunction]
+pub(crate) fn read_rinex_scale_factors(path: &str) -> PyResult<PyDataFrame> {
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(
+            format!("File not found: {}", path.display())
+        ));
+    }
+    let factors = read_scale_factors(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(format!("RINEX parsing error: {}", e)))?;
+
+    let mut systems = Vec::new();
+    let mut observables: Vec<Option<String>> = Vec::new();
+    let mut values = Vec::new();
+    for factor in &factors {
+        let targets: Vec<Option<String>> = if factor.observables.is_empty() {
+            vec![None]
+        } else {
+            factor.observables.iter().cloned().map(Some).collect()
+        };
+        for observable in targets {
+            systems.push(factor.system.to_string());
+            observables.push(observable);
+            values.push(factor.factor);
+        }
+    }
+    DataFrame::new(vec![
+        Series::new("system".into(), systems).into(),
+        Series::new("observable".into(), observables).into(),
+        Series::new("factor".into(), values).into(),
+    ])
+    .map(PyDataFrame)
+    .map_err(polars_err)
+}
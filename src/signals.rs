@@ -0,0 +1,182 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+
+use crate::combinations::{carrier_frequency, ATTRIBUTE_PRIORITY};
+use crate::frames::polars_err;
+
+/// Constellation, frequency band, signal name and tracking modes (channel attribute,
+/// description) of a signal
+type Signal = (char, char, &'static str, &'static [(char, &'static str)]);
+
+/// RINEX 3 signals per constellation, following the observation codes of RINEX 3.05
+const SIGNALS: &[Signal] = &[
+    ('G', '1', "L1", &[
+        ('C', "C/A"),
+        ('S', "L1C (D)"),
+        ('L', "L1C (P)"),
+        ('X', "L1C (D+P)"),
+        ('P', "P (AS off)"),
+        ('W', "Z-tracking"),
+        ('Y', "Y"),
+        ('M', "M"),
+        ('N', "codeless"),
+    ]),
+    ('G', '2', "L2", &[
+        ('C', "C/A"),
+        ('D', "semi-codeless"),
+        ('S', "L2C (M)"),
+        ('L', "L2C (L)"),
+        ('X', "L2C (M+L)"),
+        ('P', "P (AS off)"),
+        ('W', "Z-tracking"),
+        ('Y', "Y"),
+        ('M', "M"),
+        ('N', "codeless"),
+    ]),
+    ('G', '5', "L5", &[('I', "I"), ('Q', "Q"), ('X', "I+Q")]),
+    ('R', '1', "G1", &[('C', "C/A"), ('P', "P")]),
+    ('R', '4', "G1a", &[('A', "L1OCd"), ('B', "L1OCp"), ('X', "L1OCd+L1OCp")]),
+    ('R', '2', "G2", &[('C', "C/A"), ('P', "P")]),
+    ('R', '6', "G2a", &[('A', "L2CSI"), ('B', "L2OCp"), ('X', "L2CSI+L2OCp")]),
+    ('R', '3', "G3", &[('I', "I"), ('Q', "Q"), ('X', "I+Q")]),
+    ('E', '1', "E1", &[
+        ('A', "PRS"),
+        ('B', "I/NAV OS/CS/SoL"),
+        ('C', "no data"),
+        ('X', "B+C"),
+        ('Z', "A+B+C"),
+    ]),
+    ('E', '5', "E5a", &[('I', "F/NAV OS"), ('Q', "no data"), ('X', "I+Q")]),
+    ('E', '7', "E5b", &[('I', "I/NAV OS/CS/SoL"), ('Q', "no data"), ('X', "I+Q")]),
+    ('E', '8', "E5", &[('I', "I"), ('Q', "Q"), ('X', "I+Q")]),
+    ('E', '6', "E6", &[
+        ('A', "PRS"),
+        ('B', "C/NAV CS"),
+        ('C', "no data"),
+        ('X', "B+C"),
+        ('Z', "A+B+C"),
+    ]),
+    ('S', '1', "L1", &[('C', "C/A")]),
+    ('S', '5', "L5", &[('I', "I"), ('Q', "Q"), ('X', "I+Q")]),
+    ('J', '1', "L1", &[
+        ('C', "C/A"),
+        ('E', "L1C/B"),
+        ('S', "L1C (D)"),
+        ('L', "L1C (P)"),
+        ('X', "L1C (D+P)"),
+        ('Z', "L1-SAIF"),
+    ]),
+    ('J', '2', "L2", &[('S', "L2C (M)"), ('L', "L2C (L)"), ('X', "L2C (M+L)")]),
+    ('J', '5', "L5", &[
+        ('I', "I"),
+        ('Q', "Q"),
+        ('X', "I+Q"),
+        ('D', "L5S (I)"),
+        ('P', "L5S (Q)"),
+        ('Z', "L5S (I+Q)"),
+    ]),
+    ('J', '6', "L6", &[
+        ('S', "L6D"),
+        ('L', "L6P"),
+        ('X', "L6 (D+P)"),
+        ('E', "L6E"),
+        ('Z', "L6 (D+E)"),
+    ]),
+    ('C', '2', "B1I", &[('I', "I"), ('Q', "Q"), ('X', "I+Q")]),
+    ('C', '1', "B1C", &[
+        ('D', "data"),
+        ('P', "pilot"),
+        ('X', "data+pilot"),
+        ('S', "B1A (D)"),
+        ('L', "B1A (P)"),
+        ('Z', "B1A (D+P)"),
+    ]),
+    ('C', '5', "B2a", &[('D', "data"), ('P', "pilot"), ('X', "data+pilot")]),
+    ('C', '7', "B2b", &[
+        ('I', "B2I (I)"),
+        ('Q', "B2I (Q)"),
+        ('X', "B2I (I+Q)"),
+        ('D', "data"),
+        ('P', "pilot"),
+        ('Z', "data+pilot"),
+    ]),
+    ('C', '8', "B2", &[('D', "data"), ('P', "pilot"), ('X', "data+pilot")]),
+    ('C', '6', "B3I", &[
+        ('I', "I"),
+        ('Q', "Q"),
+        ('X', "I+Q"),
+        ('A', "B3A"),
+    ]),
+    ('I', '5', "L5", &[
+        ('A', "SPS"),
+        ('B', "RS (D)"),
+        ('C', "RS (P)"),
+        ('X', "RS (D+P)"),
+    ]),
+    ('I', '9', "S", &[
+        ('A', "SPS"),
+        ('B', "RS (D)"),
+        ('C', "RS (P)"),
+        ('X', "RS (D+P)"),
+    ]),
+];
+
+/// Frequency step (Hz) between the channels of the GLONASS FDMA bands
+fn channel_spacing(system: char, band: char) -> Option<f64> {
+    match (system, band) {
+        ('R', '1') => Some(0.5625e6),
+        ('R', '2') => Some(0.4375e6),
+        _ => None,
+    }
+}
+
+/// Harmonization table of the RINEX 3 observation codes
+pub(crate) fn signal_table() -> PolarsResult<DataFrame> {
+    let mut systems = Vec::new();
+    let mut codes = Vec::new();
+    let mut bands = Vec::new();
+    let mut names = Vec::new();
+    let mut modes = Vec::new();
+    let mut frequencies = Vec::new();
+    let mut spacings = Vec::new();
+    let mut priorities = Vec::new();
+    for &(system, band, name, tracking) in SIGNALS {
+        for &(attribute, mode) in tracking {
+            systems.push(system.to_string());
+            codes.push(format!("{band}{attribute}"));
+            bands.push(band.to_string());
+            names.push(name);
+            modes.push(mode);
+            // FDMA frequencies are given for channel 0
+            frequencies.push(carrier_frequency(system, band, Some(0)));
+            spacings.push(channel_spacing(system, band));
+            priorities.push(ATTRIBUTE_PRIORITY.iter().position(|p| *p == attribute).map(|i| i as u32));
+        }
+    }
+    DataFrame::new(vec![
+        Series::new("system".into(), systems).into(),
+        Series::new("code".into(), codes).into(),
+        Series::new("band".into(), bands).into(),
+        Series::new("signal".into(), names).into(),
+        Series::new("tracking_mode".into(), modes).into(),
+        Series::new("frequency".into(), frequencies).into(),
+        Series::new("channel_spacing".into(), spacings).into(),
+        Series::new("priority".into(), priorities).into(),
+    ])
+}
+
+/// Returns the mapping of the RINEX 3 observation codes to constellation, frequency band,
+/// carrier frequency, signal and tracking mode
+///
+/// Returns:
+///     PyDataFrame: One row per constellation and code (band and channel attribute, e.g.
+///     '1C', shared by the 'C', 'L', 'D' and 'S' observables), with columns 'system',
+///     'code', 'band', 'signal', 'tracking_mode', 'frequency' (Hz, channel 0 for GLONASS
+///     FDMA bands), 'channel_spacing' (Hz, GLONASS FDMA bands only) and 'priority' (rank
+///     of the tracking mode when pairing signals, 0 first; null if never preferred)
+#[pyfunction]
+pub(crate) fn observable_table() -> PyResult<PyDataFrame> {
+    signal_table().map(PyDataFrame).map_err(polars_err)
+}
+
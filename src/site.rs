@@ -0,0 +1,103 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::path::Path;
+
+use crate::_parse_file;
+use crate::geodesy::{ecef_to_geodetic, enu_to_ecef};
+
+/// Width of the antenna type in the 'ANT # / TYPE' record, followed by the radome code
+const ANTENNA_TYPE_WIDTH: usize = 16;
+
+/// Splits the IGS antenna type (e.g. 'TRM59800.00     SCIS') into model and radome;
+/// a missing radome is reported as 'NONE'
+fn split_radome(model: &str) -> (String, String) {
+    let (antenna, radome) = match model.char_indices().nth(ANTENNA_TYPE_WIDTH) {
+        Some((i, _)) => model.split_at(i),
+        None => (model, ""),
+    };
+    let radome = match radome.trim() {
+        "" => "NONE",
+        r => r,
+    };
+    (antenna.trim().to_string(), radome.to_string())
+}
+
+/// Applies a local East-North-Up offset (m) to an ECEF position
+fn apply_offset(position: (f64, f64, f64), enu: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (lat, lon, _) = ecef_to_geodetic(position.0, position.1, position.2);
+    let (dx, dy, dz) = enu_to_ecef(enu.0, enu.1, enu.2, lat, lon);
+    (position.0 + dx, position.1 + dy, position.2 + dz)
+}
+
+/// Reads the marker, receiver and antenna metadata of a RINEX observation header
+///
+/// Parameters:
+///     path (str): Path to the RINEX observation file
+///     phase_center_offset (tuple[float, float, float], optional): Mean phase center
+///         offset (north, east, up in meters, as given by ANTEX) from the antenna
+///         reference point
+///
+/// Returns:
+///     dict: Marker name, number and type; receiver type, serial number and firmware;
+///     antenna type, radome and serial number; 'delta_hen' eccentricities (m); the
+///     'marker_position', the 'arp_position' (marker shifted by the eccentricities) and,
+///     if the offset is given, the 'phase_center_position', all in ECEF (m)
+#[pyfunction]
+#[pyo3(signature = (path, phase_center_offset=None))]
+pub(crate) fn read_rinex_site<'py>(
+    py: Python<'py>,
+    path: &str,
+    phase_center_offset: Option<(f64, f64, f64)>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(PyErr::new::<pyo3::exceptions::PyFileNotFoundError, _>(
+            format!("File not found: {}", path.display())
+        ));
+    }
+
+    let rinex = _parse_file(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("RINEX parsing error: {}", e)
+        ))?;
+    let header = &rinex.header;
+
+    let out = PyDict::new(py);
+    let marker = header.geodetic_marker.as_ref();
+    out.set_item("marker_name", marker.map(|m| m.name.clone()))?;
+    out.set_item("marker_number", marker.and_then(|m| m.number()))?;
+    out.set_item("marker_type", marker.and_then(|m| m.marker_type).map(|t| t.to_string()))?;
+
+    let receiver = header.rcvr.as_ref();
+    out.set_item("receiver_type", receiver.map(|r| r.model.clone()))?;
+    out.set_item("receiver_serial", receiver.map(|r| r.sn.clone()))?;
+    out.set_item("receiver_firmware", receiver.map(|r| r.firmware.clone()))?;
+
+    let antenna = header.rcvr_antenna.as_ref();
+    let (antenna_type, radome) = match antenna {
+        Some(a) => {
+            let (model, radome) = split_radome(&a.model);
+            (Some(model), Some(radome))
+        },
+        None => (None, None),
+    };
+    out.set_item("antenna_type", antenna_type)?;
+    out.set_item("radome", radome)?;
+    out.set_item("antenna_serial", antenna.map(|a| a.sn.clone()))?;
+
+    // Missing eccentricities are zero: the ARP then coincides with the marker
+    let delta_hen = antenna.map_or((0.0, 0.0, 0.0), |a| {
+        (a.height.unwrap_or(0.0), a.eastern.unwrap_or(0.0), a.northern.unwrap_or(0.0))
+    });
+    out.set_item("delta_hen", delta_hen)?;
+
+    let marker_position = header.rx_position;
+    let arp_position = marker_position.map(|p| apply_offset(p, (delta_hen.1, delta_hen.2, delta_hen.0)));
+    let pc_position = arp_position
+        .zip(phase_center_offset)
+        .map(|(p, (north, east, up))| apply_offset(p, (east, north, up)));
+    out.set_item("marker_position", marker_position)?;
+    out.set_item("arp_position", arp_position)?;
+    out.set_item("phase_center_position", pc_position)?;
+    Ok(out)
+}
@@ -0,0 +1,206 @@
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use polars::prelude::TimeUnit;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use std::collections::BTreeMap;
+
+/// Statistics of a parsed RINEX observation file, e.g. to monitor automated pipelines
+///
+/// Attributes:
+///     epochs (int): Number of epochs read, after the time window and the limits
+///     signals (int): Number of observation rows read
+///     svs (dict[str, int]): Number of satellites seen per constellation, keyed by RINEX
+///         letter (e.g. {'G': 31, 'E': 24})
+///     first_epoch (datetime, optional): First epoch read, in the output timescale
+///     last_epoch (datetime, optional): Last epoch read, in the output timescale
+///     time_span (timedelta, optional): Time between the first and the last epoch
+///     interval (timedelta, optional): Detected sampling interval (most frequent
+///         spacing between epochs, to the millisecond)
+///     header_interval (timedelta, optional): Sampling interval of the 'INTERVAL' header
+///         record, frequently missing or different from the detected one
+///     scaled_observables (dict[str, list[str]]): Observables divided by the factors of the
+///         'SYS / SCALE FACTOR' header records, per constellation
+///     bytes (int): Size of the file, as stored (i.e. compressed, if it is)
+///     wall_time (float): Seconds spent parsing the file and building the DataFrame
+#[pyclass(module = "pytecgg", frozen)]
+#[derive(Clone, Debug)]
+pub(crate) struct ParseStats {
+    #[pyo3(get)]
+    epochs: usize,
+    #[pyo3(get)]
+    signals: usize,
+    #[pyo3(get)]
+    svs: BTreeMap<String, usize>,
+    #[pyo3(get)]
+    first_epoch: Option<DateTime<Utc>>,
+    #[pyo3(get)]
+    last_epoch: Option<DateTime<Utc>>,
+    #[pyo3(get)]
+    interval: Option<TimeDelta>,
+    #[pyo3(get)]
+    header_interval: Option<TimeDelta>,
+    #[pyo3(get)]
+    scaled_observables: BTreeMap<String, Vec<String>>,
+    #[pyo3(get)]
+    bytes: u64,
+    #[pyo3(get)]
+    wall_time: f64,
+}
+
+fn to_datetime(timestamp: i64, unit: TimeUnit) -> Option<DateTime<Utc>> {
+    match unit {
+        TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(timestamp)),
+        _ => DateTime::from_timestamp_micros(timestamp),
+    }
+}
+
+/// Physical epoch units in one millisecond, the resolution of the detected interval
+fn millisecond(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => 1_000_000,
+        _ => 1_000,
+    }
+}
+
+fn to_timedelta(duration: i64, unit: TimeUnit) -> TimeDelta {
+    match unit {
+        TimeUnit::Nanoseconds => TimeDelta::nanoseconds(duration),
+        _ => TimeDelta::microseconds(duration),
+    }
+}
+
+impl ParseStats {
+    /// Statistics of the epochs read (physical timestamps in `unit`) and of their rows
+    pub(crate) fn new(
+        epochs: &[i64],
+        unit: TimeUnit,
+        header_interval: Option<TimeDelta>,
+        signals: usize,
+        svs: BTreeMap<String, usize>,
+        bytes: u64,
+        wall_time: f64,
+    ) -> Self {
+        Self {
+            epochs: epochs.len(),
+            signals,
+            svs,
+            first_epoch: epochs.iter().min().and_then(|&t| to_datetime(t, unit)),
+            last_epoch: epochs.iter().max().and_then(|&t| to_datetime(t, unit)),
+            interval: crate::frames::infer_interval_rounded(epochs, millisecond(unit)).map(|dt| to_timedelta(dt, unit)),
+            header_interval,
+            scaled_observables: BTreeMap::new(),
+            bytes,
+            wall_time,
+        }
+    }
+
+    /// Records the observables divided by their declared scale factors
+    pub(crate) fn with_scaled_observables(mut self, scaled: BTreeMap<String, Vec<String>>) -> Self {
+        self.scaled_observables = scaled;
+        self
+    }
+}
+
+/// Value of a dictionary key, None if missing or None
+fn item<'py>(data: &Bound<'py, PyDict>, key: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    Ok(data.get_item(key)?.filter(|value| !value.is_none()))
+}
+
+/// Required value of a dictionary key
+fn required<'py, T: FromPyObject<'py>>(data: &Bound<'py, PyDict>, key: &str) -> PyResult<T> {
+    item(data, key)?
+        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Missing '{}'", key)))?
+        .extract()
+}
+
+/// UTC datetime of an ISO 8601 string (as written by `to_dict`) or a Python datetime
+fn datetime_item(data: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<DateTime<Utc>>> {
+    let Some(value) = item(data, key)? else {
+        return Ok(None);
+    };
+    if let Ok(text) = value.downcast::<PyString>() {
+        let text = text.to_cow()?;
+        return DateTime::parse_from_rfc3339(&text).map(|dt| Some(dt.to_utc())).map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid datetime '{}' for '{}'", text, key))
+        });
+    }
+    value.extract().map(Some)
+}
+
+/// Duration of seconds (as written by `to_dict`) or of a Python timedelta
+fn timedelta_item(data: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<TimeDelta>> {
+    let Some(value) = item(data, key)? else {
+        return Ok(None);
+    };
+    match value.extract::<f64>() {
+        Ok(seconds) => Ok(Some(TimeDelta::nanoseconds((seconds * 1e9).round() as i64))),
+        Err(_) => value.extract().map(Some),
+    }
+}
+
+#[pymethods]
+impl ParseStats {
+    #[getter]
+    fn time_span(&self) -> Option<TimeDelta> {
+        Some(self.last_epoch? - self.first_epoch?)
+    }
+
+    /// Statistics as a dictionary of JSON-compatible values, e.g. for logging: datetimes
+    /// as ISO 8601 strings and durations in seconds
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let iso = |dt: Option<DateTime<Utc>>| dt.map(|dt| dt.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+        let seconds = |dt: Option<TimeDelta>| dt.map(|dt| dt.as_seconds_f64());
+        let dict = PyDict::new(py);
+        dict.set_item("epochs", self.epochs)?;
+        dict.set_item("signals", self.signals)?;
+        dict.set_item("svs", &self.svs)?;
+        dict.set_item("first_epoch", iso(self.first_epoch))?;
+        dict.set_item("last_epoch", iso(self.last_epoch))?;
+        dict.set_item("time_span", seconds(self.time_span()))?;
+        dict.set_item("interval", seconds(self.interval))?;
+        dict.set_item("header_interval", seconds(self.header_interval))?;
+        dict.set_item("scaled_observables", &self.scaled_observables)?;
+        dict.set_item("bytes", self.bytes)?;
+        dict.set_item("wall_time", self.wall_time)?;
+        Ok(dict)
+    }
+
+    /// Statistics from a dictionary as returned by `to_dict` ('time_span' is derived)
+    #[staticmethod]
+    fn from_dict(data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        Ok(Self {
+            epochs: required(data, "epochs")?,
+            signals: required(data, "signals")?,
+            svs: item(data, "svs")?.map(|v| v.extract()).transpose()?.unwrap_or_default(),
+            first_epoch: datetime_item(data, "first_epoch")?,
+            last_epoch: datetime_item(data, "last_epoch")?,
+            interval: timedelta_item(data, "interval")?,
+            header_interval: timedelta_item(data, "header_interval")?,
+            scaled_observables: item(data, "scaled_observables")?
+                .map(|v| v.extract())
+                .transpose()?
+                .unwrap_or_default(),
+            bytes: required(data, "bytes")?,
+            wall_time: required(data, "wall_time")?,
+        })
+    }
+
+    /// Pickles the statistics through their dictionary, e.g. for multiprocessing pools
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyDict>,))> {
+        let data = slf.get().to_dict(slf.py())?;
+        Ok((slf.get_type().getattr("from_dict")?, (data,)))
+    }
+
+    fn __repr__(&self) -> String {
+        let svs: Vec<String> = self.svs.iter().map(|(c, n)| format!("{}: {}", c, n)).collect();
+        format!(
+            "ParseStats(epochs={}, signals={}, svs={{{}}}, interval={}, bytes={}, wall_time={:.3}s)",
+            self.epochs,
+            self.signals,
+            svs.join(", "),
+            self.interval.map_or("None".into(), |dt| format!("{}s", dt.as_seconds_f64())),
+            self.bytes,
+            self.wall_time,
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::LazyLock;
+
+/// Threads running the awaitable readers, kept apart from the Polars pool so that
+/// file I/O never stalls DataFrame computations
+static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
+    ThreadPoolBuilder::new()
+        .thread_name(|i| format!("pytecgg-reader-{i}"))
+        .build()
+        .expect("failed to start the reader thread pool")
+});
+
+/// Calls `func(*args, **kwargs)` on the reader thread pool, then schedules
+/// `callback(result, error)` on the asyncio event loop, which resolves the awaited future.
+/// The readers release the GIL while parsing, so several files are read concurrently.
+#[pyfunction]
+#[pyo3(signature = (func, args, kwargs, event_loop, callback))]
+pub fn spawn_blocking(
+    func: PyObject,
+    args: Py<PyTuple>,
+    kwargs: Option<Py<PyDict>>,
+    event_loop: PyObject,
+    callback: PyObject,
+) {
+    POOL.spawn(move || {
+        Python::with_gil(|py| {
+            let outcome = func
+                .bind(py)
+                .call(args.bind(py), kwargs.as_ref().map(|k| k.bind(py)));
+            let (result, error) = match outcome {
+                Ok(result) => (result.unbind(), py.None()),
+                Err(err) => (py.None(), err.into_value(py).into_any()),
+            };
+            // The loop may have been closed in the meantime: nothing is left to resolve
+            if let Err(err) = event_loop.call_method1(py, "call_soon_threadsafe", (callback, result, error)) {
+                err.write_unraisable(py, None);
+            }
+        })
+    });
+}
@@ -0,0 +1,69 @@
+use flate2::read::MultiGzDecoder;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Buffered reader of a text file, decompressing it on the fly with a '.gz' extension
+pub(crate) fn open_text(path: &Path) -> io::Result<BufReader<Box<dyn Read + Send>>> {
+    let file = File::open(path)?;
+    let reader: Box<dyn Read + Send> = if path.extension().is_some_and(|ext| ext == "gz") {
+        Box::new(MultiGzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    Ok(BufReader::with_capacity(1 << 16, reader))
+}
+
+/// Line reader reusing a single buffer, with the line terminator removed
+pub(crate) struct Lines<R> {
+    reader: R,
+    pub(crate) line: Vec<u8>,
+    /// Byte offset of the current line in the (decompressed) text
+    pub(crate) offset: u64,
+    consumed: u64,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self { reader, line: Vec::with_capacity(256), offset: 0, consumed: 0 }
+    }
+
+    /// Reads the next line, returning false at the end of the file
+    pub(crate) fn next(&mut self) -> io::Result<bool> {
+        self.line.clear();
+        let n = self.reader.read_until(b'\n', &mut self.line)?;
+        if n == 0 {
+            return Ok(false);
+        }
+        self.offset = self.consumed;
+        self.consumed += n as u64;
+        while matches!(self.line.last(), Some(b'\n' | b'\r')) {
+            self.line.pop();
+        }
+        Ok(true)
+    }
+
+    /// Skips up to `n` lines
+    pub(crate) fn skip(&mut self, n: usize) -> io::Result<()> {
+        for _ in 0..n {
+            if !self.next()? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Label of the current line, as a header record (columns 61-80)
+    pub(crate) fn label(&self) -> &str {
+        field(&self.line, 60, 80)
+    }
+}
+
+/// Trimmed text of the columns [start, end) of a line
+pub(crate) fn field(line: &[u8], start: usize, end: usize) -> &str {
+    let end = end.min(line.len());
+    if start >= end {
+        return "";
+    }
+    std::str::from_utf8(&line[start..end]).unwrap_or("").trim()
+}
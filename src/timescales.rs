@@ -0,0 +1,361 @@
+use hifitime::leap_seconds::LatestLeapSeconds;
+use pyo3::prelude::*;
+use pyo3_polars::{PyDataFrame, PySeries};
+use polars::prelude::*;
+use rinex::prelude::{Constellation, Epoch, Rinex, TimeScale};
+use std::sync::OnceLock;
+
+use crate::frames::{polars_err, units_per_second};
+use crate::{_parse_file, gpst_nanos};
+
+/// Length of a GNSS week in microseconds
+const WEEK_MICROS: i64 = 604_800_000_000;
+/// Start of GPS week 0 (1980-01-06) in Unix-like microseconds
+const GPS_WEEK_ORIGIN_MICROS: i64 = 315_964_800_000_000;
+/// Start of BeiDou week 0 (2006-01-01 BDT) in Unix-like microseconds
+const BDT_WEEK_ORIGIN_MICROS: i64 = 1_136_073_600_000_000;
+/// Galileo week 0 starts at GPS week 1024 (1999-08-22)
+const GST_WEEK_OFFSET: i64 = 1024;
+
+/// Constant offset between TAI and GPST in microseconds
+const TAI_GPST_MICROS: i64 = 19_000_000;
+/// Constant offset between GPST and BDT in nanoseconds
+const GPST_BDT_NANOS: i64 = 14_000_000_000;
+/// Offset of GLONASS time (UTC(SU) + 3h) from UTC in nanoseconds
+const GLONASST_UTC_NANOS: i64 = 3 * 3_600_000_000_000;
+
+/// Timescale in which epochs are expressed, as Unix-like timestamps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum OutputScale {
+    Gpst,
+    Gst,
+    Bdt,
+    Glonasst,
+    Utc,
+    Tai,
+}
+
+impl OutputScale {
+    /// Parses a timescale name ('GPST', 'GST', 'BDT', 'GLONASST', 'UTC' or 'TAI', case
+    /// insensitive)
+    pub(crate) fn parse(name: &str) -> PyResult<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "GPST" | "GPS" => Ok(Self::Gpst),
+            "GST" => Ok(Self::Gst),
+            "BDT" => Ok(Self::Bdt),
+            "GLONASST" | "GLOT" => Ok(Self::Glonasst),
+            "UTC" => Ok(Self::Utc),
+            "TAI" => Ok(Self::Tai),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported time scale '{}': expected 'GPST', 'GST', 'BDT', 'GLONASST', 'UTC' or 'TAI'",
+                name
+            ))),
+        }
+    }
+
+    /// Converts a Unix-like timestamp (nanoseconds) of this timescale into TAI
+    pub(crate) fn scale_to_tai(self, ts: i64) -> i64 {
+        match self {
+            Self::Gpst | Self::Gst => ts + TAI_GPST_MICROS * 1_000,
+            Self::Bdt => ts + GPST_BDT_NANOS + TAI_GPST_MICROS * 1_000,
+            Self::Tai => ts,
+            Self::Utc => ts + tai_utc_at_utc(ts),
+            Self::Glonasst => {
+                let utc = ts - GLONASST_UTC_NANOS;
+                utc + tai_utc_at_utc(utc)
+            },
+        }
+    }
+
+    /// Converts a TAI Unix-like timestamp (nanoseconds) into this timescale
+    pub(crate) fn tai_to_scale(self, tai: i64) -> i64 {
+        match self {
+            Self::Gpst | Self::Gst => tai - TAI_GPST_MICROS * 1_000,
+            Self::Bdt => tai - TAI_GPST_MICROS * 1_000 - GPST_BDT_NANOS,
+            Self::Tai => tai,
+            Self::Utc => tai - tai_utc_at_tai(tai),
+            Self::Glonasst => tai - tai_utc_at_tai(tai) + GLONASST_UTC_NANOS,
+        }
+    }
+}
+
+/// IERS leap seconds as (UTC start, TAI - UTC) pairs in Unix-like nanoseconds, built once
+fn leap_table() -> &'static [(i64, i64)] {
+    static TABLE: OnceLock<Vec<(i64, i64)>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        builtin_leap_seconds()
+            .iter()
+            .map(|e| (e.epoch * 1_000, (e.tai_utc * 1e9).round() as i64))
+            .collect()
+    })
+}
+
+/// TAI - UTC (nanoseconds) in force at a UTC timestamp; zero before 1972
+fn tai_utc_at_utc(utc: i64) -> i64 {
+    let table = leap_table();
+    match table.partition_point(|(start, _)| *start <= utc) {
+        0 => 0,
+        i => table[i - 1].1,
+    }
+}
+
+/// TAI - UTC (nanoseconds) in force at a TAI timestamp; zero before 1972
+fn tai_utc_at_tai(tai: i64) -> i64 {
+    let table = leap_table();
+    match table.partition_point(|(start, offset)| start + offset <= tai) {
+        0 => 0,
+        i => table[i - 1].1,
+    }
+}
+
+/// Converts an epoch (in any timescale) into a Unix-like timestamp in microseconds, whose
+/// calendar fields are those of the epoch in the requested timescale; UTC applies the
+/// leap seconds in force at the epoch
+pub(crate) fn epoch_micros(epoch: Epoch, scale: OutputScale) -> i64 {
+    epoch_nanos(epoch, scale).div_euclid(1_000)
+}
+
+/// Converts an epoch into a Unix-like timestamp in the requested timescale, with the
+/// precision of the given time unit
+pub(crate) fn epoch_timestamp(epoch: Epoch, scale: OutputScale, unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => epoch_nanos(epoch, scale),
+        _ => epoch_micros(epoch, scale),
+    }
+}
+
+/// Same as `epoch_micros`, in nanoseconds; integer arithmetic keeps the epochs exact
+pub(crate) fn epoch_nanos(epoch: Epoch, scale: OutputScale) -> i64 {
+    scale.tai_to_scale(OutputScale::Gpst.scale_to_tai(gpst_nanos(epoch)))
+}
+
+/// Converts Unix-like Datetime values from one timescale into another (e.g. BDT or GLONASS
+/// navigation epochs onto the GPST observation grid); the time unit and zone are kept
+///
+/// Parameters:
+///     epochs (PySeries): Datetime Series
+///     from_scale (str): Timescale of the input ('GPST', 'GST', 'BDT', 'GLONASST', 'UTC' or 'TAI')
+///     to_scale (str): Timescale of the output
+///
+/// Returns:
+///     PySeries: The converted Datetime Series
+#[pyfunction]
+pub(crate) fn convert_timescale(epochs: PySeries, from_scale: &str, to_scale: &str) -> PyResult<PySeries> {
+    let (from, to) = (OutputScale::parse(from_scale)?, OutputScale::parse(to_scale)?);
+    let epochs = epochs.0;
+    let dtype = epochs.dtype().clone();
+    let DataType::Datetime(unit, _) = &dtype else {
+        return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Expected a Datetime Series, got {}",
+            dtype
+        )));
+    };
+    let nanos_per_unit = 1_000_000_000 / units_per_second(&dtype);
+
+    let converted: Int64Chunked = epochs
+        .to_physical_repr()
+        .i64()
+        .map_err(polars_err)?
+        .apply_values(|ts| {
+            let tai = from.scale_to_tai(ts * nanos_per_unit);
+            to.tai_to_scale(tai).div_euclid(nanos_per_unit)
+        });
+    let out = converted
+        .into_series()
+        .with_name(epochs.name().clone())
+        .cast(&DataType::Datetime(*unit, None))
+        .and_then(|s| s.cast(&dtype))
+        .map_err(polars_err)?;
+    Ok(PySeries(out))
+}
+
+/// Parses the precision of output epochs ('us' or 'ns')
+pub(crate) fn parse_time_unit(name: &str) -> PyResult<TimeUnit> {
+    match name {
+        "us" => Ok(TimeUnit::Microseconds),
+        "ns" => Ok(TimeUnit::Nanoseconds),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unsupported time unit '{}': expected 'us' or 'ns'",
+            name
+        ))),
+    }
+}
+
+/// Parses an epoch data type: None for 'datetime' (Datetime column in the time unit), or
+/// the unit of the raw Int64 timestamps for 'i64_us' and 'i64_ns'
+pub(crate) fn parse_epoch_dtype(name: &str) -> PyResult<Option<TimeUnit>> {
+    match name {
+        "datetime" => Ok(None),
+        "i64_us" => Ok(Some(TimeUnit::Microseconds)),
+        "i64_ns" => Ok(Some(TimeUnit::Nanoseconds)),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unsupported epoch dtype '{}': expected 'datetime', 'i64_us' or 'i64_ns'",
+            name
+        ))),
+    }
+}
+
+/// Parses a sampling interval such as '30s', '500ms' or '1m' into nanoseconds
+pub(crate) fn parse_interval(text: &str) -> PyResult<i64> {
+    let invalid = || {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid interval '{}': expected a positive number followed by 'ns', 'us', 'ms', 's', 'm' or 'h'",
+            text
+        ))
+    };
+    let text = text.trim();
+    let split = text.find(|c: char| c.is_ascii_alphabetic()).ok_or_else(invalid)?;
+    let (value, unit) = text.split_at(split);
+    let value: f64 = value.trim().parse().map_err(|_| invalid())?;
+    let scale = match unit {
+        "ns" => 1.0,
+        "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        "m" => 60e9,
+        "h" => 3600e9,
+        _ => return Err(invalid()),
+    };
+    let nanos = (value * scale).round() as i64;
+    if nanos <= 0 {
+        return Err(invalid());
+    }
+    Ok(nanos)
+}
+
+/// Rounds a timestamp to the nearest multiple of the grid step (ties rounded up),
+/// returning the snapped timestamp and the offset removed from it
+pub(crate) fn snap_to_grid(ts: i64, step: i64) -> (i64, i64) {
+    let snapped = (ts + step / 2).div_euclid(step) * step;
+    (snapped, ts - snapped)
+}
+
+/// Continuous week number (no rollover) and seconds of week of a Unix-like timestamp
+/// (microseconds) counted from the given week origin
+pub(crate) fn week_and_tow(ts: i64, origin: i64) -> (i64, f64) {
+    let elapsed = ts - origin;
+    let week = elapsed.div_euclid(WEEK_MICROS);
+    let tow = elapsed.rem_euclid(WEEK_MICROS) as f64 * 1e-6;
+    (week, tow)
+}
+
+/// GPS week and time of week of a GPST timestamp (Unix-like microseconds)
+pub(crate) fn gps_week_and_tow(ts: i64) -> (i64, f64) {
+    week_and_tow(ts, GPS_WEEK_ORIGIN_MICROS)
+}
+
+/// Constellation-native week and time of week of a timestamp (Unix-like microseconds)
+/// expressed in the constellation's own timescale: GPS weeks, Galileo (GST) weeks and
+/// BeiDou (BDT) weeks; GLONASS has no week count
+pub(crate) fn native_week_and_tow(ts: i64, constellation: Constellation) -> Option<(i64, f64)> {
+    match constellation {
+        Constellation::GPS => Some(gps_week_and_tow(ts)),
+        Constellation::Galileo => {
+            let (week, tow) = gps_week_and_tow(ts);
+            Some((week - GST_WEEK_OFFSET, tow))
+        },
+        Constellation::BeiDou => Some(week_and_tow(ts, BDT_WEEK_ORIGIN_MICROS)),
+        _ => None,
+    }
+}
+
+/// Seconds between 1900-01-01 (hifitime leap second timestamps) and the Unix epoch
+const J1900_UNIX_SECONDS: f64 = 2_208_988_800.0;
+/// Constant offset between BDT and GPST in seconds
+const GPST_BDT_SECONDS: f64 = 14.0;
+
+/// Leap second table entry: offsets of TAI and GPST from UTC, effective from a UTC epoch
+struct LeapEntry {
+    epoch: i64,
+    tai_utc: f64,
+    source: &'static str,
+}
+
+/// IERS leap seconds known to hifitime, from 1972 onwards
+fn builtin_leap_seconds() -> Vec<LeapEntry> {
+    LatestLeapSeconds::default()
+        .filter(|leap| leap.announced_by_iers)
+        .map(|leap| LeapEntry {
+            epoch: ((leap.timestamp_tai_s - J1900_UNIX_SECONDS) * 1e6).round() as i64,
+            tai_utc: leap.delta_at,
+            source: "hifitime",
+        })
+        .collect()
+}
+
+/// Leap second information of a RINEX header: the current offset, in force at the first
+/// epoch of the file, and the future (or past) one at the given week and day, if any.
+/// BeiDou files report BDT-UTC offsets, shifted here to TAI-UTC.
+fn header_leap_seconds(rinex: &Rinex) -> Vec<LeapEntry> {
+    let Some(leap) = rinex.header.leap else {
+        return Vec::new();
+    };
+    let to_tai = match leap.timescale {
+        Some(TimeScale::BDT) => GPST_BDT_SECONDS + TAI_GPST_MICROS as f64 * 1e-6,
+        _ => TAI_GPST_MICROS as f64 * 1e-6,
+    };
+
+    let mut entries = Vec::new();
+    if let Some(first) = rinex.first_epoch() {
+        entries.push(LeapEntry {
+            epoch: epoch_micros(first, OutputScale::Utc),
+            tai_utc: leap.leap as f64 + to_tai,
+            source: "header",
+        });
+    }
+    if let (Some(delta_tls), Some(week), Some(day)) = (leap.delta_tls, leap.week, leap.day) {
+        // The change occurs at the end of day DN (1 to 7) of week WN_LSF
+        let gpst = GPS_WEEK_ORIGIN_MICROS + week as i64 * WEEK_MICROS + day as i64 * WEEK_MICROS / 7;
+        entries.push(LeapEntry {
+            epoch: gpst - (leap.leap as i64) * 1_000_000,
+            tai_utc: delta_tls as f64 + to_tai,
+            source: "header",
+        });
+    }
+    entries
+}
+
+/// Returns the leap second table, i.e. the offsets of TAI and GPST from UTC and the epochs
+/// from which they are effective, optionally along with the leap second records of a
+/// RINEX header
+///
+/// Parameters:
+///     path (str, optional): Path to a RINEX file whose 'LEAP SECONDS' record is reported
+///
+/// Returns:
+///     PyDataFrame: A DataFrame with columns 'epoch' (UTC), 'tai_utc', 'gpst_utc' (in
+///     seconds, null before the GPS epoch) and 'source' ('hifitime' or 'header'), sorted
+///     by epoch
+#[pyfunction]
+#[pyo3(signature = (path=None))]
+pub(crate) fn leap_seconds(path: Option<&str>) -> PyResult<PyDataFrame> {
+    let mut entries = builtin_leap_seconds();
+    if let Some(path) = path {
+        let rinex = _parse_file(path)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+                format!("RINEX parsing error: {}", e)
+            ))?;
+        entries.extend(header_leap_seconds(&rinex));
+    }
+    entries.sort_by_key(|e| e.epoch);
+
+    let gps_offset = TAI_GPST_MICROS as f64 * 1e-6;
+    let df = DataFrame::new(vec![
+        Series::new("epoch".into(), entries.iter().map(|e| e.epoch).collect::<Vec<_>>())
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .map_err(polars_err)?
+            .into(),
+        Series::new("tai_utc".into(), entries.iter().map(|e| e.tai_utc).collect::<Vec<_>>()).into(),
+        Series::new(
+            "gpst_utc".into(),
+            entries
+                .iter()
+                .map(|e| (e.epoch >= GPS_WEEK_ORIGIN_MICROS).then_some(e.tai_utc - gps_offset))
+                .collect::<Vec<_>>(),
+        )
+        .into(),
+        Series::new("source".into(), entries.iter().map(|e| e.source).collect::<Vec<_>>()).into(),
+    ])
+    .map_err(polars_err)?;
+    Ok(PyDataFrame(df))
+}
@@ -45,6 +45,19 @@ def nav_v3_file(test_data_dir):
     return str(test_data_dir / "v3" / "nav" / "BRDC00WRD_R_20250870000_01D_MN.rnx")
 
 
+# Site Log Fixtures
+
+
+@pytest.fixture(scope="session")
+def sitelog_file(test_data_dir):
+    return str(test_data_dir / "sitelog" / "grot00ita_20250101.log")
+
+
+@pytest.fixture
+def antex_file(test_data_dir):
+    return str(test_data_dir / "antex" / "test.atx")
+
+
 # Other Fixtures
 @pytest.fixture
 def invalid_file(tmp_path):
@@ -104,3 +117,43 @@ def real_context(parsed_rinex_obs_data):
         rinex_version=parsed_rinex_obs_data["rinex_version"],
         systems=["G", "E", "C"],
     )
+
+
+# RINEX Header Helpers
+
+
+def header_record(text: str, label: str) -> str:
+    """Header line of a RINEX-like file, with the label from column 61"""
+    return text.ljust(60) + label + "\n"
+
+
+# Binary Message Helpers
+
+
+def bits(fields):
+    """Big-endian bit packing of (value, bits) fields, zero-padded to whole bytes"""
+    bits = "".join(format(value & ((1 << n) - 1), f"0{n}b") for value, n in fields)
+    bits += "0" * (-len(bits) % 8)
+    return int(bits, 2).to_bytes(len(bits) // 8, "big")
+
+
+def crc16(data):
+    """CRC-16/CCITT (polynomial 0x1021, zero initial value) of BINEX and SBF"""
+    crc = 0
+    for byte in data:
+        crc ^= byte << 8
+        for _ in range(8):
+            crc = ((crc << 1) ^ 0x1021 if crc & 0x8000 else crc << 1) & 0xFFFF
+    return crc
+
+
+def crc24q(data):
+    """CRC-24Q of RTCM 3 frames"""
+    crc = 0
+    for byte in data:
+        crc ^= byte << 16
+        for _ in range(8):
+            crc <<= 1
+            if crc & 0x1000000:
+                crc ^= 0x1864CFB
+    return crc & 0xFFFFFF
@@ -0,0 +1,82 @@
+import asyncio
+
+import pytest
+
+from pytecgg.parsing import (
+    ReadOptions,
+    read_rinex_nav,
+    read_rinex_nav_async,
+    read_rinex_obs,
+    read_rinex_obs_async,
+)
+
+
+def test_read_rinex_obs_async(obs_v2_file, obs_v3_file):
+    """Concurrent reads match the synchronous reader"""
+
+    async def main():
+        return await asyncio.gather(
+            read_rinex_obs_async(obs_v2_file),
+            read_rinex_obs_async(obs_v3_file, options=ReadOptions(time_unit="ns")),
+        )
+
+    (df_v2, pos_v2, version_v2), (df_v3, _, version_v3) = asyncio.run(main())
+    expected, expected_pos, expected_version = read_rinex_obs(obs_v2_file)
+    assert df_v2.equals(expected)
+    assert pos_v2 == expected_pos
+    assert version_v2 == expected_version
+    assert df_v3.equals(read_rinex_obs(obs_v3_file, options=ReadOptions(time_unit="ns"))[0])
+    assert version_v3.startswith("3")
+
+
+def test_read_rinex_obs_async_event_loop(obs_v3_file):
+    """The event loop keeps running while the file is parsed"""
+
+    async def main():
+        ticks = 0
+
+        async def ticker():
+            nonlocal ticks
+            while True:
+                ticks += 1
+                await asyncio.sleep(0)
+
+        task = asyncio.create_task(ticker())
+        await read_rinex_obs_async(obs_v3_file)
+        task.cancel()
+        return ticks
+
+    assert asyncio.run(main()) > 1
+
+
+def test_read_rinex_nav_async(nav_v3_file):
+    nav = asyncio.run(read_rinex_nav_async(nav_v3_file, sort=True))
+    expected = read_rinex_nav(nav_v3_file, sort=True)
+    assert sorted(nav) == sorted(expected)
+    assert all(nav[const].equals(expected[const]) for const in expected)
+
+
+def test_read_rinex_nav_async_event_loop(nav_v3_file):
+    """The event loop keeps running while the navigation file is parsed"""
+
+    async def main():
+        ticks = 0
+
+        async def ticker():
+            nonlocal ticks
+            while True:
+                ticks += 1
+                await asyncio.sleep(0)
+
+        task = asyncio.create_task(ticker())
+        await read_rinex_nav_async(nav_v3_file)
+        task.cancel()
+        return ticks
+
+    assert asyncio.run(main()) > 1
+
+
+def test_read_rinex_obs_async_nonexistent_file(invalid_file):
+    """Errors of the reader are raised by the awaited call"""
+    with pytest.raises(FileNotFoundError):
+        asyncio.run(read_rinex_obs_async(invalid_file))
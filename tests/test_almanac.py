@@ -0,0 +1,51 @@
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import polars as pl
+import pytest
+
+from pytecgg.parsing import read_sem_almanac, read_yuma_almanac
+from pytecgg.satellites import almanac_to_ephemeris, predict_visibility, satellite_coordinates
+
+
+@pytest.fixture
+def almanac_dir(test_data_dir):
+    return test_data_dir / "almanac"
+
+
+def test_read_yuma_and_sem_almanacs(almanac_dir):
+    yuma = read_yuma_almanac(almanac_dir / "test.alm")
+    sem = read_sem_almanac(almanac_dir / "test.al3")
+
+    assert yuma.columns == sem.columns
+    assert yuma["sv"].to_list() == sem["sv"].to_list() == ["G01", "G02"]
+    assert yuma["week"].to_list() == sem["week"].to_list() == [254, 254]
+    assert yuma["toa"].to_list() == sem["toa"].to_list() == [405504.0, 405504.0]
+    # Same almanac, in radians (YUMA) and semicircles (SEM)
+    for name in ("e", "i0", "omegaDot", "sqrta", "omega0", "omega", "m0", "af0", "af1"):
+        np.testing.assert_allclose(yuma[name].to_numpy(), sem[name].to_numpy(), rtol=1e-6)
+    assert yuma["i0"][0] == pytest.approx(0.997995, abs=1e-6)
+
+
+def test_almanac_propagation(almanac_dir):
+    almanac = read_sem_almanac(almanac_dir / "test.al3")
+    epoch = datetime(2024, 2, 22, 12, tzinfo=timezone.utc)
+    ephem = almanac_to_ephemeris(almanac, epoch)
+
+    # Week 254 after the second rollover
+    assert ephem["G01"]["gps_week"] == 2302
+    epochs = pl.Series([epoch + timedelta(hours=h) for h in range(0, 24, 3)])
+    coords = satellite_coordinates(pl.Series(["G01"] * epochs.len()), epochs, ephem)
+    radius = np.linalg.norm(coords.select("sat_x", "sat_y", "sat_z").to_numpy(), axis=1)
+    a = ephem["G01"]["sqrta"] ** 2
+    assert np.all(np.abs(radius - a) <= a * ephem["G01"]["e"] + 1.0)
+
+
+def test_predict_visibility_from_almanac(almanac_dir):
+    almanac = read_yuma_almanac(almanac_dir / "test.alm")
+    start = datetime(2024, 2, 22, tzinfo=timezone.utc)
+    passes = predict_visibility(
+        almanac, (4_642_000.0, 1_028_000.0, 4_236_000.0), start, start + timedelta(days=1)
+    )
+    assert passes.height > 0
+    assert set(passes["sv"]) <= {"G01", "G02"}
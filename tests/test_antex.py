@@ -0,0 +1,68 @@
+import math
+
+import polars as pl
+import pytest
+
+from pytecgg.linear_combinations import correct_antenna_phase_center
+from pytecgg.linear_combinations.constants import C, FREQ_BANDS
+from pytecgg.parsing import read_antex
+
+
+def test_read_antex(antex_file):
+    antex = read_antex(antex_file)
+    assert antex.pco["antenna_type"].to_list() == ["TRM59800.00", "TRM59800.00", "LEIAR25.R4"]
+    assert antex.pco["radome"].to_list() == ["NONE", "NONE", "LEIT"]
+    assert antex.pco["serial"].to_list() == ["", "", "1234"]
+    assert antex.pco.row(0)[3:] == ("G01", 0.001, 0.0005, 0.09)
+
+    noazi = antex.pcv.filter(pl.col("azimuth").is_null())
+    assert noazi.height == 10 + 10 + 4
+    grid = antex.pcv.filter(pl.col("azimuth").is_not_null())
+    assert grid.height == 5 * 4
+    assert grid.filter(pl.col("azimuth") == 90.0)["pcv"].to_list() == [0.001] * 4
+
+
+def test_antex_antenna_selection(antex_file):
+    antex = read_antex(antex_file)
+    assert antex.antenna("LEIAR25.R4", "LEIT", serial="1234").pco.height == 1
+    # Unknown serial numbers fall back to the type-mean calibration
+    assert antex.antenna("TRM59800.00", serial="999").pco.height == 2
+    with pytest.raises(KeyError):
+        antex.antenna("LEIAR25.R4", "NONE")
+
+
+def test_correct_antenna_phase_center(antex_file):
+    antex = read_antex(antex_file)
+    obs = pl.DataFrame(
+        {
+            "sv": ["G05", "G05", "G05", "G07", "E11"],
+            "observable": ["L1C", "L2W", "C1C", "L1C", "L1X"],
+            "value": [0.0, 0.0, 20e6, 0.0, 0.0],
+            "azi": [0.0, 0.0, 0.0, 90.0, 0.0],
+            "ele": [90.0, 90.0, 90.0, 30.0, 90.0],
+        }
+    )
+    out = correct_antenna_phase_center(obs, antex, "TRM59800.00")
+    f1, f2 = FREQ_BANDS["G"]["L1"], FREQ_BANDS["G"]["L2"]
+    el = math.radians(30.0)
+    expected = [
+        0.09 * f1 / C,
+        0.12 * f2 / C,
+        20e6,
+        (0.0005 * math.cos(el) + 0.09 * math.sin(el) - 0.06) * f1 / C,
+        # Galileo falls back to the GPS calibration of the band
+        0.09 * f1 / C,
+    ]
+    assert out["value"].to_list() == pytest.approx(expected)
+    assert out.columns == obs.columns
+
+
+def test_correct_antenna_phase_center_azimuth_grid(antex_file):
+    antex = read_antex(antex_file)
+    obs = pl.DataFrame(
+        {"sv": ["G05"], "observable": ["L1C"], "value": [0.0], "azi": [45.0], "ele": [60.0]}
+    )
+    out = correct_antenna_phase_center(obs, antex, "LEIAR25.R4", "LEIT", serial="1234")
+    f1 = FREQ_BANDS["G"]["L1"]
+    expected = (0.15 * math.sin(math.radians(60.0)) - 0.0005) * f1 / C
+    assert out["value"][0] == pytest.approx(expected)
@@ -0,0 +1,99 @@
+import functools
+import io
+import struct
+
+import pytest
+
+from pytecgg.parsing import read_binex
+
+from .conftest import bits, crc16
+
+C = 299_792_458.0
+# GPS week 2355, 01:00:00 GPST, in minutes since the GPS epoch
+MINUTES = 2355 * 10080 + 60
+T0 = (315_964_800 + MINUTES * 60) * 10**9
+
+
+def _ubnxi(value):
+    out = [0x80 | (value >> shift) & 0x7F for shift in (21, 14, 7) if value >> shift]
+    return bytes(out + [value & 0x7F])
+
+
+def _record(record_id, message):
+    """Big-endian, forward-readable BINEX record (sync byte 0xE2)"""
+    body = _ubnxi(record_id) + _ubnxi(len(message)) + message
+    if len(body) <= 127:
+        check = bytes([functools.reduce(lambda a, b: a ^ b, body)])
+    else:
+        check = crc16(body).to_bytes(2, "big")
+    return b"\xe2" + body + check
+
+
+def _observations(clock_ns, satellites):
+    """0x7F-05 record; satellites as (prn, system, [(code, flag bytes, fields), ...])"""
+    message = bytes([0x05]) + struct.pack(">IH", MINUTES, 0)
+    message += bytes([0x80 | (len(satellites) - 1)]) + bits([(0, 2), (clock_ns, 22)])
+    for prn, system, signals in satellites:
+        message += bytes([prn]) + bits([(0, 1), (len(signals), 3), (system, 4)])
+        for code, flags, fields in signals:
+            message += bits([(int(bool(flags)), 1), (0, 2), (code, 5)]) + bytes(flags) + bits(fields)
+    return _record(0x7F, message)
+
+
+def _gps_ephemeris(prn, week, toe, m0, sqrta):
+    """0x01-01 decoded GPS ephemeris, with m0 in radians"""
+    message = bytes([0x01, prn - 1]) + struct.pack(">HIi", week, 0, toe)
+    message += struct.pack(">fifffi", -4e-9, 42, 0.0, 1e-12, 1e-5, 42)
+    message += struct.pack(">fddd", 1e-9, m0, 0.01, sqrta)
+    message += struct.pack(">6f", 1e-8, 200.0, 2e-8, -30.0, -1e-6, 5e-6)
+    message += struct.pack(">dddfff", -0.8, 0.4, 0.96, -2e-9, 1e-10, 2.0) + struct.pack(">HH", 0, 0)
+    return _record(0x01, message)
+
+
+@pytest.fixture
+def binex_log():
+    # G05 L1 C/A (with Doppler) and L2 P(Y); R03 L1 C/A on channel -4
+    l1 = (0, [0x04], [(110, 8), (0, 2), (343_780_839, 32), (36, 6), (0, 2), (1234, 22), (-256_000, 24)])
+    l2 = (10, [], [(100, 8), (2500, 16), (1, 2), (-77, 22)])
+    glonass = (0, [0x32], [(105, 8), (0, 2), (300_000_000, 32), (0, 6), (0, 2), (0, 22)])
+    records = [
+        _gps_ephemeris(5, 2355, 7200, 0.5, 5153.7),
+        _observations(-150, [(5, 0, [l1, l2]), (3, 1, [glonass])]),
+    ]
+    # Leading garbage, including a record candidate with a wrong checksum
+    return b"\x00\xe2\x01\x05junk" + b"".join(records)
+
+
+def test_read_binex_obs(binex_log):
+    obs, _ = read_binex(io.BytesIO(binex_log))
+
+    assert obs.columns == ["epoch", "sv", "observable", "value", "epoch_flag", "rx_clock_offset"]
+    assert obs["epoch"].dt.epoch("ns").unique().to_list() == [T0]
+    assert obs["rx_clock_offset"].unique().to_list() == [pytest.approx(-150e-9)]
+    values = {(sv, o): v for sv, o, v in obs.select("sv", "observable", "value").iter_rows()}
+    assert sorted(values) == [
+        ("G05", "C1C"), ("G05", "C2W"), ("G05", "D1C"), ("G05", "L1C"), ("G05", "L2W"),
+        ("G05", "S1C"), ("G05", "S2W"), ("R03", "C1C"), ("R03", "L1C"), ("R03", "S1C"),
+    ]
+    c1 = 343_780_839 * 0.064 + 0.036
+    assert values["G05", "C1C"] == pytest.approx(c1, abs=1e-6)
+    assert values["G05", "L1C"] == pytest.approx((c1 + 1234 * 2e-5) * 1575.42e6 / C, abs=1e-6)
+    assert values["G05", "D1C"] == -1000
+    # Secondary signals are differences from the first one
+    assert values["G05", "C2W"] == pytest.approx(c1 + 2.5, abs=1e-6)
+    assert values["G05", "S2W"] == pytest.approx(40.1)
+    # GLONASS channel from the signal flags
+    assert values["R03", "L1C"] == pytest.approx(19_200_000 * (1602e6 - 4 * 0.5625e6) / C, abs=1e-6)
+
+
+def test_read_binex_nav(binex_log):
+    _, nav = read_binex(io.BytesIO(binex_log))
+
+    assert list(nav) == ["GPS"]
+    gps = nav["GPS"]
+    assert gps["sv"].to_list() == ["5"]
+    assert gps["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 7200
+    assert gps["week"].item() == 2355
+    assert gps["m0"].item() == 0.5
+    assert gps["sqrta"].item() == 5153.7
+    assert gps["deltaN"].item() == pytest.approx(1e-9 * 3.141592653589793)
@@ -0,0 +1,46 @@
+import polars as pl
+
+from pytecgg.cli import main
+from pytecgg.parsing import read_rinex_obs
+
+
+def test_cli_help_and_version(capsys):
+    """Test the top-level help and version"""
+    assert main(["--help"]) == 0
+    out = capsys.readouterr().out
+    for command in ("convert", "to-parquet", "qc", "tec"):
+        assert command in out
+    assert main(["--version"]) == 0
+    assert capsys.readouterr().out.startswith("pytecgg ")
+
+
+def test_cli_usage_errors(capsys):
+    """Test that invalid command lines exit with status 2"""
+    assert main([]) == 2
+    assert main(["unknown"]) == 2
+    assert "unknown command" in capsys.readouterr().err
+    assert main(["qc"]) == 2
+    assert main(["qc", "file.rnx", "--bogus"]) == 2
+    assert "unknown option '--bogus'" in capsys.readouterr().err
+
+
+def test_cli_processing_error(capsys, tmp_path):
+    """Test that processing failures exit with status 1"""
+    assert main(["to-parquet", str(tmp_path / "missing.rnx"), str(tmp_path / "out.parquet")]) == 1
+    assert "error" in capsys.readouterr().err
+
+
+def test_cli_to_parquet(obs_v3_file, tmp_path):
+    """Test the conversion of a RINEX observation file into Parquet"""
+    output = tmp_path / "obs.parquet"
+    assert main(["to-parquet", str(obs_v3_file), str(output)]) == 0
+    expected, _, _ = read_rinex_obs(obs_v3_file)
+    assert pl.read_parquet(output).equals(expected)
+
+
+def test_cli_qc(obs_v3_file, capsys):
+    """Test the quality summary printed by the qc command"""
+    assert main(["qc", str(obs_v3_file)]) == 0
+    out = capsys.readouterr().out
+    assert "first epoch" in out
+    assert "cycle slips" in out
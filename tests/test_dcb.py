@@ -0,0 +1,57 @@
+import polars as pl
+import pytest
+
+from pytecgg.linear_combinations.constants import C
+from pytecgg.parsing import read_bernese_dcb
+from pytecgg.tec_calibration import correct_p1c1_bias
+
+_P1C1_DCB = """\
+CODE'S MONTHLY GPS P1-C1 DCB SOLUTION, YEAR 2014, MONTH 04               05-MAY-14 07:35
+--------------------------------------------------------------------------------
+
+DIFFERENTIAL (P1-C1) CODE BIASES FOR SATELLITES AND RECEIVERS:
+
+PRN / STATION NAME        VALUE (NS)  RMS (NS)
+***   ****************    *****.***   *****.***
+G01                          -0.957      0.010
+G02                           1.500      0.012
+G    ALGO 40104M002          0.368       0.021
+"""
+
+
+@pytest.fixture
+def p1c1_file(tmp_path):
+    path = tmp_path / "P1C11404.DCB"
+    path.write_text(_P1C1_DCB)
+    return path
+
+
+def test_read_bernese_dcb(p1c1_file):
+    dcb = read_bernese_dcb(p1c1_file)
+    assert dcb["system"].to_list() == ["G", "G", "G"]
+    assert dcb["sv"].to_list() == ["G01", "G02", None]
+    assert dcb["station"].to_list() == [None, None, "ALGO 40104M002"]
+    assert dcb["bias"].to_list() == [-0.957, 1.5, 0.368]
+    assert dcb["rms"].to_list() == [0.01, 0.012, 0.021]
+
+
+def test_correct_p1c1_bias(p1c1_file):
+    biases = read_bernese_dcb(p1c1_file)
+    obs = pl.DataFrame(
+        {
+            "sv": ["G01", "G01", "G01", "G03"],
+            "observable": ["C1", "P2", "L1", "C1"],
+            "value": [2e7, 2e7, 1e8, 2e7],
+        }
+    )
+    shift = -0.957e-9 * C
+
+    assert correct_p1c1_bias(obs, biases, "P1P2").equals(obs)
+    c1p2 = correct_p1c1_bias(obs, biases, "C1P2")
+    assert c1p2["value"].to_list() == pytest.approx([2e7 + shift, 2e7, 1e8, 2e7])
+    c1x2 = correct_p1c1_bias(obs, biases, "C1X2")
+    assert c1x2["value"].to_list() == pytest.approx([2e7 + shift, 2e7 + shift, 1e8, 2e7])
+    assert c1x2.columns == obs.columns
+
+    with pytest.raises(ValueError):
+        correct_p1c1_bias(obs, biases, "C1C2")
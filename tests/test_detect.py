@@ -0,0 +1,109 @@
+from datetime import datetime, timezone
+
+import pytest
+
+from pytecgg.parsing import (
+    detect_type,
+    read_ionex,
+    read_rinex,
+    read_rinex_clk,
+    read_rinex_nav,
+)
+
+from .conftest import header_record
+
+
+@pytest.fixture
+def clk_file(tmp_path):
+    path = tmp_path / "IGS0OPSFIN_20240920000_01D_30S_CLK.CLK"
+    path.write_text(
+        header_record("     3.00           C", "RINEX VERSION / TYPE")
+        + header_record("", "END OF HEADER")
+        + "AS G01  2024 04 01 00 00  0.000000  2    1.234567890123E-04  5.000000000000E-11\n"
+        + "AR ALGO 2024 04 01 00 00 30.000000  4   -2.500000000000D-09  1.000000000000D-11\n"
+        + "    3.000000000000E-14  4.000000000000E-15\n"
+    )
+    return path
+
+
+@pytest.fixture
+def ionex_file(tmp_path):
+    map_header = [
+        header_record("  2024     4     1     0     0     0", "EPOCH OF CURRENT MAP"),
+        header_record("    87.5-180.0 180.0  90.0 450.0", "LAT/LON1/LON2/DLON/H"),
+    ]
+    path = tmp_path / "igsg0920.24i"
+    path.write_text(
+        header_record("     1.0            IONOSPHERE MAPS     GPS", "IONEX VERSION / TYPE")
+        + header_record("    -1", "EXPONENT")
+        + header_record("", "END OF HEADER")
+        + header_record("     1", "START OF TEC MAP")
+        + "".join(map_header)
+        + "   10   20 9999   40   50\n"
+        + header_record("     1", "END OF TEC MAP")
+        + header_record("     1", "START OF RMS MAP")
+        + "".join(map_header)
+        + "    1    2 9999    4    5\n"
+        + header_record("     1", "END OF RMS MAP")
+        + header_record("", "END OF FILE")
+    )
+    return path
+
+
+def test_detect_type(obs_v2_file, obs_v3_file, nav_v3_file, clk_file, ionex_file):
+    """Test the detection of the file type, including Hatanaka-compressed files"""
+    assert detect_type(obs_v2_file) == "obs"
+    assert detect_type(obs_v3_file) == "obs"
+    assert detect_type(nav_v3_file) == "nav"
+    assert detect_type(clk_file) == "clk"
+    assert detect_type(ionex_file) == "ionex"
+
+
+def test_detect_type_invalid(tmp_path):
+    """Test that files without a RINEX header are rejected"""
+    path = tmp_path / "notes.txt"
+    path.write_text("not a RINEX file\n")
+    with pytest.raises(ValueError, match="Not a RINEX file"):
+        detect_type(path)
+    with pytest.raises(FileNotFoundError):
+        detect_type(tmp_path / "missing.rnx")
+
+
+def test_read_rinex_nav_rejects_obs(obs_v3_file):
+    """Test that the navigation reader checks the type of the file"""
+    with pytest.raises(ValueError, match="Not a RINEX navigation file"):
+        read_rinex_nav(obs_v3_file)
+
+
+def test_read_rinex_clk(clk_file):
+    """Test a satellite record and a receiver record with a continuation line"""
+    clk = read_rinex_clk(clk_file)
+    assert clk["type"].to_list() == ["AS", "AR"]
+    assert clk["name"].to_list() == ["G01", "ALGO"]
+    assert clk["epoch"].to_list() == [
+        datetime(2024, 4, 1, 0, 0, tzinfo=timezone.utc),
+        datetime(2024, 4, 1, 0, 0, 30, tzinfo=timezone.utc),
+    ]
+    assert clk["bias"].to_list() == pytest.approx([1.234567890123e-4, -2.5e-9])
+    assert clk["rate"].to_list() == [None, pytest.approx(3e-14)]
+    assert clk["acceleration"].null_count() == 2
+
+
+def test_read_ionex(ionex_file):
+    """Test the scaling of the TEC and RMS maps and the missing values"""
+    ionex = read_ionex(ionex_file)
+    assert ionex.columns == ["epoch", "lat", "lon", "tec", "rms"]
+    assert ionex["lon"].to_list() == [-180.0, -90.0, 0.0, 90.0, 180.0]
+    assert ionex["tec"].to_list()[:2] == pytest.approx([1.0, 2.0])
+    assert ionex["tec"][2] is None
+    assert ionex["rms"].to_list()[:2] == pytest.approx([0.1, 0.2])
+
+
+def test_read_rinex_dispatch(nav_v3_file, clk_file, ionex_file):
+    """Test that read_rinex returns the result of the reader of each type"""
+    nav = read_rinex(nav_v3_file)
+    assert isinstance(nav, dict) and "GPS" in nav
+    assert read_rinex(clk_file).equals(read_rinex_clk(clk_file))
+    assert read_rinex(ionex_file).equals(read_ionex(ionex_file))
+    with pytest.raises(ValueError, match="Options are not supported"):
+        read_rinex(clk_file, time_scale="UTC")
@@ -1,11 +1,11 @@
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
 
 import pytest
 import polars as pl
 
 from pytecgg.context import GNSSContext
 from pytecgg.parsing import read_rinex_nav
-from pytecgg.satellites.ephemeris import _get_gps_time, prepare_ephemeris
+from pytecgg.satellites.ephemeris import _get_gps_time, prepare_ephemeris, select_ephemeris
 
 
 def test_get_gps_time():
@@ -45,3 +45,153 @@ def test_prepare_ephemeris_nav_v3(nav_v3_file):
         assert len(sat) == 3
         assert eph["datetime"].tzinfo is not None
         assert eph["constellation"] == "GPS"
+
+
+def test_read_rinex_nav_week_tow(nav_v3_file):
+    """Test the GPS and native week/TOW columns of the nav DataFrames"""
+    nav_data = read_rinex_nav(nav_v3_file)
+
+    df_gps = nav_data["GPS"]
+    row = df_gps.row(0, named=True)
+    assert (row["gps_week"], row["tow"]) == _get_gps_time(row["epoch"])
+    assert df_gps["native_week"].to_list() == df_gps["gps_week"].to_list()
+
+    df_gal = nav_data["GALILEO"]
+    assert (df_gal["gps_week"] - df_gal["native_week"] == 1024).all()
+
+    df_bds = nav_data["BEIDOU"]
+    assert (df_bds["gps_week"] - df_bds["native_week"]).is_in([1356, 1357]).all()
+    assert df_bds["tow"].is_between(0, 604800, closed="left").all()
+
+    assert nav_data["GLONASS"]["native_week"].null_count() == nav_data["GLONASS"].height
+
+
+def test_read_rinex_nav_accuracy(nav_v3_file):
+    """Test the decoded URA/SISA columns of the nav DataFrames"""
+    nav_data = read_rinex_nav(nav_v3_file)
+
+    df_gps = nav_data["GPS"].drop_nulls("accuracy")
+    assert df_gps["ura_index"].is_between(0, 15).all()
+    assert (df_gps["ura"].drop_nulls() >= df_gps.drop_nulls("ura")["accuracy"]).all()
+
+    df_gal = nav_data["GALILEO"].drop_nulls("sisa")
+    assert df_gal["ura_index"].is_between(0, 255).all()
+    valid = df_gal.filter(pl.col("sisa") >= 0)
+    assert (valid["ura"] - valid["sisa"]).abs().max() < 1e-9
+
+    assert "ura" not in nav_data["GLONASS"].columns
+
+
+def test_read_rinex_nav_validity(nav_v3_file):
+    """Test the fit interval and validity window columns of the nav DataFrames"""
+    nav_data = read_rinex_nav(nav_v3_file)
+
+    for df in nav_data.values():
+        assert df["valid_from"].dtype == df["epoch"].dtype
+        assert (df["valid_until"] - df["valid_from"] == df["fit_interval"]).all()
+        assert (df["valid_from"] < df["epoch"]).all() and (df["epoch"] < df["valid_until"]).all()
+
+    df_gps = nav_data["GPS"]
+    expected = df_gps["fitInt"].fill_null(0.0).replace(0.0, 4.0)
+    assert (df_gps["fit_interval"].dt.total_seconds() == expected * 3600).all()
+    assert (nav_data["GLONASS"]["fit_interval"].dt.total_minutes() == 30).all()
+
+
+def test_read_rinex_nav_reference_times(nav_v3_file):
+    """Test the explicit clock (toc) and orbit (toe) reference time columns"""
+    nav_data = read_rinex_nav(nav_v3_file)
+
+    for const in ("GPS", "GALILEO", "BEIDOU"):
+        df = nav_data[const].drop_nulls("toe")
+        assert (df["toc"] == df["epoch"]).all()
+        assert df["toe_epoch"].dtype == df["epoch"].dtype
+        # toe is the time of week of toe_epoch, in the native timescale
+        offset = (df["toe_epoch"] - df["epoch"]).dt.total_seconds()
+        assert ((df["native_tow"] + offset) % 604800 - df["toe"]).abs().max() < 1.0
+        assert (offset.abs() <= 302400).all()
+
+    assert "toe_epoch" not in nav_data["GLONASS"].columns
+    assert "toc" in nav_data["GLONASS"].columns
+
+
+def test_select_ephemeris(nav_v3_file):
+    nav_data = read_rinex_nav(nav_v3_file)
+    gps = nav_data["GPS"].with_row_index("row")
+    healthy = gps.filter(pl.col("health") == 0)
+    record = healthy.row(len(healthy) // 2, named=True)
+    sv = f"G{int(record['sv']):02d}"
+
+    epochs = pl.Series(
+        [
+            record["toe_epoch"] + timedelta(minutes=10),
+            record["toe_epoch"] + timedelta(days=30),
+            record["toe_epoch"],
+        ]
+    )
+    out = select_ephemeris(nav_data, epochs, pl.Series([sv, sv, "X01"]))
+
+    assert out.columns == ["epoch", "sv", "constellation", "row", "toc"]
+    assert out["epoch"].to_list() == epochs.to_list()
+    selected = gps.row(out["row"][0], named=True)
+    assert (selected["sv"], selected["toe_epoch"]) == (record["sv"], record["toe_epoch"])
+    assert out.row(0, named=True)["constellation"] == "GPS"
+    # Out of every validity window, and unknown satellite
+    assert out["row"][1] is None and out["row"][2] is None
+
+    latest = select_ephemeris(nav_data, epochs[:1], pl.Series([sv]), policy="latest")
+    chosen = gps.row(latest["row"][0], named=True)
+    assert chosen["epoch"] <= epochs[0]
+
+    with pytest.raises(ValueError):
+        select_ephemeris(nav_data, epochs, pl.Series([sv] * 3), policy="first")
+
+
+_STATE_VECTOR = ["satPosX", "satPosY", "satPosZ", "velX", "velY", "velZ", "accelX", "accelY", "accelZ"]
+
+
+def test_read_rinex_nav_v2_glonass(tmp_path, nav_v3_file):
+    """Test a RINEX 2 GLONASS navigation file, with UTC epochs and zero accelerations"""
+    path = tmp_path / "glon0460.98g"
+    path.write_text(
+        "     2.10           G: GLONASS NAV DATA                     RINEX VERSION / TYPE\n"
+        "                                                            END OF HEADER\n"
+        " 3 98  2 15  0 15  0.0 0.163525342941D-03 0.363797880709D-11 0.108000000000D+05\n"
+        "   -0.106275903320D+05-0.348924636841D+00 0.931322574615D-09 0.000000000000D+00\n"
+        "   -0.944422070313D+04 0.288163375854D+01 0.931322574615D-09 0.000000000000D+00\n"
+        "    0.212257280273D+05 0.144599342346D+01 0.000000000000D+00 0.300000000000D+01\n"
+    )
+    nav_data = read_rinex_nav(path)
+    assert list(nav_data) == ["GLONASS"]
+    df = nav_data["GLONASS"]
+    assert set(df.columns) == set(read_rinex_nav(nav_v3_file)["GLONASS"].columns)
+
+    row = df.row(0, named=True)
+    assert row["epoch"] == datetime(1998, 2, 15, 0, 15, tzinfo=timezone.utc)
+    assert row["sv"] == "3"
+    assert row["satPosX"] == pytest.approx(-10627.5903320)
+    assert (row["accelZ"], row["channel"]) == (0.0, 0.0)
+
+
+def test_read_rinex_nav_v2_geo(tmp_path, nav_v3_file):
+    """Test a RINEX 2 GEO navigation file, read into the SBAS DataFrame of RINEX 3"""
+    path = tmp_path / "egno2420.01h"
+    path.write_text(
+        "     2.11           H: GEO NAV MSG DATA                     RINEX VERSION / TYPE\n"
+        "                                                            END OF HEADER\n"
+        "20 01  8 30  0  1 04.0-3.841705620289D-08-1.136868377216D-13 3.456000000000D+05\n"
+        "    4.063672000000D+04 0.000000000000D+00 0.000000000000D+00 0.000000000000D+00\n"
+        "   -1.124591600000D+04 1.000000000000D-03 0.000000000000D+00 2.000000000000D+00\n"
+        "    0.000000000000D+00 0.000000000000D+00 0.000000000000D+00 1.000000000000D+00\n"
+    )
+    nav_data = read_rinex_nav(path)
+    assert list(nav_data) == ["SBAS"]
+    df = nav_data["SBAS"]
+    assert set(df.columns) == set(read_rinex_nav(nav_v3_file)["SBAS"].columns)
+
+    row = df.row(0, named=True)
+    assert row["epoch"] == datetime(2001, 8, 30, 0, 1, 4, tzinfo=timezone.utc)
+    assert row["sv"] == "20"
+    assert row["clock_bias"] == pytest.approx(-3.841705620289e-08)
+    assert [row[c] for c in _STATE_VECTOR[:3]] == pytest.approx([40636.72, -11245.916, 0.0])
+    assert (row["ura_index"], row["ura"]) == (2, pytest.approx(4.85))
+    assert row["fit_interval"] == timedelta(minutes=6)
@@ -0,0 +1,188 @@
+import json
+from datetime import datetime, timedelta
+
+import numpy as np
+import pytest
+import polars as pl
+
+from pytecgg.export import ipp_to_geojson, write_dataset
+
+
+@pytest.fixture
+def ipp_df():
+    t0 = datetime(2024, 1, 1)
+    return pl.DataFrame(
+        {
+            "epoch": [t0 + timedelta(seconds=30 * i) for i in range(5)],
+            "sv": ["G01", "G01", "G01", "E05", "E05"],
+            "lat_ipp": [45.0, 45.1, 45.2, float("nan"), 40.0],
+            "lon_ipp": [10.0, 10.1, 10.2, 12.0, 12.1],
+            "vtec": [20.0, 21.0, float("nan"), 15.0, 16.0],
+        }
+    ).with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+
+
+def test_ipp_to_geojson_lines(ipp_df):
+    """Test one LineString per track, skipping tracks with a single IPP"""
+    collection = ipp_to_geojson(ipp_df)
+    assert collection["type"] == "FeatureCollection"
+    assert len(collection["features"]) == 1
+
+    feature = collection["features"][0]
+    assert feature["geometry"]["type"] == "LineString"
+    assert feature["geometry"]["coordinates"][0] == [10.0, 45.0]
+    props = feature["properties"]
+    assert props["sv"] == "G01"
+    assert props["start"] == "2024-01-01T00:00:00.000000Z"
+    assert props["vtec"] == [20.0, 21.0, None]
+
+
+def test_ipp_to_geojson_points(ipp_df, tmp_path):
+    """Test Point features and the output file"""
+    path = tmp_path / "ipp.geojson"
+    collection = ipp_to_geojson(ipp_df, geometry="point", path=path)
+    assert len(collection["features"]) == 4
+    assert json.loads(path.read_text()) == collection
+
+    last = collection["features"][-1]
+    assert last["geometry"] == {"type": "Point", "coordinates": [12.1, 40.0]}
+    assert last["properties"] == {"sv": "E05", "time": "2024-01-01T00:02:00.000000Z", "vtec": 16.0}
+
+
+def test_ipp_to_geojson_invalid_geometry(ipp_df):
+    with pytest.raises(ValueError):
+        ipp_to_geojson(ipp_df, geometry="polygon")
+
+
+def test_to_xarray_sv_layout(ipp_df):
+    """Test the (time, sv) layout, with missing combinations filled with NaN"""
+    pytest.importorskip("xarray")
+    from pytecgg.export import to_xarray
+
+    ds = to_xarray(ipp_df, variables=["vtec"])
+    assert ds["vtec"].dims == ("time", "sv")
+    assert ds.sizes == {"time": 5, "sv": 2}
+    assert list(ds["sv"].values) == ["E05", "G01"]
+    assert ds["vtec"].attrs["units"] == "TECU"
+    assert ds["vtec"].sel(sv="G01").isnull().sum() == 3
+    assert ds.attrs["Conventions"] == "CF-1.8"
+
+
+def test_write_netcdf_grid_round_trip(tmp_path):
+    """Test a (time, lat, lon) VTEC map written to and read back from netCDF"""
+    xr = pytest.importorskip("xarray")
+    pytest.importorskip("netCDF4")
+    from pytecgg.export import write_netcdf
+
+    df = pl.DataFrame(
+        {
+            "epoch": [datetime(2024, 1, 1)] * 4,
+            "lat": [40.0, 40.0, 42.5, 42.5],
+            "lon": [10.0, 15.0, 10.0, 15.0],
+            "vtec": [10.0, 11.0, 12.0, 13.0],
+        }
+    ).with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+    path = tmp_path / "vtec.nc"
+    write_netcdf(df, path, layout="grid", attrs={"title": "VTEC map"})
+
+    with xr.open_dataset(path) as ds:
+        assert ds["vtec"].dims == ("time", "lat", "lon")
+        assert ds["vtec"].values[0].tolist() == [[10.0, 11.0], [12.0, 13.0]]
+        assert ds["lat"].attrs["units"] == "degrees_north"
+        assert ds.attrs["title"] == "VTEC map"
+
+
+def test_write_hdf5_layout(ipp_df, tmp_path):
+    """Test the group layout and the column encoding of the HDF5 writer"""
+    h5py = pytest.importorskip("h5py")
+    from pytecgg.context import GNSSContext
+    from pytecgg.export import write_hdf5
+
+    ctx = GNSSContext(
+        receiver_pos=(4641949.0, 1393045.0, 4133287.0),
+        receiver_name="GROT",
+        rinex_version="3.04",
+        systems=["G", "E"],
+    )
+    nav = {"GPS": ipp_df.select("epoch", "sv")}
+    path = tmp_path / "data.h5"
+    write_hdf5(path, nav=nav, tec=ipp_df, header=ctx)
+
+    with h5py.File(path, "r") as f:
+        assert set(f.keys()) == {"header", "nav", "tec"}
+        assert f["header"].attrs["receiver_name"] == "grot"
+        assert list(f["header"].attrs["receiver_pos"]) == list(ctx.receiver_pos)
+        assert list(f["nav"].keys()) == ["GPS"]
+        assert list(f["tec"].attrs["columns"]) == ipp_df.columns
+
+        epochs = f["tec/epoch"][:]
+        assert epochs[1] - epochs[0] == 30_000_000
+        assert f["tec/sv"].asstr()[0] == "G01"
+        assert np.isnan(f["tec/vtec"][2])
+
+
+def test_write_dataset_hive_partitions(ipp_df, tmp_path):
+    """Test the partition layout, the derived date columns and the existing policies"""
+    df = ipp_df.with_columns(
+        pl.when(pl.col("sv") == "E05")
+        .then(pl.col("epoch").dt.offset_by("1d"))
+        .otherwise(pl.col("epoch"))
+    )
+    written = write_dataset(df, tmp_path, station="grot")
+    assert sorted(p.relative_to(tmp_path).as_posix() for p in written) == [
+        "station=grot/year=2024/doy=1/part-0.parquet",
+        "station=grot/year=2024/doy=2/part-0.parquet",
+    ]
+
+    back = pl.scan_parquet(tmp_path, hive_partitioning=True).filter(pl.col("doy") == 1)
+    assert back.select("sv").collect()["sv"].to_list() == ["G01"] * 3
+
+    write_dataset(df, tmp_path, station="grot")
+    assert len(list(tmp_path.rglob("*.parquet"))) == 2
+    write_dataset(df, tmp_path, station="grot", existing="append")
+    assert len(list(tmp_path.rglob("*.parquet"))) == 4
+    with pytest.raises(FileExistsError):
+        write_dataset(df, tmp_path, station="grot", existing="error")
+
+
+def test_write_madrigal_los_and_site(ipp_df, tmp_path):
+    """Test the Madrigal tables, parameters and file names"""
+    h5py = pytest.importorskip("h5py")
+    from pytecgg.context import GNSSContext
+    from pytecgg.export import write_madrigal
+
+    ctx = GNSSContext(
+        receiver_pos=(4641949.0, 1393045.0, 4133287.0),
+        receiver_name="GROT",
+        rinex_version="3.04",
+        systems=["G", "E"],
+    )
+    tec = ipp_df.with_columns(
+        pl.col("vtec").alias("stec"),
+        pl.lit(120.0).alias("azi"),
+        pl.lit(45.0).alias("ele"),
+    )
+    los_path, site_path = write_madrigal(tmp_path, tec, ctx)
+    assert los_path.name == "los_20240101.001.h5"
+    assert site_path.name == "site_20240101.001.h5"
+
+    with h5py.File(los_path, "r") as f:
+        records = f["Data/Table Layout"][:]
+        # The record without slant TEC is skipped
+        assert len(records) == 4
+        assert records["gps_site"][0] == b"grot"
+        assert records["gnss_type"][-1] == b"GALILEO"
+        assert records["sat_id"][-1] == 5
+        assert records["recno"].tolist() == [0, 1, 2, 3]
+        assert records["pierce_alt"][0] == pytest.approx(350.0)
+        assert records["ut1_unix"][0] == 1704067200.0
+        assert np.isnan(records["dlos_tec"]).all()
+        mnemonics = f["Metadata/Data Parameters"]["mnemonic"].tolist()
+        assert mnemonics[:2] == [b"YEAR", b"MONTH"]
+        assert b"LOS_TEC" in mnemonics
+
+    with h5py.File(site_path, "r") as f:
+        site = f["Data/Table Layout"][:]
+        assert len(site) == 1
+        assert site["gdlatr"][0] == pytest.approx(40.6, abs=0.1)
+        assert site["ut2_unix"][0] - site["ut1_unix"][0] == pytest.approx(120.0)
@@ -0,0 +1,93 @@
+from datetime import datetime, timedelta
+
+import numpy as np
+import polars as pl
+import pytest
+
+import pytecgg  # noqa: F401
+
+
+def test_gnss_frequency():
+    """Test carrier frequencies, including GLONASS FDMA channels"""
+    df = pl.DataFrame(
+        {
+            "sv": ["G01", "E11", "C20", "R05", "R07"],
+            "observable": ["L1C", "C5Q", "L2I", "L1C", "L2P"],
+        }
+    )
+    out = df.lazy().select(
+        pl.col("observable").gnss.frequency(glonass_channels={"R05": 1})
+    ).collect()
+    assert out["observable"][:4].to_list() == pytest.approx(
+        [1575.42e6, 1176.45e6, 1561.098e6, 1602.5625e6]
+    )
+    assert out["observable"][4] is None
+
+
+def test_gnss_wavelength():
+    """Test carrier wavelengths, null for unknown frequencies"""
+    df = pl.DataFrame({"sv": ["G01", "R05", "R07"], "observable": ["L1C", "L1C", "L1C"]})
+    out = df.select(pl.col("observable").gnss.wavelength(glonass_channels={"R05": 1}))
+    assert out["observable"][:2].to_list() == pytest.approx(
+        [299792458.0 / 1575.42e6, 299792458.0 / 1602.5625e6]
+    )
+    assert out["observable"][2] is None
+
+
+def test_tec_rot_over_sv():
+    """Test the rate of TEC per satellite, with null rates across data gaps"""
+    t0 = datetime(2024, 1, 1)
+    epochs = [t0 + timedelta(seconds=s) for s in (0, 30, 60, 300)]
+    df = pl.DataFrame(
+        {
+            "epoch": epochs * 2,
+            "sv": ["G01"] * 4 + ["G02"] * 4,
+            "stec": [10.0, 11.0, 13.0, 14.0, 20.0, 20.5, 20.5, 30.0],
+        }
+    )
+    out = df.with_columns(pl.col("stec").tec.rot(max_gap=60).over("sv").alias("rot"))
+    assert out["rot"].to_list() == [None, 2.0, 4.0, None, None, 1.0, 0.0, None]
+
+
+def test_tec_mapping():
+    """Test the thin-shell mapping at zenith and at low elevation"""
+    df = pl.DataFrame({"stec": [10.0, 10.0], "ele": [90.0, 10.0]})
+    out = df.select(pl.col("stec").tec.mapping("ele", h_ipp=350_000))["stec"]
+    ratio = 6_371 / (6_371 + 350)
+    expected = 10.0 * np.cos(np.arcsin(ratio * np.cos(np.radians(10.0))))
+    assert out.to_list() == pytest.approx([10.0, expected])
+
+
+def test_tec_rolling_within_arcs():
+    """Test that rolling statistics do not mix arcs and ignore the row order"""
+    t0 = datetime(2024, 1, 1)
+    epochs = [t0 + timedelta(seconds=30 * i) for i in range(4)]
+    df = pl.DataFrame(
+        {
+            "epoch": epochs * 2,
+            "id_arc_valid": ["a"] * 4 + ["b"] * 4,
+            "rot": [1.0, 3.0, 5.0, 7.0, 100.0, 100.0, 100.0, 100.0],
+        }
+    ).reverse()
+    out = df.with_columns(
+        pl.col("rot").tec.rolling_mean(60).alias("mean"),
+        pl.col("rot").tec.rolling_std(60).alias("std"),
+    ).sort("id_arc_valid", "epoch")
+    assert out["mean"].to_list() == pytest.approx([1.0, 2.0, 4.0, 6.0] + [100.0] * 4)
+    assert out["std"].to_list()[1:4] == pytest.approx([1.0, 1.0, 1.0])
+    assert out["std"][0] is None
+
+
+def test_tec_rolling_detrend():
+    """Test that a polynomial trend is removed up to the noise"""
+    t0 = datetime(2024, 1, 1)
+    seconds = np.arange(120.0)
+    df = pl.DataFrame(
+        {
+            "epoch": [t0 + timedelta(seconds=s) for s in seconds],
+            "id_arc_valid": ["a"] * 120,
+            "phase": 2.0 + 0.5 * seconds - 1e-3 * seconds**2,
+        }
+    )
+    out = df.select(pl.col("phase").tec.rolling_detrend(30, degree=2))["phase"]
+    assert out.drop_nulls().to_numpy() == pytest.approx(0.0, abs=1e-6)
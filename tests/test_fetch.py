@@ -0,0 +1,278 @@
+import time
+from datetime import date, datetime, timedelta
+from pathlib import Path
+from unittest.mock import MagicMock, patch
+
+import pytest
+import requests
+from requests.exceptions import ConnectionError, HTTPError
+
+from pytecgg.fetch import fetch_bias, fetch_brdc, fetch_clk, fetch_nav, fetch_obs, fetch_sp3
+from pytecgg.fetch.products import _candidate_tiers
+from pytecgg.fetch import set_earthdata_credentials
+from pytecgg.fetch.cache import _is_valid, _record
+from pytecgg.fetch.http import _download_resumable, _fetch_all, _session
+
+
+def _response(status, chunks=(), headers=None):
+    response = MagicMock()
+    response.status_code = status
+    response.headers = headers or {}
+    response.iter_content.return_value = list(chunks)
+    response.__enter__.return_value = response
+    if status >= 400:
+        error = HTTPError(f"{status} error")
+        error.response = response
+        response.raise_for_status.side_effect = error
+    return response
+
+
+def test_fetch_obs_daily_urls(tmp_path):
+    with patch("pytecgg.fetch.rinex._fetch_all", return_value=[]) as mock_fetch:
+        fetch_obs("graz00aut", date(2024, 12, 31), date(2025, 1, 1), tmp_path)
+    tasks = mock_fetch.call_args[0][0]
+    assert [url for url, _ in tasks] == [
+        "https://igs.bkg.bund.de/root_ftp/IGS/obs/2024/366/GRAZ00AUT_R_20243660000_01D_30S_MO.crx.gz",
+        "https://igs.bkg.bund.de/root_ftp/IGS/obs/2025/001/GRAZ00AUT_R_20250010000_01D_30S_MO.crx.gz",
+    ]
+    assert tasks[0][1] == tmp_path / "GRAZ" / "GRAZ00AUT_R_20243660000_01D_30S_MO.crx.gz"
+
+
+def test_fetch_obs_hourly_and_nav_urls(tmp_path):
+    with patch("pytecgg.fetch.rinex._fetch_all", return_value=[]) as mock_fetch:
+        fetch_obs(
+            "GRAZ00AUT",
+            datetime(2025, 3, 1, 22, 15),
+            datetime(2025, 3, 1, 23, 0),
+            tmp_path,
+            center="cddis",
+            hourly=True,
+        )
+        urls = [url for url, _ in mock_fetch.call_args[0][0]]
+        assert urls[-1] == (
+            "https://cddis.nasa.gov/archive/gnss/data/hourly/2025/060/23/"
+            "GRAZ00AUT_R_20250602300_01H_30S_MO.crx.gz"
+        )
+        assert len(urls) == 2
+
+        fetch_nav(date(2025, 3, 1), output_path=tmp_path, center="ign")
+        url, path = mock_fetch.call_args[0][0][0]
+        assert url == "https://igs.ign.fr/pub/igs/data/2025/060/BRDC00IGS_R_20250600000_01D_MN.rnx.gz"
+        assert path == tmp_path / "BRDC00IGS_R_20250600000_01D_MN.rnx.gz"
+
+
+def test_fetch_invalid_arguments(tmp_path):
+    with pytest.raises(ValueError):
+        fetch_obs("GRAZ", date(2025, 3, 1), output_path=tmp_path)
+    with pytest.raises(ValueError):
+        fetch_nav(date(2025, 3, 1), output_path=tmp_path, center="nowhere")
+
+
+def test_download_resumes_partial_file(tmp_path):
+    """Test that an interrupted download resumes with a Range request"""
+    dest = tmp_path / "file.crx.gz"
+    dest.with_name(dest.name + ".part").write_bytes(b"chunk1")
+    session = MagicMock()
+    session.get.return_value = _response(206, [b"chunk2"])
+
+    _download_resumable(session, "http://fake-url.com", dest)
+
+    assert session.get.call_args.kwargs["headers"] == {"Range": "bytes=6-"}
+    assert dest.read_bytes() == b"chunk1chunk2"
+    assert not dest.with_name(dest.name + ".part").exists()
+
+
+def test_download_retries_transient_errors(tmp_path):
+    dest = tmp_path / "file.crx.gz"
+    session = MagicMock()
+    session.get.side_effect = [
+        ConnectionError("reset"),
+        _response(503),
+        _response(200, [b"data"]),
+    ]
+    with patch("pytecgg.fetch.http.time.sleep") as mock_sleep:
+        _download_resumable(session, "http://fake-url.com", dest, retries=3)
+    assert mock_sleep.call_count == 2
+    assert dest.read_bytes() == b"data"
+
+
+def test_download_does_not_retry_missing_files(tmp_path):
+    session = MagicMock()
+    session.get.return_value = _response(404)
+    with patch("pytecgg.fetch.http.time.sleep") as mock_sleep:
+        with pytest.raises(HTTPError):
+            _download_resumable(session, "http://fake-url.com", tmp_path / "missing")
+    mock_sleep.assert_not_called()
+    assert session.get.call_count == 1
+
+
+def test_fetch_brdc_cache_and_fallback(tmp_path, monkeypatch):
+    """Test the cached BRDC path and the fallback to the next data center"""
+    monkeypatch.setenv("PYTECGG_CACHE_DIR", str(tmp_path))
+
+    def fake_fetch(tasks, retries, max_age=None):
+        url, dest = tasks[0]
+        if "bkg" in url:
+            return []
+        dest.parent.mkdir(parents=True, exist_ok=True)
+        dest.write_bytes(b"nav")
+        return [dest]
+
+    with patch("pytecgg.fetch.rinex._fetch_all", side_effect=fake_fetch) as mock_fetch:
+        path = fetch_brdc(date(2025, 3, 1), constellation="galileo")
+    assert path == tmp_path / "brdc" / "2025" / "BRDC00IGS_R_20250600000_01D_EN.rnx.gz"
+    assert path.read_bytes() == b"nav"
+    assert mock_fetch.call_count == 2
+
+    with patch("pytecgg.fetch.rinex._fetch_all", return_value=[]):
+        with pytest.raises(FileNotFoundError):
+            fetch_brdc(date(2025, 3, 2))
+
+
+def test_precise_product_tiers():
+    """Test the latency-aware selection of the product tiers"""
+    day = datetime(2025, 3, 1)
+    assert _candidate_tiers("sp3", day, None, datetime(2025, 4, 1)) == ["final", "rapid", "ultra"]
+    assert _candidate_tiers("sp3", day, None, datetime(2025, 3, 3)) == ["rapid", "ultra"]
+    assert _candidate_tiers("sp3", day, None, datetime(2025, 3, 1, 12)) == ["ultra"]
+    assert _candidate_tiers("clk", day, None, datetime(2025, 3, 3)) == ["rapid"]
+    assert _candidate_tiers("clk", day, None, datetime(2025, 3, 1, 12)) == []
+    with pytest.raises(ValueError):
+        _candidate_tiers("clk", day, "ultra", datetime(2025, 4, 1))
+
+
+def test_fetch_sp3_falls_back_to_rapid(tmp_path, monkeypatch):
+    """Test the fallback to the next tier when the final orbits are missing"""
+    monkeypatch.setenv("PYTECGG_CACHE_DIR", str(tmp_path))
+
+    def fake_fetch(tasks, retries):
+        url, dest = tasks[0]
+        if "FIN" in url:
+            return []
+        return [dest]
+
+    with patch("pytecgg.fetch.products._fetch_all", side_effect=fake_fetch) as mock_fetch:
+        path = fetch_sp3(date(2025, 3, 1))
+    assert path == tmp_path / "products" / "2355" / "IGS0OPSRAP_20250600000_01D_15M_ORB.SP3.gz"
+    # Final orbits are tried on every data center before falling back
+    assert mock_fetch.call_count == 4
+    assert mock_fetch.call_args_list[0].args[0][0][0] == (
+        "https://igs.bkg.bund.de/root_ftp/IGS/products/2355/"
+        "IGS0OPSFIN_20250600000_01D_15M_ORB.SP3.gz"
+    )
+
+    with patch("pytecgg.fetch.products._fetch_all", return_value=[]):
+        with pytest.raises(FileNotFoundError):
+            fetch_clk(date(2025, 3, 1), tier="final")
+
+
+def test_fetch_bias_products(tmp_path, monkeypatch):
+    """Test the URLs of daily and monthly bias products"""
+    monkeypatch.setenv("PYTECGG_CACHE_DIR", str(tmp_path))
+
+    def fake_fetch(tasks, retries):
+        return [tasks[0][1]]
+
+    with patch("pytecgg.fetch.bias._fetch_all", side_effect=fake_fetch) as mock_fetch:
+        osb = fetch_bias(date(2025, 3, 15))
+        dcb = fetch_bias(date(2025, 3, 15), provider="code", kind="dcb")
+        cas = fetch_bias(date(2025, 3, 15), provider="cas", kind="dcb")
+        p1c1 = fetch_bias(date(2025, 3, 15), provider="code", kind="p1c1")
+
+    assert osb.name == "COD0OPSFIN_20250740000_01D_01D_OSB.BIA.gz"
+    assert dcb == tmp_path / "bias" / "2025" / "P1P22503.DCB.Z"
+    assert cas.name == "CAS0MGXRAP_20250740000_01D_01D_DCB.BSX.gz"
+    assert p1c1.name == "P1C12503.DCB.Z"
+    assert mock_fetch.call_args_list[1].args[0][0][0] == (
+        "http://ftp.aiub.unibe.ch/CODE/2025/P1P22503.DCB.Z"
+    )
+
+    with pytest.raises(ValueError):
+        fetch_bias(date(2025, 3, 15), provider="jpl")
+    with patch("pytecgg.fetch.bias._fetch_all", return_value=[]):
+        with pytest.raises(FileNotFoundError):
+            fetch_bias(date(2025, 3, 15))
+
+
+def test_download_resumes_truncated_transfer(tmp_path):
+    """Test that a transfer shorter than announced is resumed rather than accepted"""
+    dest = tmp_path / "file.crx.gz"
+    session = MagicMock()
+    session.get.side_effect = [
+        _response(200, [b"da"], {"Content-Length": "4"}),
+        _response(206, [b"ta"], {"Content-Range": "bytes 2-3/4"}),
+    ]
+    with patch("pytecgg.fetch.http.time.sleep"):
+        _download_resumable(session, "http://fake-url.com", dest)
+    assert session.get.call_args.kwargs["headers"] == {"Range": "bytes=2-"}
+    assert dest.read_bytes() == b"data"
+
+
+def test_cache_detects_corrupted_and_stale_files(tmp_path):
+    """Test the size/checksum validation of the cached files"""
+    dest = tmp_path / "file.rnx.gz"
+    dest.write_bytes(b"data")
+    _record(dest, "http://fake-url.com")
+    assert _is_valid(dest)
+    assert _is_valid(dest, max_age=timedelta(hours=1))
+
+    with patch("pytecgg.fetch.cache.time.time", return_value=time.time() + 7200):
+        assert not _is_valid(dest, max_age=timedelta(hours=1))
+
+    dest.write_bytes(b"dat4")
+    assert not _is_valid(dest)
+    # Files not downloaded by the fetchers are trusted unless empty
+    other = tmp_path / "other.rnx"
+    other.write_bytes(b"")
+    assert not _is_valid(other)
+
+
+def test_fetch_all_downloads_again_corrupted_files(tmp_path):
+    dest = tmp_path / "file.rnx.gz"
+    dest.write_bytes(b"data")
+    _record(dest, "http://fake-url.com")
+    dest.write_bytes(b"corrupted")
+
+    def fake_download(session, url, path, retries):
+        path.write_bytes(b"data")
+
+    with patch("pytecgg.fetch.http._download_resumable", side_effect=fake_download) as mock_dl:
+        assert _fetch_all([("http://fake-url.com", dest)]) == [dest]
+        assert _fetch_all([("http://fake-url.com", dest)]) == [dest]
+    assert mock_dl.call_count == 1
+    assert dest.read_bytes() == b"data"
+
+
+def test_earthdata_credentials_only_sent_to_earthdata(tmp_path, monkeypatch):
+    """Test the netrc lookup and the host restriction of the Earthdata credentials"""
+    netrc = tmp_path / "netrc"
+    netrc.write_text("machine urs.earthdata.nasa.gov login user password secret\n")
+    netrc.chmod(0o600)
+    monkeypatch.setenv("NETRC", str(netrc))
+    monkeypatch.delenv("EARTHDATA_TOKEN", raising=False)
+    monkeypatch.delenv("EARTHDATA_USERNAME", raising=False)
+
+    with _session() as session:
+        cddis = session.prepare_request(
+            requests.Request("GET", "https://cddis.nasa.gov/archive/gnss/data/daily/")
+        )
+        bkg = session.prepare_request(
+            requests.Request("GET", "https://igs.bkg.bund.de/root_ftp/IGS/obs/")
+        )
+    assert cddis.headers["Authorization"] == "Basic dXNlcjpzZWNyZXQ="
+    assert "Authorization" not in bkg.headers
+
+    set_earthdata_credentials(token="abc")
+    try:
+        with _session() as session:
+            # Credentials are added again after the redirect to the login server
+            redirect = session.prepare_request(
+                requests.Request("GET", "https://urs.earthdata.nasa.gov/oauth/authorize")
+            )
+            redirect.headers.pop("Authorization")
+            response = MagicMock()
+            response.request.url = "https://cddis.nasa.gov/archive/"
+            session.rebuild_auth(redirect, response)
+        assert redirect.headers["Authorization"] == "Bearer abc"
+    finally:
+        set_earthdata_credentials()
@@ -0,0 +1,53 @@
+from datetime import datetime, timedelta, timezone
+
+import pytest
+
+from pytecgg.parsing import parse_rinex_filename
+
+
+def test_parse_long_filename():
+    meta = parse_rinex_filename("data/ASIR00ITA_R_20242810000_01D_30S_MO.crx.gz")
+    assert meta.convention == "long"
+    assert (meta.station, meta.site_id, meta.country) == ("asir", "ASIR00ITA", "ITA")
+    assert meta.source == "receiver"
+    assert meta.start == datetime(2024, 10, 7, tzinfo=timezone.utc)
+    assert meta.period == timedelta(days=1)
+    assert meta.sample_rate == timedelta(seconds=30)
+    assert (meta.file_type, meta.constellation) == ("observation", "M")
+    assert (meta.format, meta.compression) == ("CRINEX", "gz")
+
+
+def test_parse_long_filename_navigation_and_high_rate():
+    nav = parse_rinex_filename("BRDC00WRD_R_20250870000_01D_MN.rnx")
+    assert nav.file_type == "navigation"
+    assert nav.sample_rate is None
+
+    high_rate = parse_rinex_filename("GROT00ITA_S_20250951200_15M_05Z_GO.rnx")
+    assert high_rate.source == "stream"
+    assert high_rate.start == datetime(2025, 4, 5, 12, tzinfo=timezone.utc)
+    assert high_rate.period == timedelta(minutes=15)
+    assert high_rate.sample_rate == timedelta(seconds=0.2)
+
+
+def test_parse_short_filename():
+    daily = parse_rinex_filename("cgtc0920.14o")
+    assert daily.convention == "short"
+    assert daily.station == "cgtc"
+    assert daily.start == datetime(2014, 4, 2, tzinfo=timezone.utc)
+    assert daily.period == timedelta(days=1)
+    assert daily.country is None
+
+    hourly = parse_rinex_filename("GROT001c.24d.Z")
+    assert hourly.start == datetime(2024, 1, 1, 2, tzinfo=timezone.utc)
+    assert hourly.period == timedelta(hours=1)
+    assert (hourly.format, hourly.compression) == ("CRINEX", "Z")
+
+    nav = parse_rinex_filename("brdc0010.99g")
+    assert nav.start.year == 1999
+    assert (nav.file_type, nav.constellation) == ("navigation", "R")
+
+
+@pytest.mark.parametrize("name", ["README.md", "grot0010.24x", "grot0010.24"])
+def test_parse_invalid_filename(name):
+    with pytest.raises(ValueError):
+        parse_rinex_filename(name)
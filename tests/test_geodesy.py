@@ -0,0 +1,237 @@
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import polars as pl
+import pytest
+from pymap3d import ecef2enu, ecef2geodetic
+
+from pytecgg.geodesy import (
+    add_geomagnetic_coordinates,
+    add_solar_geometry,
+    add_tropospheric_delay,
+    common_view,
+    ecef_to_enu,
+    ecef_to_geodetic,
+    enu_to_ecef,
+    geodetic_to_ecef,
+    geomagnetic_coordinates,
+    geomagnetic_pole,
+    local_solar_time,
+    niell_mapping,
+    saastamoinen,
+    shared_ipp_coverage,
+    solar_zenith_angle,
+    station_baselines,
+)
+from pytecgg.geodesy.solar import _subsolar_point
+
+
+def test_ecef_to_geodetic_matches_pymap3d():
+    """Test the vectorized conversion against pymap3d"""
+    x = pl.Series([4_641_949.0, 6_378_137.0, 0.0])
+    y = pl.Series([1_393_045.0, 0.0, 0.0])
+    z = pl.Series([4_133_287.0, 0.0, 6_356_752.3142])
+    lat, lon, height = ecef_to_geodetic(x, y, z)
+    assert lat.name == "lat"
+    for i in range(len(x)):
+        ref = ecef2geodetic(x[i], y[i], z[i])
+        assert lat[i] == pytest.approx(ref[0], abs=1e-9)
+        assert lon[i] == pytest.approx(ref[1], abs=1e-9)
+        assert height[i] == pytest.approx(ref[2], abs=1e-4)
+
+
+def test_geodetic_round_trip():
+    """Test that geodetic_to_ecef inverts ecef_to_geodetic"""
+    lat = np.array([40.8, -33.9, 89.9])
+    lon = np.array([14.25, 151.2, -120.0])
+    height = np.array([100.0, 0.0, 3000.0])
+    x, y, z = geodetic_to_ecef(lat, lon, height)
+    assert isinstance(x, np.ndarray)
+    lat2, lon2, height2 = ecef_to_geodetic(x, y, z)
+    np.testing.assert_allclose(lat2, lat, atol=1e-9)
+    np.testing.assert_allclose(lon2, lon, atol=1e-9)
+    np.testing.assert_allclose(height2, height, atol=1e-6)
+
+
+def test_scalar_input():
+    """Test that scalar inputs give floats"""
+    x, y, z = geodetic_to_ecef(0.0, 0.0, 0.0)
+    assert (x, y, z) == pytest.approx((6_378_137.0, 0.0, 0.0))
+
+
+def test_ecef_to_enu_matches_pymap3d():
+    """Test the local frame conversion against pymap3d"""
+    reference = (4_641_949.0, 1_393_045.0, 4_133_287.0)
+    lat0, lon0, h0 = ecef2geodetic(*reference)
+    x = pl.Series([4_642_049.0, 20_000_000.0])
+    y = pl.Series([1_393_145.0, 10_000_000.0])
+    z = pl.Series([4_133_387.0, 15_000_000.0])
+    east, north, up = ecef_to_enu(x, y, z, reference)
+    for i in range(len(x)):
+        ref = ecef2enu(x[i], y[i], z[i], lat0, lon0, h0)
+        assert (east[i], north[i], up[i]) == pytest.approx(ref, abs=1e-6)
+
+
+def test_enu_velocity_round_trip():
+    """Test that velocities are rotated without translation, and back"""
+    reference = (4_641_949.0, 1_393_045.0, 4_133_287.0)
+    vx, vy, vz = np.array([100.0]), np.array([-2500.0]), np.array([3000.0])
+    e, n, u = ecef_to_enu(vx, vy, vz, reference, velocity=True)
+    assert np.hypot(np.hypot(e, n), u)[0] == pytest.approx(np.sqrt(100**2 + 2500**2 + 3000**2))
+    x, y, z = enu_to_ecef(e, n, u, reference, velocity=True)
+    np.testing.assert_allclose((x, y, z), (vx, vy, vz), atol=1e-9)
+
+
+def test_geomagnetic_pole_igrf():
+    """Test the IGRF dipole pole against its published 2020 location"""
+    lat, lon = geomagnetic_pole(datetime(2020, 1, 1, tzinfo=timezone.utc))
+    assert lat[0] == pytest.approx(80.65, abs=0.1)
+    assert lon[0] == pytest.approx(-72.68, abs=0.1)
+
+
+def test_geomagnetic_coordinates_geographic_pole():
+    """Test that a pole on the rotation axis leaves the coordinates unchanged"""
+    mlat, mlon = geomagnetic_coordinates(
+        np.array([45.0, -30.0]), np.array([10.0, -120.0]), pole=(90.0, 0.0)
+    )
+    np.testing.assert_allclose(mlat, [45.0, -30.0])
+    np.testing.assert_allclose(mlon, [10.0, -120.0])
+
+
+def test_add_geomagnetic_coordinates_mlt():
+    """Test the MLT of the subsolar point and of the antisolar point"""
+    epoch = datetime(2024, 3, 20, 12, 0, tzinfo=timezone.utc)
+    sun_lat, sun_lon = _subsolar_point(epoch)
+    assert sun_lat[0] == pytest.approx(0.0, abs=0.5)
+    df = pl.DataFrame(
+        {
+            "epoch": [epoch, epoch],
+            "lat_ipp": [sun_lat[0], -sun_lat[0]],
+            "lon_ipp": [sun_lon[0], sun_lon[0] + 180.0],
+        }
+    )
+    out = add_geomagnetic_coordinates(df, pole=(80.0, -72.0))
+    mlt = out["mlt"].to_numpy()
+    np.testing.assert_allclose(np.cos(np.pi * mlt / 12.0), [-1.0, 1.0], atol=1e-9)
+    assert out["mlat"][0] == pytest.approx(-out["mlat"][1])
+
+
+def test_solar_zenith_angle_equinox():
+    """Test the SZA at the March 2024 equinox, close to noon at Greenwich"""
+    epoch = datetime(2024, 3, 20, 12, 7, tzinfo=timezone.utc)
+    sza = solar_zenith_angle(np.array([0.0, 45.0, 0.0]), np.array([0.0, 0.0, 180.0]), epoch)
+    np.testing.assert_allclose(sza, [0.0, 45.0, 180.0], atol=0.5)
+
+
+def test_local_solar_time_equation_of_time():
+    """Test that the apparent solar time departs from the mean time by the equation of time"""
+    # In early November the Sun is about 16 minutes ahead of the mean time
+    epoch = datetime(2024, 11, 3, 12, 0, tzinfo=timezone.utc)
+    lst = local_solar_time(np.array([0.0, 90.0]), epoch)
+    np.testing.assert_allclose(lst, [12.0 + 16.4 / 60, 18.0 + 16.4 / 60], atol=0.02)
+
+
+def test_add_solar_geometry_columns():
+    """Test the SZA and LST columns added to an IPP DataFrame"""
+    df = pl.DataFrame(
+        {
+            "epoch": [datetime(2024, 6, 21, 0, 0, tzinfo=timezone.utc)],
+            "lat_ipp": [45.0],
+            "lon_ipp": [0.0],
+        }
+    )
+    out = add_solar_geometry(df)
+    assert out["sza"][0] > 90.0
+    assert min(out["lst"][0], 24.0 - out["lst"][0]) < 0.1
+
+
+def test_saastamoinen_standard_atmosphere():
+    """Test the zenith delays of the standard atmosphere at sea level"""
+    zhd, zwd = saastamoinen(45.0, 0.0)
+    assert zhd == pytest.approx(2.307, abs=1e-3)
+    assert zwd == pytest.approx(0.120, abs=5e-3)
+
+    zhd_low, _ = saastamoinen(45.0, 0.0, pressure=1000.0)
+    assert zhd_low == pytest.approx(2.307 * 1000.0 / 1013.25, abs=1e-3)
+
+
+def test_niell_mapping():
+    """Test that the mapping functions are one at the zenith and about ten at 5 degrees"""
+    epoch = datetime(2024, 1, 28, tzinfo=timezone.utc)
+    mh, mw = niell_mapping(np.array([90.0, 5.0]), 45.0, 0.0, epoch)
+    np.testing.assert_allclose([mh[0], mw[0]], [1.0, 1.0], atol=1e-12)
+    assert 9.5 < mh[1] < 11.0
+    assert 9.5 < mw[1] < 11.5
+
+
+def test_add_tropospheric_delay_met():
+    """Test the slant delays with meteorological data, and below the horizon"""
+    epoch = datetime(2024, 6, 21, 12, 0, tzinfo=timezone.utc)
+    df = pl.DataFrame({"epoch": [epoch, epoch], "ele": [90.0, -5.0]})
+    met = pl.DataFrame(
+        {"epoch": [epoch], "PR": [1000.0], "TD": [20.0], "HR": [50.0]},
+        schema_overrides={"epoch": pl.Datetime("us", "UTC")},
+    )
+    receiver_pos = geodetic_to_ecef(45.0, 0.0, 0.0)
+
+    out = add_tropospheric_delay(df, receiver_pos, met=met)
+    zhd, zwd = saastamoinen(45.0, 0.0, 1000.0, 20.0, 50.0)
+    assert out["tropo"][0] == pytest.approx(zhd + zwd, abs=1e-6)
+    assert out["tropo"][1] is None
+    assert add_tropospheric_delay(df, receiver_pos)["tropo"][0] == pytest.approx(
+        sum(saastamoinen(45.0, 0.0)), abs=1e-6
+    )
+
+
+def test_station_baselines():
+    """Test the baseline between two stations on the same meridian"""
+    stations = {
+        "aaaa": geodetic_to_ecef(45.0, 10.0, 0.0),
+        "bbbb": geodetic_to_ecef(46.0, 10.0, 0.0),
+        "cccc": geodetic_to_ecef(45.0, 11.0, 0.0),
+    }
+    df = station_baselines(stations)
+    assert df.height == 3
+    row = df.row(0, named=True)
+    assert (row["station_a"], row["station_b"]) == ("aaaa", "bbbb")
+    assert row["distance"] == pytest.approx(111.2, abs=0.1)
+    assert row["length"] == pytest.approx(111_100, rel=1e-2)
+    assert row["azimuth"] == pytest.approx(0.0, abs=0.1)
+    assert row["north"] > 0 and abs(row["east"]) < 1.0
+
+
+def test_common_view_windows():
+    """Test that common-view windows split at gaps and apply the elevation mask"""
+    epochs = [datetime(2024, 1, 1, 0, m, tzinfo=timezone.utc) for m in range(10)]
+    geometry_a = pl.DataFrame(
+        {"epoch": epochs, "sv": ["G01"] * 10, "ele": [30.0] * 10}
+    )
+    geometry_b = pl.DataFrame(
+        {
+            "epoch": epochs,
+            "sv": ["G01"] * 10,
+            "ele": [30.0, 30.0, 30.0, 5.0, 5.0, 30.0, 30.0, 30.0, 30.0, 30.0],
+        }
+    )
+    df = common_view({"aaaa": geometry_a, "bbbb": geometry_b}, min_elevation=10.0)
+    assert df["n_epochs"].to_list() == [3, 5]
+    assert df["start"][1] == epochs[5]
+    assert df["duration"][1] == timedelta(minutes=4)
+
+    df = common_view(
+        {"aaaa": geometry_a, "bbbb": geometry_b},
+        min_elevation=10.0,
+        min_duration=timedelta(minutes=3),
+    )
+    assert df.height == 1
+
+
+def test_shared_ipp_coverage():
+    """Test the overlap of the IPP cells of two stations"""
+    ipps = {
+        "aaaa": pl.DataFrame({"lat_ipp": [45.5, 46.5], "lon_ipp": [10.5, 10.5]}),
+        "bbbb": pl.DataFrame({"lat_ipp": [46.5, 47.5], "lon_ipp": [10.5, 10.5]}),
+    }
+    row = shared_ipp_coverage(ipps).row(0, named=True)
+    assert (row["cells_a"], row["cells_b"], row["shared_cells"]) == (2, 2, 1)
+    assert row["overlap"] == pytest.approx(1 / 3)
@@ -1,7 +1,11 @@
+from datetime import timedelta
+
 import numpy as np
 import polars as pl
 import pytest
 
+from pytecgg.parsing import read_rinex_nav
+from pytecgg.satellites import annotate_geometry, prepare_ephemeris, satellite_coordinates
 from pytecgg.satellites.ipp import calculate_ipp
 from pytecgg.satellites.constants import RE
 from pytecgg.context import GNSSContext
@@ -92,3 +96,48 @@ def test_no_intersection():
     assert df_res["lon_ipp"].is_nan().all()
     assert df_res["azi"].is_nan().all()
     assert df_res["ele"].is_nan().all()
+
+
+def test_annotate_geometry(nav_v3_file):
+    """Fused geometry against the step-by-step pipeline"""
+    nav = read_rinex_nav(nav_v3_file)
+    g1, g2, g3 = (f"G{int(sv):02d}" for sv in nav["GPS"]["sv"].unique().sort()[:3])
+    rec_pos = (4_642_000.0, 1_028_000.0, 4_236_000.0)
+    epoch = nav["GPS"]["epoch"][len(nav["GPS"]) // 2] + timedelta(minutes=15)
+    obs = pl.DataFrame(
+        {
+            "epoch": [epoch] * 5,
+            "sv": [g1, g1, g2, g3, "X99"],
+            "observable": ["L1C", "C1C", "L1C", "L1C", "L1C"],
+            "value": [1.0, 2.0, 3.0, 4.0, 5.0],
+        }
+    )
+
+    out = annotate_geometry(obs, nav, rec_pos, h_ipp=350_000, min_elevation=None)
+    assert out.columns == obs.columns + [
+        "sat_x", "sat_y", "sat_z", "azi", "ele", "lat_ipp", "lon_ipp", "mapping"
+    ]
+    assert out.height == obs.height
+    # Same geometry for the observables of a satellite, none without ephemeris
+    assert out.row(0)[4:] == out.row(1)[4:]
+    assert out.row(4)[4:] == (None,) * 8
+
+    ctx = GNSSContext(
+        receiver_pos=rec_pos, receiver_name="TEST", rinex_version="3.04",
+        h_ipp=350_000, systems=["G"],
+    )
+    ephem = prepare_ephemeris(nav, ctx)
+    coords = satellite_coordinates(obs["sv"][:4], obs["epoch"][:4], ephem)
+    reference = calculate_ipp(coords, ctx, min_elevation=-90)
+    fused = out.head(4)
+    for col in ("sat_x", "sat_y", "sat_z"):
+        np.testing.assert_allclose(fused[col], reference[col], atol=100.0)
+    for col in ("azi", "ele", "lat_ipp", "lon_ipp"):
+        np.testing.assert_allclose(fused[col], reference[col], atol=1e-3)
+    zenith = np.radians(90 - fused["ele"].to_numpy())
+    np.testing.assert_allclose(
+        fused["mapping"], np.cos(np.arcsin(RE / (RE + 350_000) * np.sin(zenith)))
+    )
+
+    visible = annotate_geometry(obs, nav, rec_pos, min_elevation=10.0)
+    assert (visible["ele"] >= 10.0).all()
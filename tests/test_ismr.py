@@ -0,0 +1,59 @@
+import gzip
+from datetime import datetime, timezone
+
+import pytest
+
+from pytecgg.parsing import read_ismr
+
+# GPS week 2355, 01:00:00 GPST
+T0 = datetime(2025, 2, 23, 1, tzinfo=timezone.utc)
+
+
+def _line(svid, values):
+    """ISMR line of 62 columns, from the (1-based) column values given"""
+    columns = ["nan"] * 62
+    columns[:3] = ["2355", "3600", str(svid)]
+    for index, value in values.items():
+        columns[index - 1] = str(value)
+    return ",".join(columns)
+
+
+@pytest.fixture
+def ismr_file(tmp_path):
+    gps = {5: 120.0, 6: 45.0, 7: 44.5, 8: 0.12, 9: 0.01, 14: 0.25, 25: 3000, 32: 40.1, 33: 0.2, 42: 1500}
+    gps.update({17: 20.5, 18: 0.1, 19: 20.6, 20: 0.1, 21: 20.7, 22: 0.1, 23: 20.8, 24: 0.1})
+    lines = [
+        _line(5, gps),
+        # Galileo E11, first signal only
+        _line(81, {5: 200.0, 6: 30.0, 7: 41.0, 8: 0.3, 14: 0.5}),
+        # L-band satellite, skipped
+        _line(110, {7: 40.0}),
+    ]
+    path = tmp_path / "SEPT054B.25_.ismr.gz"
+    path.write_bytes(gzip.compress("\n".join(lines).encode()))
+    return path
+
+
+def test_read_ismr_scintillation(ismr_file):
+    scintillation, _ = read_ismr(ismr_file)
+
+    assert scintillation.columns[:5] == ["epoch", "sv", "signal", "azimuth", "elevation"]
+    assert "sigma_phi_60s" in scintillation.columns
+    assert scintillation["epoch"].unique().to_list() == [T0]
+    rows = scintillation.select("sv", "signal", "s4", "sigma_phi_60s", "lock_time").rows()
+    assert rows == [
+        ("E11", "1C", 0.3, 0.5, None),
+        ("G05", "1C", 0.12, 0.25, 3000),
+        ("G05", "2L", 0.2, None, 1500),
+    ]
+    assert scintillation["cn0"].to_list() == [41.0, 44.5, 40.1]
+
+
+def test_read_ismr_tec(ismr_file):
+    _, tec = read_ismr(ismr_file, time_scale="UTC")
+
+    assert tec.columns == ["epoch", "sv", "tec", "dtec"]
+    assert tec["sv"].unique().to_list() == ["G05"]
+    # Samples 45, 30, 15 and 0 s before the end of the minute, in UTC (18 leap seconds)
+    assert [e.second for e in tec["epoch"]] == [57, 12, 27, 42]
+    assert tec["tec"].to_list() == [20.5, 20.6, 20.7, 20.8]
@@ -1,11 +1,35 @@
 from datetime import datetime, timedelta
+from pathlib import Path
 
 import polars as pl
+import pytest
 
-from pytecgg.linear_combinations.mw import _calculate_melbourne_wubbena
+from pytecgg.context import GNSSContext
+from pytecgg.parsing import ReadOptions, read_rinex_obs
+
+from pytecgg.linear_combinations.mw import (
+    _calculate_melbourne_wubbena,
+    melbourne_wubbena,
+)
 from pytecgg.linear_combinations.gflc import _calculate_gflc_phase
+from pytecgg.linear_combinations.iflc import ionosphere_free
+from pytecgg.linear_combinations.wl_nl import narrow_lane, wide_lane
 from pytecgg.linear_combinations.cs_lol_detection import detect_cs_lol
 from pytecgg.linear_combinations.lc_calculation import calculate_linear_combinations
+from pytecgg.linear_combinations.quarter_cycle import (
+    align_quarter_cycles,
+    quarter_cycle_offsets,
+)
+from pytecgg.linear_combinations.doppler import (
+    doppler_conventions,
+    normalize_doppler,
+    range_rate,
+)
+from pytecgg.linear_combinations.differences import (
+    between_satellite_differences,
+    double_differences,
+    single_differences,
+)
 
 
 def test_mw_cycle_slip():
@@ -63,6 +87,285 @@ def test_mw_cycle_slip():
     assert abs(mw_slip[2] - mw_slip[1]) > 0.5
 
 
+def test_mw_native_matches_expression():
+    """Test that the Rust MW builder agrees with the Polars expression"""
+    epochs = [datetime(2023, 1, 1, 0, 0, 0), datetime(2023, 1, 1, 0, 0, 30)]
+    values = {
+        "L1C": [1000.0, 1000.1],
+        "L2W": [800.0, 800.08],
+        "C1C": [20000000.0, 20000000.1],
+        "C2W": [20000000.5, 20000000.6],
+    }
+    obs = pl.DataFrame(
+        {
+            "epoch": [e for _ in values for e in epochs],
+            "sv": ["G01"] * 8,
+            "observable": [o for o in values for _ in epochs],
+            "value": [v for o in values for v in values[o]],
+        }
+    )
+
+    mw_native = melbourne_wubbena(obs, ("L1C", "L2W"), ("C1C", "C2W")).sort("epoch")
+    mw_expr = pl.DataFrame(
+        {"phase1": values["L1C"], "phase2": values["L2W"]}
+        | {"code1": values["C1C"], "code2": values["C2W"]}
+    ).with_columns(
+        mw=_calculate_melbourne_wubbena(
+            pl.col("phase1"),
+            pl.col("phase2"),
+            pl.col("code1"),
+            pl.col("code2"),
+            1575.42e6,
+            1227.60e6,
+        )
+    )["mw"]
+
+    assert mw_native.columns == ["epoch", "sv", "mw"]
+    for native, expected in zip(mw_native["mw"], mw_expr):
+        assert abs(native - expected) < 1e-6
+
+
+C = 299_792_458.0
+# Carrier frequencies (Hz) of the two-frequency tests: GPS L1/L2, and GLONASS G1/G2
+# for channel -4 of R01 (R02 has no channel)
+FREQUENCIES = {
+    "G01": (1575.42e6, 1227.60e6),
+    "R01": (1602e6 - 4 * 0.5625e6, 1246e6 - 4 * 0.4375e6),
+}
+CHANNELS = {"R01": -4}
+
+
+def _two_frequency_obs():
+    """Phase (cycles) and code (m) on two frequencies for a GPS and two GLONASS SVs"""
+    epoch = datetime(2023, 1, 1)
+    values = {
+        "G01": {"L1P": 1.15e8, "L2P": 8.96e7, "C1P": 2.2e7, "C2P": 2.2e7 + 3.2},
+        "R01": {"L1P": 1.2e8, "L2P": 9.3e7, "C1P": 2.25e7, "C2P": 2.25e7 + 2.1},
+        "R02": {"L1P": 1.1e8, "L2P": 8.5e7, "C1P": 2.1e7, "C2P": 2.1e7 + 1.7},
+    }
+    obs = pl.DataFrame(
+        [
+            (epoch, sv, observable, value)
+            for sv, per_sv in values.items()
+            for observable, value in per_sv.items()
+        ],
+        schema=["epoch", "sv", "observable", "value"],
+        orient="row",
+    )
+    return obs, values
+
+
+def test_ionosphere_free_native():
+    """Test the Rust L3/P3 against the f1^2/(f1^2-f2^2) coefficients, with GLONASS channels"""
+    obs, values = _two_frequency_obs()
+    iflc = ionosphere_free(
+        obs, ("L1P", "L2P"), ("C1P", "C2P"), glonass_channels=CHANNELS
+    ).sort("sv")
+
+    assert iflc.columns == ["epoch", "sv", "iflc_phase", "iflc_code"]
+    assert iflc["sv"].to_list() == ["G01", "R01", "R02"]
+    for row in iflc.filter(pl.col("sv") != "R02").iter_rows(named=True):
+        f1, f2 = FREQUENCIES[row["sv"]]
+        v = values[row["sv"]]
+        alpha = f1**2 / (f1**2 - f2**2)
+        beta = f2**2 / (f1**2 - f2**2)
+        l3 = alpha * v["L1P"] * C / f1 - beta * v["L2P"] * C / f2
+        p3 = alpha * v["C1P"] - beta * v["C2P"]
+        assert row["iflc_phase"] == pytest.approx(l3, abs=1e-6)
+        assert row["iflc_code"] == pytest.approx(p3, abs=1e-6)
+
+    # Without a frequency channel, GLONASS FDMA signals cannot be combined
+    r02 = iflc.filter(pl.col("sv") == "R02")
+    assert r02["iflc_phase"].is_null().all()
+    assert r02["iflc_code"].is_null().all()
+
+
+def test_wide_and_narrow_lane():
+    """Test the WL/NL phase and code combinations, and that MW is WL phase - NL code"""
+    obs, values = _two_frequency_obs()
+    pairs = {"phase": ("L1P", "L2P"), "code": ("C1P", "C2P")}
+    wl = wide_lane(obs, **pairs, glonass_channels=CHANNELS).sort("sv")
+    nl = narrow_lane(obs, **pairs, glonass_channels=CHANNELS).sort("sv")
+    mw = melbourne_wubbena(obs, **pairs, glonass_channels=CHANNELS).sort("sv")
+
+    assert wl.columns == ["epoch", "sv", "wl_phase", "wl_code"]
+    assert nl.columns == ["epoch", "sv", "nl_phase", "nl_code"]
+    for sv, (f1, f2) in FREQUENCIES.items():
+        v = values[sv]
+        phase1, phase2 = v["L1P"] * C / f1, v["L2P"] * C / f2
+        w = wl.filter(pl.col("sv") == sv).row(0, named=True)
+        n = nl.filter(pl.col("sv") == sv).row(0, named=True)
+        assert w["wl_phase"] == pytest.approx((f1 * phase1 - f2 * phase2) / (f1 - f2), abs=1e-5)
+        assert w["wl_code"] == pytest.approx((f1 * v["C1P"] - f2 * v["C2P"]) / (f1 - f2), abs=1e-5)
+        assert n["nl_phase"] == pytest.approx((f1 * phase1 + f2 * phase2) / (f1 + f2), abs=1e-5)
+        assert n["nl_code"] == pytest.approx((f1 * v["C1P"] + f2 * v["C2P"]) / (f1 + f2), abs=1e-5)
+
+    assert wl["sv"].equals(mw["sv"]) and nl["sv"].equals(mw["sv"])
+    known = pl.col("sv") != "R02"
+    expected = (wl["wl_phase"] - nl["nl_code"]).filter(mw["sv"] != "R02")
+    assert mw.filter(known)["mw"].to_list() == pytest.approx(expected.to_list(), abs=1e-6)
+    assert mw.filter(~known)["mw"].is_null().all()
+
+
+def test_single_differences():
+    """Test the alignment of two stations on (epoch, sv, observable)"""
+    t0, t1 = datetime(2023, 1, 1, 0, 0, 0), datetime(2023, 1, 1, 0, 0, 30)
+    station = pl.DataFrame(
+        {
+            "epoch": [t0, t0, t0, t1],
+            "sv": ["G01", "G01", "G02", "G01"],
+            "observable": ["L1C", "C1C", "L1C", "L1C"],
+            "value": [100.0, 2e7, 50.0, 101.0],
+        }
+    )
+    reference = pl.DataFrame(
+        {
+            "epoch": [t1, t0, t0],
+            "sv": ["G01", "G01", "G03"],
+            "observable": ["L1C", "L1C", "L1C"],
+            "value": [90.5, 90.0, 10.0],
+        },
+        schema_overrides={"epoch": pl.Datetime("ns")},
+    )
+
+    sd = single_differences(station, reference)
+    assert sd.columns == ["epoch", "sv", "observable", "value"]
+    assert sd["epoch"].dtype == station["epoch"].dtype
+    assert sd["epoch"].to_list() == [t0, t1]
+    assert sd["value"].to_list() == [10.0, 10.5]
+
+
+def test_double_differences():
+    """Test the highest-elevation reference per epoch and constellation"""
+    t0, t1 = datetime(2023, 1, 1, 0, 0, 0), datetime(2023, 1, 1, 0, 0, 30)
+    sd = pl.DataFrame(
+        {
+            "epoch": [t0, t0, t0, t0, t0, t1, t1],
+            "sv": ["G01", "G02", "G02", "G03", "E05", "G01", "G02"],
+            "observable": ["L1C", "L1C", "C1C", "L1C", "L1C", "L1C", "L1C"],
+            "value": [1.0, 3.0, 5.0, 7.0, 9.0, 2.0, 4.0],
+        }
+    )
+    elevations = pl.DataFrame(
+        {
+            "epoch": [t0, t0, t0, t0, t1, t1],
+            "sv": ["G01", "G02", "G03", "E05", "G01", "G02"],
+            "ele": [30.0, 60.0, 45.0, 80.0, 70.0, 65.0],
+            "azi": [0.0] * 6,
+        }
+    )
+
+    dd = double_differences(sd, elevations)
+    assert dd.columns == ["epoch", "sv", "reference_sv", "observable", "value"]
+    assert dd["sv"].to_list() == ["G01", "G03", "G02"]
+    assert dd["reference_sv"].to_list() == ["G02", "G02", "G01"]
+    assert dd["value"].to_list() == [-2.0, 4.0, 2.0]
+
+
+def test_between_satellite_differences():
+    """Test a fixed reference satellite, with the highest one where it is missing"""
+    t0, t1 = datetime(2023, 1, 1, 0, 0, 0), datetime(2023, 1, 1, 0, 0, 30)
+    obs = pl.DataFrame(
+        {
+            "epoch": [t0, t0, t0, t1, t1],
+            "sv": ["G01", "G02", "G03", "G01", "G03"],
+            "observable": ["L1C"] * 5,
+            "value": [10.0, 20.0, 40.0, 11.0, 44.0],
+        }
+    )
+    elevations = pl.DataFrame(
+        {"epoch": [t1, t1], "sv": ["G01", "G03"], "ele": [20.0, 50.0]}
+    )
+
+    sd = between_satellite_differences(obs, elevations, reference={"G": "G02"})
+    assert sd["reference_sv"].to_list() == ["G02", "G02", "G03"]
+    assert sd["sv"].to_list() == ["G01", "G03", "G01"]
+    assert sd["value"].to_list() == [-10.0, 20.0, -33.0]
+
+    with pytest.raises(ValueError):
+        between_satellite_differences(obs)
+
+
+def test_quarter_cycle_offsets():
+    """Test a quarter-cycle offset of L2X with respect to L2W, despite the ambiguities"""
+    epochs = [datetime(2023, 1, 1) + timedelta(seconds=30 * i) for i in range(4)]
+    rows = []
+    for sv, ambiguity in [("G01", 12.0), ("G02", -7.0)]:
+        for i, epoch in enumerate(epochs):
+            l2w = 1000.0 + 3.1 * i
+            rows += [
+                (epoch, sv, "L2W", l2w),
+                (epoch, sv, "L2X", l2w + ambiguity + 0.25 + 0.01 * (-1) ** i),
+                (epoch, sv, "L1C", 2000.0),
+            ]
+    obs = pl.DataFrame(rows, schema=["epoch", "sv", "observable", "value"], orient="row")
+
+    offsets = quarter_cycle_offsets(obs)
+    assert offsets.select("system", "observable", "reference", "n_sv").rows() == [
+        ("G", "L2X", "L2W", 2)
+    ]
+    assert offsets["fraction"][0] == pytest.approx(0.25, abs=1e-3)
+    assert offsets["offset"][0] == 0.25
+
+    aligned = align_quarter_cycles(obs)
+    l2x = aligned.filter(pl.col("observable") == "L2X")["value"]
+    assert l2x.to_list() == pytest.approx(
+        (obs.filter(pl.col("observable") == "L2X")["value"] - 0.25).to_list()
+    )
+    assert aligned.filter(pl.col("observable") != "L2X").equals(
+        obs.filter(pl.col("observable") != "L2X")
+    )
+
+
+def test_doppler_conventions():
+    """Test the detection and normalization of inverted and m/s Doppler observables"""
+    wavelength = 299792458.0 / 1575.42e6
+    epochs = [datetime(2023, 1, 1) + timedelta(seconds=30 * i) for i in range(5)]
+    rows = []
+    for sv, doppler in [("G01", -1500.0), ("G02", 2300.0)]:
+        for i, epoch in enumerate(epochs):
+            rows += [
+                (epoch, sv, "L1C", 1e6 - doppler * 30 * i),
+                (epoch, sv, "D1C", doppler),
+                (epoch, sv, "L1W", 1e6 - doppler * 30 * i),
+                (epoch, sv, "D1W", -doppler),
+                (epoch, sv, "L2W", 1e6 - doppler * 30 * i * 1227.60 / 1575.42),
+                (epoch, sv, "D2W", -doppler * 1227.60 / 1575.42 * 299792458.0 / 1227.60e6),
+            ]
+    obs = pl.DataFrame(rows, schema=["epoch", "sv", "observable", "value"], orient="row")
+
+    conventions = doppler_conventions(obs)
+    assert conventions.select("observable", "unit", "sign", "n_sv").rows() == [
+        ("D1C", "Hz", 1, 2),
+        ("D1W", "Hz", -1, 2),
+        ("D2W", "m/s", -1, 2),
+    ]
+
+    normalized = normalize_doppler(obs, conventions)
+    for observable in ("D1W", "D2W"):
+        band = 1.0 if observable == "D1W" else 1227.60 / 1575.42
+        values = normalized.filter(pl.col("observable") == observable)["value"]
+        expected = obs.filter(pl.col("observable") == "D1C")["value"] * band
+        assert values.to_list() == pytest.approx(expected.to_list())
+    assert normalized.filter(pl.col("observable") == "D1C").equals(
+        obs.filter(pl.col("observable") == "D1C")
+    )
+
+    rates = range_rate(normalized).filter(pl.col("sv") == "G01")
+    assert rates.filter(pl.col("observable") == "D1C")["range_rate"][0] == pytest.approx(
+        1500.0 * wavelength
+    )
+    assert rates.filter(pl.col("observable") == "L1C")["range_rate"].null_count() == 5
+
+
+def test_doppler_conventions_real_file(parsed_rinex_obs_data):
+    """Test that the Doppler of a conforming RINEX file follow the convention"""
+    conventions = doppler_conventions(parsed_rinex_obs_data["obs_data"])
+    gps = conventions.filter(pl.col("system") == "G")
+    assert not gps.is_empty()
+    assert (gps["unit"] == "Hz").all() and (gps["sign"] == 1).all()
+
+
 def test_gflc_phase_iono():
     """Test GFLC phase sensitivity to ionospheric changes"""
     freq1 = 1575.42e6  # GPS L1
@@ -158,3 +461,56 @@ def test_calculate_lc_with_real_file(parsed_rinex_obs_data, real_context):
     assert set(df_lc.columns).issuperset(expected_cols)
     assert len(real_context.freq_meta) == 3
     assert "G" in real_context.freq_meta
+
+
+def _beidou_excerpt(obs_v3_file, directory: Path, version: str) -> Path:
+    """Writes the first epochs of a RINEX 3.04 file, relabelling BeiDou B1I for 3.02"""
+    lines = Path(obs_v3_file).read_text().splitlines(keepends=True)
+    end = next(i for i, line in enumerate(lines) if i > 1000 and line.startswith(">"))
+    excerpt = lines[:end]
+    if version == "3.02":
+        excerpt = [
+            line.replace("3.04", "3.02", 1)
+            if "RINEX VERSION / TYPE" in line
+            else line.replace("2I", "1I") if line.startswith("C    8") else line
+            for line in excerpt
+        ]
+    directory.mkdir()
+    path = directory / Path(obs_v3_file).name
+    path.write_text("".join(excerpt))
+    return path
+
+
+def test_beidou_band_naming(obs_v3_file, tmp_path):
+    """Test that BeiDou B1I gives the same TEC whether recorded in band 2 (RINEX 3.04)
+    or in band 1 (RINEX 3.02), both at read time and from calculate_linear_combinations"""
+    results = {}
+    for version in ("3.04", "3.02"):
+        path = _beidou_excerpt(obs_v3_file, tmp_path / version, version)
+        gf, _, file_version = read_rinex_obs(
+            path, options=ReadOptions(geometry_free=True, constellations=["C"])
+        )
+        obs, rec_pos, _ = read_rinex_obs(path, options=ReadOptions(constellations=["C"]))
+        ctx = GNSSContext(
+            receiver_pos=rec_pos,
+            receiver_name="asir",
+            rinex_version=file_version,
+            systems=["C"],
+        )
+        lc = calculate_linear_combinations(
+            obs, ctx, combinations=["gflc_phase", "gflc_code"]
+        )
+        assert ctx.freq_meta["C"] == pytest.approx((1207.14, 1561.098))
+        results[version] = (gf, lc.select("epoch", "sv", "gflc_phase", "gflc_code"))
+
+    def assert_same_tec(df, other):
+        df, other = df.drop_nulls(), other.drop_nulls()
+        joined = df.join(other, on=["epoch", "sv"], suffix="_other")
+        assert joined.height == df.height == other.height > 0
+        for col in ("gflc_phase", "gflc_code"):
+            assert joined[col].to_list() == pytest.approx(joined[f"{col}_other"].to_list())
+
+    (gf_304, lc_304), (gf_302, lc_302) = results["3.04"], results["3.02"]
+    assert_same_tec(gf_302, gf_304)
+    assert_same_tec(lc_302, lc_304)
+    assert_same_tec(gf_304, lc_304)
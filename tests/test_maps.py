@@ -0,0 +1,57 @@
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import polars as pl
+import pytest
+
+from pytecgg.maps import grid_vtec
+
+T0 = datetime(2024, 10, 7, 12, tzinfo=timezone.utc)
+
+
+def _station(lat, lon, vtec, n_epochs=10):
+    return pl.DataFrame(
+        {
+            "epoch": [T0 + timedelta(seconds=30 * k) for k in range(n_epochs)],
+            "lat_ipp": [lat] * n_epochs,
+            "lon_ipp": [lon] * n_epochs,
+            "vtec": [vtec] * n_epochs,
+        }
+    )
+
+
+@pytest.mark.parametrize("method", ["nearest", "idw", "kriging"])
+def test_grid_vtec_methods(method):
+    stations = [_station(40.0, 10.0, 10.0), _station(42.0, 12.0, 20.0)]
+    maps = grid_vtec(stations, cadence="5m", resolution=1.0, method=method, max_distance=None)
+
+    assert maps.columns == ["epoch", "lat", "lon", "vtec", "n_obs"]
+    assert maps["epoch"].unique().to_list() == [T0]
+    assert maps.height == 3 * 3
+    # Nodes on the IPPs reproduce the observations
+    at = lambda lat, lon: maps.filter((pl.col("lat") == lat) & (pl.col("lon") == lon))["vtec"].item()
+    assert at(40.0, 10.0) == pytest.approx(10.0, abs=1e-3)
+    assert at(42.0, 12.0) == pytest.approx(20.0, abs=1e-3)
+    assert 10.0 <= at(41.0, 11.0) <= 20.0
+
+
+def test_grid_vtec_coverage_and_arrays():
+    df = _station(40.0, 10.0, 10.0, n_epochs=20)
+    maps = grid_vtec(df, lat_bounds=(38.0, 48.0), lon_bounds=(10.0, 10.0), max_distance=300.0)
+    far = maps.filter(pl.col("lat") == 48.0)
+    assert far["vtec"].null_count() == far.height
+    assert (far["n_obs"] == 0).all()
+    # Two bins of five minutes
+    assert maps["epoch"].n_unique() == 2
+
+    times, lats, lons, grids = grid_vtec(
+        df, lat_bounds=(38.0, 48.0), lon_bounds=(10.0, 10.0), output="array"
+    )
+    assert grids.shape == (2, lats.size, lons.size) == (2, 11, 1)
+    assert np.isnan(grids[0, -1, 0])
+    assert times[0] == np.datetime64("2024-10-07T12:00:00")
+
+
+def test_grid_vtec_invalid_method():
+    with pytest.raises(ValueError):
+        grid_vtec(_station(40.0, 10.0, 10.0), method="spline")
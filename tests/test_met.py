@@ -0,0 +1,43 @@
+from datetime import datetime, timezone
+
+import pytest
+
+from pytecgg.parsing import read_rinex_met
+
+from .conftest import header_record
+
+
+def test_read_rinex_met_v2(tmp_path):
+    path = tmp_path / "grot0920.14m"
+    path.write_text(
+        header_record("     2.11           METEOROLOGICAL DATA", "RINEX VERSION / TYPE")
+        + header_record("     3    PR    TD    HR", "# / TYPES OF OBSERV")
+        + header_record("", "END OF HEADER")
+        + " 14  4  2  0  0  0 1013.2   15.3   65.0\n"
+        + " 14  4  2  0  5  0 1013.0          66.5\n"
+    )
+    met = read_rinex_met(path)
+    assert met.columns == ["epoch", "PR", "TD", "HR"]
+    assert met["epoch"].to_list() == [
+        datetime(2014, 4, 2, 0, 0, tzinfo=timezone.utc),
+        datetime(2014, 4, 2, 0, 5, tzinfo=timezone.utc),
+    ]
+    assert met["PR"].to_list() == pytest.approx([1013.2, 1013.0])
+    assert met["TD"].to_list() == [15.3, None]
+
+
+def test_read_rinex_met_v3_continuation(tmp_path):
+    """Test a RINEX 3 record spanning a continuation line"""
+    types = ["PR", "TD", "HR", "ZW", "ZD", "ZT", "WD", "WS", "RI"]
+    path = tmp_path / "GROT00ITA_R_20240920000_01D_05M_MM.rnx"
+    path.write_text(
+        header_record("     3.05           M", "RINEX VERSION / TYPE")
+        + header_record(f"{len(types):6d}" + "".join(f"{t:>6s}" for t in types), "# / TYPES OF OBSERV")
+        + header_record("", "END OF HEADER")
+        + " 2024  4  1  0  0  0" + "".join(f"{v:7.1f}" for v in range(1, 9)) + "\n"
+        + "       9.0\n"
+    )
+    met = read_rinex_met(path)
+    assert met.columns == ["epoch"] + types
+    assert met["epoch"][0] == datetime(2024, 4, 1, tzinfo=timezone.utc)
+    assert met.row(0)[1:] == (1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)
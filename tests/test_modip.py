@@ -0,0 +1,33 @@
+from datetime import datetime
+
+import numpy as np
+import polars as pl
+import pytest
+
+from pytecgg.settings import settings_context
+from pytecgg.tec_calibration import calculate_modip, extract_modip
+from pytecgg.tec_calibration.calibration_preprocessing import _mapping_function
+
+
+def test_calculate_modip_per_year():
+    df = pl.DataFrame(
+        {
+            "epoch": [datetime(2024, 12, 31, 23), datetime(2025, 1, 1, 1), datetime(2025, 1, 1, 2)],
+            "lat_ipp": [41.8, 41.8, None],
+            "lon_ipp": [12.5, 12.5, 12.5],
+        }
+    )
+    out = calculate_modip(df)
+
+    assert out["modip_ipp"][0] == pytest.approx(extract_modip((12.5, 41.8), 2024, "geo")[0])
+    assert out["modip_ipp"][1] == pytest.approx(extract_modip((12.5, 41.8), 2025, "geo")[0])
+    assert out["modip_ipp"][2] is None
+
+
+def test_modip_mapping_shell_height():
+    """The modip-aware shell lies at h_ipp_equator at the magnetic equator, at h_ipp at the poles"""
+    elevation = np.array([30.0, 30.0])
+    with settings_context(h_ipp_equator=450_000):
+        mapped = _mapping_function(elevation, 350_000, modip=np.array([0.0, 90.0]))
+        assert mapped[0] == pytest.approx(_mapping_function(elevation[:1], 450_000)[0])
+        assert mapped[1] == pytest.approx(_mapping_function(elevation[:1], 350_000)[0])
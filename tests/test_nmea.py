@@ -0,0 +1,68 @@
+import io
+from datetime import date, datetime, timezone
+from functools import reduce
+
+import pytest
+
+from pytecgg.parsing import read_nmea
+
+
+def _sentence(body):
+    checksum = reduce(lambda acc, c: acc ^ ord(c), body, 0)
+    return f"${body}*{checksum:02X}\r\n"
+
+
+@pytest.fixture
+def nmea_log(tmp_path):
+    sentences = [
+        _sentence("GPGGA,235959.00,4548.1234,N,01125.5000,E,4,12,0.8,100.0,M,45.5,M,1.0,0000"),
+        _sentence("GPGSV,2,1,05,05,45,120,45,70,30,200,40,33,20,100,,12,10,010,30"),
+        _sentence("GNRMC,000000.00,A,4548.1235,N,01125.5001,E,10.0,90.0,020325,,,D"),
+        _sentence("GNGGA,000000.00,4548.1235,N,01125.5001,E,2,10,1.1,101.0,M,45.5,M,,"),
+        # Wrong checksum
+        "$GPGGA,000001.00,0000.000,N,00000.000,E,1,1,1,1,M,1,M,,*00\r\n",
+        # No fix
+        _sentence("GPGGA,000002.00,,,,,0,0,,,M,,M,,"),
+        _sentence("GAGSV,1,1,01,11,60,300,44,7"),
+    ]
+    path = tmp_path / "ship.nmea"
+    path.write_text("".join(sentences))
+    return path
+
+
+def test_read_nmea_positions(nmea_log):
+    positions, _ = read_nmea(nmea_log, time_scale="UTC")
+
+    assert positions.columns == [
+        "epoch", "lat", "lon", "height", "fix_quality", "num_satellites", "hdop", "speed", "course", "x", "y", "z",
+    ]
+    # Date of the RMC sentence, the GGA sentence before midnight on the previous day
+    assert positions["epoch"].to_list() == [
+        datetime(2025, 3, 1, 23, 59, 59, tzinfo=timezone.utc),
+        datetime(2025, 3, 2, tzinfo=timezone.utc),
+    ]
+    assert positions["lat"][0] == pytest.approx(45 + 48.1234 / 60)
+    assert positions["lon"][0] == pytest.approx(11 + 25.5 / 60)
+    # Ellipsoidal height: altitude above the geoid plus geoid separation
+    assert positions["height"].to_list() == [145.5, 146.5]
+    assert positions["fix_quality"].to_list() == [4, 2]
+    assert positions["speed"].to_list() == [None, pytest.approx(10 * 1852 / 3600)]
+    assert positions["x"][0] == pytest.approx(4_365_960.38, abs=0.01)
+
+
+def test_read_nmea_satellites(nmea_log):
+    _, satellites = read_nmea(nmea_log)
+
+    assert satellites["sv"].to_list() == ["G05", "R06", "S20", "G12", "E11"]
+    assert satellites["snr"].to_list() == [45, 40, None, 30, 44]
+    assert satellites["signal_id"].to_list() == [None] * 4 + [7]
+    # GPST epochs (18 leap seconds ahead of UTC)
+    assert satellites["epoch"][0] == datetime(2025, 3, 2, 0, 0, 17, tzinfo=timezone.utc)
+
+
+def test_read_nmea_reference():
+    gga = _sentence("GPGGA,120000.00,4548.1234,N,01125.5000,E,1,8,1.0,100.0,M,45.5,M,,")
+    with pytest.raises(ValueError):
+        read_nmea(io.StringIO(gga))
+    positions, _ = read_nmea(io.BytesIO(gga.encode()), reference=date(2025, 3, 1), time_scale="UTC")
+    assert positions["epoch"].to_list() == [datetime(2025, 3, 1, 12, tzinfo=timezone.utc)]
@@ -1,5 +1,29 @@
-from pytecgg.parsing import read_rinex_obs
+import json
+import os
+import pickle
+from datetime import date, datetime, timedelta
+
+from pytecgg.parsing import (
+    ParseStats,
+    ReadOptions,
+    RinexConformityError,
+    SiteInfo,
+    observable_table,
+    read_rinex_events,
+    read_rinex_obs,
+    read_rinex_obs_glob,
+    read_rinex_obs_types,
+    read_rinex_obs_with_stats,
+    read_rinex_phase_shifts,
+    read_rinex_scale_factors,
+    read_rinex_positions,
+    read_rinex_site,
+    rinex_time_bounds,
+    split_constellations,
+    validate_rinex_obs,
+)
 from polars import DataFrame
+import polars as pl
 import pytest
 
 
@@ -48,7 +72,451 @@ def test_read_rinex_obs_valid_v3_gzipped(obs_v3_gzip_file):
     assert version.startswith("3")
 
 
+def test_read_rinex_obs_geometry_free(obs_v3_file):
+    """Test emitting the geometry-free combinations at read time"""
+    df, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(geometry_free=True))
+    assert df.columns == ["epoch", "sv", "gflc_phase", "gflc_code"]
+    assert df.shape[0] > 0
+    assert df["gflc_phase"].drop_nulls().len() > 0
+
+
+def test_read_rinex_obs_time_scale(obs_v3_file):
+    """Test that epochs can be emitted in UTC and TAI"""
+    gpst, _, _ = read_rinex_obs(obs_v3_file)
+    utc, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(time_scale="UTC"))
+    tai, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(time_scale="TAI"))
+    assert (gpst["epoch"] - utc["epoch"]).dt.total_seconds().unique().to_list() == [18]
+    assert (tai["epoch"] - gpst["epoch"]).dt.total_seconds().unique().to_list() == [19]
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(time_scale="GLONASST"))
+
+
+def test_read_rinex_obs_nanoseconds(obs_v3_file):
+    """Test that epochs can be emitted with nanosecond precision"""
+    df_us, _, _ = read_rinex_obs(obs_v3_file)
+    df_ns, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(time_unit="ns"))
+    assert df_ns.schema["epoch"] == pl.Datetime("ns", "UTC")
+    assert df_ns["epoch"].dt.cast_time_unit("us").equals(df_us["epoch"])
+
+
+def test_read_rinex_obs_epoch_dtype(obs_v3_file):
+    """Test that epochs can be emitted as raw integer timestamps"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    for epoch_dtype, unit in (("i64_us", "us"), ("i64_ns", "ns")):
+        raw, _, _ = read_rinex_obs(
+            obs_v3_file, options=ReadOptions(time_unit="ns", epoch_dtype=epoch_dtype)
+        )
+        assert raw.schema["epoch"] == pl.Int64
+        expected = df["epoch"].dt.replace_time_zone(None).dt.cast_time_unit(unit).to_physical()
+        assert raw["epoch"].equals(expected)
+    gf, _, _ = read_rinex_obs(
+        obs_v3_file, options=ReadOptions(geometry_free=True, epoch_dtype="i64_us")
+    )
+    assert gf.schema["epoch"] == pl.Int64
+    normalized, _, _ = read_rinex_obs(
+        obs_v3_file, options=ReadOptions(normalize_doppler=True, epoch_dtype="i64_us")
+    )
+    assert normalized["epoch"].equals(raw["epoch"] // 1000)
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(epoch_dtype="i32"))
+
+
+def test_split_constellations(obs_v3_file):
+    """Test that observations can be split by constellation, as the navigation data"""
+    df, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(sort=True))
+    grouped = split_constellations(df)
+    assert set(grouped) == {"GPS", "GLONASS", "GALILEO", "BEIDOU", "QZSS", "SBAS"}
+    assert sum(part.height for part in grouped.values()) == df.height
+    assert grouped["GALILEO"].equals(df.filter(pl.col("sv").str.starts_with("E")))
+    assert grouped["GPS"]["epoch"].flags["SORTED_ASC"]
+
+
+def test_read_rinex_obs_snap_to(obs_v3_file):
+    """Test that epochs are snapped to the nominal grid, reporting the offset"""
+    df, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(snap_to="30s"))
+    assert df.schema["snap_offset"] == pl.Duration("us")
+    assert (df["epoch"].dt.second() % 30 == 0).all()
+    assert (df["epoch"].dt.microsecond() == 0).all()
+    assert (df["snap_offset"].dt.total_microseconds().abs() <= 15_000_000).all()
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(snap_to="thirty seconds"))
+
+
+def test_read_rinex_obs_duplicates(obs_v3_file):
+    """Test the duplicate-epoch handling policies"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    first, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(duplicates="first"))
+    n_unique = df.select("epoch", "sv", "observable").n_unique()
+    assert first.height == n_unique
+    if n_unique == df.height:
+        error, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(duplicates="error"))
+        assert error.height == df.height
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(duplicates="drop"))
+
+
+def test_read_rinex_obs_sort(obs_v3_file):
+    """Test that the sorted output is ordered by epoch, SV and observable"""
+    df, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(sort=True))
+    assert df["epoch"].flags["SORTED_ASC"]
+    assert df.equals(df.sort("epoch", "sv", "observable", maintain_order=True))
+
+
+def test_read_rinex_obs_limits(obs_v3_file):
+    """Test that row and memory limits abort or truncate at an epoch boundary"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    first_epoch = df.filter(pl.col("epoch") == df["epoch"][0]).height
+    limit = first_epoch + 1
+    assert read_rinex_obs(obs_v3_file, options=ReadOptions(max_rows=df.height))[0].equals(df)
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(max_rows=limit))
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(max_memory_mb=0.001))
+    with pytest.warns(UserWarning):
+        truncated, _, _ = read_rinex_obs(
+            obs_v3_file, options=ReadOptions(max_rows=limit, on_limit="truncate")
+        )
+    assert truncated.equals(df.head(first_epoch))
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(max_rows=limit, on_limit="ignore"))
+
+
+def test_read_rinex_obs_limits_before_parsing(obs_v3_file, obs_v3_hatanaka_compressed_file):
+    """Test that the limits are checked on the values of the file, before parsing it"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    with pytest.raises(ValueError):
+        read_rinex_obs(
+            obs_v3_file, options=ReadOptions(max_rows=df.height - 1, constellations=["G"])
+        )
+    with pytest.warns(UserWarning):
+        truncated, _, _ = read_rinex_obs(
+            obs_v3_hatanaka_compressed_file,
+            options=ReadOptions(max_rows=df.height // 2, on_limit="truncate"),
+        )
+    assert 0 < truncated.height <= df.height // 2
+    assert truncated.equals(df.head(truncated.height))
+
+
+def test_read_rinex_obs_options(obs_v3_file):
+    """Test the filters of reusable read options"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    start = df["epoch"].min() + timedelta(hours=1)
+    end = start + timedelta(hours=2)
+    options = ReadOptions(
+        start=start,
+        end=end,
+        constellations=["G", "E"],
+        observables=["C1C", "L1C"],
+        exclude_sv=["G01"],
+        value_dtype="float32",
+    )
+    filtered, _, _ = read_rinex_obs(obs_v3_file, options=options)
+    expected = df.filter(
+        pl.col("epoch").is_between(start, end, closed="left"),
+        pl.col("sv").str.slice(0, 1).is_in(["G", "E"]),
+        pl.col("observable").is_in(["C1C", "L1C"]),
+        pl.col("sv") != "G01",
+    )
+    assert filtered.height == expected.height > 0
+    assert filtered.schema["value"] == pl.Float32
+    assert filtered["epoch"].min() >= start and filtered["epoch"].max() < end
+
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(constellations=["X"]))
+
+
+def test_read_rinex_obs_types(obs_v2_file, obs_v3_file):
+    """Test the observation types declared in the headers against the parsed observables"""
+    declared = read_rinex_obs_types(obs_v3_file)
+    assert declared["E"][:4] == ["C1C", "L1C", "D1C", "S1C"]
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    parsed = df.group_by(pl.col("sv").str.slice(0, 1).alias("system")).agg(pl.col("observable").unique())
+    for system, observables in parsed.rows():
+        assert set(observables) <= set(declared[system])
+
+    assert read_rinex_obs_types(obs_v2_file) == {"G": ["L1", "L2", "C1", "P1", "P2", "S1", "S2"]}
+
+
+def test_read_rinex_phase_shifts(obs_v3_file):
+    """Test the phase shifts declared in the header, and their application"""
+    shifts = read_rinex_phase_shifts(obs_v3_file)
+    assert shifts.columns == ["system", "observable", "shift", "sv"]
+    l2s = shifts.filter(pl.col("system") == "G", pl.col("observable") == "L2S")
+    assert l2s["shift"].to_list() == [-0.25]
+    assert l2s["sv"].is_null().all()
+
+    options = ReadOptions(constellations=["G"], observables=["L1C", "L2S"])
+    kept, _, _ = read_rinex_obs(obs_v3_file, options=options)
+    shifted = options.copy()
+    shifted.phase_shift = "apply"
+    applied, _, _ = read_rinex_obs(obs_v3_file, options=shifted)
+    expected = kept.select(pl.when(pl.col("observable") == "L2S").then(-0.25).otherwise(0.0)).to_series()
+    assert (applied["value"] - kept["value"] - expected).abs().max() < 1e-6
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(phase_shift="invert"))
+
+
+def test_read_rinex_obs_phase_unit(obs_v3_file):
+    """Test carrier phases in meters, with FDMA wavelengths for GLONASS"""
+    options = ReadOptions(constellations=["G", "R"], observables=["L1C", "C1C"])
+    cycles, _, _ = read_rinex_obs(obs_v3_file, options=options)
+    in_meters = options.copy()
+    in_meters.phase_unit = "meters"
+    meters, _, _ = read_rinex_obs(obs_v3_file, options=in_meters)
+    assert meters.height == cycles.height
+    ratio = meters.select("sv", "observable", (pl.col("value") / cycles["value"]).alias("ratio"))
+    gps = ratio.filter(pl.col("sv").str.starts_with("G"), pl.col("observable") == "L1C")
+    assert (gps["ratio"] - 299792458.0 / 1575.42e6).abs().max() < 1e-9
+    codes = ratio.filter(pl.col("observable") == "C1C")["ratio"].drop_nulls()
+    assert (codes == 1.0).all()
+    glonass = ratio.filter(pl.col("sv").str.starts_with("R"), pl.col("observable") == "L1C")
+    assert glonass["ratio"].drop_nulls().is_between(0.186, 0.188).all()
+    assert ReadOptions.from_dict({"phase_unit": "meters"}).phase_unit == "meters"
+    with pytest.raises(ValueError):
+        read_rinex_obs(obs_v3_file, options=ReadOptions(phase_unit="feet"))
+
+
+def test_observable_table(obs_v3_file):
+    """Test the harmonization table of the observation codes, and its extension"""
+    table = observable_table()
+    assert table.select("system", "code").is_unique().all()
+    assert table["frequency"].null_count() == 0
+    l1 = table.filter(pl.col("system") == "G", pl.col("code") == "1C").row(0, named=True)
+    assert l1["signal"] == "L1" and l1["tracking_mode"] == "C/A"
+    assert l1["frequency"] == 1575.42e6 and l1["priority"] == 0
+    g1 = table.filter(pl.col("system") == "R", pl.col("code") == "1C")
+    assert g1["channel_spacing"].to_list() == [0.5625e6]
+    for system, observables in read_rinex_obs_types(obs_v3_file).items():
+        codes = set(table.filter(pl.col("system") == system)["code"])
+        assert {observable[1:] for observable in observables} <= codes
+
+    extra = pl.DataFrame(
+        {"system": ["G", "G"], "code": ["1C", "1Z"], "signal": ["L1", "L1"], "frequency": [1.0, 2.0]}
+    )
+    extended = observable_table(extra)
+    assert extended.height == table.height + 1
+    assert extended.filter(pl.col("system") == "G", pl.col("code") == "1C")["frequency"].to_list() == [1.0]
+    assert extended.filter(pl.col("code") == "1Z")["tracking_mode"].is_null().all()
+    with pytest.raises(ValueError):
+        observable_table(pl.DataFrame({"code": ["1C"]}))
+    with pytest.raises(ValueError):
+        observable_table(extra.with_columns(pl.lit(1).alias("unknown")))
+
+
+def test_read_rinex_obs_scale_factors(obs_v3_file, tmp_path):
+    """Test that the values of scaled observation types are divided by their factor"""
+    lines = open(obs_v3_file).read().splitlines(keepends=True)
+    end = next(i for i, line in enumerate(lines) if "END OF HEADER" in line)
+    record = "G   10   1 L1C".ljust(60) + "SYS / SCALE FACTOR\n"
+    scaled_file = tmp_path / "ASIR00ITA_R_20242810000_01D_30S_MO.rnx"
+    scaled_file.write_text("".join(lines[:end] + [record] + lines[end:]))
+
+    factors = read_rinex_scale_factors(scaled_file)
+    assert factors.rows() == [("G", "L1C", 10)]
+    assert read_rinex_scale_factors(obs_v3_file).is_empty()
+
+    options = ReadOptions(constellations=["G"], observables=["L1C", "C1C"])
+    df, _, _ = read_rinex_obs(obs_v3_file, options=options)
+    scaled, _, _, stats = read_rinex_obs_with_stats(scaled_file, options=options)
+    assert stats.scaled_observables == {"G": ["L1C"]}
+    ratio = scaled["value"] / df["value"]
+    expected = df.select(pl.when(pl.col("observable") == "L1C").then(0.1).otherwise(1.0)).to_series()
+    assert (ratio - expected).abs().max() < 1e-9
+
+
+def test_rinex_time_bounds(obs_v2_file, obs_v3_file, test_data_dir):
+    """Test that the scanned time bounds match the parsed epochs"""
+    crx_file = test_data_dir / "v3" / "obs" / "ASIR00ITA_R_20242810000_01D_30S_MO.crx.gz"
+    for path in (obs_v2_file, obs_v3_file, crx_file):
+        df, _, _ = read_rinex_obs(path)
+        first, last, n_epochs = rinex_time_bounds(path)
+        assert first == df["epoch"].min()
+        assert last == df["epoch"].max()
+        assert n_epochs == df["epoch"].n_unique()
+
+
+def test_read_rinex_obs_stats(obs_v3_file):
+    """Test the parse statistics returned alongside the DataFrame"""
+    df, _, _, stats = read_rinex_obs_with_stats(obs_v3_file)
+    assert isinstance(stats, ParseStats)
+    assert stats.epochs == df["epoch"].n_unique()
+    assert stats.signals == df.height
+    assert stats.svs == {
+        k: v for k, v in df.group_by(pl.col("sv").str.slice(0, 1)).agg(pl.col("sv").n_unique()).rows()
+    }
+    assert stats.first_epoch == df["epoch"].min()
+    assert stats.last_epoch == df["epoch"].max()
+    assert stats.time_span == stats.last_epoch - stats.first_epoch
+    assert stats.interval == stats.header_interval == timedelta(seconds=30)
+    assert stats.bytes == os.path.getsize(obs_v3_file)
+    assert stats.wall_time > 0
+    assert len(read_rinex_obs(obs_v3_file)) == 3
+
+
+def test_metadata_serialization(obs_v3_file):
+    """Test that options, statistics and site metadata survive pickle and JSON"""
+    options = ReadOptions(constellations=["G"], start=datetime(2024, 10, 7, 6), sort=True)
+    for restored in (
+        pickle.loads(pickle.dumps(options)),
+        ReadOptions.from_dict(json.loads(json.dumps(options.to_dict()))),
+    ):
+        assert restored.to_dict() == options.to_dict()
+        assert restored.start == datetime(2024, 10, 7, 6)
+    assert ReadOptions.from_dict({"sort": True}).time_scale == "GPST"
+    assert ReadOptions.from_dict({"normalize_doppler": True}).normalize_doppler
+    assert ReadOptions.from_dict({"ssi": True}).ssi
+    with pytest.raises(ValueError):
+        ReadOptions.from_dict({"unknown": 1})
+
+    _, _, _, stats = read_rinex_obs_with_stats(obs_v3_file)
+    for restored in (
+        pickle.loads(pickle.dumps(stats)),
+        ParseStats.from_dict(json.loads(json.dumps(stats.to_dict()))),
+    ):
+        assert restored.to_dict() == stats.to_dict()
+        assert restored.first_epoch == stats.first_epoch
+        assert restored.interval == stats.interval
+
+    site = read_rinex_site(obs_v3_file)
+    assert SiteInfo.from_dict(json.loads(json.dumps(site.to_dict()))) == site
+    assert pickle.loads(pickle.dumps(site)) == site
+
+
+def test_read_rinex_obs_strict(obs_v2_file, obs_v3_file, tmp_path):
+    """Test that strict mode accepts conforming files and reports all the violations"""
+    for path in (obs_v2_file, obs_v3_file):
+        assert validate_rinex_obs(path) == []
+        df, _, _ = read_rinex_obs(path, options=ReadOptions(strict=True))
+        assert df.height > 0
+
+    lines = open(obs_v2_file).read().splitlines(keepends=True)
+    broken = []
+    for line in lines:
+        label = line[60:].strip()
+        if label == "MARKER NAME":
+            continue
+        if label == "INTERVAL":
+            line = f"{-30.0:10.3f}".ljust(60) + "INTERVAL\n"
+        broken.append(line)
+    bad_file = tmp_path / "bad00920.14o"
+    bad_file.write_text("".join(broken))
+
+    violations = validate_rinex_obs(bad_file)
+    assert any("INTERVAL" in v for v in violations)
+    assert any("MARKER NAME" in v for v in violations)
+    df, _, _ = read_rinex_obs(bad_file)
+    assert df.height > 0
+    with pytest.raises(RinexConformityError) as excinfo:
+        read_rinex_obs(bad_file, options=ReadOptions(strict=True))
+    assert excinfo.value.violations == violations
+
+
 def test_read_rinex_obs_nonexistent_file(invalid_file):
     """Check that a FileNotFoundError is raised with a non-existent file"""
     with pytest.raises(FileNotFoundError):
         read_rinex_obs(invalid_file)
+
+
+def test_read_rinex_obs_epoch_flag(obs_v3_file):
+    """Test that the raw epoch flag is exposed as a column"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    assert "epoch_flag" in df.columns
+    assert df["epoch_flag"].is_in([0, 1, 6]).all()
+
+
+def test_read_rinex_obs_rx_clock_offset(obs_v3_file):
+    """Test that the receiver clock offset is exposed, constant within each epoch"""
+    df, _, _ = read_rinex_obs(obs_v3_file)
+    assert df.schema["rx_clock_offset"] == pl.Float64
+    per_epoch = df.group_by("epoch").agg(pl.col("rx_clock_offset").n_unique())
+    assert (per_epoch["rx_clock_offset"] == 1).all()
+
+
+def test_read_rinex_events(tmp_path):
+    """Test that event records (epoch flags 2-5) are exposed with their records"""
+    content = (
+        "     3.04           OBSERVATION DATA    M                   RINEX VERSION / TYPE\n"
+        "                                                            END OF HEADER\n"
+        "> 2024 10 07 00 00  0.0000000  0  1\n"
+        "G01  20000000.000   100000000.000 7\n"
+        "> 2024 10 07 00 00 30.0000000  4  2\n"
+        "        0.1000        0.0000        0.0000                  ANTENNA: DELTA H/E/N\n"
+        "NEW ANTENNA                                                 COMMENT\n"
+        ">                              5  0\n"
+    )
+    path = tmp_path / "events.rnx"
+    path.write_text(content)
+
+    events = read_rinex_events(path)
+    assert events["flag"].to_list() == [4, 5]
+    assert events["event"].to_list() == ["header_information", "external_event"]
+    assert events["records"][0].splitlines()[0].endswith("ANTENNA: DELTA H/E/N")
+    assert events["epoch"][1] is None
+
+
+def test_read_rinex_events_none(obs_v3_file):
+    """Test that a file without events gives an empty DataFrame"""
+    events = read_rinex_events(obs_v3_file)
+    assert events.is_empty()
+    assert events.columns == ["epoch", "flag", "event", "records"]
+
+
+def test_read_rinex_positions_static(obs_v3_file):
+    """Test that a static file gives the header position at every epoch"""
+    df, rec_pos, _ = read_rinex_obs(obs_v3_file)
+    positions = read_rinex_positions(obs_v3_file)
+    assert positions.height == df["epoch"].n_unique()
+    assert positions["source"].unique().to_list() == ["header"]
+    assert not positions["kinematic"].any()
+    assert positions.row(0)[1:4] == rec_pos
+
+
+def test_read_rinex_positions_trajectory(obs_v3_file):
+    """Test that an external trajectory is interpolated at the observation epochs"""
+    positions = read_rinex_positions(obs_v3_file)
+    first, last = positions["epoch"][0], positions["epoch"][-1]
+    trajectory = pl.DataFrame(
+        {
+            "epoch": [first, last],
+            "x": [0.0, 1000.0],
+            "y": [0.0, 0.0],
+            "z": [0.0, 0.0],
+        }
+    )
+    kinematic = read_rinex_positions(obs_v3_file, trajectory=trajectory)
+    assert kinematic["source"].unique().to_list() == ["trajectory"]
+    assert kinematic["x"].is_sorted()
+    assert kinematic["x"][-1] == pytest.approx(1000.0)
+
+
+def test_read_rinex_site(obs_v3_file):
+    """Test that the ARP is the marker shifted by the antenna eccentricities"""
+    site = read_rinex_site(obs_v3_file, phase_center_offset=(0.0, 0.0, 0.1))
+    _, rec_pos, _ = read_rinex_obs(obs_v3_file)
+    assert site.marker_position == rec_pos
+    assert site.antenna_type is not None
+    assert site.radome is not None
+
+    def dist(a, b):
+        return sum((u - v) ** 2 for u, v in zip(a, b)) ** 0.5
+
+    assert dist(site.marker_position, site.arp_position) == pytest.approx(
+        sum(d**2 for d in site.delta_hen) ** 0.5, abs=1e-6
+    )
+    assert dist(site.arp_position, site.phase_center_position) == pytest.approx(0.1)
+
+
+def test_read_rinex_obs_glob(test_data_dir, tmp_path):
+    """Test the batch reader keyed by station and date parsed from the filenames"""
+    pattern = test_data_dir / "v3" / "obs" / "ASIR*.rnx"
+    result = read_rinex_obs_glob(pattern)
+    assert list(result) == [("asir", date(2024, 10, 7))]
+    assert result[("asir", date(2024, 10, 7))].height > 0
+
+    (tmp_path / "notes.txt").write_text("not a RINEX file")
+    with pytest.warns(UserWarning, match="notes.txt"):
+        df = read_rinex_obs_glob(f"{tmp_path}/*", concat=True)
+    assert df.is_empty()
+
+    df = read_rinex_obs_glob(test_data_dir / "v*" / "obs" / "cgtc*.14o", concat=True)
+    assert df.select("station", "date").unique().rows() == [("cgtc", date(2014, 4, 2))]
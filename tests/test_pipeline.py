@@ -0,0 +1,60 @@
+from datetime import datetime
+
+import polars as pl
+import pytest
+
+from pytecgg.context import GNSSContext
+from pytecgg.linear_combinations.constants import C
+from pytecgg.linear_combinations.gflc import _meters_to_tecu
+from pytecgg.tec_calibration import TecOptions
+from pytecgg.tec_calibration.pipeline import _apply_biases
+
+
+def test_tec_options_defaults():
+    options = TecOptions()
+    assert options.systems is None
+    assert options.h_ipp is None
+    assert options.min_arc_length == 30
+    assert not options.vertical_equivalent
+
+
+def test_tec_options_dict_round_trip():
+    options = TecOptions(systems=["G", "E"], min_elevation=20.0, modip_mapping=True)
+    assert TecOptions.from_dict(options.to_dict()) == options
+    assert TecOptions.from_dict({"h_ipp": 400_000.0}).h_ipp == 400_000.0
+
+
+def test_apply_biases_removes_satellite_and_receiver_dcb():
+    ctx = GNSSContext(
+        receiver_pos=(4_641_949.0, 1_393_045.0, 4_133_287.0),
+        receiver_name="grot",
+        rinex_version="3.04",
+        systems=["GPS"],
+    )
+    ctx.freq_meta["G"] = (1575.42, 1227.60)
+    df = pl.DataFrame(
+        {
+            "epoch": [datetime(2024, 10, 7, 0, 0), datetime(2024, 10, 7, 0, 0)],
+            "sv": ["G01", "G02"],
+            "gflc_levelled": [20.0, 30.0],
+            "mapping": [0.5, 1.0],
+        }
+    )
+    biases = pl.DataFrame(
+        {
+            "system": ["G", "G", "G"],
+            "sv": ["G01", None, None],
+            "station": [None, "GROT 12764M001", "MATE 12734M008"],
+            "bias": [2.0, 1.0, 5.0],
+            "rms": [0.01, 0.02, 0.02],
+        }
+    )
+
+    out = _apply_biases(df, biases, ctx)
+
+    factor = pl.select(_meters_to_tecu(pl.lit(1575.42e6), pl.lit(1227.60e6))).item()
+    expected = 20.0 + 3.0 * 1e-9 * C * factor
+    assert out["stec"][0] == pytest.approx(expected)
+    assert out["vtec"][0] == pytest.approx(0.5 * expected)
+    # No satellite bias for G02
+    assert out["stec"][1] is None
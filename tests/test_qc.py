@@ -0,0 +1,284 @@
+import json
+import pickle
+from datetime import datetime, timedelta
+
+import polars as pl
+import pytest
+
+from pytecgg.parsing import ReadOptions, read_rinex_nav, read_rinex_obs
+from pytecgg.qc import (
+    check_nav_consistency,
+    detect_clock_jumps,
+    detect_gaps,
+    epoch_completeness,
+    estimate_multipath,
+    fill_gaps,
+    qc_report,
+    repair_clock_jumps,
+    sampling_interval,
+    snr_from_ssi,
+    snr_statistics,
+    ssi_to_dbhz,
+    QCReport,
+)
+
+
+def test_estimate_multipath_missing_code():
+    """Test that a satellite without its second code only yields MP1"""
+    c, f1, f2 = 299_792_458.0, 1575.42e6, 1227.60e6
+    t0 = datetime(2023, 1, 1)
+    rows = []
+    for i in range(12):
+        epoch = t0 + timedelta(seconds=30 * i)
+        for sv in ("G01", "G02"):
+            rho = 2.2e7 + 100.0 * i
+            rows.append((epoch, sv, "L1C", rho * f1 / c + 1000.0))
+            rows.append((epoch, sv, "L2W", rho * f2 / c - 500.0))
+            rows.append((epoch, sv, "C1C", rho))
+            if sv == "G02":
+                rows.append((epoch, sv, "C2W", rho))
+    obs = pl.DataFrame(rows, schema=["epoch", "sv", "observable", "value"], orient="row")
+
+    series, stats = estimate_multipath(obs, ("L1C", "L2W"), ("C1C", "C2W"))
+    g01 = series.filter(pl.col("sv") == "G01")
+    assert g01.height == 12
+    assert g01["mp2"].is_null().all()
+    assert g01["mp1"].abs().max() < 1e-3
+    assert series.filter(pl.col("sv") == "G02")["mp2"].abs().max() < 1e-3
+    assert stats.filter(pl.col("sv") == "G01")["observable"].to_list() == ["C1C"]
+
+
+def test_qc_report_from_path(obs_v3_file):
+    """Test the QC summary of a RINEX v3 observation file"""
+    report = qc_report(obs_v3_file)
+    assert isinstance(report, QCReport)
+    assert report.interval == timedelta(seconds=30)
+    assert report.actual_epochs <= report.expected_epochs
+    assert 0 < report.completeness <= 100
+    assert set(report.counts.columns) == {"sv", "observable", "n_obs"}
+    assert not report.multipath.is_empty()
+    assert (report.multipath["mp_rms"] >= 0).all()
+
+
+def test_qc_report_serialization(obs_v3_file):
+    """Test that a QC report survives pickle and a JSON round trip"""
+    report = qc_report(obs_v3_file)
+    restored = QCReport.from_dict(json.loads(json.dumps(report.to_dict())))
+    assert restored.first_epoch == report.first_epoch
+    assert restored.interval == report.interval
+    assert restored.completeness == report.completeness
+    assert restored.counts.to_dicts() == report.counts.to_dicts()
+    assert restored.gaps.to_dicts() == report.gaps.to_dicts()
+    assert pickle.loads(pickle.dumps(report)).n_slips == report.n_slips
+
+
+def test_qc_report_from_dataframe(parsed_rinex_obs_data):
+    """Test that a parsed DataFrame gives the same epoch summary as the file"""
+    obs = parsed_rinex_obs_data["obs_data"]
+    report = qc_report(obs)
+    assert report.first_epoch == obs["epoch"].min()
+    assert report.last_epoch == obs["epoch"].max()
+    assert report.actual_epochs == obs["epoch"].n_unique()
+    assert isinstance(report.gaps, pl.DataFrame)
+
+
+def test_detect_gaps():
+    """Test station-level and per-satellite gap detection"""
+    t0 = datetime(2023, 1, 1)
+    epochs = [t0 + timedelta(seconds=30 * i) for i in (0, 1, 2, 5, 6)]
+    obs = pl.DataFrame(
+        {
+            "epoch": epochs + epochs[:2],
+            "sv": ["G01"] * 5 + ["G02"] * 2,
+        }
+    )
+    gaps = detect_gaps(obs)
+
+    station = gaps.filter(pl.col("sv").is_null())
+    assert station.height == 1
+    assert station["start"][0] == t0 + timedelta(seconds=60)
+    assert station["end"][0] == t0 + timedelta(seconds=150)
+    assert station["n_missing"][0] == 2
+    assert gaps.filter(pl.col("sv") == "G02").is_empty()
+
+
+def test_fill_gaps():
+    """Test that only short gaps within arcs are interpolated and flagged"""
+    t0 = datetime(2023, 1, 1)
+    seconds = [0, 30, 90, 120, 300, 0, 90]
+    df = pl.DataFrame(
+        {
+            "epoch": [t0 + timedelta(seconds=s) for s in seconds],
+            "sv": ["G01"] * 7,
+            "id_arc_valid": ["a"] * 5 + ["b"] * 2,
+            "stec": [0.0, 3.0, 9.0, 12.0, 30.0, 1.0, 4.0],
+        }
+    )
+    out = fill_gaps(df, max_gap=60)
+
+    filled = out.filter(pl.col("interpolated"))
+    assert filled.height == 1
+    assert filled["epoch"][0] == t0 + timedelta(seconds=60)
+    assert filled["stec"][0] == 6.0
+    assert filled["sv"][0] == "G01"
+    assert out.filter(pl.col("id_arc_valid") == "b").height == 2
+    assert out.height == 8
+
+
+def test_sampling_interval():
+    """Test the interval inferred from jittered epochs with gaps"""
+    t0 = datetime(2023, 1, 1)
+    offsets = [0, 30, 60.0002, 89.9999, 180, 210, 240.0001, 300]
+    obs = pl.DataFrame({"epoch": [t0 + timedelta(seconds=s) for s in offsets]})
+    assert sampling_interval(obs) == timedelta(seconds=30)
+    assert sampling_interval(obs, resolution=1e-6) == timedelta(seconds=30)
+    assert sampling_interval(obs.head(1)) is None
+
+
+def test_epoch_completeness(test_data_dir, nav_v3_file):
+    """Test completeness against satellites predicted from broadcast ephemerides"""
+    obs, rx_pos, _ = read_rinex_obs(
+        test_data_dir / "v3" / "obs" / "BNEU00LAO_R_20250870000_01D_30S_MO.rnx"
+    )
+    per_epoch, per_day = epoch_completeness(obs, read_rinex_nav(nav_v3_file), rx_pos)
+
+    assert per_epoch.columns == [
+        "epoch",
+        "n_expected",
+        "n_observed",
+        "n_signals",
+        "completeness",
+    ]
+    assert (per_epoch["n_observed"] <= per_epoch["n_expected"]).all()
+    assert per_epoch["completeness"].drop_nulls().is_between(0, 100).all()
+    assert per_day.height == 1
+    assert per_day["n_expected"][0] > 0
+
+
+def test_snr_statistics(parsed_rinex_obs_data):
+    """Test SNR aggregation per SV and signal"""
+    obs = parsed_rinex_obs_data["obs_data"]
+    stats = snr_statistics(obs)
+
+    assert "elevation_bin" not in stats.columns
+    assert stats["observable"].str.starts_with("S").all()
+    assert (stats["min_snr"] <= stats["median_snr"]).all()
+    assert (stats["min_snr"] <= stats["mean_snr"]).all()
+    n_snr = obs.filter(pl.col("observable").str.starts_with("S"))["value"].count()
+    assert stats["n_obs"].sum() == n_snr
+
+
+def test_snr_from_ssi(obs_v3_file):
+    """Test SNR approximated from the signal strength indicator digits"""
+    obs, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(ssi=True))
+    obs = obs.filter(pl.col("sv").str.starts_with("G"), pl.col("observable").is_in(["L1C", "S1C"]))
+    assert obs["ssi"].dtype == pl.UInt32
+    assert obs["ssi"].drop_nulls().is_between(1, 9).all()
+
+    phases = obs.filter(pl.col("observable") == "L1C").select("epoch", "sv", ssi_to_dbhz().alias("snr"))
+    recorded = obs.filter(pl.col("observable") == "S1C").select("epoch", "sv", "value")
+    diff = phases.join(recorded, on=["epoch", "sv"]).select(pl.col("snr") - pl.col("value"))
+    assert diff.to_series().abs().median() <= 3
+
+    assert snr_from_ssi(obs).height == obs.height
+    without_snr = obs.filter(pl.col("observable") == "L1C")
+    derived = snr_from_ssi(without_snr).filter(pl.col("observable") == "S1C")
+    assert derived.height == without_snr["ssi"].count()
+    assert derived["value"].to_list() == [6.0 * n + 3 for n in without_snr["ssi"].drop_nulls()]
+    assert pl.DataFrame({"ssi": [0, 1, 9, None]}).select(ssi_to_dbhz())["ssi"].to_list() == [
+        None,
+        9.0,
+        57.0,
+        None,
+    ]
+    with pytest.raises(ValueError):
+        snr_from_ssi(obs.drop("ssi"))
+
+
+def test_clock_jumps():
+    """Test detection and repair of a 1 ms receiver clock jump in code"""
+    c, f1 = 299_792_458.0, 1575.42e6
+    t0 = datetime(2023, 1, 1)
+    rows = []
+    for i in range(6):
+        for k, sv in enumerate(["G01", "G02", "G03"]):
+            rho = 2.2e7 + 1e3 * k + 500.0 * i
+            jump = c * 1e-3 if i >= 3 else 0.0
+            epoch = t0 + timedelta(seconds=30 * i)
+            rows.append((epoch, sv, "C1C", rho + jump))
+            rows.append((epoch, sv, "L1C", rho * f1 / c))
+    obs = pl.DataFrame(rows, schema=["epoch", "sv", "observable", "value"], orient="row")
+
+    jumps = detect_clock_jumps(obs)
+    assert jumps.height == 1
+    assert jumps["epoch"][0] == t0 + timedelta(seconds=90)
+    assert jumps["jump_ms"][0] == 1
+    assert jumps["observable"][0] == "code"
+    assert jumps["n_satellites"][0] == 3
+
+    repaired, _ = repair_clock_jumps(obs)
+    assert detect_clock_jumps(repaired).is_empty()
+    phase = pl.col("observable") == "L1C"
+    assert repaired.filter(phase).equals(obs.filter(phase))
+
+
+def test_clock_jumps_phase():
+    """Test that a receiver clock jump of the phases is repaired in the phases, in cycles"""
+    c, f1 = 299_792_458.0, 1575.42e6
+    t0 = datetime(2023, 1, 1)
+    rows = []
+    for i in range(6):
+        for k, sv in enumerate(["G01", "G02", "G03"]):
+            rho = 2.2e7 + 1e3 * k + 500.0 * i
+            jump = c * 1e-3 if i >= 3 else 0.0
+            epoch = t0 + timedelta(seconds=30 * i)
+            rows.append((epoch, sv, "C1C", rho))
+            rows.append((epoch, sv, "L1C", (rho - jump) * f1 / c))
+    obs = pl.DataFrame(rows, schema=["epoch", "sv", "observable", "value"], orient="row")
+
+    jumps = detect_clock_jumps(obs)
+    assert jumps["jump_ms"].to_list() == [-1]
+    assert jumps["observable"].to_list() == ["phase"]
+
+    repaired, _ = repair_clock_jumps(obs)
+    assert detect_clock_jumps(repaired).is_empty()
+    code = pl.col("observable") == "C1C"
+    assert repaired.filter(code).equals(obs.filter(code))
+    expected = [
+        rho * f1 / c
+        for rho in (2.2e7 + 1e3 * k + 500.0 * i for i in range(6) for k in range(3))
+    ]
+    assert repaired.filter(~code)["value"].to_list() == pytest.approx(expected, abs=1e-3)
+
+
+def test_check_nav_consistency():
+    """Test the IODE/IODC and duplicated upload checks of broadcast ephemerides"""
+    t0 = datetime(2025, 3, 28, 2)
+    gps = pl.DataFrame(
+        {
+            "epoch": [t0, t0, t0 + timedelta(hours=2), t0 + timedelta(hours=4)],
+            "sv": ["1", "1", "1", "2"],
+            "iode": [10.0, 10.0, 11.0, 20.0],
+            "iodc": [10.0, 10.0, 267.0, 21.0],
+            "toe": [7200.0, 7200.0, 14400.0, 21600.0],
+            "sqrta": [5153.6, 5153.7, 5153.6, 5153.6],
+            "t_tm": [0.0, 30.0, 0.0, 0.0],
+        }
+    ).with_columns(pl.col("epoch").dt.replace_time_zone("UTC"))
+
+    report = check_nav_consistency({"GPS": gps})
+    assert report.columns == ["constellation", "sv", "epoch", "row", "issue", "detail"]
+    mismatch = report.filter(pl.col("issue") == "iode_iodc_mismatch")
+    assert mismatch["sv"].to_list() == ["G02"]
+    assert mismatch["detail"].to_list() == ["IODE 20 / IODC 21"]
+    for issue in ("conflicting_duplicate", "iod_conflict"):
+        assert report.filter(pl.col("issue") == issue)["row"].to_list() == [0, 1]
+
+    # Identical re-broadcasts are not suspect
+    same = gps.with_columns(pl.lit(5153.6).alias("sqrta"), pl.col("iode").alias("iodc"))
+    assert check_nav_consistency({"GPS": same}).is_empty()
+
+
+def test_check_nav_consistency_rinex(nav_v3_file):
+    report = check_nav_consistency(read_rinex_nav(nav_v3_file))
+    assert set(report["issue"].unique()) <= {"iode_iodc_mismatch", "conflicting_duplicate", "iod_conflict"}
@@ -0,0 +1,59 @@
+from datetime import datetime, timedelta
+
+import polars as pl
+import pytest
+
+from pytecgg.utils import resample
+
+
+def _obs() -> pl.DataFrame:
+    t0 = datetime(2024, 1, 1)
+    epochs = [t0 + timedelta(seconds=s) for s in (0, 1, 29, 30, 31)]
+    return pl.DataFrame(
+        {
+            "epoch": epochs * 2,
+            "sv": ["G01"] * 10,
+            "observable": ["C1C"] * 5 + ["L1C"] * 5,
+            "value": [1.0, 2.0, 3.0, 4.0, 5.0, 10.0, 20.0, 30.0, 40.0, 1000.0],
+        }
+    )
+
+
+def test_resample_nearest():
+    """Test that the sample nearest to each grid epoch is kept"""
+    out = resample(_obs(), every="30s")
+    assert out["epoch"].unique().to_list() == [
+        datetime(2024, 1, 1),
+        datetime(2024, 1, 1, 0, 0, 30),
+    ]
+    assert out.filter(pl.col("observable") == "C1C")["value"].to_list() == [1.0, 4.0]
+    assert out.columns == _obs().columns
+
+
+def test_resample_mean_keeps_phase():
+    """Test that code is averaged while carrier phase is taken at the nearest sample"""
+    out = resample(_obs(), every="30s", agg="mean")
+    assert out.filter(pl.col("observable") == "C1C")["value"].to_list() == pytest.approx([1.5, 4.0])
+    assert out.filter(pl.col("observable") == "L1C")["value"].to_list() == [10.0, 40.0]
+
+
+def test_resample_mean_within_arc():
+    """Test that samples of another arc are not averaged"""
+    t0 = datetime(2024, 1, 1)
+    df = pl.DataFrame(
+        {
+            "epoch": [t0 + timedelta(seconds=s) for s in (29, 30, 31)],
+            "sv": ["G01"] * 3,
+            "id_arc_valid": ["a", "b", "b"],
+            "gflc_phase": [1.0, 10.0, 12.0],
+        }
+    )
+    out = resample(df, every="30s", agg="mean")
+    assert out["gflc_phase"].to_list() == pytest.approx([11.0])
+    assert out["id_arc_valid"].to_list() == ["b"]
+
+
+def test_resample_invalid_agg():
+    """Test that unsupported aggregations are rejected"""
+    with pytest.raises(ValueError):
+        resample(_obs(), agg="median")
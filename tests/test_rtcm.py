@@ -0,0 +1,88 @@
+import gzip
+import math
+import os
+from datetime import date
+
+import pytest
+
+from pytecgg.parsing import read_rtcm
+
+from .test_stream import T0, _frame, _msm4
+
+
+def _sign_magnitude(value, bits):
+    return (1 << (bits - 1)) | -value if value < 0 else value
+
+
+def _gps_ephemeris(prn, week, toc, m0, sqrta):
+    """GPS ephemeris message (1019), with angles in semicircles"""
+    fields = [(1019, 12), (prn, 6), (week % 1024, 10), (2, 4), (1, 2), (-100, 14), (42, 8), (toc // 16, 16)]
+    fields += [(0, 8), (-50, 16), (12345, 22), (42, 10), (-1000, 16), (1000, 16), (round(m0 * 2**31), 32)]
+    fields += [(-200, 16), (round(0.01 * 2**33), 32), (300, 16), (round(sqrta * 2**19), 32), (toc // 16, 16)]
+    fields += [(10, 16), (-(2**29), 32), (-10, 16), (round(0.3 * 2**31), 32), (64, 16), (2**28, 32)]
+    fields += [(-5000, 24), (-9, 8), (0, 6), (0, 1), (0, 1)]
+    return _frame(fields)
+
+
+def _glonass_ephemeris(prn, channel, tb, x_km):
+    """GLONASS ephemeris message (1020), with tb in units of 15 minutes"""
+    state = [(0, 24), (_sign_magnitude(x_km * 2**11, 27), 27), (0, 5)] + [(0, 24), (0, 27), (0, 5)] * 2
+    fields = [(1020, 12), (prn, 6), (channel + 7, 5), (0, 4), (0, 5), (44, 6), (1, 1), (0, 1), (0, 1), (tb, 7)]
+    fields += state + [(0, 1), (_sign_magnitude(-5, 11), 11), (0, 3), (_sign_magnitude(12345, 22), 22)]
+    fields += [(0, 5), (3, 5), (0, 1), (0, 4), (0, 11), (0, 2), (0, 1), (0, 11), (0, 32), (0, 5), (0, 22), (0, 1), (0, 7)]
+    return _frame(fields)
+
+
+@pytest.fixture
+def rtcm_file(tmp_path):
+    gps = _gps_ephemeris(5, 2355, 118_800, 0.5, 5153.7)
+    frames = [
+        gps,
+        _msm4(3_600_000, [5], [2], [73], [400], [1000], [-5000], [45]),
+        # Repeated broadcast of the same ephemeris
+        gps,
+        _glonass_ephemeris(3, -4, 4, 12345),
+        _msm4(3_601_000, [5], [2], [73], [401], [0], [0], [44]),
+    ]
+    path = tmp_path / "graz.rtcm3.gz"
+    path.write_bytes(gzip.compress(b"".join(frames)))
+    # Modification time (end of the recording) used as reference date
+    os.utime(path, ns=(T0 + 10**9, T0 + 10**9))
+    return path
+
+
+def test_read_rtcm(rtcm_file):
+    obs, nav, position = read_rtcm(rtcm_file)
+
+    assert obs.columns == ["epoch", "sv", "observable", "value", "epoch_flag", "rx_clock_offset"]
+    assert obs["epoch"].dt.epoch("ns").unique().sort().to_list() == [T0, T0 + 10**9]
+    assert str(obs["epoch"].dtype.time_zone) == "UTC"
+    assert position is None
+
+    assert sorted(nav) == ["GLONASS", "GPS"]
+    gps = nav["GPS"]
+    assert gps.height == 1
+    assert gps["sv"].to_list() == ["5"]
+    # Time of clock 118800 s into GPS week 2355
+    assert gps["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 118_800
+    assert gps["gps_week"].item() == 2355
+    assert gps["week"].item() == 2355
+    assert gps["toe"].item() == 118_800
+    assert gps["m0"].item() == pytest.approx(0.5 * math.pi)
+    assert gps["sqrta"].item() == pytest.approx(5153.7, abs=1e-5)
+    assert gps["clock_bias"].item() == pytest.approx(12345 * 2**-31)
+
+    glonass = nav["GLONASS"]
+    # tb = 01:00 Moscow time, i.e. 22:00 UTC of the previous day
+    assert glonass["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 - 7200
+    assert glonass["satPosX"].item() == 12345
+    assert glonass["channel"].item() == -4
+    assert glonass["clock_bias"].item() == pytest.approx(-12345 * 2**-30)
+
+
+def test_read_rtcm_stream(rtcm_file):
+    with gzip.open(rtcm_file, "rb") as stream:
+        obs, nav, _ = read_rtcm(stream, reference=date(2025, 2, 24), nav_time_scale="GPST")
+    assert obs.height == 6
+    # GLONASS epochs converted from UTC to GPST (18 leap seconds)
+    assert nav["GLONASS"]["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 - 7200 + 18
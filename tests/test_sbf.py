@@ -0,0 +1,141 @@
+import io
+import math
+import struct
+
+import pytest
+
+from pytecgg.parsing import read_sbf
+
+from .conftest import crc16
+
+C = 299_792_458.0
+# GPS week 2355, 01:00:00 GPST
+WEEK, TOW_MS = 2355, 3_600_000
+T0 = (315_964_800 + WEEK * 604_800) * 10**9 + TOW_MS * 10**6
+
+
+def _block(number, body, tow=TOW_MS, week=WEEK):
+    """SBF block, padded to a multiple of 4 bytes, with its CRC"""
+    body = struct.pack("<IH", tow, week) + body
+    body += bytes(-(len(body) + 8) % 4)
+    data = struct.pack("<HH", number, len(body) + 8) + body
+    return b"$@" + struct.pack("<H", crc16(data)) + data
+
+
+def _meas_epoch(satellites):
+    """MeasEpoch; satellites as (svid, main signal, [secondary signals])"""
+    body = struct.pack("<BBBBBB", len(satellites), 20, 12, 0, 0, 0)
+    for svid, (signal, code_m, doppler, carrier_offset, cn0, obs_info), secondary in satellites:
+        code = round(code_m * 1000)
+        body += struct.pack("<BBBB", 0, signal, svid, code >> 32) + struct.pack("<Ii", code & 0xFFFFFFFF, doppler)
+        body += struct.pack("<HbBHBB", carrier_offset & 0xFFFF, carrier_offset >> 16, cn0, 0, obs_info, len(secondary))
+        for signal2, code_offset, carrier_offset2, doppler_offset, cn02 in secondary:
+            msb = (code_offset >> 16) & 0x07 | ((doppler_offset >> 16) & 0x1F) << 3
+            body += struct.pack("<BBBBbB", signal2, 0, cn02, msb, carrier_offset2 >> 16, 0)
+            body += struct.pack("<HHH", code_offset & 0xFFFF, carrier_offset2 & 0xFFFF, doppler_offset & 0xFFFF)
+    return _block(4027, body)
+
+
+def _gps_nav(prn, toe, m0, sqrta):
+    """GPSNav, with m0 in semicircles"""
+    body = struct.pack("<BBHBBBBHBBBB", prn, 0, WEEK % 1024, 1, 2, 0, 0, 42, 42, 42, 0, 0)
+    body += struct.pack("<fIfff", -4e-9, toe, 0.0, 1e-12, 1e-5)
+    body += struct.pack("<ffdfdfdI", 42.0, 1e-9, m0, 1e-6, 0.01, 2e-6, sqrta, toe)
+    body += struct.pack("<fdfdfdffHH", 1e-8, -0.25, 2e-8, 0.3, 200.0, 0.125, -2e-9, 1e-10, WEEK % 1024, WEEK % 1024)
+    return _block(5891, body)
+
+
+def _gal_nav(svid, toe, m0):
+    """GALNav (I/NAV), with m0 in semicircles"""
+    body = struct.pack("<BBdddddd", svid, 2, 5440.6, m0, 0.001, 0.3, 0.125, -0.25)
+    body += struct.pack("<ff7f", -2e-9, 1e-10, 1e-9, 1e-6, 2e-6, 10.0, -20.0, 1e-8, 2e-8)
+    body += struct.pack("<IIffdHHHH", toe, toe, 0.0, 1e-12, 1e-4, (WEEK - 1024) % 4096, (WEEK - 1024) % 4096, 77, 0)
+    body += struct.pack("<BBBBfff", 0, 255, 107, 255, 2e-9, 3e-9, -2e10)
+    return _block(4002, body)
+
+
+def _ismr(channels):
+    """ISMR; channels as (svid, signal, s4, sigma_phi)"""
+    body = struct.pack("<BB", len(channels), 8)
+    for svid, signal, s4, sigma_phi in channels:
+        body += struct.pack("<BBBBHH", 0, signal, svid, 0, s4, sigma_phi)
+    return _block(4086, body)
+
+
+@pytest.fixture
+def sbf_log():
+    # G05 L1 C/A with L2C; R03 L1 C/A on channel -4; E11 E1 with E5a (no Doppler)
+    g05 = (5, (0, 22_001_973.732, -10_000_000, 1234, 140, 0), [(3, 2500, -5000, 100, 120)])
+    r03 = (40, (8, 21_000_000.0, 20_000, 0, 132, 4 << 3), [])
+    e11 = (81, (17, 23_000_000.0, 500_000, -70_000, 124, 0), [(20, -1000, 0, -16 << 16, 100)])
+    blocks = [
+        _gps_nav(5, 7200, 0.5, 5153.7),
+        _meas_epoch([g05, r03, e11]),
+        _gal_nav(81, 7200, 0.25),
+        _ismr([(5, 0, 123, 45), (81, 17, 65535, 300)]),
+    ]
+    # Leading garbage, including a false sync with an implausible length
+    return b"\x00$@junk!!" + b"".join(blocks)
+
+
+def test_read_sbf_obs(sbf_log):
+    obs, _, _ = read_sbf(io.BytesIO(sbf_log))
+
+    assert obs.columns == ["epoch", "sv", "observable", "value", "epoch_flag", "rx_clock_offset"]
+    assert obs["epoch"].dt.epoch("ns").unique().to_list() == [T0]
+    assert str(obs["epoch"].dtype.time_zone) == "UTC"
+    values = {(sv, o): v for sv, o, v in obs.select("sv", "observable", "value").iter_rows()}
+    assert sorted(values) == [
+        ("E11", "C1C"), ("E11", "C5Q"), ("E11", "D1C"), ("E11", "L1C"), ("E11", "L5Q"), ("E11", "S1C"), ("E11", "S5Q"),
+        ("G05", "C1C"), ("G05", "C2L"), ("G05", "D1C"), ("G05", "D2L"), ("G05", "L1C"), ("G05", "L2L"), ("G05", "S1C"), ("G05", "S2L"),
+        ("R03", "C1C"), ("R03", "D1C"), ("R03", "L1C"), ("R03", "S1C"),
+    ]
+    assert values["G05", "C1C"] == pytest.approx(22_001_973.732, abs=1e-6)
+    assert values["G05", "L1C"] == pytest.approx(22_001_973.732 * 1575.42e6 / C + 1.234, abs=1e-6)
+    assert values["G05", "D1C"] == pytest.approx(-1000)
+    assert values["G05", "S1C"] == 45
+    # Secondary signals are offsets from the main signal
+    assert values["G05", "C2L"] == pytest.approx(22_001_976.232, abs=1e-6)
+    assert values["G05", "L2L"] == pytest.approx(22_001_976.232 * 1227.6e6 / C - 5, abs=1e-6)
+    assert values["G05", "D2L"] == pytest.approx(-1000 * 1227.6 / 1575.42 + 0.01)
+    assert values["G05", "S2L"] == 40
+    # GLONASS channel from the observation info
+    assert values["R03", "L1C"] == pytest.approx(21_000_000 * (1602e6 - 4 * 0.5625e6) / C, abs=1e-6)
+    assert values["E11", "C5Q"] == pytest.approx(22_999_999, abs=1e-6)
+
+
+def test_read_sbf_nav(sbf_log):
+    _, nav, _ = read_sbf(io.BytesIO(sbf_log))
+
+    assert sorted(nav) == ["GALILEO", "GPS"]
+    gps = nav["GPS"]
+    assert gps["sv"].to_list() == ["5"]
+    assert gps["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 7200
+    assert gps["week"].item() == 2355
+    assert gps["m0"].item() == pytest.approx(0.5 * math.pi)
+    assert gps["sqrta"].item() == 5153.7
+    assert gps["accuracy"].item() == pytest.approx(4.85)
+    assert (gps["ura_index"].item(), gps["ura"].item()) == (2, 4.85)
+
+    galileo = nav["GALILEO"]
+    assert galileo["sv"].to_list() == ["11"]
+    assert galileo["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 7200
+    assert galileo["week"].item() == 2355
+    assert galileo["source"].item() == 517
+    assert galileo["m0"].item() == pytest.approx(0.25 * math.pi)
+    assert galileo["sisa"].item() == pytest.approx(3.12)
+    assert galileo["ura_index"].item() == 107
+    assert galileo["ura"].item() == pytest.approx(3.12)
+    assert galileo["clock_bias"].item() == 1e-4
+
+
+def test_read_sbf_ismr(sbf_log):
+    _, _, ismr = read_sbf(io.BytesIO(sbf_log))
+
+    assert ismr.columns == ["epoch", "sv", "signal", "s4", "sigma_phi_60s"]
+    assert ismr["epoch"].dt.epoch("ns").to_list() == [T0, T0]
+    assert ismr["sv"].to_list() == ["G05", "E11"]
+    assert ismr["signal"].to_list() == ["1C", "1C"]
+    # Do-not-use values are null
+    assert ismr["s4"].to_list() == [pytest.approx(0.123), None]
+    assert ismr["sigma_phi_60s"].to_list() == [pytest.approx(0.045), pytest.approx(0.3)]
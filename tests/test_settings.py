@@ -0,0 +1,93 @@
+import numpy as np
+import polars as pl
+import pytest
+
+import pytecgg
+from pytecgg import GNSSContext, configure, get_settings, reset_settings, settings_context
+from pytecgg.linear_combinations.gflc import _calculate_gflc_code
+from pytecgg.parsing import ReadOptions, read_rinex_obs
+from pytecgg.tec_calibration.calibration_preprocessing import _mapping_function
+
+
+@pytest.fixture(autouse=True)
+def default_settings():
+    reset_settings()
+    yield
+    reset_settings()
+
+
+def _context(**kwargs):
+    return GNSSContext(
+        receiver_pos=(4444444.0, 1111111.0, 1234567.0),
+        receiver_name="GROT",
+        rinex_version="3.04",
+        systems=["G"],
+        **kwargs,
+    )
+
+
+def test_configure_context_default():
+    """The shell height of the settings is the default of new contexts"""
+    assert _context().h_ipp == 350_000
+    configure(h_ipp=450_000)
+    assert get_settings().h_ipp == 450_000
+    assert _context().h_ipp == 450_000
+    assert _context(h_ipp=300_000).h_ipp == 300_000
+
+
+def test_settings_context_restores():
+    with settings_context(h_ipp=400_000, min_elevation=15) as settings:
+        assert settings.min_elevation == 15
+        assert _context().h_ipp == 400_000
+    assert get_settings() == pytecgg.Settings()
+
+
+def test_configure_invalid():
+    with pytest.raises(ValueError):
+        configure(shell_height=400_000)
+    with pytest.raises(ValueError):
+        configure(mapping_function="niell")
+    assert get_settings() == pytecgg.Settings()
+
+
+def test_mapping_function_settings():
+    """The Rust and Python mapping functions follow the configured model"""
+    df = pl.DataFrame({"stec": [10.0, 10.0], "ele": [90.0, 20.0]})
+    thin_shell = df.select(pl.col("stec").tec.mapping("ele"))["stec"]
+    assert thin_shell.to_numpy() == pytest.approx(
+        _mapping_function(df["ele"].to_numpy(), 350_000) * 10
+    )
+    with settings_context(mapping_function="mslm", h_ipp=506_700):
+        mslm = df.select(pl.col("stec").tec.mapping("ele"))["stec"]
+        expected = np.cos(np.arcsin(6_371 / 6_877.7 * np.sin(np.radians(0.9782 * 70))))
+        assert mslm.to_list() == pytest.approx([10.0, 10 * expected])
+        assert _mapping_function(np.array([20.0]), 506_700) == pytest.approx([expected])
+
+
+def test_tecu_settings():
+    """The geometry-free combinations scale with the TEC conversion constants"""
+    df = pl.DataFrame({"c1": [20_000_000.0], "c2": [20_000_005.0]})
+    expr = _calculate_gflc_code(pl.col("c1"), pl.col("c2"), pl.lit(1575.42e6), pl.lit(1227.60e6))
+    default = df.select(expr).item()
+    with settings_context(tecu=1e15):
+        expr = _calculate_gflc_code(pl.col("c1"), pl.col("c2"), pl.lit(1575.42e6), pl.lit(1227.60e6))
+        assert df.select(expr).item() == pytest.approx(10 * default)
+
+
+def test_earth_radius_settings(obs_v3_file):
+    """The shell radius and the read-time TEC units follow the settings"""
+    df = pl.DataFrame({"stec": [10.0], "ele": [20.0]})
+    with settings_context(earth_radius=6_378_137, h_ipp=450_000):
+        mapped = df.select(pl.col("stec").tec.mapping("ele")).item()
+        expected = np.cos(np.arcsin(6_378_137 / 6_828_137 * np.sin(np.radians(70))))
+        assert mapped == pytest.approx(10 * expected)
+        assert _mapping_function(np.array([20.0]), 450_000) == pytest.approx([expected])
+    with pytest.raises(ValueError):
+        configure(earth_radius=-1)
+
+    default, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(geometry_free=True))
+    with settings_context(tecu=1e15):
+        scaled, _, _ = read_rinex_obs(obs_v3_file, options=ReadOptions(geometry_free=True))
+    assert scaled["gflc_code"].to_numpy() == pytest.approx(
+        10 * default["gflc_code"].to_numpy(), nan_ok=True
+    )
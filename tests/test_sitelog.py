@@ -0,0 +1,39 @@
+from datetime import datetime, timezone
+
+import pytest
+
+from pytecgg.parsing import read_igs_sitelog
+
+
+def test_read_igs_sitelog_identification(sitelog_file):
+    log = read_igs_sitelog(sitelog_file)
+    assert log.site_id == "GROT00ITA"
+    assert log.site_name == "Grottaminarda"
+    assert log.domes == "12761M001"
+    assert log.position == (4641949.0, 1393045.0, 4133287.0)
+    assert log.lat == pytest.approx(40 + 41 / 60 + 28.5 / 3600)
+    assert log.lon == pytest.approx(15 + 4 / 60 + 10.25 / 3600)
+    assert log.height == 468.5
+
+
+def test_read_igs_sitelog_equipment_history(sitelog_file):
+    """Test the receiver and antenna histories, skipping the blank templates"""
+    log = read_igs_sitelog(sitelog_file)
+    assert log.receivers["receiver_type"].to_list() == ["LEICA GR10", "SEPT POLARX5"]
+    assert log.receivers["removed"].to_list()[1] is None
+    assert log.antennas["antenna_type"].to_list() == ["LEIAR25.R4", "TRM59800.00"]
+    assert log.antennas["radome"].to_list() == ["LEIT", "NONE"]
+    assert log.antennas["ecc_up"].to_list() == [0.083, 0.1]
+    assert log.equipment_changes == [
+        datetime(2020, 6, 10, 8, 30, tzinfo=timezone.utc),
+        datetime(2022, 3, 15, 10, 0, tzinfo=timezone.utc),
+    ]
+
+
+def test_hardware_periods(sitelog_file):
+    periods = read_igs_sitelog(sitelog_file).hardware_periods()
+    assert periods.height == 3
+    assert periods["receiver_type"].to_list() == ["LEICA GR10", "SEPT POLARX5", "SEPT POLARX5"]
+    assert periods["antenna_type"].to_list() == ["LEIAR25.R4", "LEIAR25.R4", "TRM59800.00"]
+    assert periods["end"].to_list()[:2] == periods["start"].to_list()[1:]
+    assert periods["end"][-1] is None
@@ -0,0 +1,132 @@
+from unittest.mock import MagicMock, patch
+
+import polars as pl
+import pytest
+
+from pytecgg.pytecgg import RtcmDecoder
+from pytecgg.stream import NtripClient, NtripError, ntrip_sourcetable
+
+from .conftest import bits, crc24q
+
+C = 299_792_458.0
+# GPS week 2355, TOW 3600 s: 2025-02-23 01:00:00 GPST, in Unix-like nanoseconds
+T0 = (315_964_800 + 2355 * 604_800 + 3600) * 10**9
+
+
+def _frame(fields):
+    """RTCM 3 frame of a message given as (value, bits) fields"""
+    payload = bits(fields)
+    header = bytes([0xD3, len(payload) >> 8, len(payload) & 0xFF])
+    return header + payload + crc24q(header + payload).to_bytes(3, "big")
+
+
+def _msm4(tow_ms, sats, sigs, rough_ms, rough_mod, pr, cp, cnr, multiple=False):
+    """GPS MSM4 message (1074) with all the cells of the given satellites and signals"""
+    ncell = len(sats) * len(sigs)
+    fields = [
+        (1074, 12),
+        (1, 12),
+        (tow_ms, 30),
+        (int(multiple), 1),
+        (0, 18),
+        (sum(1 << (64 - s) for s in sats), 64),
+        (sum(1 << (32 - s) for s in sigs), 32),
+        ((1 << ncell) - 1, ncell),
+    ]
+    fields += [(v, 8) for v in rough_ms] + [(v, 10) for v in rough_mod]
+    fields += [(v, 15) for v in pr] + [(v, 22) for v in cp]
+    fields += [(15, 4)] * ncell + [(0, 1)] * ncell + [(v, 6) for v in cnr]
+    return _frame(fields)
+
+
+@pytest.fixture
+def rtcm_frames():
+    """Two epochs of G05 observations (L1 C/A and L2 P(Y), invalid L2 pseudorange)"""
+    return [
+        _msm4(3_600_000, [5], [2, 10], [73], [400], [1000, -16384], [-5000, 2000], [45, 40], True),
+        _msm4(3_601_000, [5], [2], [73], [401], [0], [0], [44]),
+    ]
+
+
+def test_rtcm_decoder_msm4(rtcm_frames):
+    # Leading garbage, including a candidate frame with a wrong CRC
+    stream = b"\x00\xd3\x00\x02ab\x00\x00\x00" + b"".join(rtcm_frames)
+    decoder = RtcmDecoder(T0 + 2 * 86_400 * 10**9)
+    # Frames split across chunks are reassembled
+    decoder.feed(stream[:40])
+    decoder.feed(stream[40:])
+    df = decoder.take_obs()
+
+    assert df.columns == ["epoch", "sv", "observable", "value", "epoch_flag", "rx_clock_offset"]
+    first = df.filter(pl.col("epoch") == df["epoch"].min())
+    assert first["observable"].to_list() == ["C1C", "L1C", "S1C", "L2W", "S2W"]
+    assert df["epoch"].dt.epoch("ns").unique().sort().to_list() == [T0, T0 + 10**9]
+    values = dict(zip(first["observable"], first["value"]))
+    rough = 73 + 400 / 1024
+    assert values["C1C"] == pytest.approx((rough + 1000 * 2**-24) * C * 1e-3, abs=1e-6)
+    assert values["L1C"] == pytest.approx((rough - 5000 * 2**-29) * 1575.42e3, abs=1e-6)
+    assert values["S2W"] == 40
+    assert decoder.station_id == 1
+    assert decoder.crc_errors == 1
+    assert decoder.take_obs().height == 0
+
+
+def test_rtcm_decoder_pending_epoch(rtcm_frames):
+    """Epochs announced as continued by the multiple message bit are held back"""
+    decoder = RtcmDecoder(T0)
+    decoder.feed(rtcm_frames[0])
+    assert decoder.take_obs().height == 0
+    assert decoder.take_obs(flush=True).height == 5
+
+
+def _socket(*packets):
+    sock = MagicMock()
+    sock.recv.side_effect = list(packets) + [b""]
+    return sock
+
+
+def test_ntrip_client_chunked_stream():
+    sock = _socket(
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nab",
+        b"cd\r\n2\r\nef\r\n0\r\n\r\n",
+    )
+    with patch("pytecgg.stream.ntrip.socket.create_connection", return_value=sock):
+        with NtripClient("caster", "MOUNT", username="user", password="pass") as client:
+            data = b"".join(client.chunks())
+    assert data == b"abcdef"
+    request = sock.sendall.call_args_list[0].args[0]
+    assert request.startswith(b"GET /MOUNT HTTP/1.1\r\n")
+    assert b"Authorization: Basic dXNlcjpwYXNz" in request
+
+
+def test_ntrip_client_v1_and_errors():
+    sock = _socket(b"ICY 200 OK\r\n\xd3\x00", b"\x01")
+    with patch("pytecgg.stream.ntrip.socket.create_connection", return_value=sock):
+        with NtripClient("caster", "MOUNT", gga="$GPGGA,...") as client:
+            assert b"".join(client.chunks()) == b"\xd3\x00\x01"
+    assert sock.sendall.call_args_list[1].args[0] == b"$GPGGA,...\r\n"
+
+    for response, error in [
+        (b"HTTP/1.1 401 Unauthorized\r\n\r\n", PermissionError),
+        (b"SOURCETABLE 200 OK\r\n\r\nENDSOURCETABLE\r\n", NtripError),
+    ]:
+        with patch("pytecgg.stream.ntrip.socket.create_connection", return_value=_socket(response)):
+            with pytest.raises(error):
+                NtripClient("caster", "MISSING").connect()
+
+
+def test_ntrip_sourcetable():
+    table = (
+        b"SOURCETABLE 200 OK\r\nContent-Type: text/plain\r\n\r\n"
+        b"CAS;caster;2101;Example;;0;AUT;47.07;15.49;0.0.0.0;0;http://example.org\r\n"
+        b"STR;GRAZ00AUT0;Graz;RTCM 3.3;1077(1),1087(1);2;GPS+GLO;IGS;AUT;47.07;15.49;0;0;"
+        b"SEPT POLARX5;none;B;N;9600;\r\n"
+        b"ENDSOURCETABLE\r\n"
+    )
+    with patch("pytecgg.stream.ntrip.socket.create_connection", return_value=_socket(table)):
+        df = ntrip_sourcetable("caster")
+    assert df.height == 1
+    assert df["mountpoint"][0] == "GRAZ00AUT0"
+    assert df["lat"][0] == pytest.approx(47.07)
+    assert df["nmea"][0] is False
+    assert df["bitrate"][0] == 9600
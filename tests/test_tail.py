@@ -0,0 +1,56 @@
+import pytest
+
+from pytecgg.parsing import read_rinex_obs
+from pytecgg.stream import tail_rinex_obs
+
+
+def _split(path, epochs, marker):
+    """Header and the first records of a RINEX file, split at the epoch lines"""
+    lines = open(path, "rb").read().splitlines(keepends=True)
+    end = next(i for i, line in enumerate(lines) if b"END OF HEADER" in line) + 1
+    starts = [i for i in range(end, len(lines)) if marker(lines[i])][: epochs + 1]
+    header = b"".join(lines[:end])
+    records = [b"".join(lines[a:b]) for a, b in zip(starts, starts[1:])]
+    return header, records
+
+
+@pytest.mark.parametrize("version", ["v2", "v3"])
+def test_tail_rinex_obs(tmp_path, obs_v2_file, obs_v3_file, version):
+    source, marker = {
+        "v2": (obs_v2_file, lambda line: line.startswith(b" 14  4  2")),
+        "v3": (obs_v3_file, lambda line: line.startswith(b">")),
+    }[version]
+    header, records = _split(source, 4, marker)
+    path = tmp_path / source.split("/")[-1]
+
+    path.write_bytes(header[:100])
+    tail = tail_rinex_obs(path)
+    assert tail.read() is None
+
+    # Two complete epochs, the third one being written
+    cut = records[2].index(b"\n") + 10
+    path.write_bytes(header + records[0] + records[1] + records[2][:cut])
+    first = tail.read()
+    assert first["epoch"].n_unique() == 2
+    assert tail.read() is None
+
+    with open(path, "ab") as f:
+        f.write(records[2][cut:] + records[3])
+    # The last epoch is held back until the next one starts, unless flushing
+    second = tail.read()
+    assert second["epoch"].n_unique() == 1
+    third = tail.read(flush=True)
+    assert third["epoch"].n_unique() == 1
+    assert tail.read(flush=True) is None
+
+    full_path = tmp_path / "full" / path.name
+    full_path.parent.mkdir()
+    full_path.write_bytes(header + b"".join(records))
+    full = read_rinex_obs(full_path)[0]
+    assert first.height + second.height + third.height == full.height
+    assert third["epoch"].max() == full["epoch"].max()
+
+
+def test_tail_rinex_obs_compressed(obs_v3_gzip_file):
+    with pytest.raises(ValueError):
+        tail_rinex_obs(obs_v3_gzip_file)
@@ -0,0 +1,50 @@
+from datetime import datetime, timezone
+
+import polars as pl
+
+from pytecgg.timescales import convert_timescale, gpst_utc_offset, leap_seconds
+
+
+def test_leap_seconds_table():
+    """Test the built-in leap second table"""
+    table = leap_seconds()
+    assert table.columns == ["epoch", "tai_utc", "gpst_utc", "source"]
+    assert table["epoch"].is_sorted()
+    last = table.row(-1, named=True)
+    assert last["tai_utc"] == 37.0
+    assert last["gpst_utc"] == 18.0
+    assert last["epoch"] == datetime(2017, 1, 1, tzinfo=timezone.utc)
+
+
+def test_leap_seconds_header(obs_v3_file):
+    """Test that the header leap seconds are reported when present"""
+    table = leap_seconds(obs_v3_file)
+    header = table.filter(pl.col("source") == "header")
+    assert (header["gpst_utc"] == 18.0).all()
+
+
+def test_gpst_utc_offset():
+    """Test the GPST - UTC offset at given epochs"""
+    epochs = pl.Series(
+        "epoch",
+        [
+            datetime(2024, 10, 7, tzinfo=timezone.utc),
+            datetime(2010, 1, 1, tzinfo=timezone.utc),
+            datetime(1975, 1, 1, tzinfo=timezone.utc),
+        ],
+    )
+    assert gpst_utc_offset(epochs).to_list() == [18.0, 15.0, None]
+
+
+def test_convert_timescale():
+    """Test conversions between GNSS timescales"""
+    epochs = pl.Series("epoch", [datetime(2024, 10, 7, 12, tzinfo=timezone.utc)])
+    bdt = convert_timescale(epochs, "GPST", "BDT")
+    assert bdt.dtype == epochs.dtype
+    assert bdt[0] == datetime(2024, 10, 7, 11, 59, 46, tzinfo=timezone.utc)
+    glo = convert_timescale(epochs, "GPST", "GLONASST")
+    assert glo[0] == datetime(2024, 10, 7, 14, 59, 42, tzinfo=timezone.utc)
+    back = convert_timescale(glo, "GLONASST", "GPST")
+    assert back.equals(epochs)
+    tai = convert_timescale(epochs, "UTC", "TAI")
+    assert tai[0] == datetime(2024, 10, 7, 12, 0, 37, tzinfo=timezone.utc)
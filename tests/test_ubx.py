@@ -0,0 +1,142 @@
+import io
+import math
+import struct
+
+import pytest
+
+from pytecgg.parsing import read_ubx
+
+from .conftest import bits
+
+# GPS week 2355, 01:00:00 GPST
+WEEK, TOW = 2355, 3600.0
+T0 = (315_964_800 + WEEK * 604_800 + int(TOW)) * 10**9
+
+
+def _message(msg_class, msg_id, payload):
+    """UBX message, with its 8-bit Fletcher checksum"""
+    body = bytes([msg_class, msg_id]) + struct.pack("<H", len(payload)) + payload
+    a = b = 0
+    for byte in body:
+        a = (a + byte) & 0xFF
+        b = (b + a) & 0xFF
+    return b"\xb5\x62" + body + bytes([a, b])
+
+
+def _rawx(measurements):
+    """RXM-RAWX; measurements as (gnssId, svId, sigId, pr, cp, do, cno, trkStat)"""
+    payload = struct.pack("<dHbBBB2x", TOW, WEEK, 18, len(measurements), 0x01, 1)
+    for gnss, sv, sig, pr, cp, do, cno, status in measurements:
+        payload += struct.pack("<ddfBBBBHBBBBBx", pr, cp, do, gnss, sv, sig, 0, 500, cno, 0, 0, 0, status)
+    return _message(0x02, 0x15, payload)
+
+
+def _sfrbx(gnss, sv, words):
+    payload = struct.pack("<BBBBBBBx", gnss, sv, 0, 0, len(words), 0, 2)
+    return _message(0x02, 0x13, payload + b"".join(struct.pack("<I", w) for w in words))
+
+
+def _lnav(sv, subframe_id, fields):
+    """GPS LNAV subframe, from the data bits following the handover word"""
+    data = bits([(0x8B, 8), (0, 16), (int(TOW) // 6 + 1, 17), (0, 2), (subframe_id, 3), (0, 2)] + fields)
+    words = [int.from_bytes(data[3 * i : 3 * i + 3], "big") << 6 for i in range(10)]
+    return _sfrbx(0, sv, words)
+
+
+def _gps_ephemeris(sv, toe, m0, sqrta, iode=42):
+    """Subframes 1-3, with m0 in semicircles"""
+    sf1 = [(WEEK % 1024, 10), (1, 2), (2, 4), (0, 6), (0, 2), (0, 1), (0, 87), (-9, 8), (iode, 8)]
+    sf1 += [(toe // 16, 16), (0, 8), (-1000, 16), (12345, 22), (0, 2)]
+    sf2 = [(iode, 8), (-50, 16), (42, 16), (round(m0 * 2**31), 32), (-1000, 16), (round(0.01 * 2**33), 32)]
+    sf2 += [(1000, 16), (round(sqrta * 2**19), 32), (toe // 16, 16), (0, 1), (0, 5), (0, 2)]
+    sf3 = [(10, 16), (-(2**29), 32), (-10, 16), (round(0.3 * 2**31), 32), (300, 16), (2**28, 32)]
+    sf3 += [(-5000, 24), (iode, 8), (64, 14), (0, 2)]
+    return [_lnav(sv, 1, sf1), _lnav(sv, 2, sf2), _lnav(sv, 3, sf3)]
+
+
+def _galileo_ephemeris(sv, toe, m0, iodnav=77):
+    """I/NAV words 1-5, as nominal pages of E1-B, with m0 in semicircles"""
+    words = [
+        [(1, 6), (iodnav, 10), (toe // 60, 14), (round(m0 * 2**31), 32), (round(0.001 * 2**33), 32)]
+        + [(round(5440.6 * 2**19), 32), (0, 2)],
+        [(2, 6), (iodnav, 10), (2**29, 32), (round(0.3 * 2**31), 32), (-(2**28), 32), (-64, 14), (0, 2)],
+        [(3, 6), (iodnav, 10), (-5000, 24), (42, 16), (-100, 16), (100, 16), (200, 16), (-20, 16), (107, 8)],
+        [(4, 6), (iodnav, 10), (sv, 6), (5, 16), (-5, 16), (toe // 60, 14), (-(2**20), 31), (-4, 21), (0, 6), (0, 2)],
+        [(5, 6), (0, 11), (0, 11), (0, 14), (0, 5), (8, 10), (9, 10), (0, 2), (0, 2), (0, 1), (0, 1)]
+        + [(WEEK - 1024, 12), (int(TOW), 20), (0, 23)],
+    ]
+    messages = []
+    for fields in words:
+        data = int.from_bytes(bits(fields), "big")
+        # Even part: even/odd and page type bits, 112 data bits, tail and padding
+        even = bits([(0, 2), (data >> 16, 112), (0, 14)])
+        # Odd part: even/odd and page type bits, the last 16 data bits and the rest of the page
+        odd = bits([(0b10, 2), (data & 0xFFFF, 16), (0, 110)])
+        page = even + odd
+        messages.append(_sfrbx(2, sv, [int.from_bytes(page[4 * i : 4 * i + 4], "big") for i in range(8)]))
+    return messages
+
+
+@pytest.fixture
+def ubx_log():
+    measurements = [
+        # G05 L1 C/A and L2C (phase not locked), R03 without valid pseudorange, E11 E1-B
+        (0, 5, 0, 22_001_973.732, 115_620_000.5, -1000.0, 45, 0x07),
+        (0, 5, 3, 22_001_975.1, 0.0, 0.0, 40, 0x01),
+        (6, 3, 0, 21_000_000.0, 112_000_000.0, 0.0, 43, 0x02),
+        (2, 11, 1, 23_000_000.0, 120_000_000.0, 50.0, 41, 0x03),
+    ]
+    gps = _gps_ephemeris(5, 7200, 0.5, 5153.7)
+    messages = gps[:2] + _galileo_ephemeris(11, 7200, 0.25) + [_rawx(measurements)] + gps[2:]
+    # Leading garbage, including a false sync with an implausible length
+    return b"\x00\xb5\x62\x02\x15junk" + b"".join(messages)
+
+
+def test_read_ubx_obs(ubx_log):
+    obs, _ = read_ubx(io.BytesIO(ubx_log))
+
+    assert obs.columns == ["epoch", "sv", "observable", "value", "epoch_flag", "rx_clock_offset"]
+    assert obs["epoch"].dt.epoch("ns").unique().to_list() == [T0]
+    assert str(obs["epoch"].dtype.time_zone) == "UTC"
+    values = {(sv, o): v for sv, o, v in obs.select("sv", "observable", "value").iter_rows()}
+    assert sorted(values) == [
+        ("E11", "C1B"), ("E11", "D1B"), ("E11", "L1B"), ("E11", "S1B"),
+        ("G05", "C1C"), ("G05", "C2L"), ("G05", "D1C"), ("G05", "L1C"), ("G05", "S1C"), ("G05", "S2L"),
+        ("R03", "L1C"), ("R03", "S1C"),
+    ]
+    assert values["G05", "C1C"] == 22_001_973.732
+    assert values["G05", "L1C"] == 115_620_000.5
+    assert values["G05", "D1C"] == -1000
+    assert values["G05", "S2L"] == 40
+
+
+def test_read_ubx_nav(ubx_log):
+    _, nav = read_ubx(io.BytesIO(ubx_log))
+
+    assert sorted(nav) == ["GALILEO", "GPS"]
+    gps = nav["GPS"]
+    assert gps["sv"].to_list() == ["5"]
+    # Time of clock 7200 s into GPS week 2355, resolved from the 10-bit week number
+    assert gps["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 7200
+    assert gps["week"].item() == 2355
+    assert gps["iode"].item() == 42
+    assert gps["m0"].item() == pytest.approx(0.5 * math.pi)
+    assert gps["sqrta"].item() == pytest.approx(5153.7, abs=1e-5)
+    assert gps["clock_bias"].item() == pytest.approx(12345 * 2**-31)
+    assert gps["tgd"].item() == pytest.approx(-9 * 2**-31)
+
+    galileo = nav["GALILEO"]
+    assert galileo["sv"].to_list() == ["11"]
+    assert galileo["epoch"].dt.epoch("s").item() == 315_964_800 + 2355 * 604_800 + 7200
+    assert galileo["week"].item() == 2355
+    assert galileo["source"].item() == 517
+    assert galileo["m0"].item() == pytest.approx(0.25 * math.pi)
+    assert galileo["sisa"].item() == pytest.approx(3.12)
+    assert galileo["clock_bias"].item() == pytest.approx(-(2**20) * 2**-34)
+
+
+def test_read_ubx_inconsistent_subframes(ubx_log):
+    # Subframe 3 of a new upload, not matching subframes 1-2
+    newer = _gps_ephemeris(5, 7200, 0.5, 5153.7, iode=43)
+    _, nav = read_ubx(io.BytesIO(ubx_log[: -len(newer[2])] + newer[2]))
+    assert "GPS" not in nav
@@ -0,0 +1,41 @@
+from datetime import datetime, timedelta, timezone
+
+import polars as pl
+
+from pytecgg.parsing import read_rinex_nav
+from pytecgg.satellites import predict_visibility
+
+
+def test_predict_visibility_gps(nav_v3_file):
+    nav = read_rinex_nav(nav_v3_file)
+    start = datetime(2025, 3, 28, 6, tzinfo=timezone.utc)
+    end = start + timedelta(hours=6)
+    site = (4_642_000.0, 1_028_000.0, 4_236_000.0)
+
+    passes = predict_visibility({"GPS": nav["GPS"]}, site, start, end, mask=10.0)
+
+    assert passes.columns == [
+        "sv",
+        "rise",
+        "set",
+        "duration",
+        "max_elevation",
+        "culmination",
+        "rise_azimuth",
+        "set_azimuth",
+    ]
+    assert passes.height > 0
+    assert passes["sv"].str.starts_with("G").all()
+    assert (passes["rise"] >= start).all() and (passes["set"] <= end).all()
+    assert (passes["duration"] == passes["set"] - passes["rise"]).all()
+    assert (passes["max_elevation"] >= 10.0).all()
+    assert passes.filter(
+        (pl.col("culmination") < pl.col("rise")) | (pl.col("culmination") > pl.col("set"))
+    ).is_empty()
+
+
+def test_predict_visibility_empty_window(nav_v3_file):
+    nav = read_rinex_nav(nav_v3_file)
+    start = datetime(2025, 3, 28, 6, tzinfo=timezone.utc)
+    passes = predict_visibility(nav, (6378137.0, 0.0, 0.0), start, start - timedelta(hours=1))
+    assert passes.is_empty()
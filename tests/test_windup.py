@@ -0,0 +1,65 @@
+from datetime import datetime, timedelta, timezone
+
+import numpy as np
+import polars as pl
+import pytest
+
+from pytecgg.context import GNSSContext
+from pytecgg.linear_combinations import correct_phase_windup
+from pytecgg.satellites import phase_windup
+
+
+@pytest.fixture
+def equator_ctx():
+    return GNSSContext(
+        receiver_pos=(6378137.0, 0.0, 0.0),
+        receiver_name="TEST",
+        rinex_version="3.04",
+        h_ipp=350_000,
+        systems=["G"],
+    )
+
+
+def test_phase_windup_continuous(equator_ctx):
+    """A satellite passing overhead, with a missing position in the middle of its arc"""
+    t0 = datetime(2024, 6, 21, 6, tzinfo=timezone.utc)
+    theta = np.radians(np.linspace(-30.0, 30.0, 121))
+    x, z = 26_560e3 * np.cos(theta), 26_560e3 * np.sin(theta)
+    x[60] = np.nan
+    df = pl.DataFrame(
+        {
+            "sv": ["G01"] * theta.size,
+            "epoch": [t0 + timedelta(minutes=i) for i in range(theta.size)],
+            "sat_x": x,
+            "sat_y": np.zeros(theta.size),
+            "sat_z": z,
+        }
+    )
+    out = phase_windup(df, equator_ctx)
+    assert out.columns == df.columns + ["windup"]
+    assert out["windup"][60] is None
+    windup = out["windup"].drop_nulls().to_numpy()
+    assert np.all(np.isfinite(windup))
+    assert np.max(np.abs(np.diff(windup))) < 0.1
+
+
+def test_correct_phase_windup():
+    t0 = datetime(2024, 6, 21, tzinfo=timezone.utc)
+    obs = pl.DataFrame(
+        {
+            "epoch": [t0] * 3 + [t0 + timedelta(seconds=30)],
+            "sv": ["G01", "G01", "G02", "G01"],
+            "observable": ["L1C", "C1C", "L1C", "L2W"],
+            "value": [100.0, 2e7, 50.0, 80.0],
+        }
+    )
+    windup = pl.DataFrame(
+        {
+            "epoch": [t0, t0 + timedelta(seconds=30)],
+            "sv": ["G01", "G01"],
+            "windup": [0.25, -0.5],
+        }
+    )
+    out = correct_phase_windup(obs, windup)
+    assert out["value"].to_list() == [99.75, 2e7, 50.0, 80.5]
+    assert out.columns == obs.columns